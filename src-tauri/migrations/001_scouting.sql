@@ -0,0 +1,34 @@
+-- ============================================
+-- 001: 球探系统（评分迷雾）
+-- ============================================
+
+-- 球探派遣表：每支队伍可将球探派往某个赛区（职业选手）或某个赛区的选秀池
+CREATE TABLE IF NOT EXISTS scouting_assignments (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    target_type TEXT NOT NULL,
+    region_id INTEGER NOT NULL,
+    assigned_season INTEGER NOT NULL,
+    phases_active INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id),
+    UNIQUE(save_id, team_id, target_type, region_id)
+);
+
+-- 球探情报表：每支队伍对每名选手/选秀新秀的了解程度 (0.0 ~ 1.0)
+CREATE TABLE IF NOT EXISTS scouting_knowledge (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    subject_type TEXT NOT NULL,
+    subject_id INTEGER NOT NULL,
+    knowledge REAL NOT NULL DEFAULT 0.0,
+    updated_season INTEGER NOT NULL,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id),
+    UNIQUE(save_id, team_id, subject_type, subject_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_scouting_knowledge_team ON scouting_knowledge(save_id, team_id, subject_type)
@@ -0,0 +1,27 @@
+-- ============================================
+-- 002: 青训管线（年度新秀届次）
+-- ============================================
+
+-- 年度新秀届次记录：每赛季每赛区一条，记录人才密度、基建水平与届次强弱
+CREATE TABLE IF NOT EXISTS talent_classes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    season_id INTEGER NOT NULL,
+    region_id INTEGER NOT NULL,
+    talent_density REAL NOT NULL,
+    infrastructure REAL NOT NULL,
+    ability_shift INTEGER NOT NULL DEFAULT 0,
+    is_golden INTEGER NOT NULL DEFAULT 0,
+    prospects_generated INTEGER NOT NULL DEFAULT 0,
+    to_draft_pool INTEGER NOT NULL DEFAULT 0,
+    to_free_agency INTEGER NOT NULL DEFAULT 0,
+    immigrants INTEGER NOT NULL DEFAULT 0,
+    emigrants INTEGER NOT NULL DEFAULT 0,
+    avg_potential REAL NOT NULL DEFAULT 0.0,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    UNIQUE(save_id, season_id, region_id)
+);
+
+-- 选秀池新秀的出身赛区（跨赛区流动的新秀与 region_id 不同）
+ALTER TABLE draft_pool ADD COLUMN origin_region_id INTEGER
@@ -0,0 +1,25 @@
+-- ============================================
+-- 003: 老将角色转型（转位置 / 选手兼教练 / 第六人）
+-- ============================================
+
+-- 转位置训练记录：开始时立即转到新位置并承受能力惩罚，满一个赛季后恢复大部分能力
+CREATE TABLE IF NOT EXISTS position_retraining (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    player_id INTEGER NOT NULL,
+    team_id INTEGER,
+    from_position TEXT NOT NULL,
+    to_position TEXT NOT NULL,
+    start_season INTEGER NOT NULL,
+    ability_penalty INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'InProgress',
+    completed_season INTEGER,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (player_id) REFERENCES players(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_position_retraining_player ON position_retraining(save_id, player_id, status);
+
+-- 合同角色：Starter / Sub / Prospect / SixthMan / PlayingCoach
+ALTER TABLE players ADD COLUMN contract_role TEXT NOT NULL DEFAULT 'Starter'
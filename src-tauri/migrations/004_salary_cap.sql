@@ -0,0 +1,19 @@
+-- ============================================
+-- 004: 薪资帽与奢侈税（死钱记录）
+-- ============================================
+
+-- 死钱：解约/裁员后仍计入当赛季薪资帽的金额
+CREATE TABLE IF NOT EXISTS team_dead_cap (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    season_id INTEGER NOT NULL,
+    player_id INTEGER,
+    amount INTEGER NOT NULL,
+    reason TEXT NOT NULL DEFAULT '',
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_team_dead_cap_team ON team_dead_cap(save_id, team_id, season_id)
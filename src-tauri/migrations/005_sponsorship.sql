@@ -0,0 +1,29 @@
+-- ============================================
+-- 005: 赞助合同（多赛季合同 + 绩效奖金 + 提前解约）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS sponsor_contracts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    sponsor_code TEXT NOT NULL,
+    sponsor_name TEXT NOT NULL,
+    category TEXT NOT NULL,
+    tier TEXT NOT NULL,
+    start_season INTEGER NOT NULL,
+    end_season INTEGER NOT NULL,
+    annual_base INTEGER NOT NULL,
+    title_bonus INTEGER NOT NULL DEFAULT 0,
+    international_bonus INTEGER NOT NULL DEFAULT 0,
+    brand_floor REAL NOT NULL DEFAULT 0,
+    min_win_rate REAL NOT NULL DEFAULT 0,
+    is_exclusive INTEGER NOT NULL DEFAULT 0,
+    status TEXT NOT NULL DEFAULT 'Active',
+    terminated_season INTEGER,
+    termination_reason TEXT,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_sponsor_contracts_team ON sponsor_contracts(save_id, team_id, status)
@@ -0,0 +1,23 @@
+-- ============================================
+-- 006: 局内时间线（对线、资源、团战、经济曲线）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS game_timelines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    match_id INTEGER NOT NULL,
+    game_number INTEGER NOT NULL,
+    winner_id INTEGER NOT NULL,
+    duration_minutes INTEGER NOT NULL,
+    final_gold_diff INTEGER NOT NULL DEFAULT 0,
+    timeline_json TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(save_id, match_id, game_number),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (match_id) REFERENCES matches(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_timelines_match ON game_timelines(save_id, match_id);
+
+-- 存档级开关：关闭后回退到旧的随机数据生成；已有存档默认关闭，新存档创建时开启
+ALTER TABLE saves ADD COLUMN game_timeline_enabled INTEGER NOT NULL DEFAULT 0
@@ -0,0 +1,10 @@
+-- ============================================
+-- 007: 阵容强势期曲线（存档级规则覆盖，未配置时使用内置 comp_phase_curves.json）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS comp_phase_rulesets (
+    save_id TEXT PRIMARY KEY,
+    ruleset_json TEXT NOT NULL,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+)
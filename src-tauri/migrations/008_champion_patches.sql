@@ -0,0 +1,21 @@
+-- ============================================
+-- 008: 英雄版本补丁（赛季内按阶段对单个英雄加强/削弱）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS champion_patch_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    season_id INTEGER NOT NULL,
+    patch_number INTEGER NOT NULL,
+    phase TEXT NOT NULL,
+    champion_id INTEGER NOT NULL,
+    delta REAL NOT NULL,
+    new_strength REAL NOT NULL,
+    reason TEXT NOT NULL DEFAULT '',
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(save_id, season_id, phase, champion_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_champion_patch_changes_season
+    ON champion_patch_changes(save_id, season_id)
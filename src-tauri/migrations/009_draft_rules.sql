@@ -0,0 +1,15 @@
+-- ============================================
+-- 009: 系列赛 BP 规则（按赛事阶段配置：标准 / 全局BP / 软全局BP / 全局禁用池）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS tournament_draft_rules (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    tournament_id INTEGER NOT NULL,
+    stage TEXT NOT NULL DEFAULT '',
+    rule TEXT NOT NULL DEFAULT 'Standard',
+    global_bans_json TEXT NOT NULL DEFAULT '[]',
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(save_id, tournament_id, stage),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+)
@@ -0,0 +1,6 @@
+-- ============================================
+-- 010: 对局地图边（蓝色方 / 红色方）
+-- ============================================
+
+-- 旧数据中主队总是先手选人，即蓝色方
+ALTER TABLE game_draft_results ADD COLUMN home_side TEXT NOT NULL DEFAULT 'Blue'
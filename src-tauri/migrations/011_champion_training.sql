@@ -0,0 +1,27 @@
+-- ============================================
+-- 011: 英雄熟练度训练计划（阶段间分配训练时间）
+-- ============================================
+
+-- 熟练度训练进度（满 100 升一级）
+ALTER TABLE player_champion_mastery ADD COLUMN training_progress REAL NOT NULL DEFAULT 0;
+
+CREATE TABLE IF NOT EXISTS champion_training_plans (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    season_id INTEGER NOT NULL,
+    phase TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    champion_id INTEGER NOT NULL,
+    hours INTEGER NOT NULL,
+    is_auto INTEGER NOT NULL DEFAULT 0,
+    applied INTEGER NOT NULL DEFAULT 0,
+    progress_gain REAL NOT NULL DEFAULT 0,
+    tier_after TEXT,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(save_id, season_id, phase, player_id, champion_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (player_id) REFERENCES players(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_champion_training_team ON champion_training_plans(save_id, season_id, phase, team_id)
@@ -0,0 +1,25 @@
+-- ============================================
+-- 012: 系列赛局间战术（换体系 / 针对选手 / 提高侵略性）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS series_tactics (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    match_id INTEGER NOT NULL,
+    game_number INTEGER NOT NULL,
+    team_id INTEGER NOT NULL,
+    comp_pool_json TEXT NOT NULL DEFAULT '[]',
+    target_player_id INTEGER,
+    aggressive INTEGER NOT NULL DEFAULT 0,
+    is_manual INTEGER NOT NULL DEFAULT 0,
+    reasons_json TEXT NOT NULL DEFAULT '[]',
+    power_shift REAL NOT NULL DEFAULT 0,
+    opponent_shift REAL NOT NULL DEFAULT 0,
+    variance_scale REAL NOT NULL DEFAULT 1,
+    applied INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(save_id, match_id, game_number, team_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_series_tactics_match ON series_tactics(save_id, match_id)
@@ -0,0 +1,11 @@
+-- ============================================
+-- 013: 天梯积分系统（Glicko-2 / TrueSkill，积分偏差与波动率）
+-- ============================================
+
+ALTER TABLE ladder_tournament ADD COLUMN rating_system TEXT NOT NULL DEFAULT 'Glicko2';
+
+ALTER TABLE ladder_rating ADD COLUMN rating_deviation REAL NOT NULL DEFAULT 350;
+
+ALTER TABLE ladder_rating ADD COLUMN volatility REAL NOT NULL DEFAULT 0.06;
+
+ALTER TABLE ladder_match ADD COLUMN rating_details_json TEXT
@@ -0,0 +1,44 @@
+-- ============================================
+-- 014: 跨赛季天梯档案（软重置、段位、赛季定级记录）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS ladder_profile (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    subject_type TEXT NOT NULL,
+    subject_id INTEGER NOT NULL,
+    rating INTEGER NOT NULL DEFAULT 1200,
+    rating_deviation REAL NOT NULL DEFAULT 350,
+    volatility REAL NOT NULL DEFAULT 0.06,
+    peak_rating INTEGER NOT NULL DEFAULT 1200,
+    games_played INTEGER NOT NULL DEFAULT 0,
+    wins INTEGER NOT NULL DEFAULT 0,
+    losses INTEGER NOT NULL DEFAULT 0,
+    season_games INTEGER NOT NULL DEFAULT 0,
+    season_peak INTEGER NOT NULL DEFAULT 1200,
+    updated_season INTEGER NOT NULL,
+    UNIQUE(save_id, subject_type, subject_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_ladder_profile_rating ON ladder_profile(save_id, subject_type, rating);
+
+CREATE TABLE IF NOT EXISTS ladder_profile_season (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    subject_type TEXT NOT NULL,
+    subject_id INTEGER NOT NULL,
+    season INTEGER NOT NULL,
+    final_rating INTEGER NOT NULL,
+    peak_rating INTEGER NOT NULL,
+    games_played INTEGER NOT NULL DEFAULT 0,
+    wins INTEGER NOT NULL DEFAULT 0,
+    UNIQUE(save_id, subject_type, subject_id, season),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+ALTER TABLE ladder_tournament ADD COLUMN profile_synced INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE ladder_rating ADD COLUMN start_rating INTEGER NOT NULL DEFAULT 1200;
+
+ALTER TABLE ladder_rating ADD COLUMN start_deviation REAL NOT NULL DEFAULT 350
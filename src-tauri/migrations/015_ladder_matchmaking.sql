@@ -0,0 +1,7 @@
+-- ============================================
+-- 015: 天梯匹配质量与排队时长
+-- ============================================
+
+ALTER TABLE ladder_match ADD COLUMN match_quality REAL;
+
+ALTER TABLE ladder_match ADD COLUMN queue_time_secs INTEGER
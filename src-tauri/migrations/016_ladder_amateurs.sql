@@ -0,0 +1,24 @@
+-- ============================================
+-- 016: 天梯路人/主播选手池
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS ladder_amateur (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    player_id INTEGER NOT NULL,
+    kind TEXT NOT NULL DEFAULT 'Amateur',
+    platform TEXT,
+    joined_season INTEGER NOT NULL,
+    status TEXT NOT NULL DEFAULT 'active',
+    outcome TEXT,
+    draft_pool_id INTEGER,
+    discovered_season INTEGER,
+    discovered_tournament_id INTEGER,
+    UNIQUE(save_id, player_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (player_id) REFERENCES players(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_ladder_amateur_status ON ladder_amateur(save_id, status);
+
+ALTER TABLE ladder_tournament ADD COLUMN amateurs_reviewed INTEGER NOT NULL DEFAULT 0
@@ -0,0 +1,38 @@
+-- ============================================
+-- 017: Meta 版本演化（每赛季可有多次补丁，记录调整原因）
+-- ============================================
+
+-- SQLite 无法修改唯一约束，重建 meta_versions：(save_id, season_id) → (save_id, season_id, patch_number)
+-- 迁移运行器在单个事务中执行本文件，DROP 之后任一语句失败都会整体回滚
+CREATE TABLE IF NOT EXISTS meta_versions_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    season_id INTEGER NOT NULL,
+    patch_number INTEGER NOT NULL DEFAULT 1,
+    phase TEXT,
+    meta_type TEXT NOT NULL,
+    meta_name TEXT NOT NULL,
+    weight_top REAL NOT NULL DEFAULT 1.0,
+    weight_jug REAL NOT NULL DEFAULT 1.0,
+    weight_mid REAL NOT NULL DEFAULT 1.0,
+    weight_adc REAL NOT NULL DEFAULT 1.0,
+    weight_sup REAL NOT NULL DEFAULT 1.0,
+    reason TEXT,
+    since_draft_rowid INTEGER NOT NULL DEFAULT 0,
+    UNIQUE(save_id, season_id, patch_number),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+INSERT INTO meta_versions_new (
+    id, save_id, season_id, patch_number, phase, meta_type, meta_name,
+    weight_top, weight_jug, weight_mid, weight_adc, weight_sup
+)
+SELECT id, save_id, season_id, 1, 'SpringRegular', meta_type, meta_name,
+       weight_top, weight_jug, weight_mid, weight_adc, weight_sup
+FROM meta_versions;
+
+DROP TABLE meta_versions;
+
+ALTER TABLE meta_versions_new RENAME TO meta_versions;
+
+CREATE INDEX IF NOT EXISTS idx_meta_versions_save ON meta_versions(save_id, season_id)
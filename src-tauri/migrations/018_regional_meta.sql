@@ -0,0 +1,19 @@
+-- ============================================
+-- 018: 赛区版本理解（各赛区对阵容风格的偏好，国际赛后相互扩散）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS region_meta_styles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    region_id INTEGER NOT NULL,
+    aggressive REAL NOT NULL DEFAULT 0.0,
+    scaling REAL NOT NULL DEFAULT 0.0,
+    utility REAL NOT NULL DEFAULT 0.0,
+    splitpush REAL NOT NULL DEFAULT 0.0,
+    teamfight REAL NOT NULL DEFAULT 0.0,
+    adopted_from TEXT,
+    updated_season INTEGER NOT NULL DEFAULT 1,
+    UNIQUE(save_id, region_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (region_id) REFERENCES regions(id) ON DELETE CASCADE
+)
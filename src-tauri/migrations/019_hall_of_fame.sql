@@ -0,0 +1,38 @@
+-- ============================================
+-- 019: 名人堂评选（退役等待期、入选理由、战队名人堂、退役球衣）
+-- ============================================
+
+ALTER TABLE hall_of_fame ADD COLUMN retire_season INTEGER;
+
+ALTER TABLE hall_of_fame ADD COLUMN reasoning TEXT;
+
+CREATE TABLE IF NOT EXISTS team_hall_of_fame (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    team_name TEXT NOT NULL,
+    induction_season INTEGER NOT NULL,
+    promoted_season INTEGER,
+    tier TEXT NOT NULL,
+    total_score INTEGER NOT NULL,
+    reasoning TEXT,
+    UNIQUE(save_id, team_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id)
+);
+
+CREATE TABLE IF NOT EXISTS retired_jerseys (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    player_name TEXT NOT NULL,
+    seasons_with_team INTEGER NOT NULL,
+    retired_season INTEGER NOT NULL,
+    UNIQUE(save_id, team_id, player_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id),
+    FOREIGN KEY (player_id) REFERENCES players(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_retired_jerseys_team ON retired_jerseys(save_id, team_id)
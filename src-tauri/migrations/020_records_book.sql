@@ -0,0 +1,56 @@
+-- ============================================
+-- 020: 纪录簿（全球/赛区/联赛纪录、纪录历史、连胜追踪）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS records_book (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    record_type TEXT NOT NULL,
+    scope_type TEXT NOT NULL,
+    scope_key TEXT NOT NULL DEFAULT '',
+    scope_label TEXT NOT NULL,
+    holder_kind TEXT NOT NULL,
+    holder_id INTEGER NOT NULL,
+    holder_name TEXT NOT NULL,
+    value REAL NOT NULL,
+    season_id INTEGER NOT NULL,
+    tournament_id INTEGER,
+    match_id INTEGER,
+    UNIQUE(save_id, record_type, scope_type, scope_key),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS record_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    record_type TEXT NOT NULL,
+    scope_type TEXT NOT NULL,
+    scope_key TEXT NOT NULL DEFAULT '',
+    scope_label TEXT NOT NULL,
+    holder_kind TEXT NOT NULL,
+    holder_id INTEGER NOT NULL,
+    holder_name TEXT NOT NULL,
+    value REAL NOT NULL,
+    previous_holder_id INTEGER,
+    previous_holder_name TEXT,
+    previous_value REAL,
+    season_id INTEGER NOT NULL,
+    tournament_id INTEGER,
+    match_id INTEGER,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_record_history_lookup ON record_history(save_id, record_type, scope_type, scope_key);
+
+CREATE TABLE IF NOT EXISTS record_streaks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    team_id INTEGER NOT NULL,
+    scope_type TEXT NOT NULL,
+    scope_key TEXT NOT NULL DEFAULT '',
+    current_streak INTEGER NOT NULL DEFAULT 0,
+    UNIQUE(save_id, team_id, scope_type, scope_key),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE,
+    FOREIGN KEY (team_id) REFERENCES teams(id)
+)
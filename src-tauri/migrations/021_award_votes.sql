@@ -0,0 +1,28 @@
+-- ============================================
+-- 021: 年度奖项投票明细（媒体/职业选手/球迷三方得票）
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS award_votes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    season_id INTEGER NOT NULL,
+    award_id TEXT NOT NULL,
+    award_name TEXT NOT NULL,
+    scope_key TEXT NOT NULL DEFAULT '',
+    scope_label TEXT NOT NULL DEFAULT '',
+    player_id INTEGER NOT NULL,
+    player_name TEXT NOT NULL,
+    team_id INTEGER,
+    team_name TEXT NOT NULL DEFAULT '',
+    position TEXT NOT NULL DEFAULT '',
+    media_points INTEGER NOT NULL DEFAULT 0,
+    pros_points INTEGER NOT NULL DEFAULT 0,
+    fans_points INTEGER NOT NULL DEFAULT 0,
+    first_place_votes INTEGER NOT NULL DEFAULT 0,
+    total_score REAL NOT NULL DEFAULT 0,
+    vote_rank INTEGER NOT NULL,
+    is_winner INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_award_votes_lookup ON award_votes(save_id, season_id, award_id, scope_key)
@@ -0,0 +1,13 @@
+-- ============================================
+-- 022: 补齐旧存档退役选手的退役赛季
+-- ============================================
+
+-- 此前部分退役路径未写入 retire_season，名人堂只评选有退役赛季记录的选手
+-- 仅对迁移时已存在的退役选手补齐：取其最后一个有赛季统计的赛季，无统计则记为 0（视为早已退役）
+UPDATE players
+SET retire_season = COALESCE(
+    (SELECT MAX(pss.season_id) FROM player_season_stats pss
+     WHERE pss.save_id = players.save_id AND pss.player_id = players.id),
+    0
+)
+WHERE status IN ('Retired', 'RETIRED') AND retire_season IS NULL
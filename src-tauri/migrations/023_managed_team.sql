@@ -0,0 +1,6 @@
+-- ============================================
+-- 023: 存档的玩家执教队伍
+-- ============================================
+
+-- 球探迷雾默认以该队视角展示选手评分，未设置时为全知的观察者视角
+ALTER TABLE saves ADD COLUMN managed_team_id INTEGER
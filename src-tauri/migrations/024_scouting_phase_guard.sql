@@ -0,0 +1,7 @@
+-- ============================================
+-- 024: 球探派遣记录最近一次推进的阶段
+-- ============================================
+
+-- complete_phase 可能重复执行，同一 (赛季, 阶段) 只推进一次考察进度
+ALTER TABLE scouting_assignments ADD COLUMN last_advanced_season INTEGER;
+ALTER TABLE scouting_assignments ADD COLUMN last_advanced_phase TEXT
@@ -0,0 +1,15 @@
+-- ============================================
+-- 025: 国际赛风格扩散记录
+-- ============================================
+
+-- 每项国际赛事只扩散一次冠军赛区风格，阶段重复结算时跳过
+CREATE TABLE IF NOT EXISTS region_meta_spreads (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    save_id TEXT NOT NULL,
+    season_id INTEGER NOT NULL,
+    tournament_id INTEGER NOT NULL,
+    source_region_id INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(save_id, tournament_id),
+    FOREIGN KEY (save_id) REFERENCES saves(id) ON DELETE CASCADE
+)
@@ -2,7 +2,9 @@
 
 use crate::commands::save_commands::{AppState, CommandResult};
 use crate::db::SaveRepository;
+use crate::engines::{AwardVoteResult, AwardVotingEngine, VoteTally};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use sqlx::Row;
 use tauri::State;
 
@@ -128,6 +130,8 @@ pub struct AnnualAwardsData {
     pub rookie_of_the_year: Option<RookiePlayer>,
     /// 是否已颁发过奖项
     pub already_awarded: bool,
+    /// 各奖项投票明细（颁奖后才有）
+    pub award_votes: Vec<AwardVoteResult>,
 }
 
 /// 计算六维归一化维度
@@ -295,21 +299,35 @@ pub async fn get_annual_awards_data(
     // 检查是否已颁发过年度奖项
     let already_awarded = check_annual_awards_exist(&pool, &save_id, current_season).await;
 
+    // 已投票的赛季以投票结果为准，否则按评分预览
+    let award_votes = AwardVotingEngine::load_results(&pool, &save_id, current_season as i64, None)
+        .await
+        .unwrap_or_default();
+    let voted_winner = |award_id: &str| voted_winners(&award_votes, award_id).next().map(|t| t.player_id as i64);
+    let top20_ranks: HashMap<u64, u32> =
+        voted_winners(&award_votes, "annual_top20").map(|t| (t.player_id, t.rank)).collect();
+    let all_pro_tiers: HashMap<u64, u8> =
+        voted_winners(&award_votes, "all_pro").map(|t| (t.player_id, t.rank as u8)).collect();
+
     // 获取年度Top20（按 yearly_top_score 排序）
-    let top20 = get_top20_players(&pool, &save_id, current_season).await?;
+    let top20 = get_top20_players(&pool, &save_id, current_season, &top20_ranks).await?;
     log::info!("get_annual_awards_data: top20 count={}", top20.len());
 
     // 获取三阵
-    let (all_pro_1st, all_pro_2nd, all_pro_3rd) = get_all_pro_teams(&pool, &save_id, current_season).await?;
+    let (all_pro_1st, all_pro_2nd, all_pro_3rd) =
+        get_all_pro_teams(&pool, &save_id, current_season, &all_pro_tiers).await?;
 
     // 获取最稳定选手
-    let most_consistent = get_most_consistent(&pool, &save_id, current_season).await?;
+    let most_consistent =
+        get_most_consistent(&pool, &save_id, current_season, voted_winner("most_consistent")).await?;
 
     // 获取最具统治力选手
-    let most_dominant = get_most_dominant(&pool, &save_id, current_season).await?;
+    let most_dominant =
+        get_most_dominant(&pool, &save_id, current_season, voted_winner("most_dominant")).await?;
 
     // 获取年度最佳新秀（20岁及以下）
-    let rookie_of_the_year = get_rookie_of_the_year(&pool, &save_id, current_season).await?;
+    let rookie_of_the_year =
+        get_rookie_of_the_year(&pool, &save_id, current_season, voted_winner("rookie")).await?;
 
     Ok(CommandResult::ok(AnnualAwardsData {
         season_id: current_season,
@@ -321,9 +339,55 @@ pub async fn get_annual_awards_data(
         most_dominant,
         rookie_of_the_year,
         already_awarded,
+        award_votes,
     }))
 }
 
+/// 某奖项（所有评选范围）的获奖者
+fn voted_winners<'a>(votes: &'a [AwardVoteResult], award_id: &'a str) -> impl Iterator<Item = &'a VoteTally> {
+    votes.iter().filter(move |r| r.award_id == award_id).flat_map(|r| r.winners())
+}
+
+/// 获取年度奖项投票明细（媒体/职业选手/球迷三方得票）
+#[tauri::command]
+pub async fn get_award_votes(
+    state: State<'_, AppState>,
+    season_id: Option<u64>,
+    award_id: Option<String>,
+) -> Result<CommandResult<Vec<AwardVoteResult>>, String> {
+    let guard = state.db.read().await;
+    let db = match guard.as_ref() {
+        Some(db) => db,
+        None => return Ok(CommandResult::err("Database not initialized")),
+    };
+
+    let current_save = state.current_save_id.read().await;
+    let save_id = match current_save.as_ref() {
+        Some(id) => id.clone(),
+        None => return Ok(CommandResult::err("No save loaded")),
+    };
+
+    let pool = match db.get_pool().await {
+        Ok(p) => p,
+        Err(e) => return Ok(CommandResult::err(format!("Failed to get pool: {}", e))),
+    };
+
+    let season = match season_id {
+        Some(s) => s,
+        None => {
+            let save = SaveRepository::get_by_id(&pool, &save_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            save.current_season as u64
+        }
+    };
+
+    match AwardVotingEngine::load_results(&pool, &save_id, season as i64, award_id.as_deref()).await {
+        Ok(results) => Ok(CommandResult::ok(results)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
 /// 检查是否已颁发过年度奖项
 async fn check_annual_awards_exist(
     pool: &sqlx::SqlitePool,
@@ -347,13 +411,27 @@ async fn check_annual_awards_exist(
     }
 }
 
-/// 获取年度Top20选手
+/// 获取年度Top20选手（vote_ranks 非空时按投票名次排列）
 async fn get_top20_players(
     pool: &sqlx::SqlitePool,
     save_id: &str,
     season_id: u64,
+    vote_ranks: &HashMap<u64, u32>,
 ) -> Result<Vec<Top20Player>, String> {
-    let rows = sqlx::query(
+    // 已投票时直接取得票选手（评分窗口外的当选者也要展示），否则取评分前20
+    let voted_ids: Vec<i64> = vote_ranks.keys().map(|id| *id as i64).collect();
+    let filter = if voted_ids.is_empty() {
+        "AND (pss.games_played > 0 OR COALESCE(gpp_count.real_games_played, 0) > 0)
+        ORDER BY pss.yearly_top_score DESC
+        LIMIT 20"
+            .to_string()
+    } else {
+        format!(
+            "AND pss.player_id IN ({})",
+            voted_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",")
+        )
+    };
+    let sql = format!(
         r#"
         SELECT
             pss.player_id,
@@ -384,18 +462,22 @@ async fn get_top20_players(
             GROUP BY gpp.save_id, gpp.player_id
         ) gpp_count ON pss.save_id = gpp_count.save_id AND pss.player_id = gpp_count.player_id
         WHERE pss.save_id = ? AND pss.season_id = ?
-          AND (pss.games_played > 0 OR COALESCE(gpp_count.real_games_played, 0) > 0)
-        ORDER BY pss.yearly_top_score DESC
-        LIMIT 20
-        "#
-    )
-    .bind(save_id)
-    .bind(season_id as i64)
-    .bind(save_id)
-    .bind(season_id as i64)
-    .fetch_all(pool)
-    .await
-    .map_err(|e| e.to_string())?;
+        {}
+        "#,
+        filter
+    );
+    let mut query = sqlx::query(&sql)
+        .bind(save_id)
+        .bind(season_id as i64)
+        .bind(save_id)
+        .bind(season_id as i64);
+    for id in &voted_ids {
+        query = query.bind(id);
+    }
+    let rows = query
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())?;
 
     let mut players = Vec::new();
     for (idx, row) in rows.iter().enumerate() {
@@ -459,27 +541,50 @@ async fn get_top20_players(
         }
     }
 
-    players.sort_by(|a, b| b.yearly_score.partial_cmp(&a.yearly_score).unwrap_or(std::cmp::Ordering::Equal));
-    for (i, player) in players.iter_mut().enumerate() {
-        player.rank = (i + 1) as u32;
+    if vote_ranks.is_empty() {
+        players.sort_by(|a, b| b.yearly_score.partial_cmp(&a.yearly_score).unwrap_or(std::cmp::Ordering::Equal));
+        for (i, player) in players.iter_mut().enumerate() {
+            player.rank = (i + 1) as u32;
+        }
+    } else {
+        for player in &mut players {
+            player.rank = vote_ranks[&player.player_id];
+        }
+        players.sort_by_key(|p| p.rank);
     }
 
     Ok(players)
 }
 
-/// 获取年度最佳阵容三阵（每位置Top3）
+/// 获取年度最佳阵容三阵（每位置Top3，vote_tiers 非空时按投票结果分阵）
 async fn get_all_pro_teams(
     pool: &sqlx::SqlitePool,
     save_id: &str,
     season_id: u64,
+    vote_tiers: &HashMap<u64, u8>,
 ) -> Result<(Vec<AllProPlayer>, Vec<AllProPlayer>, Vec<AllProPlayer>), String> {
     let positions = vec!["TOP", "JUG", "MID", "ADC", "SUP"];
     let mut first_team = Vec::new();
     let mut second_team = Vec::new();
     let mut third_team = Vec::new();
 
+    // 已投票时直接取该位置的当选者（评分窗口外的当选者也要展示），否则取评分前3
+    let voted_ids: Vec<i64> = vote_tiers.keys().map(|id| *id as i64).collect();
+    let filter = if voted_ids.is_empty() {
+        "AND (pss.games_played > 0 OR COALESCE(gpp_count.real_games_played, 0) > 0)
+            ORDER BY pss.yearly_top_score DESC
+            LIMIT 3"
+            .to_string()
+    } else {
+        format!(
+            "AND pss.player_id IN ({})
+            ORDER BY pss.yearly_top_score DESC",
+            voted_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",")
+        )
+    };
+
     for position in positions {
-        let rows = sqlx::query(
+        let sql = format!(
             r#"
             SELECT
                 pss.player_id,
@@ -508,22 +613,34 @@ async fn get_all_pro_teams(
                 GROUP BY gpp.save_id, gpp.player_id
             ) gpp_count ON pss.save_id = gpp_count.save_id AND pss.player_id = gpp_count.player_id
             WHERE pss.save_id = ? AND pss.season_id = ? AND UPPER(pss.position) = UPPER(?)
-              AND (pss.games_played > 0 OR COALESCE(gpp_count.real_games_played, 0) > 0)
-            ORDER BY pss.yearly_top_score DESC
-            LIMIT 3
-            "#
-        )
-        .bind(save_id)
-        .bind(season_id as i64)
-        .bind(save_id)
-        .bind(season_id as i64)
-        .bind(position)
-        .fetch_all(pool)
-        .await
-        .map_err(|e| e.to_string())?;
+            {}
+            "#,
+            filter
+        );
+        let mut query = sqlx::query(&sql)
+            .bind(save_id)
+            .bind(season_id as i64)
+            .bind(save_id)
+            .bind(season_id as i64)
+            .bind(position);
+        for id in &voted_ids {
+            query = query.bind(id);
+        }
+        let rows = query
+            .fetch_all(pool)
+            .await
+            .map_err(|e| e.to_string())?;
 
         for (tier_idx, row) in rows.iter().enumerate() {
-            let tier = (tier_idx + 1) as u8;
+            let player_id = row.get::<i64, _>("player_id") as u64;
+            let tier = if vote_tiers.is_empty() {
+                (tier_idx + 1) as u8
+            } else {
+                match vote_tiers.get(&player_id) {
+                    Some(tier) => *tier,
+                    None => continue,
+                }
+            };
             let avg_impact = row.get::<f64, _>("avg_impact");
             let avg_performance = row.get::<f64, _>("avg_performance");
             let best_performance = row.get::<f64, _>("best_performance");
@@ -538,7 +655,7 @@ async fn get_all_pro_teams(
             );
 
             let player = AllProPlayer {
-                player_id: row.get::<i64, _>("player_id") as u64,
+                player_id,
                 player_name: row.get::<String, _>("player_name"),
                 team_id: row.get::<Option<i64>, _>("team_id").unwrap_or(0) as u64,
                 team_name: row.get::<String, _>("team_name"),
@@ -562,11 +679,12 @@ async fn get_all_pro_teams(
     Ok((first_team, second_team, third_team))
 }
 
-/// 获取最稳定选手（consistency_score 最高，>=30场）
+/// 获取最稳定选手（consistency_score 最高，>=30场；已投票则取得票第一）
 async fn get_most_consistent(
     pool: &sqlx::SqlitePool,
     save_id: &str,
     season_id: u64,
+    winner_id: Option<i64>,
 ) -> Result<Option<SpecialAwardPlayer>, String> {
     let row = sqlx::query(
         r#"
@@ -597,6 +715,7 @@ async fn get_most_consistent(
         ) gpp_count ON pss.save_id = gpp_count.save_id AND pss.player_id = gpp_count.player_id
         WHERE pss.save_id = ? AND pss.season_id = ?
           AND (pss.games_played > 0 OR COALESCE(gpp_count.real_games_played, 0) > 0)
+          AND (? IS NULL OR pss.player_id = ?)
         ORDER BY pss.consistency_score DESC
         LIMIT 1
         "#
@@ -605,6 +724,8 @@ async fn get_most_consistent(
     .bind(season_id as i64)
     .bind(save_id)
     .bind(season_id as i64)
+    .bind(winner_id)
+    .bind(winner_id)
     .fetch_optional(pool)
     .await
     .map_err(|e| e.to_string())?;
@@ -644,11 +765,12 @@ async fn get_most_consistent(
     }))
 }
 
-/// 获取最具统治力选手（dominance_score 最高，>=20场）
+/// 获取最具统治力选手（dominance_score 最高，>=20场；已投票则取得票第一）
 async fn get_most_dominant(
     pool: &sqlx::SqlitePool,
     save_id: &str,
     season_id: u64,
+    winner_id: Option<i64>,
 ) -> Result<Option<SpecialAwardPlayer>, String> {
     let row = sqlx::query(
         r#"
@@ -680,6 +802,7 @@ async fn get_most_dominant(
         ) gpp_count ON pss.save_id = gpp_count.save_id AND pss.player_id = gpp_count.player_id
         WHERE pss.save_id = ? AND pss.season_id = ?
           AND (pss.games_played > 0 OR COALESCE(gpp_count.real_games_played, 0) > 0)
+          AND (? IS NULL OR pss.player_id = ?)
         ORDER BY COALESCE(pss.dominance_score, 0.0) DESC
         LIMIT 1
         "#
@@ -688,6 +811,8 @@ async fn get_most_dominant(
     .bind(season_id as i64)
     .bind(save_id)
     .bind(season_id as i64)
+    .bind(winner_id)
+    .bind(winner_id)
     .fetch_optional(pool)
     .await
     .map_err(|e| e.to_string())?;
@@ -726,11 +851,12 @@ async fn get_most_dominant(
     }))
 }
 
-/// 获取年度最佳新秀（20岁及以下，IM得分最高）
+/// 获取年度最佳新秀（20岁及以下，IM得分最高；已投票则取得票第一）
 async fn get_rookie_of_the_year(
     pool: &sqlx::SqlitePool,
     save_id: &str,
     season_id: u64,
+    winner_id: Option<i64>,
 ) -> Result<Option<RookiePlayer>, String> {
     let row = sqlx::query(
         r#"
@@ -762,6 +888,7 @@ async fn get_rookie_of_the_year(
         ) gpp_count ON pss.save_id = gpp_count.save_id AND pss.player_id = gpp_count.player_id
         WHERE pss.save_id = ? AND pss.season_id = ? AND p.age <= 20
           AND (pss.games_played > 0 OR COALESCE(gpp_count.real_games_played, 0) > 0)
+          AND (? IS NULL OR pss.player_id = ?)
         ORDER BY pss.yearly_top_score DESC
         LIMIT 1
         "#
@@ -770,6 +897,8 @@ async fn get_rookie_of_the_year(
     .bind(season_id as i64)
     .bind(save_id)
     .bind(season_id as i64)
+    .bind(winner_id)
+    .bind(winner_id)
     .fetch_optional(pool)
     .await
     .map_err(|e| e.to_string())?;
@@ -3,8 +3,12 @@
 use crate::commands::save_commands::{AppState, CommandResult};
 use crate::engines::bp_engine::{hard_counter_pairs, soft_counter_pairs, CompType};
 use crate::engines::champion::CHAMPIONS;
+use crate::engines::champion_patch::ChampionPatchEngine;
+use crate::engines::draft_scouting::{DraftScoutingEngine, DraftScoutingReport};
 use crate::engines::meta_engine::MetaType;
+use crate::engines::side_selection::MapSide;
 use crate::get_pool;
+use crate::models::ChampionPatch;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use std::collections::HashMap;
@@ -20,6 +24,11 @@ pub struct ChampionInfo {
     pub position: String,
     pub archetype: String,
     pub archetype_name: String,
+    pub positions: Vec<String>,
+    pub tags: Vec<String>,
+    pub base_strength: f64,
+    /// 各可用位置的强度修正（主位置为 0）
+    pub role_strength: HashMap<String, f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -50,6 +59,10 @@ pub struct CompStatInfo {
     pub comp_type: String,
     pub pick_count: u32,
     pub win_count: u32,
+    pub blue_pick_count: u32,
+    pub blue_win_count: u32,
+    pub red_pick_count: u32,
+    pub red_win_count: u32,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -153,7 +166,7 @@ fn comp_display_name(comp_type: CompType) -> &'static str {
 
 // ── 命令 ────────────────────────────────────────────────
 
-/// 获取全部50个英雄列表
+/// 获取全部英雄列表
 #[tauri::command]
 pub fn get_champion_list() -> CommandResult<Vec<ChampionInfo>> {
     let champions: Vec<ChampionInfo> = CHAMPIONS
@@ -165,6 +178,14 @@ pub fn get_champion_list() -> CommandResult<Vec<ChampionInfo>> {
             position: format!("{:?}", c.position),
             archetype: c.archetype.id().to_string(),
             archetype_name: c.archetype.display_name().to_string(),
+            positions: c.positions.iter().map(|p| format!("{:?}", p)).collect(),
+            tags: c.tags.iter().map(|t| t.id().to_string()).collect(),
+            base_strength: c.base_strength,
+            role_strength: c
+                .positions
+                .iter()
+                .map(|p| (format!("{:?}", p), c.role_strength(*p).unwrap_or(0.0)))
+                .collect(),
         })
         .collect();
 
@@ -268,6 +289,30 @@ pub async fn get_champion_stats(
     Ok(CommandResult::ok(result))
 }
 
+/// 获取英雄版本补丁记录（不传赛季则返回全部赛季）
+#[tauri::command]
+pub async fn get_champion_patch_notes(
+    state: State<'_, AppState>,
+    save_id: String,
+    season_id: Option<i64>,
+) -> Result<CommandResult<Vec<ChampionPatch>>, String> {
+    let pool = get_pool!(state);
+    let patches = ChampionPatchEngine::load_patch_notes(&pool, &save_id, season_id.filter(|&s| s > 0)).await?;
+    Ok(CommandResult::ok(patches))
+}
+
+/// 获取队伍的 BP 球探报告（常用英雄、舒适英雄、体系偏好）
+#[tauri::command]
+pub async fn get_draft_scouting_report(
+    state: State<'_, AppState>,
+    save_id: String,
+    team_id: u64,
+) -> Result<CommandResult<DraftScoutingReport>, String> {
+    let pool = get_pool!(state);
+    let report = DraftScoutingEngine::load_report(&pool, &save_id, team_id).await;
+    Ok(CommandResult::ok(report))
+}
+
 #[tauri::command]
 pub async fn get_draft_result(
     state: State<'_, AppState>,
@@ -354,7 +399,7 @@ pub async fn get_comp_stats(
     let pool = get_pool!(state);
 
     let mut sql = String::from(
-        "SELECT d.home_comp, d.away_comp, m.home_team_id, g.winner_team_id
+        "SELECT d.home_comp, d.away_comp, d.home_side, m.home_team_id, g.winner_team_id
          FROM game_draft_results d
          JOIN match_games g ON g.save_id = d.save_id
               AND g.match_id = d.match_id AND g.game_number = d.game_number
@@ -376,40 +421,47 @@ pub async fn get_comp_stats(
     }
     let rows = query.fetch_all(&pool).await.map_err(|e| e.to_string())?;
 
-    // comp_type → (pick_count, win_count)
-    let mut stats: HashMap<String, (u32, u32)> = HashMap::new();
+    // comp_type → 总计 / 蓝色方 / 红色方的 (pick_count, win_count)
+    let mut stats: HashMap<String, [(u32, u32); 3]> = HashMap::new();
 
     for row in &rows {
         let home_comp: Option<String> = row.get("home_comp");
         let away_comp: Option<String> = row.get("away_comp");
+        let home_side = MapSide::from_id(&row.get::<String, _>("home_side")).unwrap_or_default();
         let home_team_id: i64 = row.get("home_team_id");
         let winner_team_id: i64 = row.get("winner_team_id");
 
         let home_won = home_team_id == winner_team_id;
 
-        if let Some(comp) = home_comp {
+        for (comp, side, won) in [
+            (home_comp, home_side, home_won),
+            (away_comp, home_side.opposite(), !home_won),
+        ] {
+            let Some(comp) = comp else { continue };
             let entry = stats.entry(comp).or_default();
-            entry.0 += 1;
-            if home_won {
-                entry.1 += 1;
-            }
-        }
-
-        if let Some(comp) = away_comp {
-            let entry = stats.entry(comp).or_default();
-            entry.0 += 1;
-            if !home_won {
-                entry.1 += 1;
+            let side_idx = match side {
+                MapSide::Blue => 1,
+                MapSide::Red => 2,
+            };
+            for idx in [0, side_idx] {
+                entry[idx].0 += 1;
+                if won {
+                    entry[idx].1 += 1;
+                }
             }
         }
     }
 
     let mut result: Vec<CompStatInfo> = stats
         .into_iter()
-        .map(|(comp_type, (pick_count, win_count))| CompStatInfo {
+        .map(|(comp_type, [total, blue, red])| CompStatInfo {
             comp_type,
-            pick_count,
-            win_count,
+            pick_count: total.0,
+            win_count: total.1,
+            blue_pick_count: blue.0,
+            blue_win_count: blue.1,
+            red_pick_count: red.0,
+            red_win_count: red.1,
         })
         .collect();
 
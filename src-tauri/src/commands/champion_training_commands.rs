@@ -0,0 +1,93 @@
+//! 英雄熟练度训练 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::engines::{ChampionTrainingEngine, ScoutingEngine};
+use crate::models::{TrainingAllocation, TrainingPlanSummary};
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 查询存档当前赛季与阶段
+async fn current_season_phase(pool: &sqlx::SqlitePool, save_id: &str) -> Result<(i64, String), String> {
+    let row: Option<(i64, String)> =
+        sqlx::query_as("SELECT current_season, current_phase FROM saves WHERE id = ?")
+            .bind(save_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    row.ok_or_else(|| "存档不存在".to_string())
+}
+
+/// 获取队伍本阶段的英雄训练计划
+#[tauri::command]
+pub async fn get_champion_training_plan(
+    state: State<'_, AppState>,
+    team_id: u64,
+) -> Result<CommandResult<TrainingPlanSummary>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+    let (season, phase) = current_season_phase(&pool, &save_id).await?;
+
+    match ChampionTrainingEngine::load_plan_summary(&pool, &save_id, season, &phase, team_id).await {
+        Ok(summary) => Ok(CommandResult::ok(summary)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 为选手安排本阶段的英雄训练（阶段结束时结算）
+#[tauri::command]
+pub async fn set_champion_training_plan(
+    state: State<'_, AppState>,
+    player_id: u64,
+    allocations: Vec<TrainingAllocation>,
+) -> Result<CommandResult<()>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+    let (season, phase) = current_season_phase(&pool, &save_id).await?;
+
+    let team_id: Option<i64> = sqlx::query_scalar("SELECT team_id FROM players WHERE save_id = ? AND id = ?")
+        .bind(&save_id)
+        .bind(player_id as i64)
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .flatten();
+    let Some(team_id) = team_id else {
+        return Ok(CommandResult::err("选手不属于任何队伍".to_string()));
+    };
+    // 只能为执教队伍的选手安排训练，其他队伍由 AI 自动安排
+    match ScoutingEngine::managed_team(&pool, &save_id).await {
+        Ok(Some(managed)) if managed == team_id as u64 => {}
+        Ok(_) => return Ok(CommandResult::err("只能为执教队伍的选手安排训练")),
+        Err(e) => return Ok(CommandResult::err(e)),
+    }
+
+    let masteries = ChampionTrainingEngine::load_player_masteries(&pool, &save_id, player_id).await?;
+    if let Err(e) = ChampionTrainingEngine::validate_plan(&allocations, &masteries) {
+        return Ok(CommandResult::err(e));
+    }
+
+    match ChampionTrainingEngine::save_plan(
+        &pool, &save_id, season, &phase, team_id as u64, player_id, &allocations, false,
+    )
+    .await
+    {
+        Ok(()) => Ok(CommandResult::ok(())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 取消选手本阶段尚未结算的训练计划
+#[tauri::command]
+pub async fn clear_champion_training_plan(
+    state: State<'_, AppState>,
+    player_id: u64,
+) -> Result<CommandResult<()>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+    let (season, phase) = current_season_phase(&pool, &save_id).await?;
+
+    match ChampionTrainingEngine::clear_plan(&pool, &save_id, season, &phase, player_id).await {
+        Ok(()) => Ok(CommandResult::ok(())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
@@ -0,0 +1,46 @@
+//! 阵容强势期规则 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::engines::{CompPhaseEngine, CompPhaseRuleset};
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 获取当前存档生效的阵容强势期规则
+#[tauri::command]
+pub async fn get_comp_phase_ruleset(
+    state: State<'_, AppState>,
+) -> Result<CommandResult<CompPhaseRuleset>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    Ok(CommandResult::ok(CompPhaseEngine::load_ruleset(&pool, &save_id).await))
+}
+
+/// 覆盖当前存档的阵容强势期规则
+#[tauri::command]
+pub async fn update_comp_phase_ruleset(
+    state: State<'_, AppState>,
+    ruleset: CompPhaseRuleset,
+) -> Result<CommandResult<CompPhaseRuleset>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match CompPhaseEngine::save_ruleset(&pool, &save_id, &ruleset).await {
+        Ok(()) => Ok(CommandResult::ok(ruleset)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 恢复内置的阵容强势期规则
+#[tauri::command]
+pub async fn reset_comp_phase_ruleset(
+    state: State<'_, AppState>,
+) -> Result<CommandResult<CompPhaseRuleset>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match CompPhaseEngine::reset_ruleset(&pool, &save_id).await {
+        Ok(()) => Ok(CommandResult::ok(CompPhaseRuleset::builtin().clone())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
@@ -1,9 +1,11 @@
 use crate::commands::save_commands::{AppState, CommandResult};
-use crate::engines::{DraftEngine, MarketValueEngine, RookieGenerator, RookieGenerationConfig, TraitEngine};
+use crate::engines::{DraftEngine, LadderProfileEngine, MarketValueEngine, RookieGenerator, RookieGenerationConfig, ScoutingEngine, TraitEngine};
+use crate::models::{ScoutSubject, ScoutedRatings};
+use crate::services::GameFlowService;
 use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use tauri::State;
 
 /// 选秀球员信息
@@ -20,6 +22,8 @@ pub struct DraftPlayerInfo {
     pub tag: String,
     pub draft_rank: u32,
     pub is_picked: bool,
+    /// 指定观察队伍时的球探情报（此时 ability/potential 为估计值）
+    pub scouting: Option<ScoutedRatings>,
 }
 
 /// 选秀顺位信息
@@ -143,6 +147,7 @@ pub async fn generate_draft_pool(
                 tag: row.get("tag"),
                 draft_rank: 0,
                 is_picked: false,
+                scouting: None,
             }
         })
         .collect();
@@ -372,6 +377,8 @@ pub async fn get_draft_order(
 }
 
 /// 获取可选秀球员
+///
+/// 按执教队伍对选秀池的球探情报返回能力/潜力区间（未设置执教队伍时为观察者视角）。
 #[tauri::command]
 pub async fn get_available_draft_players(
     state: State<'_, AppState>,
@@ -415,7 +422,7 @@ pub async fn get_available_draft_players(
     .await
     .map_err(|e| e.to_string())?;
 
-    let infos: Vec<DraftPlayerInfo> = rows
+    let mut infos: Vec<DraftPlayerInfo> = rows
         .iter()
         .map(|row| DraftPlayerInfo {
             id: row.get::<i64, _>("id") as u64,
@@ -429,9 +436,53 @@ pub async fn get_available_draft_players(
             tag: row.get("tag"),
             draft_rank: row.get::<i64, _>("draft_rank") as u32,
             is_picked: row.get::<i64, _>("is_picked") != 0,
+            scouting: None,
         })
         .collect();
 
+    // 球探迷雾：情报按 draft_pool 记录保存，通过 game_id 关联
+    let viewer = ScoutingEngine::managed_team(&pool, &save_id).await?;
+    if let Some(viewer) = viewer {
+        let pool_ids: HashMap<String, u64> = sqlx::query(
+            "SELECT id, game_id FROM draft_pool WHERE save_id = ? AND region_id = ?"
+        )
+        .bind(&save_id)
+        .bind(region_id as i64)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .iter()
+        .map(|r| (r.get::<String, _>("game_id"), r.get::<i64, _>("id") as u64))
+        .collect();
+
+        let knowledge_map = ScoutingEngine::get_knowledge_map(
+            &pool, &save_id, viewer, ScoutSubject::Prospect, current_season,
+        )
+        .await?;
+        let ladder_map = LadderProfileEngine::knowledge_map(&pool, &save_id, ScoutSubject::Prospect).await?;
+        let same_region = ScoutingEngine::team_region(&pool, viewer).await? == Some(region_id);
+        let baseline = ScoutingEngine::baseline_knowledge(ScoutSubject::Prospect, same_region);
+
+        for info in infos.iter_mut() {
+            let pool_id = pool_ids.get(&info.game_id).copied().unwrap_or(info.id);
+            let knowledge = knowledge_map.get(&pool_id).copied().unwrap_or(0.0)
+                .max(baseline)
+                .max(ladder_map.get(&pool_id).copied().unwrap_or(0.0));
+            let scouted = ScoutingEngine::scout_ratings(
+                viewer,
+                ScoutSubject::Prospect,
+                pool_id,
+                info.ability,
+                info.potential,
+                0,
+                knowledge,
+            );
+            info.ability = scouted.ability_range.estimate();
+            info.potential = scouted.potential_range.estimate();
+            info.scouting = Some(scouted);
+        }
+    }
+
     Ok(CommandResult::ok(infos))
 }
 
@@ -541,38 +592,54 @@ pub async fn make_draft_pick(
     let draft_market_value = MarketValueEngine::calculate_base_market_value(draft_ability, draft_age, draft_potential, &draft_tag, &draft_position) as i64;
     let draft_contract_years: i64 = 3;
 
-    // 创建正式球员
-    let new_player_id: i64 = sqlx::query(
-        r#"
-        INSERT INTO players (
-            save_id, game_id, real_name, nationality, age, ability, potential, stability,
-            tag, status, position, team_id, salary, market_value, contract_end_season,
-            join_season, is_starter, home_region_id, region_loyalty
-        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 'Active', ?, ?, ?, ?, ?, ?, 0, ?, ?)
-        RETURNING id
-        "#,
+    // 天梯发掘的路人沿用原选手档案，其余新建正式球员
+    let promoted = GameFlowService::promote_drafted_amateur(
+        &pool,
+        &save_id,
+        region_id,
+        &draft_game_id,
+        team_id as i64,
+        current_season,
+        draft_salary,
+        draft_market_value,
+        current_season + draft_contract_years,
+        region_loyalty,
     )
-    .bind(&save_id)
-    .bind(player_row.get::<String, _>("game_id"))
-    .bind(player_row.get::<Option<String>, _>("real_name"))
-    .bind(player_row.get::<Option<String>, _>("nationality"))
-    .bind(player_row.get::<i64, _>("age"))
-    .bind(player_row.get::<i64, _>("ability"))
-    .bind(player_row.get::<i64, _>("potential"))
-    .bind(80i64) // stability
-    .bind(player_row.get::<String, _>("tag"))
-    .bind(player_row.get::<String, _>("position"))
-    .bind(team_id as i64)
-    .bind(draft_salary)
-    .bind(draft_market_value)
-    .bind(current_season + draft_contract_years)
-    .bind(current_season)
-    .bind(region_id)  // home_region_id = 选秀赛区
-    .bind(region_loyalty)
-    .fetch_one(&pool)
-    .await
-    .map_err(|e| e.to_string())?
-    .get("id");
+    .await?;
+    let new_player_id: i64 = match promoted {
+        Some(player_id) => player_id,
+        None => sqlx::query(
+                r#"
+                INSERT INTO players (
+                    save_id, game_id, real_name, nationality, age, ability, potential, stability,
+                    tag, status, position, team_id, salary, market_value, contract_end_season,
+                    join_season, is_starter, home_region_id, region_loyalty
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 'Active', ?, ?, ?, ?, ?, ?, 0, ?, ?)
+                RETURNING id
+                "#,
+            )
+            .bind(&save_id)
+            .bind(player_row.get::<String, _>("game_id"))
+            .bind(player_row.get::<Option<String>, _>("real_name"))
+            .bind(player_row.get::<Option<String>, _>("nationality"))
+            .bind(player_row.get::<i64, _>("age"))
+            .bind(player_row.get::<i64, _>("ability"))
+            .bind(player_row.get::<i64, _>("potential"))
+            .bind(80i64) // stability
+            .bind(player_row.get::<String, _>("tag"))
+            .bind(player_row.get::<String, _>("position"))
+            .bind(team_id as i64)
+            .bind(draft_salary)
+            .bind(draft_market_value)
+            .bind(current_season + draft_contract_years)
+            .bind(current_season)
+            .bind(region_id)  // home_region_id = 选秀赛区
+            .bind(region_loyalty)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| e.to_string())?
+            .get("id"),
+    };
 
     // 记录选秀结果
     sqlx::query(
@@ -610,8 +677,8 @@ pub async fn make_draft_pick(
     .await
     .map_err(|e| format!("记录选秀合同失败: {}", e))?;
 
-    // 为新秀生成特性
-    {
+    // 为新秀生成特性（天梯路人已有特性）
+    if promoted.is_none() {
         let mut rng = rand::rngs::StdRng::from_entropy();
         let traits = TraitEngine::generate_random_traits(draft_ability, draft_age, &mut rng);
         for t in &traits {
@@ -1066,6 +1133,7 @@ pub async fn get_draft_region_status(
             tag: row.get("tag"),
             draft_rank: row.get::<i64, _>("draft_rank") as u32,
             is_picked: row.get::<i64, _>("is_picked") != 0,
+            scouting: None,
         })
         .collect();
 
@@ -0,0 +1,83 @@
+//! 系列赛 BP 规则 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::engines::DraftRulesEngine;
+use crate::models::{DraftRule, TournamentDraftRule};
+use crate::{get_pool, get_save_id};
+use serde::{Deserialize, Serialize};
+use tauri::State;
+
+/// BP 规则选项
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DraftRuleOption {
+    pub rule: DraftRule,
+    pub name: String,
+}
+
+/// 获取全部可选的 BP 规则
+#[tauri::command]
+pub fn get_draft_rule_options() -> CommandResult<Vec<DraftRuleOption>> {
+    let options = DraftRule::all()
+        .iter()
+        .map(|rule| DraftRuleOption {
+            rule: *rule,
+            name: rule.name().to_string(),
+        })
+        .collect();
+    CommandResult::ok(options)
+}
+
+/// 获取赛事已配置的 BP 规则
+#[tauri::command]
+pub async fn get_tournament_draft_rules(
+    state: State<'_, AppState>,
+    tournament_id: u64,
+) -> Result<CommandResult<Vec<TournamentDraftRule>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match DraftRulesEngine::list_rules(&pool, &save_id, tournament_id).await {
+        Ok(rules) => Ok(CommandResult::ok(rules)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 设置赛事（阶段）的 BP 规则；stage 为空时作为该赛事全部阶段的默认规则
+#[tauri::command]
+pub async fn set_tournament_draft_rule(
+    state: State<'_, AppState>,
+    tournament_id: u64,
+    stage: Option<String>,
+    rule: DraftRule,
+    global_bans: Option<Vec<u8>>,
+) -> Result<CommandResult<TournamentDraftRule>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    let draft_rule = TournamentDraftRule {
+        tournament_id,
+        stage: stage.unwrap_or_default(),
+        rule,
+        global_bans: global_bans.unwrap_or_default(),
+    };
+    match DraftRulesEngine::save_rule(&pool, &save_id, &draft_rule).await {
+        Ok(()) => Ok(CommandResult::ok(draft_rule)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 删除赛事（阶段）的 BP 规则，恢复为上级默认
+#[tauri::command]
+pub async fn delete_tournament_draft_rule(
+    state: State<'_, AppState>,
+    tournament_id: u64,
+    stage: Option<String>,
+) -> Result<CommandResult<()>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match DraftRulesEngine::delete_rule(&pool, &save_id, tournament_id, &stage.unwrap_or_default()).await {
+        Ok(()) => Ok(CommandResult::ok(())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
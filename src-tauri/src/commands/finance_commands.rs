@@ -1,5 +1,5 @@
 use crate::commands::save_commands::{AppState, CommandResult};
-use crate::engines::FinancialEngine;
+use crate::engines::{FinancialEngine, SponsorshipEngine};
 use crate::models::TournamentType;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
@@ -52,6 +52,8 @@ pub struct SeasonFinanceReport {
     pub salary_expense: u64,
     pub prize_money: u64,
     pub sponsorship: u64,
+    /// 赞助绩效奖金（夺冠 / 晋级国际赛）
+    pub sponsor_bonus: u64,
     pub league_share: u64,
     pub transfer_net: i64,
     pub operating_cost: u64,
@@ -624,6 +626,12 @@ pub async fn get_season_finance_report(
         .bind(&save_id).bind(team_id as i64).bind(target_season)
         .fetch_one(&pool).await.unwrap_or(0);
 
+        let saved_sponsor_bonus: i64 = sqlx::query_scalar(
+            "SELECT COALESCE(SUM(amount), 0) FROM financial_transactions WHERE save_id = ? AND team_id = ? AND season_id = ? AND transaction_type = 'SponsorBonus'"
+        )
+        .bind(&save_id).bind(team_id as i64).bind(target_season)
+        .fetch_one(&pool).await.unwrap_or(0);
+
         let saved_league_share: i64 = sqlx::query_scalar(
             "SELECT COALESCE(SUM(amount), 0) FROM financial_transactions WHERE save_id = ? AND team_id = ? AND season_id = ? AND transaction_type = 'LeagueShare'"
         )
@@ -665,6 +673,7 @@ pub async fn get_season_finance_report(
             salary_expense: saved_salary as u64,
             prize_money: saved_prize as u64,
             sponsorship: saved_sponsorship as u64,
+            sponsor_bonus: saved_sponsor_bonus as u64,
             league_share: saved_league_share as u64,
             transfer_net: saved_transfer_in - saved_transfer_out,
             operating_cost: saved_operating as u64,
@@ -753,7 +762,23 @@ pub async fn get_season_finance_report(
         brand_value,
     };
 
-    let sponsorship = engine.calculate_sponsorship(&team);
+    // 有赞助合同时按合同基础赞助费计算，否则沿用品牌公式预估
+    let sponsor_contracts = SponsorshipEngine::load_contracts(&pool, &save_id, Some(team_id), true).await?;
+    let covering: Vec<_> = sponsor_contracts.iter().filter(|c| c.covers(target_season as u32)).collect();
+    let sponsorship = if covering.is_empty() {
+        engine.calculate_sponsorship(&team)
+    } else {
+        covering.iter().map(|c| c.annual_base.max(0) as u64).sum()
+    };
+    let sponsor_bonus: i64 = sqlx::query_scalar(
+        "SELECT COALESCE(SUM(amount), 0) FROM financial_transactions WHERE save_id = ? AND team_id = ? AND season_id = ? AND transaction_type = 'SponsorBonus'"
+    )
+    .bind(&save_id)
+    .bind(team_id as i64)
+    .bind(target_season)
+    .fetch_one(&pool)
+    .await
+    .unwrap_or(0);
     let league_share = engine.calculate_league_share(&region_code, None);
     let operating_cost = engine.calculate_operating_cost(salary_expense as u64);
 
@@ -768,7 +793,7 @@ pub async fn get_season_finance_report(
     .unwrap_or(0);
 
     let transfer_net = transfer_income - transfer_expense;
-    let total_income = sponsorship + league_share + prize_money as u64 + weak_team_subsidy as u64
+    let total_income = sponsorship + sponsor_bonus as u64 + league_share + prize_money as u64 + weak_team_subsidy as u64
         + if transfer_net > 0 { transfer_net as u64 } else { 0 };
     let total_expense = salary_expense as u64 + operating_cost
         + if transfer_net < 0 { (-transfer_net) as u64 } else { 0 };
@@ -799,6 +824,7 @@ pub async fn get_season_finance_report(
         salary_expense: salary_expense as u64,
         prize_money: prize_money as u64,
         sponsorship,
+        sponsor_bonus: sponsor_bonus as u64,
         league_share,
         transfer_net,
         operating_cost,
@@ -4,7 +4,10 @@ use crate::db::{MatchRepository, MatchGameDetailRepository, TournamentRepository
 use crate::db::repository::PlayerStatsRepository;
 use crate::models::PlayerTournamentStats;
 use crate::models::{TournamentStatus, MatchFormat};
-use crate::engines::{ConditionEngine, PlayerFormFactors, TraitType, TraitEngine, TraitContext};
+use crate::engines::{ConditionEngine, PlayerFormFactors, TraitType, TraitContext};
+use crate::engines::meta_engine::MetaWeights;
+use crate::engines::sim_core::{PerformanceInput, SimulationCore};
+use crate::engines::game_timeline::{GameTimelineEngine, TimelineInput, TimelinePlayer};
 use crate::models::match_game_detail::{SaveMatchDetailsInput, SaveGameInput, SavePerformanceInput};
 use crate::services::LeagueService;
 use rand::{Rng, SeedableRng};
@@ -315,12 +318,6 @@ fn simulate_game_with_players(
     trait_ctx: &TraitContext,
     rng: &mut impl Rng,
 ) -> (Vec<PlayerGameStats>, Vec<PlayerGameStats>, f64, f64) {
-    fn gaussian_random(rng: &mut impl Rng) -> f64 {
-        let u: f64 = rng.gen::<f64>().max(0.0001);
-        let v: f64 = rng.gen::<f64>().max(0.0001);
-        (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
-    }
-
     fn generate_team_stats(
         players: &[PlayerData],
         duration: u32,
@@ -331,6 +328,7 @@ fn simulate_game_with_players(
         let mut total_actual_ability = 0.0;
 
         let mut player_performances: Vec<(f64, f64, f64, f64, Vec<ActivatedTraitInfo>)> = Vec::new();
+        let mut player_abilities_with_pos: Vec<(f64, &str)> = Vec::new();
         for player in players {
             let player_trait_ctx = TraitContext {
                 age: player.age,
@@ -339,8 +337,6 @@ fn simulate_game_with_players(
                 ..trait_ctx.clone()
             };
 
-            let modifiers = TraitEngine::calculate_combined_modifiers(&player.traits, &player_trait_ctx);
-
             let activated_traits: Vec<ActivatedTraitInfo> = player.traits.iter().filter_map(|t| {
                 let (effect_desc, value, is_positive) = match t {
                     TraitType::Clutch if player_trait_ctx.is_playoff || player_trait_ctx.is_international => {
@@ -390,26 +386,29 @@ fn simulate_game_with_players(
                 })
             }).collect();
 
-            let (modified_ability, modified_stability, modified_condition, ability_ceiling) =
-                TraitEngine::apply_modifiers(
-                    player.ability,
-                    player.stability,
-                    player.condition,
-                    &modifiers,
-                );
-
-            let sigma = (100.0 - modified_stability as f64) / 10.0;
-            let condition_bonus = modified_condition as f64;
-            let stability_noise = gaussian_random(rng) * sigma;
-            let raw_ability = modified_ability as f64 + condition_bonus + stability_noise;
-            let min_ability = (modified_ability as f64 - 15.0).max(0.0);
-            let max_ability = (ability_ceiling as f64).min(100.0);
-            let actual_ability = raw_ability.clamp(min_ability, max_ability);
+            // 特性修正 → 稳定性噪声 → 钳位（统一模拟核心）
+            let roll = SimulationCore::roll_player(
+                &PerformanceInput {
+                    ability: player.ability,
+                    stability: player.stability,
+                    condition: player.condition,
+                    traits: &player.traits,
+                    bonus: 0.0,
+                    condition_adjust: 0,
+                },
+                &player_trait_ctx,
+                rng,
+            );
+            let (condition_bonus, stability_noise, actual_ability) =
+                (roll.condition_bonus, roll.stability_noise, roll.actual_ability);
 
             total_actual_ability += actual_ability;
+            player_abilities_with_pos.push((actual_ability, player.position.as_str()));
             player_performances.push((player.ability as f64, condition_bonus, stability_noise, actual_ability, activated_traits));
         }
 
+        // 队伍战力：该路径不区分版本，按均衡权重计算
+        let team_power = SimulationCore::team_power(&player_abilities_with_pos, &MetaWeights::balanced());
         let team_avg = if !players.is_empty() { total_actual_ability / players.len() as f64 } else { 0.0 };
 
         for (i, player) in players.iter().enumerate() {
@@ -430,7 +429,7 @@ fn simulate_game_with_players(
             };
 
             let mvp_score = kda * 0.4 + (damage as f64 / 10000.0) * 0.3 + (gold as f64 / 10000.0) * 0.3;
-            let impact_score = ((actual_ability - team_avg) * 10.0).round() / 10.0;
+            let impact_score = SimulationCore::impact_score(actual_ability, team_avg);
 
             stats.push(PlayerGameStats {
                 player_id: player.id,
@@ -455,7 +454,7 @@ fn simulate_game_with_players(
             });
         }
 
-        (stats, team_avg)
+        (stats, team_power)
     }
 
     let (home_stats, home_avg) = generate_team_stats(home_players, duration, trait_ctx, rng);
@@ -533,9 +532,10 @@ async fn simulate_match_core(
     let mut away_score: u8 = 0;
     let mut games_data: Vec<SaveGameInput> = Vec::new();
     let mut game_number: u8 = 1;
+    let timeline_enabled = GameTimelineEngine::is_enabled(pool, save_id).await;
 
     while home_score < wins_needed && away_score < wins_needed {
-        let duration = 25 + rng.gen_range(0..25);
+        let mut duration = 25 + rng.gen_range(0..25);
 
         let is_international = matches!(
             tournament_type.as_str(),
@@ -556,21 +556,17 @@ async fn simulate_match_core(
             games_since_rest: 0,
         };
 
-        let (home_player_stats, away_player_stats, home_perf, away_perf) = simulate_game_with_players(
+        let (mut home_player_stats, mut away_player_stats, home_perf, away_perf) = simulate_game_with_players(
             &home_players, &away_players,
             duration,
             &trait_ctx,
             &mut rng,
         );
 
-        let game_std_dev = 3.0;
-        let u1: f64 = rng.gen::<f64>().max(0.0001);
-        let u2: f64 = rng.gen::<f64>().max(0.0001);
-        let gaussian = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
-        let performance_diff = home_perf - away_perf;
-        let final_diff = performance_diff + gaussian * game_std_dev;
+        // 单局发挥 N(队伍战力, σ)，发挥值高者获胜
+        let resolution = SimulationCore::resolve_detailed_game(home_perf, away_perf, 0.0, 1.0, &mut rng);
 
-        let winner_id = if final_diff > 0.0 {
+        let winner_id = if resolution.home_won {
             home_team_id
         } else {
             away_team_id
@@ -578,6 +574,31 @@ async fn simulate_match_core(
 
         let loser_id = if winner_id == home_team_id { away_team_id } else { home_team_id };
 
+        // 局内时间线：该入口不走BP，按无阵容体系推演
+        if timeline_enabled {
+            let timeline = GameTimelineEngine::simulate(
+                &TimelineInput {
+                    home_team_id: home_team_id as u64,
+                    away_team_id: away_team_id as u64,
+                    home_players: home_player_stats.iter().map(TimelinePlayer::from).collect(),
+                    away_players: away_player_stats.iter().map(TimelinePlayer::from).collect(),
+                    home_comp: None,
+                    away_comp: None,
+                    home_won: winner_id == home_team_id,
+                },
+                &mut rng,
+            );
+            for p in home_player_stats.iter_mut().chain(away_player_stats.iter_mut()) {
+                if let Some(line) = timeline.player_line(p.player_id) {
+                    p.apply_timeline_line(line, duration, timeline.duration_minutes);
+                }
+            }
+            duration = timeline.duration_minutes;
+            if let Err(e) = GameTimelineEngine::save_timeline(pool, save_id, match_id, game_number as u32, &timeline).await {
+                log::warn!("{}", e);
+            }
+        }
+
         let all_stats: Vec<&PlayerGameStats> = home_player_stats.iter()
             .chain(away_player_stats.iter())
             .collect();
@@ -0,0 +1,48 @@
+//! 局内时间线 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::engines::GameTimelineEngine;
+use crate::models::SavedGameTimeline;
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 获取一场比赛各小局的时间线（未启用时间线时模拟的小局不会有记录）
+#[tauri::command]
+pub async fn get_match_timelines(
+    state: State<'_, AppState>,
+    match_id: u64,
+) -> Result<CommandResult<Vec<SavedGameTimeline>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match GameTimelineEngine::load_match_timelines(&pool, &save_id, match_id).await {
+        Ok(list) => Ok(CommandResult::ok(list)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 当前存档是否启用局内时间线
+#[tauri::command]
+pub async fn get_game_timeline_enabled(
+    state: State<'_, AppState>,
+) -> Result<CommandResult<bool>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    Ok(CommandResult::ok(GameTimelineEngine::is_enabled(&pool, &save_id).await))
+}
+
+/// 开关局内时间线；关闭后小局数据回退为按发挥值随机生成
+#[tauri::command]
+pub async fn set_game_timeline_enabled(
+    state: State<'_, AppState>,
+    enabled: bool,
+) -> Result<CommandResult<bool>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match GameTimelineEngine::set_enabled(&pool, &save_id, enabled).await {
+        Ok(()) => Ok(CommandResult::ok(enabled)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
@@ -1,6 +1,6 @@
 use crate::commands::{ApiResponse, AppState};
 use crate::db::{HonorRepository, PlayerRepository, TournamentResultRepository};
-use crate::engines::HonorEngine;
+use crate::engines::{HonorEngine, RecordType};
 use crate::models::Honor;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
@@ -1092,6 +1092,259 @@ pub async fn get_hall_of_fame(
                 "peak_ability": row.try_get::<i64, _>("peak_ability").ok(),
                 "career_seasons": row.try_get::<i64, _>("career_seasons").ok(),
                 "honors_json": row.try_get::<String, _>("honors_json").unwrap_or_default(),
+                "retire_season": row.try_get::<Option<i64>, _>("retire_season").unwrap_or(None),
+                "reasoning": parse_reasoning(row.try_get::<Option<String>, _>("reasoning").unwrap_or(None)),
+            })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// 入选理由（JSON 字符串数组）
+fn parse_reasoning(raw: Option<String>) -> Vec<String> {
+    raw.and_then(|r| serde_json::from_str(&r).ok()).unwrap_or_default()
+}
+
+/// 获取战队名人堂
+#[tauri::command]
+pub async fn get_team_hall_of_fame(
+    state: State<'_, AppState>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let guard = state.db.read().await;
+    let db = guard
+        .as_ref()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+
+    let pool = db
+        .get_pool()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let current_save = state.current_save_id.read().await;
+    let save_id = current_save
+        .as_ref()
+        .ok_or_else(|| "No save loaded".to_string())?
+        .clone();
+
+    let rows = sqlx::query(
+        "SELECT * FROM team_hall_of_fame WHERE save_id = ? ORDER BY total_score DESC",
+    )
+    .bind(&save_id)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("查询战队名人堂失败: {}", e))?;
+
+    let entries: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            serde_json::json!({
+                "id": row.get::<i64, _>("id"),
+                "team_id": row.get::<i64, _>("team_id"),
+                "team_name": row.get::<String, _>("team_name"),
+                "induction_season": row.get::<i64, _>("induction_season"),
+                "promoted_season": row.get::<Option<i64>, _>("promoted_season"),
+                "tier": row.get::<String, _>("tier"),
+                "total_score": row.get::<i64, _>("total_score"),
+                "reasoning": parse_reasoning(row.get::<Option<String>, _>("reasoning")),
+            })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// 获取退役球衣（可按战队筛选）
+#[tauri::command]
+pub async fn get_retired_jerseys(
+    state: State<'_, AppState>,
+    team_id: Option<i64>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let guard = state.db.read().await;
+    let db = guard
+        .as_ref()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+
+    let pool = db
+        .get_pool()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let current_save = state.current_save_id.read().await;
+    let save_id = current_save
+        .as_ref()
+        .ok_or_else(|| "No save loaded".to_string())?
+        .clone();
+
+    let rows = sqlx::query(
+        r#"
+        SELECT j.*, t.name AS team_name, p.position
+        FROM retired_jerseys j
+        JOIN teams t ON t.id = j.team_id AND t.save_id = j.save_id
+        LEFT JOIN players p ON p.id = j.player_id AND p.save_id = j.save_id
+        WHERE j.save_id = ? AND (? IS NULL OR j.team_id = ?)
+        ORDER BY j.retired_season DESC, j.id
+        "#,
+    )
+    .bind(&save_id)
+    .bind(team_id)
+    .bind(team_id)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("查询退役球衣失败: {}", e))?;
+
+    let entries: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            serde_json::json!({
+                "id": row.get::<i64, _>("id"),
+                "team_id": row.get::<i64, _>("team_id"),
+                "team_name": row.get::<String, _>("team_name"),
+                "player_id": row.get::<i64, _>("player_id"),
+                "player_name": row.get::<String, _>("player_name"),
+                "position": row.get::<Option<String>, _>("position"),
+                "seasons_with_team": row.get::<i64, _>("seasons_with_team"),
+                "retired_season": row.get::<i64, _>("retired_season"),
+            })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+fn record_type_name(record_type: &str) -> String {
+    RecordType::from_id(record_type)
+        .map(|t| t.name().to_string())
+        .unwrap_or_else(|| record_type.to_string())
+}
+
+fn record_display_value(record_type: &str, value: f64) -> String {
+    RecordType::from_id(record_type)
+        .map(|t| t.format_value(value))
+        .unwrap_or_else(|| value.to_string())
+}
+
+/// 获取纪录簿（可按范围筛选：GLOBAL / REGION / LEAGUE）
+#[tauri::command]
+pub async fn get_records_book(
+    state: State<'_, AppState>,
+    scope_type: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let guard = state.db.read().await;
+    let db = guard
+        .as_ref()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+
+    let pool = db
+        .get_pool()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let current_save = state.current_save_id.read().await;
+    let save_id = current_save
+        .as_ref()
+        .ok_or_else(|| "No save loaded".to_string())?
+        .clone();
+
+    let rows = sqlx::query(
+        r#"
+        SELECT * FROM records_book
+        WHERE save_id = ? AND (? IS NULL OR scope_type = ?)
+        ORDER BY CASE scope_type WHEN 'GLOBAL' THEN 0 WHEN 'REGION' THEN 1 ELSE 2 END, scope_key, record_type
+        "#,
+    )
+    .bind(&save_id)
+    .bind(&scope_type)
+    .bind(&scope_type)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("查询纪录簿失败: {}", e))?;
+
+    let entries: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            let record_type: String = row.get("record_type");
+            let value: f64 = row.get("value");
+            serde_json::json!({
+                "record_type": record_type,
+                "record_name": record_type_name(&record_type),
+                "scope_type": row.get::<String, _>("scope_type"),
+                "scope_key": row.get::<String, _>("scope_key"),
+                "scope_label": row.get::<String, _>("scope_label"),
+                "holder_kind": row.get::<String, _>("holder_kind"),
+                "holder_id": row.get::<i64, _>("holder_id"),
+                "holder_name": row.get::<String, _>("holder_name"),
+                "value": value,
+                "display_value": record_display_value(&record_type, value),
+                "season_id": row.get::<i64, _>("season_id"),
+                "tournament_id": row.get::<Option<i64>, _>("tournament_id"),
+                "match_id": row.get::<Option<i64>, _>("match_id"),
+            })
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// 获取某项纪录的历史（每次被刷新的记录，最新在前）
+#[tauri::command]
+pub async fn get_record_history(
+    state: State<'_, AppState>,
+    record_type: String,
+    scope_type: String,
+    scope_key: Option<String>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let guard = state.db.read().await;
+    let db = guard
+        .as_ref()
+        .ok_or_else(|| "Database not initialized".to_string())?;
+
+    let pool = db
+        .get_pool()
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let current_save = state.current_save_id.read().await;
+    let save_id = current_save
+        .as_ref()
+        .ok_or_else(|| "No save loaded".to_string())?
+        .clone();
+
+    let rows = sqlx::query(
+        r#"
+        SELECT * FROM record_history
+        WHERE save_id = ? AND record_type = ? AND scope_type = ? AND scope_key = ?
+        ORDER BY id DESC
+        "#,
+    )
+    .bind(&save_id)
+    .bind(&record_type)
+    .bind(&scope_type)
+    .bind(scope_key.unwrap_or_default())
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("查询纪录历史失败: {}", e))?;
+
+    let entries: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            let value: f64 = row.get("value");
+            let previous_value: Option<f64> = row.get("previous_value");
+            serde_json::json!({
+                "id": row.get::<i64, _>("id"),
+                "record_type": &record_type,
+                "scope_label": row.get::<String, _>("scope_label"),
+                "holder_kind": row.get::<String, _>("holder_kind"),
+                "holder_id": row.get::<i64, _>("holder_id"),
+                "holder_name": row.get::<String, _>("holder_name"),
+                "value": value,
+                "display_value": record_display_value(&record_type, value),
+                "previous_holder_name": row.get::<Option<String>, _>("previous_holder_name"),
+                "previous_value": previous_value,
+                "previous_display_value": previous_value.map(|v| record_display_value(&record_type, v)),
+                "season_id": row.get::<i64, _>("season_id"),
+                "tournament_id": row.get::<Option<i64>, _>("tournament_id"),
+                "match_id": row.get::<Option<i64>, _>("match_id"),
             })
         })
         .collect();
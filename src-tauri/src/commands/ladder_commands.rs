@@ -1,8 +1,16 @@
 use crate::commands::save_commands::{AppState, CommandResult};
-use crate::engines::ladder::{LadderMatchmaker, LadderRatingEngine, LadderSimulator};
+use crate::engines::champion_patch::ChampionPatchEngine;
+use crate::engines::comp_phase::CompPhaseEngine;
+use crate::engines::ladder::{
+    AmateurKind, LadderMatchmaker, LadderProfile, LadderProfileEngine, LadderRatingEngine, LadderSeasonRecord,
+    LadderSimulator, MatchmakingHistory, PlayerRating, RatingSystem,
+};
+use crate::engines::ladder::matchmaker::REPEAT_MEMORY_ROUNDS;
 use crate::engines::ladder::matchmaker::LadderPlayer;
 use crate::engines::ladder::simulator::PlayerFullData;
 use crate::engines::traits::TraitType;
+use crate::models::ScoutSubject;
+use crate::services::GameFlowService;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
 use std::collections::HashMap;
@@ -19,6 +27,8 @@ pub struct LadderTournamentInfo {
     pub total_rounds: i32,
     pub current_round: i32,
     pub status: String,
+    #[serde(default)]
+    pub rating_system: RatingSystem,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -37,6 +47,10 @@ pub struct LadderRankingEntry {
     pub mvp_count: i32,
     pub avg_influence: f64,
     pub max_rating: i32,
+    pub rating_deviation: f64,
+    pub volatility: f64,
+    /// 路人/主播类型（职业选手为空）
+    pub amateur_kind: Option<AmateurKind>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -50,6 +64,8 @@ pub struct LadderMatchInfo {
     pub red_avg_rating: i32,
     pub winner_side: Option<String>,
     pub mvp_player_name: Option<String>,
+    pub match_quality: Option<f64>,
+    pub queue_time_secs: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -151,6 +167,9 @@ pub async fn initialize_ladder_tournament(
         .map_err(|e| e.to_string())?;
     }
 
+    GameFlowService::enroll_ladder_amateurs(&pool, &save_id, tournament_id).await?;
+    LadderProfileEngine::carry_into_event(&pool, &save_id, tournament_id).await?;
+
     Ok(CommandResult::ok(LadderTournamentInfo {
         id: tournament_id,
         save_id,
@@ -161,6 +180,7 @@ pub async fn initialize_ladder_tournament(
         total_rounds: 12,
         current_round: 0,
         status: "pending".to_string(),
+        rating_system: RatingSystem::default(),
     }))
 }
 
@@ -181,31 +201,86 @@ pub async fn simulate_ladder_round(
 
     let next_round = current_round + 1;
 
-    let players: Vec<LadderPlayer> = sqlx::query_as::<_, (i64, String, String, String, Option<String>, i32)>(
-        "SELECT player_id, player_name, game_id, position, team_name, rating FROM ladder_rating WHERE ladder_tournament_id = ?"
+    let rating_system: String = sqlx::query_scalar(
+        "SELECT rating_system FROM ladder_tournament WHERE id = ?"
+    )
+    .bind(tournament_id)
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    let rating_system = RatingSystem::parse(&rating_system).unwrap_or_default();
+
+    let rating_rows = sqlx::query_as::<_, (i64, String, String, String, Option<String>, i32, f64, f64, i32)>(
+        "SELECT player_id, player_name, game_id, position, team_name, rating, rating_deviation, volatility, games_played FROM ladder_rating WHERE ladder_tournament_id = ?"
     )
     .bind(tournament_id)
     .fetch_all(&pool)
     .await
-    .map_err(|e| e.to_string())?
-    .into_iter()
-    .map(|(player_id, player_name, game_id, position, team_name, rating)| LadderPlayer {
-        player_id,
-        player_name,
-        game_id,
-        position,
-        team_name,
-        rating,
-    })
-    .collect();
+    .map_err(|e| e.to_string())?;
 
-    let mut bye_players = Vec::new();
-    let matches = LadderMatchmaker::create_round_matches(players, &mut bye_players);
+    let player_ratings: HashMap<i64, PlayerRating> = rating_rows.iter()
+        .map(|(player_id, _, _, _, _, rating, deviation, volatility, _)| {
+            (*player_id, PlayerRating {
+                rating: *rating as f64,
+                deviation: *deviation,
+                volatility: *volatility,
+            })
+        })
+        .collect();
 
-    let version_tiers: HashMap<u8, crate::engines::champion::VersionTier> = 
-        crate::engines::champion::calculate_version_tiers(crate::engines::meta_engine::MetaType::Balanced)
+    // 匹配历史：已轮空次数 = 已进行轮数 - 已参赛场次；近几轮的相遇与队友关系
+    let mut history = MatchmakingHistory::default();
+    for (player_id, _, _, _, _, _, _, _, games_played) in &rating_rows {
+        history.bye_counts.insert(*player_id, (current_round - games_played).max(0) as u32);
+    }
+    let recent_teams: Vec<(String, String)> = sqlx::query_as(
+        "SELECT blue_team_json, red_team_json FROM ladder_match WHERE ladder_tournament_id = ? AND round_number > ?"
+    )
+    .bind(tournament_id)
+    .bind(current_round - REPEAT_MEMORY_ROUNDS)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    for (blue_json, red_json) in &recent_teams {
+        let ids = |json: &str| -> Vec<i64> {
+            serde_json::from_str::<Vec<LadderPlayerInfo>>(json)
+                .unwrap_or_default()
+                .iter()
+                .map(|p| p.player_id)
+                .collect()
+        };
+        history.record_match(&ids(blue_json), &ids(red_json));
+    }
+
+    let players: Vec<LadderPlayer> = rating_rows
         .into_iter()
+        .map(|(player_id, player_name, game_id, position, team_name, rating, _, _, _)| LadderPlayer {
+            player_id,
+            player_name,
+            game_id,
+            position,
+            team_name,
+            rating,
+        })
         .collect();
+
+    let mut bye_players = Vec::new();
+    let matches = LadderMatchmaker::create_round_matches(players, &mut bye_players, &history);
+
+    let ladder_season: i64 = sqlx::query_scalar(
+        "SELECT season FROM ladder_tournament WHERE id = ?"
+    )
+    .bind(tournament_id)
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    let version_tiers: HashMap<u8, crate::engines::champion::VersionTier> = ChampionPatchEngine::load_version_tiers(
+        &pool,
+        &save_id,
+        ladder_season,
+        crate::engines::meta_engine::MetaType::Balanced,
+    )
+    .await;
     
     let mastery_rows: Vec<(i64, i32, String)> = sqlx::query_as(
         "SELECT player_id, champion_id, mastery_tier FROM player_champion_mastery WHERE save_id = ?"
@@ -237,24 +312,26 @@ pub async fn simulate_ladder_round(
         }
     }
 
-    let player_full_data: HashMap<i64, PlayerFullData> = sqlx::query_as::<_, (i64, i32)>(
-        "SELECT id, ability FROM players WHERE save_id = ?"
+    let player_full_data: HashMap<i64, PlayerFullData> = sqlx::query_as::<_, (i64, i32, i32, i32)>(
+        "SELECT id, ability, stability, age FROM players WHERE save_id = ?"
     )
     .bind(&save_id)
     .fetch_all(&pool)
     .await
     .map_err(|e| e.to_string())?
     .into_iter()
-    .map(|(id, ability)| {
+    .map(|(id, ability, stability, age)| {
         (id, PlayerFullData {
             ability: ability as u8,
+            stability: stability as u8,
+            age: age as u8,
             masteries: mastery_map.remove(&id).unwrap_or_default(),
             traits: trait_map.remove(&id).unwrap_or_default(),
         })
     })
     .collect();
 
-    let simulator = LadderSimulator::new();
+    let simulator = LadderSimulator::with_phase_ruleset(CompPhaseEngine::load_ruleset(&pool, &save_id).await);
 
     for (match_number, ladder_match) in matches.iter().enumerate() {
         let result = simulator.simulate_match(
@@ -269,17 +346,18 @@ pub async fn simulate_ladder_round(
         let red_team_json = serde_json::to_string(&result.red_team).unwrap_or_default();
         let performances_json = serde_json::to_string(&result.performances).unwrap_or_default();
 
-        let blue_players: Vec<(i64, i32)> = ladder_match.blue_team.players.iter()
-            .map(|p| (p.player_id, p.rating))
-            .collect();
-        let red_players: Vec<(i64, i32)> = ladder_match.red_team.players.iter()
-            .map(|p| (p.player_id, p.rating))
-            .collect();
+        let rating_of = |p: &LadderPlayer| {
+            (p.player_id, player_ratings.get(&p.player_id).copied().unwrap_or_default())
+        };
+        let blue_players: Vec<(i64, PlayerRating)> = ladder_match.blue_team.players.iter().map(rating_of).collect();
+        let red_players: Vec<(i64, PlayerRating)> = ladder_match.red_team.players.iter().map(rating_of).collect();
 
         let rating_updates = LadderRatingEngine::calculate_rating_changes(
+            rating_system,
             &blue_players,
             &red_players,
-            &result.performances,
+            ladder_match.blue_avg_rating,
+            ladder_match.red_avg_rating,
             &result.performances,
             &result.winner_side,
         );
@@ -288,6 +366,15 @@ pub async fn simulate_ladder_round(
             .map(|u| (u.player_id.to_string(), u.rating_change))
             .collect();
         let rating_changes_json = serde_json::to_string(&rating_changes_map).unwrap_or_default();
+        let rating_details_map: HashMap<String, RatingDetail> = rating_updates.iter()
+            .map(|u| (u.player_id.to_string(), RatingDetail {
+                rating: u.new_rating,
+                deviation: u.new_deviation,
+                volatility: u.new_volatility,
+                expected_score: u.expected_score,
+            }))
+            .collect();
+        let rating_details_json = serde_json::to_string(&rating_details_map).unwrap_or_default();
 
         sqlx::query(
             r#"
@@ -295,8 +382,9 @@ pub async fn simulate_ladder_round(
                 save_id, ladder_tournament_id, round_number, match_number,
                 blue_team_json, red_team_json, blue_avg_rating, red_avg_rating,
                 blue_power, red_power, winner_side, mvp_player_id, mvp_player_name,
-                game_duration, performances_json, draft_result_json, rating_changes_json
-            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                game_duration, performances_json, draft_result_json, rating_changes_json,
+                rating_details_json, match_quality, queue_time_secs
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(&save_id)
@@ -316,6 +404,9 @@ pub async fn simulate_ladder_round(
         .bind(performances_json)
         .bind(result.draft_result.as_deref())
         .bind(&rating_changes_json)
+        .bind(&rating_details_json)
+        .bind(ladder_match.quality)
+        .bind(ladder_match.queue_time_secs as i64)
         .execute(&pool)
         .await
         .map_err(|e| e.to_string())?;
@@ -330,6 +421,8 @@ pub async fn simulate_ladder_round(
                 r#"
                 UPDATE ladder_rating
                 SET rating = ?,
+                    rating_deviation = ?,
+                    volatility = ?,
                     games_played = games_played + 1,
                     wins = wins + ?,
                     losses = losses + ?,
@@ -341,6 +434,8 @@ pub async fn simulate_ladder_round(
                 "#
             )
             .bind(update.new_rating)
+            .bind(update.new_deviation)
+            .bind(update.new_volatility)
             .bind(if is_winner { 1 } else { 0 })
             .bind(if is_winner { 0 } else { 1 })
             .bind(if is_mvp { 1 } else { 0 })
@@ -373,11 +468,12 @@ pub async fn get_ladder_rankings(
     tournament_id: i64,
 ) -> Result<CommandResult<Vec<LadderRankingEntry>>, String> {
     let (pool, _save_id) = get_pool_and_save!(state);
-    let rankings: Vec<(i64, String, String, String, Option<String>, i32, i32, i32, i32, i32, f64, i32)> = sqlx::query_as(
+    let rankings: Vec<(i64, String, String, String, Option<String>, i32, i32, i32, i32, i32, f64, i32, f64, f64, Option<String>)> = sqlx::query_as(
         r#"
-        SELECT lr.player_id, lr.player_name, COALESCE(NULLIF(lr.game_id, ''), p.game_id, lr.player_name) as game_id, lr.position, lr.team_name, lr.rating, lr.games_played, lr.wins, lr.losses, lr.mvp_count, lr.avg_influence, lr.max_rating
+        SELECT lr.player_id, lr.player_name, COALESCE(NULLIF(lr.game_id, ''), p.game_id, lr.player_name) as game_id, lr.position, lr.team_name, lr.rating, lr.games_played, lr.wins, lr.losses, lr.mvp_count, lr.avg_influence, lr.max_rating, lr.rating_deviation, lr.volatility, la.kind
         FROM ladder_rating lr
         LEFT JOIN players p ON lr.player_id = p.id
+        LEFT JOIN ladder_amateur la ON la.save_id = lr.save_id AND la.player_id = lr.player_id
         WHERE lr.ladder_tournament_id = ?
         ORDER BY lr.rating DESC, lr.wins DESC, lr.mvp_count DESC
         "#
@@ -409,6 +505,9 @@ pub async fn get_ladder_rankings(
             mvp_count: row.9,
             avg_influence: row.10,
             max_rating: row.11,
+            rating_deviation: row.12,
+            volatility: row.13,
+            amateur_kind: row.14.as_deref().and_then(AmateurKind::parse),
         }
     }).collect();
 
@@ -424,8 +523,8 @@ pub async fn get_ladder_matches(
     let (pool, _save_id) = get_pool_and_save!(state);
 
     let rows = if let Some(round) = round_number {
-        sqlx::query_as::<_, (i64, i32, i32, String, String, i32, i32, Option<String>, Option<String>)>(
-            "SELECT id, round_number, match_number, blue_team_json, red_team_json, blue_avg_rating, red_avg_rating, winner_side, mvp_player_name FROM ladder_match WHERE ladder_tournament_id = ? AND round_number = ? ORDER BY match_number"
+        sqlx::query_as::<_, (i64, i32, i32, String, String, i32, i32, Option<String>, Option<String>, Option<f64>, Option<i32>)>(
+            "SELECT id, round_number, match_number, blue_team_json, red_team_json, blue_avg_rating, red_avg_rating, winner_side, mvp_player_name, match_quality, queue_time_secs FROM ladder_match WHERE ladder_tournament_id = ? AND round_number = ? ORDER BY match_number"
         )
         .bind(tournament_id)
         .bind(round)
@@ -433,8 +532,8 @@ pub async fn get_ladder_matches(
         .await
         .map_err(|e| e.to_string())?
     } else {
-        sqlx::query_as::<_, (i64, i32, i32, String, String, i32, i32, Option<String>, Option<String>)>(
-            "SELECT id, round_number, match_number, blue_team_json, red_team_json, blue_avg_rating, red_avg_rating, winner_side, mvp_player_name FROM ladder_match WHERE ladder_tournament_id = ? ORDER BY round_number, match_number"
+        sqlx::query_as::<_, (i64, i32, i32, String, String, i32, i32, Option<String>, Option<String>, Option<f64>, Option<i32>)>(
+            "SELECT id, round_number, match_number, blue_team_json, red_team_json, blue_avg_rating, red_avg_rating, winner_side, mvp_player_name, match_quality, queue_time_secs FROM ladder_match WHERE ladder_tournament_id = ? ORDER BY round_number, match_number"
         )
         .bind(tournament_id)
         .fetch_all(&pool)
@@ -473,6 +572,8 @@ pub async fn get_ladder_matches(
             red_avg_rating: row.6,
             winner_side: row.7,
             mvp_player_name: row.8,
+            match_quality: row.9,
+            queue_time_secs: row.10,
         }
     }).collect();
 
@@ -497,6 +598,8 @@ pub struct LadderMatchDetail {
     pub performances: Option<HashMap<String, f64>>,
     pub draft_result_json: Option<String>,
     pub rating_changes: Option<HashMap<String, i32>>,
+    pub match_quality: Option<f64>,
+    pub queue_time_secs: Option<i32>,
 }
 
 #[tauri::command]
@@ -511,7 +614,8 @@ pub async fn get_ladder_match_detail(
         SELECT id, round_number, match_number, blue_team_json, red_team_json,
                blue_avg_rating, red_avg_rating, blue_power, red_power,
                winner_side, mvp_player_id, mvp_player_name, game_duration,
-               performances_json, draft_result_json, rating_changes_json
+               performances_json, draft_result_json, rating_changes_json,
+               match_quality, queue_time_secs
         FROM ladder_match WHERE id = ?
         "#
     )
@@ -574,6 +678,8 @@ pub async fn get_ladder_match_detail(
         performances,
         draft_result_json: row.get("draft_result_json"),
         rating_changes,
+        match_quality: row.get("match_quality"),
+        queue_time_secs: row.get("queue_time_secs"),
     }))
 }
 
@@ -598,7 +704,7 @@ pub async fn complete_ladder_tournament(
     state: State<'_, AppState>,
     tournament_id: i64,
 ) -> Result<CommandResult<LadderCompletionResult>, String> {
-    let (pool, _save_id) = get_pool_and_save!(state);
+    let (pool, save_id) = get_pool_and_save!(state);
     let tournament_info: (i32, i32, String) = sqlx::query_as(
         "SELECT current_round, total_rounds, status FROM ladder_tournament WHERE id = ?"
     )
@@ -644,6 +750,9 @@ pub async fn complete_ladder_tournament(
     .await
     .map_err(|e| e.to_string())?;
 
+    LadderProfileEngine::sync_from_event(&pool, &save_id, tournament_id).await?;
+    GameFlowService::review_ladder_amateurs(&pool, &save_id, tournament_id).await?;
+
     Ok(CommandResult::ok(LadderCompletionResult {
         total_players: rankings.len() as i32,
         rewards_distributed: rewards,
@@ -673,7 +782,7 @@ pub async fn get_ladder_tournaments(
     };
 
     let rows = sqlx::query(
-        "SELECT id, save_id, season, event_type, event_name, edition, total_rounds, current_round, status FROM ladder_tournament WHERE save_id = ? AND season = ? ORDER BY id"
+        "SELECT id, save_id, season, event_type, event_name, edition, total_rounds, current_round, status, rating_system FROM ladder_tournament WHERE save_id = ? AND season = ? ORDER BY id"
     )
     .bind(&save_id)
     .bind(season_id)
@@ -692,19 +801,66 @@ pub async fn get_ladder_tournaments(
             total_rounds: row.get("total_rounds"),
             current_round: row.get("current_round"),
             status: row.get("status"),
+            rating_system: RatingSystem::parse(row.get::<String, _>("rating_system").as_str()).unwrap_or_default(),
         }
     }).collect();
 
     Ok(CommandResult::ok(tournaments))
 }
 
+/// 单局结算后的积分状态（存入 ladder_match.rating_details_json）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RatingDetail {
+    pub rating: i32,
+    pub deviation: f64,
+    pub volatility: f64,
+    pub expected_score: f64,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RatingHistoryPoint {
     pub round: i32,
     pub rating: i32,
     pub change: i32,
+    pub deviation: f64,
+    pub volatility: f64,
+    /// 该局赛前预期胜率（起点为空）
+    pub expected_score: Option<f64>,
 }
 
+/// 设置天梯赛积分系统，只能在第一轮开始前修改
+#[tauri::command]
+pub async fn set_ladder_rating_system(
+    state: State<'_, AppState>,
+    tournament_id: i64,
+    rating_system: RatingSystem,
+) -> Result<CommandResult<()>, String> {
+    let (pool, save_id) = get_pool_and_save!(state);
+
+    let current_round: Option<i32> = sqlx::query_scalar(
+        "SELECT current_round FROM ladder_tournament WHERE id = ? AND save_id = ?"
+    )
+    .bind(tournament_id)
+    .bind(&save_id)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| format!("查询天梯赛失败: {}", e))?;
+
+    match current_round {
+        None => return Ok(CommandResult::err("天梯赛不存在")),
+        Some(round) if round > 0 => return Ok(CommandResult::err("天梯赛已开始，无法更换积分系统")),
+        _ => {}
+    }
+
+    sqlx::query("UPDATE ladder_tournament SET rating_system = ? WHERE id = ?")
+        .bind(rating_system.as_str())
+        .bind(tournament_id)
+        .execute(&pool)
+        .await
+        .map_err(|e| format!("更新积分系统失败: {}", e))?;
+
+    Ok(CommandResult::ok(()))
+}
 #[tauri::command]
 pub async fn get_player_ladder_rating_history(
     state: State<'_, AppState>,
@@ -715,7 +871,7 @@ pub async fn get_player_ladder_rating_history(
 
     let rows = sqlx::query(
         r#"
-        SELECT round_number, rating_changes_json
+        SELECT round_number, rating_changes_json, rating_details_json
         FROM ladder_match
         WHERE ladder_tournament_id = ? AND rating_changes_json IS NOT NULL
         ORDER BY round_number ASC, match_number ASC
@@ -727,23 +883,50 @@ pub async fn get_player_ladder_rating_history(
     .map_err(|e| e.to_string())?;
 
     let mut history: Vec<RatingHistoryPoint> = Vec::new();
-    let mut current_rating = 1200i32;
-
-    history.push(RatingHistoryPoint { round: 0, rating: current_rating, change: 0 });
+    let initial = PlayerRating::default();
+    // 本届起始积分来自跨赛季天梯档案
+    let start: Option<(i32, f64)> = sqlx::query_as(
+        "SELECT start_rating, start_deviation FROM ladder_rating WHERE ladder_tournament_id = ? AND player_id = ?"
+    )
+    .bind(tournament_id)
+    .bind(player_id)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    let (start_rating, start_deviation) = start.unwrap_or((initial.rating as i32, initial.deviation));
+    let mut current_rating = start_rating;
+
+    history.push(RatingHistoryPoint {
+        round: 0,
+        rating: current_rating,
+        change: 0,
+        deviation: start_deviation,
+        volatility: initial.volatility,
+        expected_score: None,
+    });
 
     for row in &rows {
         let round_number: i32 = row.get("round_number");
         let json: Option<String> = row.get("rating_changes_json");
+        let details: Option<HashMap<String, RatingDetail>> = row.get::<Option<String>, _>("rating_details_json")
+            .and_then(|json| serde_json::from_str(&json).ok());
         if let Some(json_str) = json {
             if let Ok(changes) = serde_json::from_str::<HashMap<String, i32>>(&json_str) {
                 let pid_str = player_id.to_string();
                 if let Some(&change) = changes.get(&pid_str) {
                     current_rating += change;
+                    // 旧存档对局没有积分详情，沿用上一个点的偏差与波动率
+                    let prev = history.last().map(|p| (p.deviation, p.volatility)).unwrap_or((initial.deviation, initial.volatility));
+                    let detail = details.as_ref().and_then(|d| d.get(&pid_str));
                     history.push(RatingHistoryPoint {
                         round: round_number,
-                        rating: current_rating,
+                        rating: detail.map(|d| d.rating).unwrap_or(current_rating),
                         change,
+                        deviation: detail.map(|d| d.deviation).unwrap_or(prev.0),
+                        volatility: detail.map(|d| d.volatility).unwrap_or(prev.1),
+                        expected_score: detail.map(|d| d.expected_score),
                     });
+                    current_rating = history.last().map(|p| p.rating).unwrap_or(current_rating);
                 }
             }
         }
@@ -751,3 +934,37 @@ pub async fn get_player_ladder_rating_history(
 
     Ok(CommandResult::ok(history))
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LadderProfileDetail {
+    pub profile: Option<LadderProfile>,
+    pub seasons: Vec<LadderSeasonRecord>,
+}
+
+/// 获取选手的跨赛季天梯档案与往届定级
+#[tauri::command]
+pub async fn get_ladder_profile(
+    state: State<'_, AppState>,
+    player_id: i64,
+) -> Result<CommandResult<LadderProfileDetail>, String> {
+    let (pool, save_id) = get_pool_and_save!(state);
+
+    let profile = LadderProfileEngine::load_profile(&pool, &save_id, ScoutSubject::Player, player_id as u64).await?;
+    let seasons = LadderProfileEngine::season_history(&pool, &save_id, ScoutSubject::Player, player_id as u64).await?;
+
+    Ok(CommandResult::ok(LadderProfileDetail { profile, seasons }))
+}
+
+/// 获取跨赛季天梯总榜
+#[tauri::command]
+pub async fn get_ladder_leaderboard(
+    state: State<'_, AppState>,
+    limit: Option<u32>,
+) -> Result<CommandResult<Vec<LadderProfile>>, String> {
+    let (pool, save_id) = get_pool_and_save!(state);
+
+    match LadderProfileEngine::leaderboard(&pool, &save_id, ScoutSubject::Player, limit.unwrap_or(100)).await {
+        Ok(list) => Ok(CommandResult::ok(list)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
@@ -1,5 +1,7 @@
 // Type definitions and public API re-export
 use serde::{Deserialize, Serialize};
+use crate::engines::game_timeline::TimelinePlayer;
+use crate::engines::series_tactics::GameTactics;
 
 // ==================== 类型定义 ====================
 
@@ -42,6 +44,9 @@ pub struct DetailedGameResult {
     pub away_bp_bonus: Option<f64>,
     pub home_version_bonus: Option<f64>,
     pub away_version_bonus: Option<f64>,
+    // 局间战术（第一局为空）
+    pub home_tactics: Option<GameTactics>,
+    pub away_tactics: Option<GameTactics>,
 }
 
 /// 球员MVP信息
@@ -93,6 +98,16 @@ pub struct PlayerGameStats {
     pub activated_traits: Vec<ActivatedTraitInfo>,  // 本局激活的特性效果
 }
 
+impl From<&PlayerGameStats> for TimelinePlayer {
+    fn from(p: &PlayerGameStats) -> Self {
+        TimelinePlayer {
+            player_id: p.player_id,
+            position: p.position.clone(),
+            performance: p.actual_ability,
+        }
+    }
+}
+
 /// 激活的特性效果信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActivatedTraitInfo {
@@ -203,6 +218,50 @@ impl TeamMatchStats {
     }
 }
 
+impl PlayerGameStats {
+    /// 用局内时间线推导的数据覆盖随机生成的 KDA/经济/补刀；
+    /// 伤害/承伤/视野保留原随机值的每分钟强度，按新时长与时间线中的参团/阵亡变化折算，保证 MVP 分与时间线一致
+    pub fn apply_timeline_line(
+        &mut self,
+        line: &crate::models::TimelinePlayerLine,
+        old_duration: u32,
+        new_duration: u32,
+    ) {
+        let duration_ratio = if old_duration > 0 { new_duration as f64 / old_duration as f64 } else { 1.0 };
+        let involvement_ratio =
+            ((1 + line.kills + line.assists) as f64 / (1 + self.kills + self.assists) as f64).clamp(0.5, 2.0);
+        let death_ratio = ((1 + line.deaths) as f64 / (1 + self.deaths) as f64).clamp(0.5, 2.0);
+
+        self.damage_dealt = (self.damage_dealt as f64 * duration_ratio * involvement_ratio) as u64;
+        self.damage_taken = (self.damage_taken as f64 * duration_ratio * death_ratio) as u64;
+        self.vision_score = (self.vision_score as f64 * duration_ratio) as u32;
+
+        self.kills = line.kills;
+        self.deaths = line.deaths;
+        self.assists = line.assists;
+        self.cs = line.cs;
+        self.gold = line.gold;
+
+        let kda = if self.deaths > 0 {
+            (self.kills + self.assists) as f64 / self.deaths as f64
+        } else {
+            (self.kills + self.assists) as f64
+        };
+        self.mvp_score = kda * 0.4 + (self.damage_dealt as f64 / 10000.0) * 0.3 + (self.gold as f64 / 10000.0) * 0.3;
+    }
+}
+
+impl From<&crate::models::TimelineEvent> for GameEvent {
+    fn from(e: &crate::models::TimelineEvent) -> Self {
+        Self {
+            time_minutes: e.minute,
+            event_type: e.kind.as_str().to_string(),
+            description: e.description.clone(),
+            team_id: e.team_id,
+        }
+    }
+}
+
 // ==================== 模块声明 ====================
 
 pub mod simulation;
@@ -1,14 +1,22 @@
 use crate::commands::save_commands::{AppState, CommandResult};
-use crate::engines::{ConditionContext, ConditionEngine, PlayerFormFactors, TraitType, TraitEngine, TraitContext, MetaEngine, MetaWeights};
+use crate::engines::{ConditionContext, ConditionEngine, MatchContext, PlayerFormFactors, RivalryEngine, TraitType, TraitContext, MetaEngine, MetaWeights};
 use crate::engines::lineup_engine::{LineupCandidate, LineupEngine, SubstitutionContext, SubstitutionDecision};
-use crate::engines::bp_engine::{BpEngine, CompType, PlayerChampionPool, SeriesContext, TeamSide};
-use crate::engines::champion::{self, MasteryTier, VersionTier};
+use crate::engines::bp_engine::{BpEngine, PlayerChampionPool, SeriesContext, TeamSide};
+use crate::engines::champion::{MasteryTier, VersionTier};
+use crate::engines::champion_patch::ChampionPatchEngine;
+use crate::engines::draft_rules::DraftRulesEngine;
+use crate::engines::draft_scouting::DraftScoutingEngine;
+use crate::engines::game_timeline::{GameTimelineEngine, TimelineInput, TimelinePlayer};
 use crate::engines::meta_engine::MetaType;
+use crate::engines::series_tactics::SeriesTacticsEngine;
+use crate::engines::sim_core::{PerformanceInput, SimulationCore};
+use crate::engines::side_selection::SideSelectionEngine;
+use crate::engines::SeriesRecordInput;
 use crate::models::MatchFormat;
 use crate::models::player::Position;
 use crate::models::transfer::AITeamPersonality;
 use crate::models::tournament_result::PlayerTournamentStats;
-use crate::services::LeagueService;
+use crate::services::{GameFlowService, LeagueService};
 use crate::db::{MatchRepository, PlayerTournamentStatsRepository};
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
@@ -248,8 +256,8 @@ async fn simulate_single_match_internal(
     let tournament_id: i64 = match_row.get("tournament_id");
     let home_team_id: i64 = match_row.get("home_team_id");
     let away_team_id: i64 = match_row.get("away_team_id");
-    let _home_power: f64 = match_row.get("home_power");
-    let _away_power: f64 = match_row.get("away_power");
+    let home_power: f64 = match_row.get("home_power");
+    let away_power: f64 = match_row.get("away_power");
     let format_str: String = match_row.get("format");
     let stage: String = match_row.get("stage");
     let home_team_name: String = match_row.get("home_team_name");
@@ -268,6 +276,33 @@ async fn simulate_single_match_internal(
     let (mut home_players, mut home_bench) = get_match_roster(pool, &ctx.save_id, home_team_id as u64, ctx.current_season).await?;
     let (mut away_players, mut away_bench) = get_match_roster(pool, &ctx.save_id, away_team_id as u64, ctx.current_season).await?;
 
+    // 宿敌之战：赛前按比赛压力重新计算双方状态
+    let is_rivalry = RivalryEngine::is_rivalry(pool, &ctx.save_id, home_team_id, away_team_id)
+        .await
+        .unwrap_or(false);
+    if is_rivalry {
+        let rivalry_ctx = MatchContext { game_number: 1, is_rivalry: true, ..Default::default() };
+        for player in home_players
+            .iter_mut()
+            .chain(away_players.iter_mut())
+            .chain(home_bench.iter_mut())
+            .chain(away_bench.iter_mut())
+        {
+            let condition_ctx = build_condition_context(
+                player.satisfaction,
+                player.season_games_played,
+                player.traits.contains(&TraitType::Ironman),
+                Some(&rivalry_ctx),
+            );
+            player.condition = ConditionEngine::calculate_condition_full(
+                player.age,
+                player.ability,
+                &player.form_factors,
+                &condition_ctx,
+            );
+        }
+    }
+
     // 获取队伍 personality（用于换人决策）
     let home_personality = get_team_personality(pool, home_team_id as u64).await;
     let away_personality = get_team_personality(pool, away_team_id as u64).await;
@@ -441,7 +476,7 @@ async fn simulate_single_match_internal(
     
     // 获取Meta类型和版本权重
     let meta_type_row: Option<String> = sqlx::query_scalar(
-        "SELECT meta_type FROM meta_versions WHERE save_id = ? AND season_id = ? LIMIT 1"
+        "SELECT meta_type FROM meta_versions WHERE save_id = ? AND season_id = ? ORDER BY patch_number DESC LIMIT 1"
     )
     .bind(&ctx.save_id)
     .bind(ctx.current_season)
@@ -452,14 +487,16 @@ async fn simulate_single_match_internal(
     let meta_type = meta_type_row
         .and_then(|s| MetaType::from_id(&s))
         .unwrap_or(MetaType::Balanced);
-    let version_tiers: HashMap<u8, VersionTier> = champion::calculate_version_tiers(meta_type)
-        .into_iter()
-        .collect();
+    let version_tiers: HashMap<u8, VersionTier> =
+        ChampionPatchEngine::load_version_tiers(pool, &ctx.save_id, ctx.current_season, meta_type).await;
     
     let mut bp_rng = StdRng::from_entropy();
-    let home_team_comp_history = load_team_comp_history(pool, &ctx.save_id, home_team_id).await;
-    let away_team_comp_history = load_team_comp_history(pool, &ctx.save_id, away_team_id).await;
+    let home_scouting = DraftScoutingEngine::load_report(pool, &ctx.save_id, home_team_id as u64).await;
+    let away_scouting = DraftScoutingEngine::load_report(pool, &ctx.save_id, away_team_id as u64).await;
     let mut series_ctx: Option<SeriesContext> = None;
+    let higher_seed = SideSelectionEngine::higher_seed(home_power, away_power);
+    let mut draft_rules = DraftRulesEngine::load_series_rules(pool, &ctx.save_id, tournament_id as u64, &stage).await;
+    let timeline_enabled = GameTimelineEngine::is_enabled(pool, &ctx.save_id).await;
 
     while home_score < wins_needed && away_score < wins_needed {
         // === BP系统：每局比赛前运行BP ===
@@ -477,17 +514,55 @@ async fn simulate_single_match_internal(
             }
         }
         
-        // 运行BP
-        let draft = BpEngine::run_draft(
+        // 选边：第一局（及BO5决胜局）高种子选边，其余由上一局败方选边
+        let side_chooser = SideSelectionEngine::side_chooser(
+            format,
+            game_number,
+            home_score,
+            away_score,
+            higher_seed,
+            series_ctx.as_ref().and_then(|c| c.prev_loser_side),
+        );
+        let side_choice = SideSelectionEngine::choose(
+            side_chooser,
+            &home_pool_vec,
+            &away_pool_vec,
+            &version_tiers,
+            meta_type,
+        );
+
+        // 运行BP（蓝色方第一手选人）
+        let mut draft = BpEngine::run_draft_on_side(
+            side_choice.home_side,
             &home_pool_vec,
             &away_pool_vec,
             &version_tiers,
             meta_type,
             &mut bp_rng,
-            &home_team_comp_history,
-            &away_team_comp_history,
+            &home_scouting,
+            &away_scouting,
             series_ctx.as_ref(),
+            &draft_rules,
         );
+        draft.record_side_choice(&side_choice);
+        draft_rules.record_game(&draft.home_picks, &draft.away_picks);
+
+        // 局间战术：第一局双方按原计划，之后的战术随小局记录
+        let (home_tactics, away_tactics) = series_ctx
+            .as_ref()
+            .map(|c| (c.home_tactics.clone(), c.away_tactics.clone()))
+            .unwrap_or_default();
+        if game_number > 1 {
+            for (team_id, tactics) in [(home_team_id, &home_tactics), (away_team_id, &away_tactics)] {
+                if let Err(e) = SeriesTacticsEngine::save_tactics(
+                    pool, &ctx.save_id, match_id, game_number, team_id as u64, tactics, true,
+                )
+                .await
+                {
+                    log::warn!("{}", e);
+                }
+            }
+        }
         
         // 保存BP结果到数据库
         let bans_json = serde_json::to_string(&draft.bans).unwrap_or_default();
@@ -503,8 +578,8 @@ async fn simulate_single_match_internal(
         sqlx::query(
             r#"
             INSERT OR REPLACE INTO game_draft_results
-                (save_id, match_id, game_number, bans_json, home_picks_json, away_picks_json, home_comp, away_comp, draft_narrative_json)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                (save_id, match_id, game_number, bans_json, home_picks_json, away_picks_json, home_comp, away_comp, draft_narrative_json, home_side)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#
         )
         .bind(&ctx.save_id)
@@ -516,6 +591,7 @@ async fn simulate_single_match_internal(
         .bind(&home_comp)
         .bind(&away_comp)
         .bind(&narrative_json)
+        .bind(side_choice.home_side.as_str())
         .execute(pool)
         .await
         .ok();
@@ -543,7 +619,7 @@ async fn simulate_single_match_internal(
         pending_home_subs.clear();
         pending_away_subs.clear();
 
-        let duration = 25 + rng.gen_range(0..25);
+        let mut duration = 25 + rng.gen_range(0..25);
 
         // 记录本局出场选手
         for p in home_players.iter().chain(away_players.iter()) {
@@ -571,31 +647,65 @@ async fn simulate_single_match_internal(
             games_since_rest: 0,
         };
 
-        let (home_player_stats, away_player_stats, home_perf, away_perf) = simulate_game_with_players(
+        let (mut home_player_stats, mut away_player_stats, home_perf, away_perf) = simulate_game_with_players(
             &home_players, &away_players,
             duration,
             &trait_ctx,
             &ctx.meta_weights,
+            &draft.home_bp_modifiers,
+            &draft.away_bp_modifiers,
             &mut rng,
         );
 
-        // 根据队伍发挥战力决定胜负（加入正态分布增加不确定性）
-        // 使用 Box-Muller 变换生成高斯随机数
-        let game_std_dev = 3.0; // 局内波动标准差
-        let u1: f64 = rng.r#gen::<f64>().max(0.0001);
-        let u2: f64 = rng.r#gen::<f64>().max(0.0001);
-        let gaussian = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
-
-        // 双方发挥战力差值 + 随机波动
-        let performance_diff = home_perf - away_perf;
-        let final_diff = performance_diff + gaussian * game_std_dev;
+        // 单局发挥 N(队伍战力, σ)：主队战力计入地图边与局间战术修正，战术可放大波动
+        let (tactic_shift, tactic_variance) = SeriesTacticsEngine::game_modifier(&home_tactics, &away_tactics);
+        let resolution = SimulationCore::resolve_detailed_game(
+            home_perf,
+            away_perf,
+            SideSelectionEngine::side_bonus(meta_type, side_choice.home_side) + tactic_shift,
+            tactic_variance,
+            &mut rng,
+        );
 
-        let winner_id = if final_diff > 0.0 {
+        let winner_id = if resolution.home_won {
             home_team_id as u64
         } else {
             away_team_id as u64
         };
 
+        // 生成关键事件：启用时间线时，时长与选手数据均由局内事件推导
+        let events = if timeline_enabled {
+            let timeline = GameTimelineEngine::simulate(
+                &TimelineInput {
+                    home_team_id: home_team_id as u64,
+                    away_team_id: away_team_id as u64,
+                    home_players: home_player_stats.iter().map(TimelinePlayer::from).collect(),
+                    away_players: away_player_stats.iter().map(TimelinePlayer::from).collect(),
+                    home_comp: draft.home_comp,
+                    away_comp: draft.away_comp,
+                    home_won: winner_id == home_team_id as u64,
+                },
+                &mut rng,
+            );
+            for p in home_player_stats.iter_mut().chain(away_player_stats.iter_mut()) {
+                if let Some(line) = timeline.player_line(p.player_id) {
+                    p.apply_timeline_line(line, duration, timeline.duration_minutes);
+                }
+            }
+            duration = timeline.duration_minutes;
+            if let Err(e) = GameTimelineEngine::save_timeline(pool, &ctx.save_id, match_id, game_number as u32, &timeline).await {
+                log::warn!("{}", e);
+            }
+            timeline.events.iter().map(GameEvent::from).collect()
+        } else {
+            generate_key_events(
+                home_team_id as u64, away_team_id as u64,
+                winner_id == home_team_id as u64,
+                duration,
+                &mut rng,
+            )
+        };
+
         // 选择MVP（仅从胜方队伍中选择）
         let winner_player_stats: Vec<&PlayerGameStats> = if winner_id == home_team_id as u64 {
             home_player_stats.iter().collect()
@@ -604,14 +714,6 @@ async fn simulate_single_match_internal(
         };
         let game_mvp = select_mvp(&winner_player_stats, winner_id);
 
-        // 生成关键事件
-        let events = generate_key_events(
-            home_team_id as u64, away_team_id as u64,
-            winner_id == home_team_id as u64,
-            duration,
-            &mut rng,
-        );
-
         // 更新队伍总统计
         update_team_stats(&mut total_home_stats, &home_player_stats, &events, true);
         update_team_stats(&mut total_away_stats, &away_player_stats, &events, false);
@@ -641,6 +743,8 @@ async fn simulate_single_match_internal(
             away_bp_bonus: Some(away_bp_avg),
             home_version_bonus: Some(0.0),
             away_version_bonus: Some(0.0),
+            home_tactics: (game_number > 1).then_some(home_tactics),
+            away_tactics: (game_number > 1).then_some(away_tactics),
         });
 
         let home_won_this_game = winner_id == home_team_id as u64;
@@ -669,6 +773,7 @@ async fn simulate_single_match_internal(
             away_score,
             game_number,
             wins_needed,
+            ..Default::default()
         });
 
         // === 更新选手英雄池 games_played / games_won ===
@@ -701,6 +806,11 @@ async fn simulate_single_match_internal(
 
         // 局间状态更新：出场选手独立随机波动（制造状态分化），板凳选手休息恢复
         if bo_count > 1 && home_score < wins_needed && away_score < wins_needed {
+            let rivalry_ctx = is_rivalry.then(|| MatchContext {
+                game_number: game_number + 1,
+                is_rivalry: true,
+                ..Default::default()
+            });
             for player in home_players.iter_mut() {
                 player.form_factors = ConditionEngine::update_form_factors_between_games(
                     player.form_factors.clone(), home_won_this_game,
@@ -712,6 +822,7 @@ async fn simulate_single_match_internal(
                     player.satisfaction,
                     season_games_played,
                     player.traits.contains(&TraitType::Ironman),
+                    rivalry_ctx.as_ref(),
                 );
                 player.condition = ConditionEngine::calculate_condition_full(
                     player.age,
@@ -731,6 +842,7 @@ async fn simulate_single_match_internal(
                     player.satisfaction,
                     season_games_played,
                     player.traits.contains(&TraitType::Ironman),
+                    rivalry_ctx.as_ref(),
                 );
                 player.condition = ConditionEngine::calculate_condition_full(
                     player.age,
@@ -748,6 +860,7 @@ async fn simulate_single_match_internal(
                     player.satisfaction,
                     season_games_played,
                     player.traits.contains(&TraitType::Ironman),
+                    rivalry_ctx.as_ref(),
                 );
                 player.condition = ConditionEngine::calculate_condition_full(
                     player.age,
@@ -811,6 +924,44 @@ async fn simulate_single_match_internal(
             }
         }
 
+        // 局间战术：为下一局做出调整（玩家预设优先，否则由 AI 决定）
+        if bo_count > 1 && home_score < wins_needed && away_score < wins_needed {
+            if let (Some(series), Some(last_game)) = (series_ctx.as_mut(), games.last()) {
+                let next_game = game_number + 1;
+                let gap = last_game.home_performance - last_game.away_performance;
+                let home_next = match SeriesTacticsEngine::load_manual(
+                    pool, &ctx.save_id, match_id, next_game, home_team_id as u64,
+                )
+                .await
+                {
+                    Some(tactics) => tactics,
+                    None => SeriesTacticsEngine::ai_choose(
+                        TeamSide::Home,
+                        series,
+                        &home_scouting.comp_history(),
+                        standout_player(&last_game.away_players),
+                        gap,
+                    ),
+                };
+                let away_next = match SeriesTacticsEngine::load_manual(
+                    pool, &ctx.save_id, match_id, next_game, away_team_id as u64,
+                )
+                .await
+                {
+                    Some(tactics) => tactics,
+                    None => SeriesTacticsEngine::ai_choose(
+                        TeamSide::Away,
+                        series,
+                        &away_scouting.comp_history(),
+                        standout_player(&last_game.home_players),
+                        -gap,
+                    ),
+                };
+                series.home_tactics = home_next;
+                series.away_tactics = away_next;
+            }
+        }
+
         game_number += 1;
     }
 
@@ -1122,6 +1273,19 @@ async fn simulate_single_match_internal(
         winner_id,
     ).await.ok();
 
+    // 更新纪录簿（详细模拟有小局选手数据，可冲击KDA/击杀纪录）
+    let record_input = SeriesRecordInput {
+        match_id: match_id as i64,
+        tournament_id,
+        home_team_id,
+        away_team_id,
+        winner_id: winner_id as i64,
+        durations: games.iter().map(|g| g.duration_minutes).collect(),
+    };
+    if let Err(e) = GameFlowService::update_records_after_series(pool, &ctx.save_id, ctx.current_season, &record_input).await {
+        log::warn!("更新纪录簿失败: {}", e);
+    }
+
     // 如果是季后赛比赛，推进对阵生成后续比赛
     let is_playoff = stage.contains("WINNERS")
         || stage.contains("LOSERS")
@@ -1172,65 +1336,18 @@ async fn simulate_single_match_internal(
 
 // ==================== 辅助函数 ====================
 
-async fn load_team_comp_history(
-    pool: &sqlx::SqlitePool,
-    save_id: &str,
-    team_id: i64,
-) -> Vec<(CompType, u32)> {
-    let rows = match sqlx::query(
-        r#"
-        SELECT d.home_comp, d.away_comp, m.home_team_id, m.away_team_id
-        FROM game_draft_results d
-        JOIN matches m ON m.id = d.match_id
-        WHERE d.save_id = ? AND (m.home_team_id = ? OR m.away_team_id = ?)
-        "#,
-    )
-    .bind(save_id)
-    .bind(team_id)
-    .bind(team_id)
-    .fetch_all(pool)
-    .await
-    {
-        Ok(rows) => rows,
-        Err(_) => return Vec::new(),
-    };
-
-    let mut comp_counts: HashMap<CompType, u32> = HashMap::new();
-    for row in rows {
-        let home_team_id: i64 = row.get("home_team_id");
-        let away_team_id: i64 = row.get("away_team_id");
-
-        let comp_str: Option<String> = if team_id == home_team_id {
-            row.get("home_comp")
-        } else if team_id == away_team_id {
-            row.get("away_comp")
-        } else {
-            None
-        };
-
-        if let Some(comp_str) = comp_str {
-            if let Some(comp) = CompType::from_id(comp_str.trim()) {
-                *comp_counts.entry(comp).or_insert(0) += 1;
-            }
-        }
-    }
-
-    let mut history: Vec<(CompType, u32)> = comp_counts.into_iter().collect();
-    history.sort_by(|left, right| right.1.cmp(&left.1));
-    history
-}
-
 fn build_condition_context(
     satisfaction: u8,
     season_games_played: u32,
     has_ironman: bool,
+    match_context: Option<&MatchContext>,
 ) -> ConditionContext {
     ConditionContext {
+        match_context: match_context.cloned(),
         season_games_played,
         satisfaction,
         international_events: 0,
         has_ironman,
-        ..Default::default()
     }
 }
 
@@ -1267,6 +1384,7 @@ fn build_player_data(
         satisfaction,
         season_games_played,
         traits.contains(&TraitType::Ironman),
+        None,
     );
     let condition =
         ConditionEngine::calculate_condition_full(age, ability, &form_factors, &condition_ctx);
@@ -1500,25 +1618,23 @@ async fn get_team_personality(
         .unwrap_or(AITeamPersonality::Balanced)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn simulate_game_with_players(
     home_players: &[PlayerData],
     away_players: &[PlayerData],
     duration: u32,
     trait_ctx: &TraitContext,
     meta_weights: &MetaWeights,
+    home_bp_modifiers: &HashMap<u64, f64>,
+    away_bp_modifiers: &HashMap<u64, f64>,
     rng: &mut impl Rng,
 ) -> (Vec<PlayerGameStats>, Vec<PlayerGameStats>, f64, f64) {
-    fn gaussian_random(rng: &mut impl Rng) -> f64 {
-        let u: f64 = rng.r#gen::<f64>().max(0.0001);
-        let v: f64 = rng.r#gen::<f64>().max(0.0001);
-        (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
-    }
-
     fn generate_team_stats(
         players: &[PlayerData],
         duration: u32,
         trait_ctx: &TraitContext,
         meta_weights: &MetaWeights,
+        bp_modifiers: &HashMap<u64, f64>,
         rng: &mut impl Rng
     ) -> (Vec<PlayerGameStats>, f64) {
         let mut stats = Vec::new();
@@ -1536,9 +1652,6 @@ fn simulate_game_with_players(
                 ..trait_ctx.clone()
             };
 
-            // 计算特性修正
-            let modifiers = TraitEngine::calculate_combined_modifiers(&player.traits, &player_trait_ctx);
-
             // 构建激活特性列表
             let activated_traits: Vec<ActivatedTraitInfo> = player.traits.iter().filter_map(|t| {
                 let (effect_desc, value, is_positive) = match t {
@@ -1589,31 +1702,21 @@ fn simulate_game_with_players(
                 })
             }).collect();
 
-            // 应用特性修正到基础属性
-            let (modified_ability, modified_stability, modified_condition, ability_ceiling) =
-                TraitEngine::apply_modifiers(
-                    player.ability,
-                    player.stability,
-                    player.condition,
-                    &modifiers,
-                );
-
-            // 稳定性标准差: σ = (100 - stability) / 10
-            let sigma = (100.0 - modified_stability as f64) / 10.0;
-
-            // 状态加成（应用特性修正后的 condition）
-            let condition_bonus = modified_condition as f64;
-
-            // 稳定性波动（高斯噪声）
-            let stability_noise = gaussian_random(rng) * sigma;
-
-            // 原始实际能力
-            let raw_ability = modified_ability as f64 + condition_bonus + stability_noise;
-
-            // 钳位到合理范围，考虑特性修正的能力上限
-            let min_ability = (modified_ability as f64 - 15.0).max(0.0);
-            let max_ability = (ability_ceiling as f64).min(100.0);
-            let actual_ability = raw_ability.clamp(min_ability, max_ability);
+            // 特性修正 → 稳定性噪声 → 钳位（统一模拟核心）
+            let roll = SimulationCore::roll_player(
+                &PerformanceInput {
+                    ability: player.ability,
+                    stability: player.stability,
+                    condition: player.condition,
+                    traits: &player.traits,
+                    bonus: bp_modifiers.get(&player.id).copied().unwrap_or(0.0),
+                    condition_adjust: 0,
+                },
+                &player_trait_ctx,
+                rng,
+            );
+            let (condition_bonus, stability_noise, actual_ability) =
+                (roll.condition_bonus, roll.stability_noise, roll.actual_ability);
 
             total_actual_ability += actual_ability;
             player_abilities_with_pos.push((actual_ability, player.position.clone()));
@@ -1624,7 +1727,7 @@ fn simulate_game_with_players(
         let weighted_input: Vec<(f64, &str)> = player_abilities_with_pos.iter()
             .map(|(a, p)| (*a, p.as_str()))
             .collect();
-        let team_power = MetaEngine::calculate_team_power_weighted(&weighted_input, meta_weights);
+        let team_power = SimulationCore::team_power(&weighted_input, meta_weights);
         let team_avg = if !players.is_empty() { total_actual_ability / players.len() as f64 } else { 0.0 };
 
         // 第二遍：生成详细统计
@@ -1649,7 +1752,7 @@ fn simulate_game_with_players(
             let mvp_score = kda * 0.4 + (damage as f64 / 10000.0) * 0.3 + (gold as f64 / 10000.0) * 0.3;
 
             // 影响力分数：相对于队伍平均值的偏差
-            let impact_score = ((actual_ability - team_avg) * 10.0).round() / 10.0;
+            let impact_score = SimulationCore::impact_score(actual_ability, team_avg);
 
             stats.push(PlayerGameStats {
                 player_id: player.id,
@@ -1678,12 +1781,23 @@ fn simulate_game_with_players(
     }
 
     // 生成双方统计
-    let (home_stats, home_avg) = generate_team_stats(home_players, duration, trait_ctx, meta_weights, rng);
-    let (away_stats, away_avg) = generate_team_stats(away_players, duration, trait_ctx, meta_weights, rng);
+    let (home_stats, home_avg) =
+        generate_team_stats(home_players, duration, trait_ctx, meta_weights, home_bp_modifiers, rng);
+    let (away_stats, away_avg) =
+        generate_team_stats(away_players, duration, trait_ctx, meta_weights, away_bp_modifiers, rng);
 
     (home_stats, away_stats, home_avg, away_avg)
 }
 
+/// 上一局发挥最突出的选手及其高出队伍均值的幅度
+fn standout_player(stats: &[PlayerGameStats]) -> Option<(u64, f64)> {
+    let avg = stats.iter().map(|p| p.actual_ability).sum::<f64>() / stats.len().max(1) as f64;
+    stats
+        .iter()
+        .max_by(|a, b| a.actual_ability.partial_cmp(&b.actual_ability).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|p| (p.player_id, p.actual_ability - avg))
+}
+
 fn select_mvp(stats: &[&PlayerGameStats], team_id: u64) -> PlayerMvpInfo {
     let best = stats.iter()
         .max_by(|a, b| a.mvp_score.partial_cmp(&b.mvp_score).unwrap())
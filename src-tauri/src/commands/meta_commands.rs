@@ -2,6 +2,7 @@
 
 use crate::commands::save_commands::{AppState, CommandResult};
 use crate::engines::meta_engine::{MetaType, get_meta_weights};
+use crate::engines::regional_meta::{RegionStyleProfile, RegionalMetaEngine};
 use crate::models::meta::{MetaInfo, MetaWeightsInfo, MetaHistoryEntry, MetaTypeInfo};
 use sqlx::Row;
 use tauri::State;
@@ -25,9 +26,9 @@ pub async fn get_current_meta(
     .map_err(|e| e.to_string())?
     .unwrap_or(1);
 
-    // 查询当前赛季 meta
+    // 查询当前赛季最新一次补丁的 meta
     let row = sqlx::query(
-        "SELECT meta_type, meta_name, weight_top, weight_jug, weight_mid, weight_adc, weight_sup FROM meta_versions WHERE save_id = ? AND season_id = ?"
+        "SELECT meta_type, meta_name, weight_top, weight_jug, weight_mid, weight_adc, weight_sup, patch_number, reason FROM meta_versions WHERE save_id = ? AND season_id = ? ORDER BY patch_number DESC LIMIT 1"
     )
     .bind(&save_id)
     .bind(current_season)
@@ -54,6 +55,8 @@ pub async fn get_current_meta(
                     adc: r.get("weight_adc"),
                     sup: r.get("weight_sup"),
                 },
+                patch_number: r.get("patch_number"),
+                reason: r.get("reason"),
             }
         }
         None => {
@@ -66,6 +69,8 @@ pub async fn get_current_meta(
                 weights: MetaWeightsInfo {
                     top: 1.0, jug: 1.0, mid: 1.0, adc: 1.0, sup: 1.0,
                 },
+                patch_number: 1,
+                reason: None,
             }
         }
     };
@@ -73,7 +78,7 @@ pub async fn get_current_meta(
     Ok(CommandResult::ok(info))
 }
 
-/// 获取 Meta 历史版本列表
+/// 获取 Meta 历史版本列表（含季中补丁，按赛季、补丁顺序）
 #[tauri::command]
 pub async fn get_meta_history(
     state: State<'_, AppState>,
@@ -82,7 +87,7 @@ pub async fn get_meta_history(
     let pool = get_pool!(state);
 
     let rows = sqlx::query(
-        "SELECT season_id, meta_type, meta_name, weight_top, weight_jug, weight_mid, weight_adc, weight_sup FROM meta_versions WHERE save_id = ? ORDER BY season_id ASC"
+        "SELECT season_id, patch_number, phase, meta_type, meta_name, weight_top, weight_jug, weight_mid, weight_adc, weight_sup, reason FROM meta_versions WHERE save_id = ? ORDER BY season_id ASC, patch_number ASC"
     )
     .bind(&save_id)
     .fetch_all(&pool)
@@ -91,6 +96,8 @@ pub async fn get_meta_history(
 
     let history: Vec<MetaHistoryEntry> = rows.iter().map(|r| MetaHistoryEntry {
         season_id: r.get("season_id"),
+        patch_number: r.get("patch_number"),
+        phase: r.get("phase"),
         meta_type: r.get("meta_type"),
         meta_name: r.get("meta_name"),
         weight_top: r.get("weight_top"),
@@ -98,11 +105,26 @@ pub async fn get_meta_history(
         weight_mid: r.get("weight_mid"),
         weight_adc: r.get("weight_adc"),
         weight_sup: r.get("weight_sup"),
+        reason: r.get("reason"),
     }).collect();
 
     Ok(CommandResult::ok(history))
 }
 
+/// 获取各赛区对版本的理解（风格档案）
+#[tauri::command]
+pub async fn get_region_meta_styles(
+    state: State<'_, AppState>,
+    save_id: String,
+) -> Result<CommandResult<Vec<RegionStyleProfile>>, String> {
+    let pool = get_pool!(state);
+
+    match RegionalMetaEngine::load_profiles(&pool, &save_id).await {
+        Ok(profiles) => Ok(CommandResult::ok(profiles)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
 /// 获取全部 20 种 Meta 类型配置
 #[tauri::command]
 pub fn get_all_meta_types() -> CommandResult<Vec<MetaTypeInfo>> {
@@ -21,6 +21,17 @@ pub mod perf_commands;
 pub mod meta_commands;
 pub mod champion_commands;
 pub mod ladder_commands;
+pub mod scouting_commands;
+pub mod talent_pipeline_commands;
+pub mod role_transition_commands;
+pub mod salary_cap_commands;
+pub mod sponsorship_commands;
+pub mod game_timeline_commands;
+pub mod comp_phase_commands;
+pub mod draft_rule_commands;
+pub mod champion_training_commands;
+pub mod series_tactics_commands;
+pub mod rivalry_commands;
 
 pub use save_commands::*;
 pub use team_commands::*;
@@ -59,6 +70,17 @@ pub use perf_commands::*;
 pub use meta_commands::*;
 pub use champion_commands::*;
 pub use ladder_commands::*;
+pub use scouting_commands::*;
+pub use talent_pipeline_commands::*;
+pub use role_transition_commands::*;
+pub use salary_cap_commands::*;
+pub use sponsorship_commands::*;
+pub use game_timeline_commands::*;
+pub use comp_phase_commands::*;
+pub use draft_rule_commands::*;
+pub use champion_training_commands::*;
+pub use series_tactics_commands::*;
+pub use rivalry_commands::*;
 
 use serde::{Deserialize, Serialize};
 
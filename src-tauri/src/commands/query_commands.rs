@@ -864,7 +864,7 @@ pub async fn search_players(
     .await
     .map_err(|e| e.to_string())?;
 
-    let players: Vec<PlayerSearchResult> = rows
+    let mut players: Vec<PlayerSearchResult> = rows
         .iter()
         .filter(|row| {
             // 过滤position
@@ -893,9 +893,21 @@ pub async fn search_players(
             age: row.get::<i64, _>("age") as u8,
             team_id: row.get::<Option<i64>, _>("team_id").map(|v| v as u64),
             team_name: row.get("team_name"),
+            scouting: None,
         })
         .collect();
 
+    // 球探迷雾：按执教队伍视角返回评分区间，并按估计能力排序
+    if let Some(view) = crate::commands::scouting_commands::managed_fog_view(&pool, &save_id).await? {
+        for p in players.iter_mut() {
+            let scouted = view.scout(p.id, p.team_id, p.ability, p.potential, 0);
+            p.ability = scouted.ability_range.estimate();
+            p.potential = scouted.potential_range.estimate();
+            p.scouting = Some(scouted);
+        }
+        players.sort_by_key(|p| std::cmp::Reverse(p.ability));
+    }
+
     Ok(CommandResult::ok(players))
 }
 
@@ -911,6 +923,8 @@ pub struct PlayerSearchResult {
     pub age: u8,
     pub team_id: Option<u64>,
     pub team_name: Option<String>,
+    /// 执教队伍视角的球探情报（观察者视角为 None）
+    pub scouting: Option<crate::models::ScoutedRatings>,
 }
 
 /// 获取阶段显示名称
@@ -0,0 +1,70 @@
+//! 历史交锋与宿敌 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::engines::{PlayerHeadToHead, RivalryEngine, TeamHeadToHead};
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 获取两支战队的历史交锋
+#[tauri::command]
+pub async fn get_team_head_to_head(
+    state: State<'_, AppState>,
+    team_a_id: u64,
+    team_b_id: u64,
+) -> Result<CommandResult<TeamHeadToHead>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match RivalryEngine::team_head_to_head(&pool, &save_id, team_a_id as i64, team_b_id as i64).await {
+        Ok(h2h) => Ok(CommandResult::ok(h2h)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取宿敌列表（可限定某支战队），按宿敌积分降序
+#[tauri::command]
+pub async fn get_team_rivalries(
+    state: State<'_, AppState>,
+    team_id: Option<u64>,
+    limit: Option<usize>,
+) -> Result<CommandResult<Vec<TeamHeadToHead>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match RivalryEngine::rivalries(&pool, &save_id, team_id.map(|id| id as i64), limit.unwrap_or(20)).await {
+        Ok(list) => Ok(CommandResult::ok(list)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取两名选手的同位置对位交锋
+#[tauri::command]
+pub async fn get_player_head_to_head(
+    state: State<'_, AppState>,
+    player_id: u64,
+    opponent_id: u64,
+) -> Result<CommandResult<Option<PlayerHeadToHead>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match RivalryEngine::player_head_to_head(&pool, &save_id, player_id as i64, opponent_id as i64).await {
+        Ok(h2h) => Ok(CommandResult::ok(h2h)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取选手最常交手的对位对手
+#[tauri::command]
+pub async fn get_player_lane_opponents(
+    state: State<'_, AppState>,
+    player_id: u64,
+    limit: Option<usize>,
+) -> Result<CommandResult<Vec<PlayerHeadToHead>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match RivalryEngine::lane_opponents(&pool, &save_id, player_id as i64, limit.unwrap_or(10)).await {
+        Ok(list) => Ok(CommandResult::ok(list)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
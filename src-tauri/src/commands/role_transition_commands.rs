@@ -0,0 +1,86 @@
+//! 老将角色转型 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::commands::scouting_commands::current_season;
+use crate::engines::{RoleTransitionEngine, ScoutingEngine};
+use crate::models::{ContractRole, Position, PositionRetraining};
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 校验选手属于执教队伍，返回拒绝原因
+async fn check_managed_player(pool: &sqlx::SqlitePool, save_id: &str, player_id: u64) -> Result<(), String> {
+    let team_id: Option<i64> = sqlx::query_scalar("SELECT team_id FROM players WHERE save_id = ? AND id = ?")
+        .bind(save_id)
+        .bind(player_id as i64)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .flatten();
+    match ScoutingEngine::managed_team(pool, save_id).await? {
+        Some(managed) if team_id == Some(managed as i64) => Ok(()),
+        _ => Err("只能为执教队伍的选手安排转型".to_string()),
+    }
+}
+
+/// 开始转位置训练（Top / Jug / Mid / Adc / Sup）
+#[tauri::command]
+pub async fn start_position_retraining(
+    state: State<'_, AppState>,
+    player_id: u64,
+    to_position: String,
+) -> Result<CommandResult<PositionRetraining>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    if !["Top", "Jug", "Mid", "Adc", "Sup"].contains(&to_position.as_str()) {
+        return Ok(CommandResult::err(format!("未知的位置: {}", to_position)));
+    }
+    if let Err(e) = check_managed_player(&pool, &save_id, player_id).await {
+        return Ok(CommandResult::err(e));
+    }
+    let season = current_season(&pool, &save_id).await?;
+
+    match RoleTransitionEngine::start_retraining(&pool, &save_id, player_id, Position::from_str(&to_position), season).await {
+        Ok(r) => Ok(CommandResult::ok(r)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取转位置训练记录（不传队伍则返回全部）
+#[tauri::command]
+pub async fn get_position_retrainings(
+    state: State<'_, AppState>,
+    team_id: Option<u64>,
+) -> Result<CommandResult<Vec<PositionRetraining>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match RoleTransitionEngine::list_retrainings(&pool, &save_id, team_id).await {
+        Ok(list) => Ok(CommandResult::ok(list)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 为老将签下选手兼教练（PlayingCoach）或第六人（SixthMan）合同，返回调整后的薪资
+#[tauri::command]
+pub async fn assign_veteran_role(
+    state: State<'_, AppState>,
+    player_id: u64,
+    role: String,
+) -> Result<CommandResult<i64>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    let role = ContractRole::parse(&role);
+    if !role.is_veteran_role() {
+        return Ok(CommandResult::err(format!("{} 不是老将角色", role.as_str())));
+    }
+    if let Err(e) = check_managed_player(&pool, &save_id, player_id).await {
+        return Ok(CommandResult::err(e));
+    }
+
+    match RoleTransitionEngine::assign_veteran_role(&pool, &save_id, player_id, role).await {
+        Ok(salary) => Ok(CommandResult::ok(salary)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
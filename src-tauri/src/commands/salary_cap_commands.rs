@@ -0,0 +1,24 @@
+//! 薪资帽 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::commands::scouting_commands::current_season;
+use crate::engines::SalaryCapEngine;
+use crate::models::TeamCapSheet;
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 获取球队薪资表（在约合同、到期预留额、死钱、剩余空间与预估奢侈税）
+#[tauri::command]
+pub async fn get_team_cap_sheet(
+    state: State<'_, AppState>,
+    team_id: u64,
+) -> Result<CommandResult<TeamCapSheet>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+    let season = current_season(&pool, &save_id).await?;
+
+    match SalaryCapEngine::build_cap_sheet(&pool, &save_id, team_id as i64, season).await {
+        Ok(sheet) => Ok(CommandResult::ok(sheet)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::db::{DatabaseManager, SaveRepository};
+use crate::engines::game_timeline::GameTimelineEngine;
 use crate::models::Save;
 use crate::models::init_config::{GameInitConfig, RegionInitConfig, TeamInitConfig, PlayerInitConfig, DraftPoolPlayerInitConfig};
 use crate::services::InitService;
@@ -145,6 +146,12 @@ pub async fn create_save(
         return Ok(CommandResult::err(format!("Failed to create save: {}", e)));
     }
 
+    // 新存档默认启用局内时间线（旧存档沿用迁移默认的关闭状态）
+    if let Err(e) = GameTimelineEngine::set_enabled(&pool, &save.id, true).await {
+        let _ = SaveRepository::delete(&pool, &save.id).await;
+        return Ok(CommandResult::err(e));
+    }
+
     // 初始化游戏数据 (队伍、选手等)
     if let Err(e) = InitService::initialize_game_data(&pool, &save.id, save.current_season).await {
         // 如果初始化失败，删除已创建的存档
@@ -431,6 +438,12 @@ pub async fn create_save_with_config(
         return Ok(CommandResult::err(format!("Failed to create save: {}", e)));
     }
 
+    // 新存档默认启用局内时间线（旧存档沿用迁移默认的关闭状态）
+    if let Err(e) = GameTimelineEngine::set_enabled(&pool, &save.id, true).await {
+        let _ = SaveRepository::delete(&pool, &save.id).await;
+        return Ok(CommandResult::err(e));
+    }
+
     // 使用自定义配置初始化游戏数据
     if let Err(e) = InitService::initialize_game_data_with_config(&pool, &save.id, save.current_season, &config).await {
         let _ = SaveRepository::delete(&pool, &save.id).await;
@@ -0,0 +1,188 @@
+//! 球探系统 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::db::PlayerRepository;
+use crate::engines::{PlayerFogView, ScoutingEngine};
+use crate::models::{ScoutSubject, ScoutTarget, ScoutedRatings, ScoutingAssignment};
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 查询存档当前赛季
+pub(crate) async fn current_season(pool: &sqlx::SqlitePool, save_id: &str) -> Result<i64, String> {
+    let season: Option<i64> = sqlx::query_scalar("SELECT current_season FROM saves WHERE id = ?")
+        .bind(save_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(season.unwrap_or(1))
+}
+
+/// 球探迷雾视角：仅以存档的执教队伍视角展示，未设置时为观察者视角（返回 None）
+///
+/// 视角不接受前端指定，避免查看其他队伍（含其本队真实评分）的情报。
+pub(crate) async fn managed_fog_view(
+    pool: &sqlx::SqlitePool,
+    save_id: &str,
+) -> Result<Option<PlayerFogView>, String> {
+    let Some(viewer) = ScoutingEngine::managed_team(pool, save_id).await? else {
+        return Ok(None);
+    };
+    let season = current_season(pool, save_id).await?;
+    ScoutingEngine::player_fog_view(pool, save_id, viewer, season).await.map(Some)
+}
+
+/// 设置执教队伍（选手详情、选秀池默认以该队的球探情报展示）
+#[tauri::command]
+pub async fn set_managed_team(
+    state: State<'_, AppState>,
+    team_id: Option<u64>,
+) -> Result<CommandResult<()>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match ScoutingEngine::set_managed_team(&pool, &save_id, team_id).await {
+        Ok(()) => Ok(CommandResult::ok(())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取执教队伍
+#[tauri::command]
+pub async fn get_managed_team(state: State<'_, AppState>) -> Result<CommandResult<Option<u64>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match ScoutingEngine::managed_team(&pool, &save_id).await {
+        Ok(team) => Ok(CommandResult::ok(team)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 派遣球探到某赛区（Region）或某赛区选秀池（DraftPool）
+#[tauri::command]
+pub async fn assign_scout(
+    state: State<'_, AppState>,
+    team_id: u64,
+    target_type: String,
+    region_id: u64,
+) -> Result<CommandResult<ScoutingAssignment>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    // 只能为执教队伍派遣球探，避免借 AI 队伍的情报绕过迷雾
+    match ScoutingEngine::managed_team(&pool, &save_id).await {
+        Ok(Some(managed)) if managed == team_id => {}
+        Ok(_) => return Ok(CommandResult::err("只能为执教队伍派遣球探")),
+        Err(e) => return Ok(CommandResult::err(e)),
+    }
+
+    let target = match ScoutTarget::parse(&target_type) {
+        Some(t) => t,
+        None => return Ok(CommandResult::err(format!("未知的球探目标类型: {}", target_type))),
+    };
+    let season = current_season(&pool, &save_id).await?;
+
+    match ScoutingEngine::assign(&pool, &save_id, team_id, target, region_id, season).await {
+        Ok(assignment) => Ok(CommandResult::ok(assignment)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 召回球探
+#[tauri::command]
+pub async fn recall_scout(
+    state: State<'_, AppState>,
+    assignment_id: i64,
+) -> Result<CommandResult<()>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    // 只能召回执教队伍自己的球探
+    let managed = match ScoutingEngine::managed_team(&pool, &save_id).await {
+        Ok(Some(managed)) => managed,
+        Ok(None) => return Ok(CommandResult::err("只能召回执教队伍的球探")),
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+    match ScoutingEngine::list_assignments(&pool, &save_id, Some(managed)).await {
+        Ok(list) if list.iter().any(|a| a.id == assignment_id && a.team_id == managed) => {}
+        Ok(_) => return Ok(CommandResult::err("只能召回执教队伍的球探")),
+        Err(e) => return Ok(CommandResult::err(e)),
+    }
+
+    match ScoutingEngine::recall(&pool, &save_id, assignment_id).await {
+        Ok(()) => Ok(CommandResult::ok(())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取队伍的球探派遣列表
+#[tauri::command]
+pub async fn get_scouting_assignments(
+    state: State<'_, AppState>,
+    team_id: u64,
+) -> Result<CommandResult<Vec<ScoutingAssignment>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match ScoutingEngine::managed_team(&pool, &save_id).await {
+        Ok(Some(managed)) if managed == team_id => {}
+        Ok(_) => return Ok(CommandResult::err("只能查看执教队伍的球探派遣")),
+        Err(e) => return Ok(CommandResult::err(e)),
+    }
+
+    match ScoutingEngine::list_assignments(&pool, &save_id, Some(team_id)).await {
+        Ok(list) => Ok(CommandResult::ok(list)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取某队对某名选手的球探报告
+#[tauri::command]
+pub async fn get_scouting_report(
+    state: State<'_, AppState>,
+    team_id: u64,
+    player_id: u64,
+) -> Result<CommandResult<ScoutedRatings>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    // 已设置执教队伍时只能查看本队的球探报告
+    match ScoutingEngine::managed_team(&pool, &save_id).await {
+        Ok(Some(managed)) if managed != team_id => {
+            return Ok(CommandResult::err("只能查看执教队伍的球探报告"));
+        }
+        Ok(_) => {}
+        Err(e) => return Ok(CommandResult::err(e)),
+    }
+
+    let player = match PlayerRepository::get_by_id(&pool, player_id).await {
+        Ok(p) => p,
+        Err(e) => return Ok(CommandResult::err(format!("Failed to get player: {}", e))),
+    };
+    let season = current_season(&pool, &save_id).await?;
+
+    let trait_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM player_traits WHERE player_id = ?")
+        .bind(player_id as i64)
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let knowledge = match ScoutingEngine::player_knowledge(
+        &pool, &save_id, team_id, player_id, player.team_id, season,
+    )
+    .await
+    {
+        Ok(k) => k,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+
+    Ok(CommandResult::ok(ScoutingEngine::scout_ratings(
+        team_id,
+        ScoutSubject::Player,
+        player_id,
+        player.ability,
+        player.potential,
+        trait_count as usize,
+        knowledge,
+    )))
+}
@@ -0,0 +1,96 @@
+//! 系列赛局间战术 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::engines::{GameTactics, SeriesTacticRecord, SeriesTacticsEngine};
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 获取某场比赛各小局的局间战术
+#[tauri::command]
+pub async fn get_series_tactics(
+    state: State<'_, AppState>,
+    match_id: u64,
+) -> Result<CommandResult<Vec<SeriesTacticRecord>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match SeriesTacticsEngine::load_match(&pool, &save_id, match_id).await {
+        Ok(records) => Ok(CommandResult::ok(records)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 为队伍预设某一小局的战术（比赛模拟时优先于 AI 决策）
+#[tauri::command]
+pub async fn set_series_tactics(
+    state: State<'_, AppState>,
+    match_id: u64,
+    team_id: u64,
+    game_number: u8,
+    tactics: GameTactics,
+) -> Result<CommandResult<()>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    let row: Option<(Option<i64>, Option<i64>, String)> = sqlx::query_as(
+        "SELECT home_team_id, away_team_id, status FROM matches WHERE save_id = ? AND id = ?",
+    )
+    .bind(&save_id)
+    .bind(match_id as i64)
+    .fetch_optional(&pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    let Some((home_id, away_id, status)) = row else {
+        return Ok(CommandResult::err("比赛不存在".to_string()));
+    };
+    if status.eq_ignore_ascii_case("COMPLETED") {
+        return Ok(CommandResult::err("比赛已结束".to_string()));
+    }
+    let opponent_id = if home_id == Some(team_id as i64) {
+        away_id
+    } else if away_id == Some(team_id as i64) {
+        home_id
+    } else {
+        return Ok(CommandResult::err("队伍未参加该比赛".to_string()));
+    };
+
+    let opponent_players: Vec<i64> =
+        sqlx::query_scalar("SELECT id FROM players WHERE save_id = ? AND team_id = ?")
+            .bind(&save_id)
+            .bind(opponent_id)
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    let opponent_players: Vec<u64> = opponent_players.into_iter().map(|id| id as u64).collect();
+
+    let mut tactics = tactics;
+    tactics.is_manual = true;
+    if tactics.reasons.is_empty() {
+        tactics.reasons.push("教练指定战术".to_string());
+    }
+    if let Err(e) = SeriesTacticsEngine::validate(&tactics, game_number, &opponent_players) {
+        return Ok(CommandResult::err(e));
+    }
+
+    match SeriesTacticsEngine::save_tactics(&pool, &save_id, match_id, game_number, team_id, &tactics, false).await {
+        Ok(()) => Ok(CommandResult::ok(())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 取消尚未生效的预设战术
+#[tauri::command]
+pub async fn clear_series_tactics(
+    state: State<'_, AppState>,
+    match_id: u64,
+    team_id: u64,
+    game_number: u8,
+) -> Result<CommandResult<()>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match SeriesTacticsEngine::clear_manual(&pool, &save_id, match_id, game_number, team_id).await {
+        Ok(()) => Ok(CommandResult::ok(())),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
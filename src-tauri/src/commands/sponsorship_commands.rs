@@ -0,0 +1,120 @@
+//! 赞助合同 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::commands::scouting_commands::current_season;
+use crate::engines::{ScoutingEngine, SponsorshipEngine};
+use crate::models::{SponsorContract, SponsorOffer};
+use crate::{get_pool, get_save_id};
+use sqlx::Row;
+use tauri::State;
+
+/// 查询球队的品牌价值、战力与赛区
+async fn team_profile(pool: &sqlx::SqlitePool, save_id: &str, team_id: u64) -> Result<(f64, f64, i64), String> {
+    let row = sqlx::query("SELECT brand_value, power_rating, region_id FROM teams WHERE id = ? AND save_id = ?")
+        .bind(team_id as i64)
+        .bind(save_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询球队失败: {}", e))?
+        .ok_or_else(|| format!("球队 {} 不存在", team_id))?;
+    Ok((row.get("brand_value"), row.get("power_rating"), row.get("region_id")))
+}
+
+/// 校验球队为执教队伍（赞助由玩家为本队谈判，AI 球队自动签约）
+async fn check_managed_team(pool: &sqlx::SqlitePool, save_id: &str, team_id: u64) -> Result<(), String> {
+    match ScoutingEngine::managed_team(pool, save_id).await? {
+        Some(managed) if managed == team_id => Ok(()),
+        _ => Err("只能为执教队伍洽谈赞助".to_string()),
+    }
+}
+
+/// 获取赞助合同（不传队伍则返回全部，含已到期/解约的历史合同）
+#[tauri::command]
+pub async fn get_sponsor_contracts(
+    state: State<'_, AppState>,
+    team_id: Option<u64>,
+) -> Result<CommandResult<Vec<SponsorContract>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match SponsorshipEngine::load_contracts(&pool, &save_id, team_id, false).await {
+        Ok(list) => Ok(CommandResult::ok(list)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
+
+/// 获取球队当前可签的赞助报价
+#[tauri::command]
+pub async fn get_sponsor_offers(
+    state: State<'_, AppState>,
+    team_id: u64,
+) -> Result<CommandResult<Vec<SponsorOffer>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    if let Err(e) = check_managed_team(&pool, &save_id, team_id).await {
+        return Ok(CommandResult::err(e));
+    }
+    let (brand_value, power_rating, region_id) = match team_profile(&pool, &save_id, team_id).await {
+        Ok(p) => p,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+    let held = match SponsorshipEngine::load_contracts(&pool, &save_id, Some(team_id), true).await {
+        Ok(held) => held,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+    let taken = match SponsorshipEngine::taken_exclusive(&pool, &save_id, region_id, team_id as i64).await {
+        Ok(taken) => taken,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+
+    Ok(CommandResult::ok(SponsorshipEngine::available_offers(brand_value, power_rating, &held, &taken)))
+}
+
+/// 签下赞助合同（从当前赛季起生效，赛季结算时支付）
+#[tauri::command]
+pub async fn sign_sponsor_contract(
+    state: State<'_, AppState>,
+    team_id: u64,
+    sponsor_code: String,
+) -> Result<CommandResult<SponsorContract>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    if let Err(e) = check_managed_team(&pool, &save_id, team_id).await {
+        return Ok(CommandResult::err(e));
+    }
+    let Some(profile) = SponsorshipEngine::find_profile(&sponsor_code) else {
+        return Ok(CommandResult::err(format!("未知的赞助商: {}", sponsor_code)));
+    };
+    let (brand_value, power_rating, region_id) = match team_profile(&pool, &save_id, team_id).await {
+        Ok(p) => p,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+    let held = match SponsorshipEngine::load_contracts(&pool, &save_id, Some(team_id), true).await {
+        Ok(held) => held,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+    let taken = match SponsorshipEngine::taken_exclusive(&pool, &save_id, region_id, team_id as i64).await {
+        Ok(taken) => taken,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+    if let Err(e) = SponsorshipEngine::can_sign(profile, brand_value, &held, &taken) {
+        return Ok(CommandResult::err(e));
+    }
+
+    let season = current_season(&pool, &save_id).await? as u32;
+    let offer = SponsorshipEngine::build_offer(profile, brand_value, power_rating);
+    let id = match SponsorshipEngine::insert_contract(&pool, &save_id, team_id as i64, &offer, season).await {
+        Ok(id) => id,
+        Err(e) => return Ok(CommandResult::err(e)),
+    };
+
+    match SponsorshipEngine::load_contracts(&pool, &save_id, Some(team_id), true).await {
+        Ok(list) => match list.into_iter().find(|c| c.id == id) {
+            Some(c) => Ok(CommandResult::ok(c)),
+            None => Ok(CommandResult::err("签约后未找到赞助合同")),
+        },
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
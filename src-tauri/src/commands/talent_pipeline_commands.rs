@@ -0,0 +1,22 @@
+//! 青训管线 Tauri 命令
+
+use crate::commands::save_commands::{AppState, CommandResult};
+use crate::models::TalentClassRecord;
+use crate::services::GameFlowService;
+use crate::{get_pool, get_save_id};
+use tauri::State;
+
+/// 获取青训届次记录（不传赛季则返回所有赛季）
+#[tauri::command]
+pub async fn get_talent_classes(
+    state: State<'_, AppState>,
+    season_id: Option<u64>,
+) -> Result<CommandResult<Vec<TalentClassRecord>>, String> {
+    let pool = get_pool!(state);
+    let save_id = get_save_id!(state);
+
+    match GameFlowService::load_talent_classes(&pool, &save_id, season_id).await {
+        Ok(classes) => Ok(CommandResult::ok(classes)),
+        Err(e) => Ok(CommandResult::err(e)),
+    }
+}
@@ -1,7 +1,7 @@
 use crate::commands::save_commands::{AppState, CommandResult};
 use crate::db::{PlayerRepository, TeamRepository};
-use crate::engines::{MarketValueEngine, PlayerHonorRecord, PlayerFormFactors, ConditionEngine, TraitType};
-use crate::models::{Player, Team};
+use crate::engines::{MarketValueEngine, PlayerFogView, PlayerHonorRecord, PlayerFormFactors, ConditionEngine, ScoutingEngine, TraitType};
+use crate::models::{Player, ScoutedRatings, Team};
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Sqlite, Row};
 use tauri::State;
@@ -434,6 +434,9 @@ pub struct PlayerInfo {
     pub is_starter: bool,
     pub satisfaction: u8,
     pub loyalty: u8,
+    /// 执教队伍视角的球探情报（本队选手为精确值，观察者视角为 None）
+    #[serde(default)]
+    pub scouting: Option<ScoutedRatings>,
 }
 
 impl From<Player> for PlayerInfo {
@@ -459,10 +462,40 @@ impl From<Player> for PlayerInfo {
             is_starter: p.is_starter,
             satisfaction: p.satisfaction,
             loyalty: p.loyalty,
+            scouting: None,
         }
     }
 }
 
+/// 球探迷雾：按观察队伍的了解程度把能力/潜力替换为区间估计值，身价按评分区间折算
+pub(crate) fn apply_player_fog(info: &mut PlayerInfo, view: &PlayerFogView, trait_count: usize) -> ScoutedRatings {
+    let mut scouted = view.scout(info.id, info.team_id, info.ability, info.potential, trait_count);
+    // 身价只随能力/年龄/潜力变化时的比例折算，标签与位置系数在比例中抵消
+    let age = info.age;
+    let base = |a: u8, p: u8| MarketValueEngine::calculate_base_market_value(a, age, p, "NORMAL", "MID");
+    let market_range = ScoutingEngine::derived_value_range(info.market_value, info.ability, info.potential, &scouted, base);
+    let calculated_range = ScoutingEngine::derived_value_range(
+        info.calculated_market_value, info.ability, info.potential, &scouted, base,
+    );
+    info.market_value = market_range.estimate();
+    info.calculated_market_value = calculated_range.estimate();
+    info.ability = scouted.ability_range.estimate();
+    info.potential = scouted.potential_range.estimate();
+    scouted.market_value_range = Some(calculated_range);
+    info.scouting = Some(scouted.clone());
+    scouted
+}
+
+/// 选手列表按执教队伍视角遮蔽评分
+async fn fog_player_infos(pool: &Pool<Sqlite>, save_id: &str, infos: &mut [PlayerInfo]) -> Result<(), String> {
+    if let Some(view) = crate::commands::scouting_commands::managed_fog_view(pool, save_id).await? {
+        for info in infos.iter_mut() {
+            apply_player_fog(info, &view, 0);
+        }
+    }
+    Ok(())
+}
+
 /// 从 league_standings 聚合战队比赛数据
 async fn populate_team_stats(pool: &Pool<Sqlite>, save_id: &str, teams: &mut Vec<TeamInfo>) {
     let rows = sqlx::query(
@@ -596,7 +629,10 @@ pub async fn get_all_players(
         Err(e) => return Ok(CommandResult::err(format!("Failed to get players: {}", e))),
     };
 
-    let infos: Vec<PlayerInfo> = players.into_iter().map(|p| p.into()).collect();
+    let mut infos: Vec<PlayerInfo> = players.into_iter().map(|p| p.into()).collect();
+    if let Err(e) = fog_player_infos(&pool, &save_id, &mut infos).await {
+        return Ok(CommandResult::err(e));
+    }
     Ok(CommandResult::ok(infos))
 }
 
@@ -678,10 +714,19 @@ pub async fn get_team_roster(
         .into_iter()
         .partition(|p| p.is_starter);
 
+    let mut starters: Vec<PlayerInfo> = starters.into_iter().map(|p| p.into()).collect();
+    let mut substitutes: Vec<PlayerInfo> = substitutes.into_iter().map(|p| p.into()).collect();
+    if let Err(e) = fog_player_infos(&pool, &save_id, &mut starters).await {
+        return Ok(CommandResult::err(e));
+    }
+    if let Err(e) = fog_player_infos(&pool, &save_id, &mut substitutes).await {
+        return Ok(CommandResult::err(e));
+    }
+
     Ok(CommandResult::ok(TeamRoster {
         team: team_info,
-        starters: starters.into_iter().map(|p| p.into()).collect(),
-        substitutes: substitutes.into_iter().map(|p| p.into()).collect(),
+        starters,
+        substitutes,
     }))
 }
 
@@ -733,7 +778,13 @@ pub async fn get_player(
         Err(e) => return Ok(CommandResult::err(format!("Failed to get player: {}", e))),
     };
 
-    Ok(CommandResult::ok(player.into()))
+    let mut infos = vec![PlayerInfo::from(player)];
+    if let Some(save_id) = state.current_save_id.read().await.clone() {
+        if let Err(e) = fog_player_infos(&pool, &save_id, &mut infos).await {
+            return Ok(CommandResult::err(e));
+        }
+    }
+    Ok(CommandResult::ok(infos.into_iter().next().unwrap()))
 }
 
 /// 设置首发阵容
@@ -1097,6 +1148,8 @@ pub struct PlayerFullDetail {
     pub player: PlayerInfo,
     pub traits: Vec<TraitInfo>,
     pub condition_info: PlayerConditionInfo,
+    /// 指定观察队伍时的球探情报（此时 ability/potential 为估计值，traits 仅含已揭示部分）
+    pub scouting: Option<ScoutedRatings>,
 }
 
 /// 获取选手特性列表
@@ -1186,6 +1239,8 @@ pub async fn get_player_condition(
 }
 
 /// 获取选手完整详情（包含特性和状态）
+///
+/// 按执教队伍的球探情报返回：能力/潜力以区间给出，身价按评分区间折算，未揭示的特性被隐藏。
 #[tauri::command]
 pub async fn get_player_full_detail(
     state: State<'_, AppState>,
@@ -1217,7 +1272,7 @@ pub async fn get_player_full_detail(
     .await
     .unwrap_or_default();
 
-    let traits: Vec<TraitInfo> = trait_rows.into_iter()
+    let mut traits: Vec<TraitInfo> = trait_rows.into_iter()
         .filter_map(|row| {
             let trait_str: String = row.get("trait_type");
             parse_trait_type(&trait_str).map(|t| t.into())
@@ -1227,18 +1282,32 @@ pub async fn get_player_full_detail(
     // 获取状态因子
     let factors = get_or_create_form_factors(&pool, player_id).await;
 
-    // 计算 condition
+    // 球探迷雾：按执教队伍的了解程度隐藏真实评分及其派生数值
+    let save_id = state.current_save_id.read().await.clone();
+    let view = match save_id.as_deref() {
+        Some(save_id) => crate::commands::scouting_commands::managed_fog_view(&pool, save_id).await?,
+        None => None,
+    };
+    let age = player.age;
+    let mut info: PlayerInfo = player.into();
+    let scouting = view.map(|view| {
+        let scouted = apply_player_fog(&mut info, &view, traits.len());
+        traits.truncate(traits.len() - scouted.hidden_trait_count as usize);
+        scouted
+    });
+
+    // 计算 condition（迷雾下按估计能力计算，避免反推真实评分）
     let condition = ConditionEngine::calculate_condition(
-        player.age,
-        player.ability,
+        age,
+        info.ability,
         &factors,
         None,
     );
 
-    let condition_range = ConditionEngine::get_condition_range_by_age(player.age);
+    let condition_range = ConditionEngine::get_condition_range_by_age(age);
 
     Ok(CommandResult::ok(PlayerFullDetail {
-        player: player.into(),
+        player: info,
         traits,
         condition_info: PlayerConditionInfo {
             player_id,
@@ -1250,6 +1319,7 @@ pub async fn get_player_full_detail(
             condition,
             condition_range,
         },
+        scouting,
     }))
 }
 
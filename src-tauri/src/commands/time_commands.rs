@@ -7,6 +7,7 @@ use crate::models::{
 use crate::services::{GameFlowService, LeagueService};
 use crate::engines::match_simulation::{MatchSimulationEngine, MatchSimContext};
 use crate::engines::meta_engine::MetaEngine;
+use crate::engines::SeriesRecordInput;
 use crate::models::transfer::AITeamPersonality;
 use serde::{Deserialize, Serialize};
 use sqlx::Row;
@@ -324,7 +325,7 @@ pub async fn time_simulate_all(
                         match_info.id, match_info.tournament_id, &match_info.stage,
                         match_info.format.clone(), match_info.home_team_id, match_info.away_team_id,
                         home_players, away_players, hb, ab, &sim_ctx, &meta_weights,
-                        &hp, &ap, save.current_season as u32,
+                        &hp, &ap, save.current_season as u32, None,
                     )
                 } else {
                     let home_team = TeamRepository::get_by_id(&pool, match_info.home_team_id)
@@ -346,6 +347,11 @@ pub async fn time_simulate_all(
 
                 let played = match_engine.take_last_games_played();
                 update_season_games_played(&pool, &save_id, played).await.ok();
+                if let Err(e) = GameFlowService::update_records_after_series(
+                    &pool, &save_id, save.current_season as i64, &SeriesRecordInput::from_match_result(&result),
+                ).await {
+                    log::warn!("更新纪录簿失败: {}", e);
+                }
 
                 simulated_count += 1;
 
@@ -410,7 +416,7 @@ pub async fn time_simulate_all(
                         match_info.id, match_info.tournament_id, &match_info.stage,
                         match_info.format.clone(), match_info.home_team_id, match_info.away_team_id,
                         home_players, away_players, hb, ab, &sim_ctx, &meta_weights,
-                        &hp, &ap, save.current_season as u32,
+                        &hp, &ap, save.current_season as u32, None,
                     )
                 } else {
                     let home_team = TeamRepository::get_by_id(&pool, match_info.home_team_id)
@@ -432,6 +438,11 @@ pub async fn time_simulate_all(
 
                 let played = match_engine.take_last_games_played();
                 update_season_games_played(&pool, &save_id, played).await.ok();
+                if let Err(e) = GameFlowService::update_records_after_series(
+                    &pool, &save_id, save.current_season as i64, &SeriesRecordInput::from_match_result(&result),
+                ).await {
+                    log::warn!("更新纪录簿失败: {}", e);
+                }
 
                 simulated_count += 1;
             }
@@ -599,7 +610,7 @@ pub async fn time_simulate_next(
             match_info.id, match_info.tournament_id, &match_info.stage,
             match_info.format.clone(), match_info.home_team_id, match_info.away_team_id,
             home_players, away_players, hb3, ab3, &sim_ctx, &meta_weights,
-            &hp3, &ap3, save.current_season as u32,
+            &hp3, &ap3, save.current_season as u32, None,
         )
     } else {
         league_service.simulate_match(&match_info, home_team.power_rating, away_team.power_rating)
@@ -616,6 +627,11 @@ pub async fn time_simulate_next(
 
     let played = match_engine.take_last_games_played();
     update_season_games_played(&pool, &save_id, played).await.ok();
+    if let Err(e) = GameFlowService::update_records_after_series(
+        &pool, &save_id, save.current_season as i64, &SeriesRecordInput::from_match_result(&result),
+    ).await {
+        log::warn!("更新纪录簿失败: {}", e);
+    }
 
     // 保存选手赛事统计（用于MVP计算）
     let _ = save_quick_player_stats(
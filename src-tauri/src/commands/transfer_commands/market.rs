@@ -1,4 +1,5 @@
 use crate::commands::save_commands::{AppState, CommandResult};
+use crate::engines::salary_cap::{SalaryCapEngine, DEAD_CAP_RATIO};
 use crate::engines::TransferEngine;
 use crate::models::transfer::*;
 use sqlx::Row;
@@ -402,6 +403,11 @@ pub async fn release_player(
         .await
         .ok();
 
+    // 剩余薪资按比例计入本赛季死钱
+    let dead_cap = (player.get::<i64, _>("salary") as f64 * DEAD_CAP_RATIO) as i64;
+    SalaryCapEngine::record_dead_cap(&pool, &save_id, team_id, season_id, player_id, dead_cap, "主动解约").await?;
+    let cap_space = SalaryCapEngine::build_cap_sheet(&pool, &save_id, team_id, season_id).await?.cap_space;
+
     let window: Option<(i64,)> = sqlx::query_as(
         "SELECT id FROM transfer_windows WHERE save_id = ? AND status = 'IN_PROGRESS' LIMIT 1"
     )
@@ -439,5 +445,7 @@ pub async fn release_player(
         team_name,
         release_fee,
         remaining_balance: team_balance - release_fee,
+        dead_cap,
+        cap_space,
     }))
 }
@@ -117,4 +117,8 @@ pub struct ReleasePlayerResult {
     pub team_name: String,
     pub release_fee: i64,
     pub remaining_balance: i64,
+    /// 计入本赛季薪资帽的死钱
+    pub dead_cap: i64,
+    /// 解约后剩余薪资空间
+    pub cap_space: i64,
 }
@@ -62,6 +62,31 @@ impl MigrationManager {
         let migrations: Vec<(&str, &str)> = vec![
             // 未来的增量迁移在这里注册
             // ("001_feature_name", include_str!("../../migrations/001_feature_name.sql")),
+            ("001_scouting", include_str!("../../migrations/001_scouting.sql")),
+            ("002_talent_pipeline", include_str!("../../migrations/002_talent_pipeline.sql")),
+            ("003_role_transitions", include_str!("../../migrations/003_role_transitions.sql")),
+            ("004_salary_cap", include_str!("../../migrations/004_salary_cap.sql")),
+            ("005_sponsorship", include_str!("../../migrations/005_sponsorship.sql")),
+            ("006_game_timeline", include_str!("../../migrations/006_game_timeline.sql")),
+            ("007_comp_phase_curves", include_str!("../../migrations/007_comp_phase_curves.sql")),
+            ("008_champion_patches", include_str!("../../migrations/008_champion_patches.sql")),
+            ("009_draft_rules", include_str!("../../migrations/009_draft_rules.sql")),
+            ("010_game_sides", include_str!("../../migrations/010_game_sides.sql")),
+            ("011_champion_training", include_str!("../../migrations/011_champion_training.sql")),
+            ("012_series_tactics", include_str!("../../migrations/012_series_tactics.sql")),
+            ("013_ladder_rating_system", include_str!("../../migrations/013_ladder_rating_system.sql")),
+            ("014_ladder_profiles", include_str!("../../migrations/014_ladder_profiles.sql")),
+            ("015_ladder_matchmaking", include_str!("../../migrations/015_ladder_matchmaking.sql")),
+            ("016_ladder_amateurs", include_str!("../../migrations/016_ladder_amateurs.sql")),
+            ("017_meta_evolution", include_str!("../../migrations/017_meta_evolution.sql")),
+            ("018_regional_meta", include_str!("../../migrations/018_regional_meta.sql")),
+            ("019_hall_of_fame", include_str!("../../migrations/019_hall_of_fame.sql")),
+            ("020_records_book", include_str!("../../migrations/020_records_book.sql")),
+            ("021_award_votes", include_str!("../../migrations/021_award_votes.sql")),
+            ("022_legacy_retire_season", include_str!("../../migrations/022_legacy_retire_season.sql")),
+            ("023_managed_team", include_str!("../../migrations/023_managed_team.sql")),
+            ("024_scouting_phase_guard", include_str!("../../migrations/024_scouting_phase_guard.sql")),
+            ("025_region_meta_spreads", include_str!("../../migrations/025_region_meta_spreads.sql")),
         ];
 
         for (name, sql) in migrations {
@@ -74,38 +99,48 @@ impl MigrationManager {
             .map_err(|e| e.to_string())?;
 
             if applied.is_none() {
-                // 执行迁移 SQL（逐条执行，跳过空语句）
-                for statement in sql.split(';') {
-                    let trimmed = statement.trim();
-                    if !trimmed.is_empty() {
-                        let result = sqlx::query(trimmed)
-                            .execute(pool)
-                            .await;
-
-                        // 处理错误：忽略 "duplicate column name" 错误（列已存在）
-                        if let Err(e) = result {
-                            let err_msg = e.to_string();
-                            if err_msg.contains("duplicate column name") {
-                                // 列已存在，跳过这条语句
-                                continue;
-                            } else {
-                                // 其他错误，中断迁移
-                                return Err(format!("Migration {} failed: {}", name, err_msg));
-                            }
-                        }
+                Self::apply_migration(pool, name, sql).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 应用单个迁移：整个迁移在同一事务中执行，任一语句失败则全部回滚
+    /// （如 017 重建 meta_versions 时，DROP 之后失败不会丢表）
+    pub(crate) async fn apply_migration(pool: &Pool<Sqlite>, name: &str, sql: &str) -> Result<(), String> {
+        let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+
+        // 执行迁移 SQL（逐条执行，跳过空语句）
+        for statement in sql.split(';') {
+            let trimmed = statement.trim();
+            if !trimmed.is_empty() {
+                let result = sqlx::query(trimmed)
+                    .execute(&mut *tx)
+                    .await;
+
+                // 处理错误：忽略 "duplicate column name" 错误（列已存在）
+                if let Err(e) = result {
+                    let err_msg = e.to_string();
+                    if err_msg.contains("duplicate column name") {
+                        // 列已存在，跳过这条语句
+                        continue;
+                    } else {
+                        // 其他错误，中断迁移（事务随 tx 丢弃自动回滚）
+                        return Err(format!("Migration {} failed: {}", name, err_msg));
                     }
                 }
-
-                // 记录迁移已应用
-                sqlx::query("INSERT INTO _migrations (name) VALUES (?)")
-                    .bind(name)
-                    .execute(pool)
-                    .await
-                    .map_err(|e| e.to_string())?;
             }
         }
 
-        Ok(())
+        // 记录迁移已应用
+        sqlx::query("INSERT INTO _migrations (name) VALUES (?)")
+            .bind(name)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        tx.commit().await.map_err(|e| e.to_string())
     }
 
     /// 获取当前迁移版本
@@ -147,6 +147,7 @@ pub(crate) fn parse_player_status(s: &str) -> PlayerStatus {
     match s {
         "Active" => PlayerStatus::Active,
         "Retired" => PlayerStatus::Retired,
+        "Amateur" => PlayerStatus::Amateur,
         _ => PlayerStatus::Active,
     }
 }
@@ -231,6 +232,11 @@ pub(crate) fn parse_honor_type(s: &str) -> HonorType {
         "ANNUAL_MOST_CONSISTENT" => HonorType::AnnualMostConsistent,
         "ANNUAL_MOST_DOMINANT" => HonorType::AnnualMostDominant,
         "ANNUAL_ROOKIE" => HonorType::AnnualRookie,
+        "ANNUAL_MOST_IMPROVED" => HonorType::AnnualMostImproved,
+        "ANNUAL_COMEBACK" => HonorType::AnnualComeback,
+        "ANNUAL_SIXTH_MAN" => HonorType::AnnualSixthMan,
+        "ANNUAL_BEST_IMPORT" => HonorType::AnnualBestImport,
+        "ANNUAL_REGIONAL_MVP" => HonorType::AnnualRegionalMvp,
         // 兼容旧存档
         "ANNUAL_BEST_TOP"
         | "ANNUAL_BEST_JUNGLE"
@@ -264,6 +270,11 @@ pub(crate) fn honor_type_to_db_string(honor_type: &HonorType) -> &'static str {
         HonorType::AnnualMostConsistent => "ANNUAL_MOST_CONSISTENT",
         HonorType::AnnualMostDominant => "ANNUAL_MOST_DOMINANT",
         HonorType::AnnualRookie => "ANNUAL_ROOKIE",
+        HonorType::AnnualMostImproved => "ANNUAL_MOST_IMPROVED",
+        HonorType::AnnualComeback => "ANNUAL_COMEBACK",
+        HonorType::AnnualSixthMan => "ANNUAL_SIXTH_MAN",
+        HonorType::AnnualBestImport => "ANNUAL_BEST_IMPORT",
+        HonorType::AnnualRegionalMvp => "ANNUAL_REGIONAL_MVP",
     }
 }
 
@@ -298,6 +309,8 @@ pub(crate) fn parse_event_type(s: &str) -> EventType {
         "ContractExpire" => EventType::ContractExpire,
         "PlayerAging" => EventType::PlayerAging,
         "SeasonSettlement" => EventType::SeasonSettlement,
+        "AmateurDiscovery" => EventType::AmateurDiscovery,
+        "RecordBroken" => EventType::RecordBroken,
         _ => EventType::SeasonSettlement,
     }
 }
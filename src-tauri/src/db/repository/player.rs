@@ -1,6 +1,6 @@
 use crate::db::DatabaseError;
 use crate::models::*;
-use sqlx::{Pool, Row, Sqlite};
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
 use super::helpers::*;
 
 pub struct PlayerRepository;
@@ -11,15 +11,25 @@ impl PlayerRepository {
         pool: &Pool<Sqlite>,
         save_id: &str,
         player: &Player,
+    ) -> Result<u64, DatabaseError> {
+        let mut conn = pool.acquire().await.map_err(|e| DatabaseError::Connection(e.to_string()))?;
+        Self::create_with_conn(&mut conn, save_id, player).await
+    }
+
+    /// 在指定连接（可为事务）上创建选手
+    pub async fn create_with_conn(
+        conn: &mut SqliteConnection,
+        save_id: &str,
+        player: &Player,
     ) -> Result<u64, DatabaseError> {
         let result = sqlx::query(
             r#"
             INSERT INTO players (
                 save_id, game_id, real_name, nationality, age, ability, potential,
                 stability, tag, status, position, team_id, salary, market_value,
-                contract_end_season, join_season, is_starter
+                contract_end_season, join_season, is_starter, retire_season
             )
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(save_id)
@@ -39,7 +49,8 @@ impl PlayerRepository {
         .bind(player.contract_end_season.map(|s| s as i64))
         .bind(player.join_season as i64)
         .bind(player.is_starter)
-        .execute(pool)
+        .bind(player.retire_season.map(|s| s as i64))
+        .execute(&mut *conn)
         .await
         .map_err(|e| DatabaseError::Query(e.to_string()))?;
 
@@ -101,7 +112,8 @@ impl PlayerRepository {
                 game_id = ?, real_name = ?, nationality = ?, age = ?,
                 ability = ?, potential = ?, stability = ?, tag = ?,
                 status = ?, position = ?, team_id = ?, salary = ?,
-                market_value = ?, contract_end_season = ?, is_starter = ?
+                market_value = ?, contract_end_season = ?, is_starter = ?,
+                retire_season = COALESCE(?, retire_season)
             WHERE id = ?
             "#,
         )
@@ -120,6 +132,7 @@ impl PlayerRepository {
         .bind(player.market_value as i64)
         .bind(player.contract_end_season.map(|s| s as i64))
         .bind(player.is_starter)
+        .bind(player.retire_season.map(|s| s as i64))
         .bind(player.id as i64)
         .execute(pool)
         .await
@@ -249,3 +249,158 @@ async fn test_player_get_by_team() {
     let players = PlayerRepository::get_by_team(&pool, team_id).await.unwrap();
     assert_eq!(players.len(), 2);
 }
+
+#[tokio::test]
+async fn test_pending_migrations_apply_on_baseline() {
+    use crate::db::migrations::MigrationManager;
+
+    let pool = setup_test_db().await;
+    MigrationManager::mark_baseline_applied(&pool).await.unwrap();
+    MigrationManager::run_pending_migrations(&pool).await.unwrap();
+
+    let patch_column: Result<Option<(i64,)>, _> =
+        sqlx::query_as("SELECT patch_number FROM meta_versions LIMIT 1")
+            .fetch_optional(&pool)
+            .await;
+    assert!(patch_column.is_ok());
+}
+
+#[tokio::test]
+async fn test_failed_migration_rolls_back() {
+    use crate::db::migrations::MigrationManager;
+
+    let pool = setup_test_db().await;
+    MigrationManager::mark_baseline_applied(&pool).await.unwrap();
+
+    let sql = "CREATE TABLE meta_versions_tmp (id INTEGER); DROP TABLE meta_versions; SELECT * FROM missing_table";
+    let result = MigrationManager::apply_migration(&pool, "999_broken", sql).await;
+    assert!(result.is_err());
+
+    let table_exists = |name: &'static str| {
+        let pool = pool.clone();
+        async move {
+            sqlx::query_as::<_, (i64,)>("SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = ?")
+                .bind(name)
+                .fetch_one(&pool)
+                .await
+                .unwrap()
+                .0
+        }
+    };
+    assert_eq!(table_exists("meta_versions").await, 1);
+    assert_eq!(table_exists("meta_versions_tmp").await, 0);
+
+    let recorded: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM _migrations WHERE name = '999_broken'")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(recorded.0, 0);
+}
+
+#[tokio::test]
+async fn test_player_update_keeps_retire_season() {
+    let pool = setup_test_db().await;
+    let save = make_save("Retire Season Test");
+    SaveRepository::create(&pool, &save).await.unwrap();
+
+    let mut player = make_player(1);
+    player.team_id = None;
+    player.id = PlayerRepository::create(&pool, &save.id, &player).await.unwrap();
+
+    player.status = PlayerStatus::Retired;
+    player.retire_season = Some(5);
+    PlayerRepository::update(&pool, &player).await.unwrap();
+    assert_eq!(PlayerRepository::get_by_id(&pool, player.id).await.unwrap().retire_season, Some(5));
+
+    // 未携带退役赛季的更新不会抹掉已记录的值
+    player.retire_season = None;
+    PlayerRepository::update(&pool, &player).await.unwrap();
+    assert_eq!(PlayerRepository::get_by_id(&pool, player.id).await.unwrap().retire_season, Some(5));
+}
+
+#[tokio::test]
+async fn test_drafting_discovered_amateur_reuses_player_row() {
+    use crate::db::migrations::MigrationManager;
+    use crate::services::GameFlowService;
+
+    let pool = setup_test_db().await;
+    MigrationManager::mark_baseline_applied(&pool).await.unwrap();
+    MigrationManager::run_pending_migrations(&pool).await.unwrap();
+
+    let save = make_save("Amateur Draft Test");
+    SaveRepository::create(&pool, &save).await.unwrap();
+    sqlx::query("INSERT INTO regions (save_id, name, short_name) VALUES (?, 'LPL', 'LPL')")
+        .bind(&save.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    let team_id = TeamRepository::create(&pool, &save.id, &make_team(1)).await.unwrap() as i64;
+
+    let mut amateur = make_player(0);
+    amateur.game_id = "LadderKid".to_string();
+    amateur.team_id = None;
+    amateur.status = PlayerStatus::Amateur;
+    amateur.age = 17;
+    amateur.ability = 60;
+    amateur.potential = 80;
+    let player_id = PlayerRepository::create(&pool, &save.id, &amateur).await.unwrap() as i64;
+    sqlx::query("UPDATE players SET home_region_id = 1 WHERE id = ?")
+        .bind(player_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("INSERT INTO ladder_amateur (save_id, player_id, kind, platform, joined_season, status) VALUES (?, ?, 'Amateur', 'douyu', 1, 'active')")
+        .bind(&save.id)
+        .bind(player_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let tournament_id: i64 = sqlx::query_scalar(
+        "INSERT INTO ladder_tournament (save_id, season, event_type, event_name, edition, status) VALUES (?, 1, 'douyu', '斗鱼巅峰赛', 1, 'completed') RETURNING id"
+    )
+    .bind(&save.id)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    sqlx::query("INSERT INTO ladder_rating (save_id, ladder_tournament_id, player_id, player_name, position, rating, games_played) VALUES (?, ?, ?, 'LadderKid', 'MID', 1500, 10)")
+        .bind(&save.id)
+        .bind(tournament_id)
+        .bind(player_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let discoveries = GameFlowService::review_ladder_amateurs(&pool, &save.id, tournament_id).await.unwrap();
+    assert_eq!(discoveries.len(), 1);
+    let in_pool: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM draft_pool WHERE save_id = ? AND game_id = 'LadderKid'")
+        .bind(&save.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(in_pool, 1);
+
+    let promoted = GameFlowService::promote_drafted_amateur(
+        &pool, &save.id, 1, "LadderKid", team_id, 1, 500_000, 2_000_000, 4, 80,
+    )
+    .await
+    .unwrap();
+    assert_eq!(promoted, Some(player_id));
+
+    // 选中后只有一条同 ID 选手记录，且已转为该队正式球员
+    let rows: Vec<(i64, String, Option<i64>)> =
+        sqlx::query_as("SELECT id, status, team_id FROM players WHERE save_id = ? AND game_id = 'LadderKid'")
+            .bind(&save.id)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+    assert_eq!(rows, vec![(player_id, "Active".to_string(), Some(team_id))]);
+
+    // 已转正的路人不会被再次认领
+    let again = GameFlowService::promote_drafted_amateur(
+        &pool, &save.id, 1, "LadderKid", team_id, 1, 500_000, 2_000_000, 4, 80,
+    )
+    .await
+    .unwrap();
+    assert_eq!(again, None);
+}
@@ -0,0 +1,872 @@
+//! 年度奖项投票引擎 (Award Voting Engine)
+//!
+//! 年度颁奖典礼的奖项由可插拔的奖项注册表 `AwardRegistry` 定义：
+//! - 每个奖项声明评选范围（全球 / 每个赛区 / 每个位置）、获奖名额、参评资格、核心指标与颁发的荣誉
+//! - 获奖者由媒体、职业选手、球迷三方评审团模拟投票产生：
+//!   媒体看重全年表现与稳定性，职业选手看重统治力，球迷更看重战队成绩与冠军光环，随机性也最大
+//! - 每位评委投出一张排序选票（10-7-5-3-1 计分），三方得票按权重折算为总分后排名
+//! - 得票明细写入 award_votes，供颁奖典礼展示
+
+use std::collections::BTreeMap;
+
+use rand::Rng;
+use rand_distr::StandardNormal;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::models::{ContractRole, HonorType};
+
+/// 单张选票各名次得分（名额超过 5 个的奖项按 选票长度-名次 计分）
+const BALLOT_POINTS: [u32; 5] = [10, 7, 5, 3, 1];
+/// 参评最少场次
+const MIN_GAMES: i32 = 10;
+/// 替补登场场次门槛（第六人奖）
+const MIN_SUB_APPEARANCES: i32 = 5;
+/// 完整赛季场次门槛（进步奖、复出奖、外援奖）
+const FULL_SEASON_GAMES: i32 = 20;
+/// 年度得分较前一赛季下滑超过该值视为低谷（复出奖）
+const SLUMP_MARGIN: f64 = 5.0;
+/// 位置评选顺序
+const POSITIONS: [&str; 5] = ["TOP", "JUG", "MID", "ADC", "SUP"];
+
+/// 选手某赛季的年度得分与场次
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeasonLine {
+    pub yearly_score: f64,
+    pub games_played: i32,
+}
+
+/// 参评选手
+#[derive(Debug, Clone)]
+pub struct AwardCandidate {
+    pub player_id: u64,
+    pub player_name: String,
+    pub team_id: u64,
+    pub team_name: String,
+    pub position: String,
+    pub region_id: u64,
+    pub region_name: String,
+    pub home_region_id: Option<u64>,
+    pub age: u8,
+    /// 合同角色
+    pub contract_role: ContractRole,
+    /// 本赛季替补登场场次
+    pub sub_appearances: i32,
+    pub yearly_score: f64,
+    pub consistency_score: f64,
+    pub dominance_score: f64,
+    pub champion_bonus: f64,
+    pub team_win_rate: f64,
+    pub games_played: i32,
+    /// 上赛季
+    pub previous: Option<SeasonLine>,
+    /// 前两个赛季
+    pub two_seasons_ago: Option<SeasonLine>,
+}
+
+impl AwardCandidate {
+    /// 相比上赛季的年度得分提升（上赛季场次不足则无法比较）
+    pub fn improvement(&self) -> Option<f64> {
+        self.previous
+            .filter(|p| p.games_played >= MIN_GAMES)
+            .map(|p| self.yearly_score - p.yearly_score)
+    }
+
+    /// 第六人：签约为替补/第六人角色，或本赛季多次替补登场（不看颁奖时的首发状态）
+    pub fn is_sixth_man(&self) -> bool {
+        matches!(self.contract_role, ContractRole::SixthMan | ContractRole::Sub)
+            || self.sub_appearances >= MIN_SUB_APPEARANCES
+    }
+
+    /// 上赛季陷入低谷（伤停/替补或状态下滑），本赛季回到此前水准
+    pub fn is_comeback(&self) -> bool {
+        let Some(before) = self.two_seasons_ago.filter(|s| s.games_played >= MIN_GAMES) else {
+            return false;
+        };
+        let slumped = match self.previous {
+            None => true,
+            Some(prev) => {
+                prev.games_played < MIN_GAMES || prev.yearly_score < before.yearly_score - SLUMP_MARGIN
+            }
+        };
+        slumped && self.yearly_score >= before.yearly_score - SLUMP_MARGIN
+    }
+
+    /// 外援：出生赛区与效力战队所在赛区不同
+    pub fn is_import(&self) -> bool {
+        self.region_id != 0 && self.home_region_id.is_some_and(|home| home != self.region_id)
+    }
+
+    fn position_key(&self) -> String {
+        self.position.to_uppercase()
+    }
+}
+
+/// 评审团
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoterGroup {
+    Media,
+    Pros,
+    Fans,
+}
+
+/// 评审团关注点：各维度在心目中评分的占比
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VoterLens {
+    /// 奖项核心指标
+    pub criterion: f64,
+    /// 全年表现（年度得分）
+    pub performance: f64,
+    pub consistency: f64,
+    pub dominance: f64,
+    /// 冠军光环与战队成绩
+    pub fame: f64,
+}
+
+impl VoterGroup {
+    pub const ALL: [VoterGroup; 3] = [VoterGroup::Media, VoterGroup::Pros, VoterGroup::Fans];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VoterGroup::Media => "MEDIA",
+            VoterGroup::Pros => "PROS",
+            VoterGroup::Fans => "FANS",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            VoterGroup::Media => "媒体",
+            VoterGroup::Pros => "职业选手",
+            VoterGroup::Fans => "球迷",
+        }
+    }
+
+    /// 评委人数
+    pub fn voters(&self) -> usize {
+        match self {
+            VoterGroup::Media => 40,
+            VoterGroup::Pros => 30,
+            VoterGroup::Fans => 50,
+        }
+    }
+
+    /// 在总分中的权重
+    pub fn weight(&self) -> f64 {
+        match self {
+            VoterGroup::Media => 0.40,
+            VoterGroup::Pros => 0.35,
+            VoterGroup::Fans => 0.25,
+        }
+    }
+
+    pub fn lens(&self) -> VoterLens {
+        match self {
+            VoterGroup::Media => VoterLens { criterion: 0.55, performance: 0.20, consistency: 0.15, dominance: 0.05, fame: 0.05 },
+            VoterGroup::Pros => VoterLens { criterion: 0.50, performance: 0.10, consistency: 0.05, dominance: 0.30, fame: 0.05 },
+            VoterGroup::Fans => VoterLens { criterion: 0.40, performance: 0.15, consistency: 0.00, dominance: 0.10, fame: 0.35 },
+        }
+    }
+
+    /// 单张选票的评分扰动（标准差，评分区间 0-100）
+    pub fn noise(&self) -> f64 {
+        match self {
+            VoterGroup::Media => 4.0,
+            VoterGroup::Pros => 3.0,
+            VoterGroup::Fans => 9.0,
+        }
+    }
+}
+
+/// 评选范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AwardScope {
+    /// 全球一次评选
+    Global,
+    /// 每个赛区各评一次
+    Region,
+    /// 每个位置各评一次
+    Position,
+}
+
+/// 奖项定义
+#[derive(Debug, Clone)]
+pub struct AwardCategory {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub scope: AwardScope,
+    /// 每个评选范围内的获奖名额
+    pub winners: usize,
+    /// 参评资格
+    pub eligible: fn(&AwardCandidate) -> bool,
+    /// 奖项核心指标，越高越好
+    pub criterion: fn(&AwardCandidate) -> f64,
+    /// 第 rank 名（从 1 开始）获得的荣誉
+    pub grants: fn(usize) -> Vec<HonorType>,
+}
+
+/// 奖项注册表，`default()` 为标准年度奖项
+#[derive(Debug, Clone)]
+pub struct AwardRegistry {
+    categories: Vec<AwardCategory>,
+}
+
+impl AwardRegistry {
+    pub fn new() -> Self {
+        Self { categories: Vec::new() }
+    }
+
+    /// 注册奖项，id 相同则替换原定义
+    pub fn register(&mut self, category: AwardCategory) -> &mut Self {
+        match self.categories.iter_mut().find(|c| c.id == category.id) {
+            Some(existing) => *existing = category,
+            None => self.categories.push(category),
+        }
+        self
+    }
+
+    pub fn categories(&self) -> &[AwardCategory] {
+        &self.categories
+    }
+
+    pub fn get(&self, id: &str) -> Option<&AwardCategory> {
+        self.categories.iter().find(|c| c.id == id)
+    }
+}
+
+impl Default for AwardRegistry {
+    fn default() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register(AwardCategory {
+                id: "annual_top20",
+                name: "年度Top20",
+                scope: AwardScope::Global,
+                winners: 20,
+                eligible: |c| c.games_played >= MIN_GAMES,
+                criterion: |c| c.yearly_score,
+                grants: |rank| {
+                    if rank == 1 {
+                        vec![HonorType::AnnualMvp, HonorType::AnnualTop20]
+                    } else {
+                        vec![HonorType::AnnualTop20]
+                    }
+                },
+            })
+            .register(AwardCategory {
+                id: "all_pro",
+                name: "年度最佳阵容",
+                scope: AwardScope::Position,
+                winners: 3,
+                eligible: |c| c.games_played >= MIN_GAMES,
+                criterion: |c| c.yearly_score,
+                grants: |rank| match rank {
+                    1 => vec![HonorType::AnnualAllPro1st],
+                    2 => vec![HonorType::AnnualAllPro2nd],
+                    3 => vec![HonorType::AnnualAllPro3rd],
+                    _ => Vec::new(),
+                },
+            })
+            .register(AwardCategory {
+                id: "most_consistent",
+                name: "年度最稳定选手",
+                scope: AwardScope::Global,
+                winners: 1,
+                eligible: |c| c.games_played >= 30,
+                criterion: |c| c.consistency_score,
+                grants: |_| vec![HonorType::AnnualMostConsistent],
+            })
+            .register(AwardCategory {
+                id: "most_dominant",
+                name: "年度最具统治力",
+                scope: AwardScope::Global,
+                winners: 1,
+                eligible: |c| c.games_played >= FULL_SEASON_GAMES,
+                criterion: |c| c.dominance_score,
+                grants: |_| vec![HonorType::AnnualMostDominant],
+            })
+            .register(AwardCategory {
+                id: "rookie",
+                name: "年度最佳新秀",
+                scope: AwardScope::Global,
+                winners: 1,
+                eligible: |c| c.age <= 20 && c.games_played >= MIN_GAMES,
+                criterion: |c| c.yearly_score,
+                grants: |_| vec![HonorType::AnnualRookie],
+            })
+            .register(AwardCategory {
+                id: "most_improved",
+                name: "年度进步最快选手",
+                scope: AwardScope::Global,
+                winners: 1,
+                eligible: |c| {
+                    c.games_played >= FULL_SEASON_GAMES
+                        && !c.is_comeback()
+                        && c.improvement().is_some_and(|delta| delta > 0.0)
+                },
+                criterion: |c| c.improvement().unwrap_or(0.0),
+                grants: |_| vec![HonorType::AnnualMostImproved],
+            })
+            .register(AwardCategory {
+                id: "comeback",
+                name: "年度最佳复出选手",
+                scope: AwardScope::Global,
+                winners: 1,
+                eligible: |c| c.games_played >= FULL_SEASON_GAMES && c.is_comeback(),
+                criterion: |c| c.yearly_score - c.previous.map(|p| p.yearly_score).unwrap_or(0.0),
+                grants: |_| vec![HonorType::AnnualComeback],
+            })
+            .register(AwardCategory {
+                id: "sixth_man",
+                name: "年度最佳第六人",
+                scope: AwardScope::Global,
+                winners: 1,
+                eligible: |c| c.games_played >= MIN_GAMES && c.is_sixth_man(),
+                criterion: |c| c.yearly_score,
+                grants: |_| vec![HonorType::AnnualSixthMan],
+            })
+            .register(AwardCategory {
+                id: "best_import",
+                name: "年度最佳外援",
+                scope: AwardScope::Global,
+                winners: 1,
+                eligible: |c| c.is_import() && c.games_played >= FULL_SEASON_GAMES,
+                criterion: |c| c.yearly_score,
+                grants: |_| vec![HonorType::AnnualBestImport],
+            })
+            .register(AwardCategory {
+                id: "regional_mvp",
+                name: "赛区年度MVP",
+                scope: AwardScope::Region,
+                winners: 1,
+                eligible: |c| c.region_id != 0 && c.games_played >= MIN_GAMES,
+                criterion: |c| c.yearly_score,
+                grants: |_| vec![HonorType::AnnualRegionalMvp],
+            });
+        registry
+    }
+}
+
+/// 单个选手的得票
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VoteTally {
+    pub player_id: u64,
+    pub player_name: String,
+    pub team_id: u64,
+    pub team_name: String,
+    pub position: String,
+    pub media_points: u32,
+    pub pros_points: u32,
+    pub fans_points: u32,
+    pub first_place_votes: u32,
+    /// 三方得票率按权重折算的总分（0-100）
+    pub total_score: f64,
+    pub rank: u32,
+    pub is_winner: bool,
+}
+
+impl VoteTally {
+    fn new(candidate: &AwardCandidate) -> Self {
+        Self {
+            player_id: candidate.player_id,
+            player_name: candidate.player_name.clone(),
+            team_id: candidate.team_id,
+            team_name: candidate.team_name.clone(),
+            position: candidate.position.clone(),
+            media_points: 0,
+            pros_points: 0,
+            fans_points: 0,
+            first_place_votes: 0,
+            total_score: 0.0,
+            rank: 0,
+            is_winner: false,
+        }
+    }
+
+    pub fn points(&self, group: VoterGroup) -> u32 {
+        match group {
+            VoterGroup::Media => self.media_points,
+            VoterGroup::Pros => self.pros_points,
+            VoterGroup::Fans => self.fans_points,
+        }
+    }
+
+    fn add_points(&mut self, group: VoterGroup, points: u32) {
+        match group {
+            VoterGroup::Media => self.media_points += points,
+            VoterGroup::Pros => self.pros_points += points,
+            VoterGroup::Fans => self.fans_points += points,
+        }
+    }
+}
+
+/// 一个奖项在一个评选范围内的投票结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AwardVoteResult {
+    pub award_id: String,
+    pub award_name: String,
+    /// 评选范围键：全球为空，赛区为 region_id，位置为 TOP/JUG/...
+    pub scope_key: String,
+    pub scope_label: String,
+    /// 按名次排列的得票（仅含获得选票的选手）
+    pub tallies: Vec<VoteTally>,
+}
+
+impl AwardVoteResult {
+    pub fn winners(&self) -> impl Iterator<Item = &VoteTally> {
+        self.tallies.iter().filter(|t| t.is_winner)
+    }
+}
+
+/// 候选人在 0-100 区间内归一化后的各维度
+struct NormalizedFeatures {
+    criterion: f64,
+    performance: f64,
+    consistency: f64,
+    dominance: f64,
+    fame: f64,
+}
+
+impl NormalizedFeatures {
+    fn blend(&self, lens: &VoterLens) -> f64 {
+        self.criterion * lens.criterion
+            + self.performance * lens.performance
+            + self.consistency * lens.consistency
+            + self.dominance * lens.dominance
+            + self.fame * lens.fame
+    }
+}
+
+fn normalize(values: &[f64]) -> Vec<f64> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if !(max - min).is_finite() || max - min < 1e-9 {
+        return vec![50.0; values.len()];
+    }
+    values.iter().map(|v| (v - min) / (max - min) * 100.0).collect()
+}
+
+fn ballot_points(ballot_size: usize, place: usize) -> u32 {
+    if ballot_size <= BALLOT_POINTS.len() {
+        BALLOT_POINTS[place]
+    } else {
+        (ballot_size - place) as u32
+    }
+}
+
+pub struct AwardVotingEngine;
+
+impl AwardVotingEngine {
+    /// 对一组参评选手进行三方投票，返回按名次排列的得票
+    pub fn run_vote<R: Rng + ?Sized>(
+        category: &AwardCategory,
+        candidates: &[&AwardCandidate],
+        rng: &mut R,
+    ) -> Vec<VoteTally> {
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+        let ballot_size = category.winners.max(BALLOT_POINTS.len()).min(candidates.len());
+
+        let column = |f: &dyn Fn(&AwardCandidate) -> f64| -> Vec<f64> {
+            normalize(&candidates.iter().map(|c| f(c)).collect::<Vec<_>>())
+        };
+        let criterion = column(&|c| (category.criterion)(c));
+        let performance = column(&|c| c.yearly_score);
+        let consistency = column(&|c| c.consistency_score);
+        let dominance = column(&|c| c.dominance_score);
+        let champion = column(&|c| c.champion_bonus);
+        let win_rate = column(&|c| c.team_win_rate);
+        let features: Vec<NormalizedFeatures> = (0..candidates.len())
+            .map(|i| NormalizedFeatures {
+                criterion: criterion[i],
+                performance: performance[i],
+                consistency: consistency[i],
+                dominance: dominance[i],
+                fame: champion[i] * 0.5 + win_rate[i] * 0.5,
+            })
+            .collect();
+
+        let mut tallies: Vec<VoteTally> = candidates.iter().map(|c| VoteTally::new(c)).collect();
+        for group in VoterGroup::ALL {
+            let lens = group.lens();
+            let base: Vec<f64> = features.iter().map(|f| f.blend(&lens)).collect();
+            for _ in 0..group.voters() {
+                let mut ballot: Vec<(usize, f64)> = base
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, score)| {
+                        let z: f64 = rng.sample(StandardNormal);
+                        (idx, score + z * group.noise())
+                    })
+                    .collect();
+                ballot.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                for (place, (idx, _)) in ballot.iter().take(ballot_size).enumerate() {
+                    tallies[*idx].add_points(group, ballot_points(ballot_size, place));
+                    if place == 0 {
+                        tallies[*idx].first_place_votes += 1;
+                    }
+                }
+            }
+        }
+
+        let max_points = ballot_points(ballot_size, 0) as f64;
+        for tally in &mut tallies {
+            tally.total_score = VoterGroup::ALL
+                .iter()
+                .map(|g| g.weight() * tally.points(*g) as f64 / (g.voters() as f64 * max_points) * 100.0)
+                .sum();
+        }
+        tallies.retain(|t| VoterGroup::ALL.iter().any(|g| t.points(*g) > 0));
+        tallies.sort_by(|a, b| {
+            b.total_score
+                .partial_cmp(&a.total_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(b.first_place_votes.cmp(&a.first_place_votes))
+                .then(a.player_id.cmp(&b.player_id))
+        });
+        for (idx, tally) in tallies.iter_mut().enumerate() {
+            tally.rank = (idx + 1) as u32;
+            tally.is_winner = idx < category.winners;
+        }
+        tallies
+    }
+
+    /// 按注册表顺序评选全部奖项
+    pub fn run_ceremony<R: Rng + ?Sized>(
+        registry: &AwardRegistry,
+        candidates: &[AwardCandidate],
+        rng: &mut R,
+    ) -> Vec<AwardVoteResult> {
+        let mut results = Vec::new();
+        for category in registry.categories() {
+            let eligible: Vec<&AwardCandidate> = candidates.iter().filter(|c| (category.eligible)(c)).collect();
+            for (scope_key, scope_label, pool) in Self::split_scope(category.scope, &eligible) {
+                let tallies = Self::run_vote(category, &pool, rng);
+                if tallies.is_empty() {
+                    continue;
+                }
+                results.push(AwardVoteResult {
+                    award_id: category.id.to_string(),
+                    award_name: category.name.to_string(),
+                    scope_key,
+                    scope_label,
+                    tallies,
+                });
+            }
+        }
+        results
+    }
+
+    fn split_scope<'a>(
+        scope: AwardScope,
+        eligible: &[&'a AwardCandidate],
+    ) -> Vec<(String, String, Vec<&'a AwardCandidate>)> {
+        match scope {
+            AwardScope::Global => vec![(String::new(), String::new(), eligible.to_vec())],
+            AwardScope::Position => POSITIONS
+                .iter()
+                .map(|pos| {
+                    let pool = eligible.iter().copied().filter(|c| c.position_key() == *pos).collect();
+                    (pos.to_string(), pos.to_string(), pool)
+                })
+                .collect(),
+            AwardScope::Region => {
+                let mut regions: BTreeMap<u64, (String, Vec<&'a AwardCandidate>)> = BTreeMap::new();
+                for c in eligible {
+                    regions
+                        .entry(c.region_id)
+                        .or_insert_with(|| (c.region_name.clone(), Vec::new()))
+                        .1
+                        .push(c);
+                }
+                regions
+                    .into_iter()
+                    .map(|(region_id, (label, pool))| (region_id.to_string(), label, pool))
+                    .collect()
+            }
+        }
+    }
+
+    /// 保存本赛季投票明细（重新评选时覆盖）
+    pub async fn save_results(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        results: &[AwardVoteResult],
+    ) -> Result<(), String> {
+        sqlx::query("DELETE FROM award_votes WHERE save_id = ? AND season_id = ?")
+            .bind(save_id)
+            .bind(season_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("清除投票记录失败: {}", e))?;
+
+        for result in results {
+            for tally in &result.tallies {
+                sqlx::query(
+                    r#"
+                    INSERT INTO award_votes (
+                        save_id, season_id, award_id, award_name, scope_key, scope_label,
+                        player_id, player_name, team_id, team_name, position,
+                        media_points, pros_points, fans_points, first_place_votes,
+                        total_score, vote_rank, is_winner
+                    ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    "#,
+                )
+                .bind(save_id)
+                .bind(season_id)
+                .bind(&result.award_id)
+                .bind(&result.award_name)
+                .bind(&result.scope_key)
+                .bind(&result.scope_label)
+                .bind(tally.player_id as i64)
+                .bind(&tally.player_name)
+                .bind((tally.team_id != 0).then_some(tally.team_id as i64))
+                .bind(&tally.team_name)
+                .bind(&tally.position)
+                .bind(tally.media_points as i64)
+                .bind(tally.pros_points as i64)
+                .bind(tally.fans_points as i64)
+                .bind(tally.first_place_votes as i64)
+                .bind(tally.total_score)
+                .bind(tally.rank as i64)
+                .bind(tally.is_winner)
+                .execute(pool)
+                .await
+                .map_err(|e| format!("保存投票记录失败: {}", e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 读取某赛季的投票明细，可按奖项过滤
+    pub async fn load_results(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        award_id: Option<&str>,
+    ) -> Result<Vec<AwardVoteResult>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT award_id, award_name, scope_key, scope_label, player_id, player_name,
+                   COALESCE(team_id, 0) as team_id, team_name, position,
+                   media_points, pros_points, fans_points, first_place_votes,
+                   total_score, vote_rank, is_winner
+            FROM award_votes
+            WHERE save_id = ? AND season_id = ? AND (? IS NULL OR award_id = ?)
+            ORDER BY id
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(award_id)
+        .bind(award_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询投票记录失败: {}", e))?;
+
+        let mut results: Vec<AwardVoteResult> = Vec::new();
+        for row in &rows {
+            let award_id: String = row.get("award_id");
+            let scope_key: String = row.get("scope_key");
+            let tally = VoteTally {
+                player_id: row.get::<i64, _>("player_id") as u64,
+                player_name: row.get("player_name"),
+                team_id: row.get::<i64, _>("team_id") as u64,
+                team_name: row.get("team_name"),
+                position: row.get("position"),
+                media_points: row.get::<i64, _>("media_points") as u32,
+                pros_points: row.get::<i64, _>("pros_points") as u32,
+                fans_points: row.get::<i64, _>("fans_points") as u32,
+                first_place_votes: row.get::<i64, _>("first_place_votes") as u32,
+                total_score: row.get("total_score"),
+                rank: row.get::<i64, _>("vote_rank") as u32,
+                is_winner: row.get::<i64, _>("is_winner") != 0,
+            };
+            match results.last_mut() {
+                Some(last) if last.award_id == award_id && last.scope_key == scope_key => last.tallies.push(tally),
+                _ => results.push(AwardVoteResult {
+                    award_id,
+                    award_name: row.get("award_name"),
+                    scope_key,
+                    scope_label: row.get("scope_label"),
+                    tallies: vec![tally],
+                }),
+            }
+        }
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn candidate(player_id: u64, position: &str, yearly_score: f64) -> AwardCandidate {
+        AwardCandidate {
+            player_id,
+            player_name: format!("P{}", player_id),
+            team_id: player_id,
+            team_name: format!("T{}", player_id),
+            position: position.to_string(),
+            region_id: 1,
+            region_name: "LPL".to_string(),
+            home_region_id: Some(1),
+            age: 23,
+            contract_role: ContractRole::Starter,
+            sub_appearances: 0,
+            yearly_score,
+            consistency_score: 70.0,
+            dominance_score: 60.0,
+            champion_bonus: 0.0,
+            team_win_rate: 0.5,
+            games_played: 40,
+            previous: None,
+            two_seasons_ago: None,
+        }
+    }
+
+    #[test]
+    fn test_default_registry_and_override() {
+        let mut registry = AwardRegistry::default();
+        for id in [
+            "annual_top20", "all_pro", "most_consistent", "most_dominant", "rookie",
+            "most_improved", "comeback", "sixth_man", "best_import", "regional_mvp",
+        ] {
+            assert!(registry.get(id).is_some(), "缺少奖项 {}", id);
+        }
+        let count = registry.categories().len();
+
+        let top20 = registry.get("annual_top20").unwrap();
+        assert_eq!((top20.grants)(1), vec![HonorType::AnnualMvp, HonorType::AnnualTop20]);
+        assert_eq!((top20.grants)(2), vec![HonorType::AnnualTop20]);
+
+        registry.register(AwardCategory {
+            id: "rookie",
+            name: "年度最佳新人",
+            scope: AwardScope::Global,
+            winners: 1,
+            eligible: |c| c.age <= 19,
+            criterion: |c| c.yearly_score,
+            grants: |_| vec![HonorType::AnnualRookie],
+        });
+        assert_eq!(registry.categories().len(), count);
+        assert_eq!(registry.get("rookie").unwrap().name, "年度最佳新人");
+    }
+
+    #[test]
+    fn test_candidate_narratives() {
+        let mut improved = candidate(1, "MID", 80.0);
+        improved.previous = Some(SeasonLine { yearly_score: 60.0, games_played: 40 });
+        improved.two_seasons_ago = Some(SeasonLine { yearly_score: 55.0, games_played: 40 });
+        assert_eq!(improved.improvement(), Some(20.0));
+        assert!(!improved.is_comeback());
+
+        let mut comeback = candidate(2, "TOP", 78.0);
+        comeback.previous = Some(SeasonLine { yearly_score: 40.0, games_played: 6 });
+        comeback.two_seasons_ago = Some(SeasonLine { yearly_score: 80.0, games_played: 45 });
+        assert!(comeback.is_comeback());
+        assert_eq!(comeback.improvement(), None);
+
+        let mut still_slumping = comeback.clone();
+        still_slumping.yearly_score = 60.0;
+        assert!(!still_slumping.is_comeback());
+
+        let mut import = candidate(3, "ADC", 70.0);
+        assert!(!import.is_import());
+        import.home_region_id = Some(2);
+        assert!(import.is_import());
+
+        // 第六人按合同角色或替补登场判定，首发合同且极少替补登场的不计入
+        let mut sixth = candidate(4, "JUG", 65.0);
+        assert!(!sixth.is_sixth_man());
+        sixth.sub_appearances = MIN_SUB_APPEARANCES;
+        assert!(sixth.is_sixth_man());
+        sixth.sub_appearances = 0;
+        sixth.contract_role = ContractRole::SixthMan;
+        assert!(sixth.is_sixth_man());
+        sixth.contract_role = ContractRole::PlayingCoach;
+        assert!(!sixth.is_sixth_man());
+    }
+
+    #[test]
+    fn test_vote_points_and_clear_winner() {
+        let registry = AwardRegistry::default();
+        let category = registry.get("most_consistent").unwrap();
+        let players: Vec<AwardCandidate> = (1..=8)
+            .map(|id| {
+                let mut c = candidate(id, "MID", 60.0);
+                c.consistency_score = if id == 5 { 98.0 } else { 50.0 + id as f64 };
+                c
+            })
+            .collect();
+        let refs: Vec<&AwardCandidate> = players.iter().collect();
+        let mut rng = StdRng::seed_from_u64(7);
+        let tallies = AwardVotingEngine::run_vote(category, &refs, &mut rng);
+
+        assert_eq!(tallies[0].player_id, 5);
+        assert!(tallies[0].is_winner);
+        assert_eq!(tallies.iter().filter(|t| t.is_winner).count(), 1);
+        let ballot_total: u32 = BALLOT_POINTS.iter().sum();
+        for group in VoterGroup::ALL {
+            let points: u32 = tallies.iter().map(|t| t.points(group)).sum();
+            assert_eq!(points, ballot_total * group.voters() as u32);
+        }
+        assert!(tallies.windows(2).all(|w| w[0].total_score >= w[1].total_score));
+    }
+
+    #[test]
+    fn test_fans_favor_champions() {
+        let registry = AwardRegistry::default();
+        let category = registry.get("annual_top20").unwrap();
+        let mut star = candidate(1, "ADC", 80.0);
+        star.champion_bonus = 10.0;
+        star.team_win_rate = 0.9;
+        let plain = candidate(2, "ADC", 80.0);
+        let filler: Vec<AwardCandidate> = (3..=6).map(|id| candidate(id, "SUP", 50.0)).collect();
+        let mut refs = vec![&star, &plain];
+        refs.extend(filler.iter());
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let tallies = AwardVotingEngine::run_vote(category, &refs, &mut rng);
+        let star_tally = tallies.iter().find(|t| t.player_id == 1).unwrap();
+        let plain_tally = tallies.iter().find(|t| t.player_id == 2).unwrap();
+        assert!(star_tally.fans_points > plain_tally.fans_points);
+        assert_eq!(star_tally.rank, 1);
+    }
+
+    #[test]
+    fn test_ceremony_scopes() {
+        let mut players = Vec::new();
+        for (i, pos) in POSITIONS.iter().enumerate() {
+            for j in 0..4u64 {
+                let id = (i as u64) * 10 + j + 1;
+                let mut c = candidate(id, pos, 90.0 - j as f64 * 10.0);
+                if i % 2 == 1 {
+                    c.region_id = 2;
+                    c.region_name = "LCK".to_string();
+                    c.home_region_id = Some(2);
+                }
+                players.push(c);
+            }
+        }
+        let mut rng = StdRng::seed_from_u64(3);
+        let results = AwardVotingEngine::run_ceremony(&AwardRegistry::default(), &players, &mut rng);
+
+        let all_pro: Vec<&AwardVoteResult> = results.iter().filter(|r| r.award_id == "all_pro").collect();
+        assert_eq!(all_pro.len(), 5);
+        assert!(all_pro.iter().all(|r| r.winners().count() == 3));
+
+        let regional: Vec<&AwardVoteResult> = results.iter().filter(|r| r.award_id == "regional_mvp").collect();
+        assert_eq!(regional.len(), 2);
+        assert_eq!(regional[0].scope_label, "LPL");
+        assert!(regional.iter().all(|r| r.winners().count() == 1));
+
+        // 无人满足资格的奖项不产生结果
+        assert!(results.iter().all(|r| r.award_id != "comeback" && r.award_id != "best_import"));
+        assert_eq!(results.iter().find(|r| r.award_id == "annual_top20").unwrap().winners().count(), 20);
+    }
+}
@@ -1,7 +1,12 @@
 use super::champion::{
     self, get_champions_by_position, Archetype, Champion, MasteryTier, VersionTier,
 };
+use super::draft_rules::SeriesDraftRules;
+use super::draft_scouting::DraftScoutingReport;
 use super::meta_engine::MetaType;
+use super::regional_meta::RegionStyleProfile;
+use super::series_tactics::GameTactics;
+use super::side_selection::{MapSide, SideChoice};
 use super::traits::TraitType;
 use crate::models::player::Position;
 use rand::rngs::StdRng;
@@ -10,6 +15,15 @@ use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
+/// 摇摆位加成：英雄可由己方多个未锁定位置使用，对手无法判断分路
+const FLEX_PICK_BONUS: i32 = 4;
+/// 从该手开始为 counter 位（第二轮选人），可针对对手已亮出的对线英雄
+const COUNTER_PICK_SLOT_START: usize = 6;
+/// 位置强度修正 → 选人评分的换算系数
+const ROLE_STRENGTH_PICK_SCALE: f64 = 8.0;
+/// 位置强度修正 → BP 战力修正的换算系数
+const ROLE_STRENGTH_MODIFIER_SCALE: f64 = 2.0;
+
 const POSITIONS: [Position; 5] = [
     Position::Top,
     Position::Jug,
@@ -180,6 +194,15 @@ pub enum TeamSide {
     Away,
 }
 
+impl TeamSide {
+    pub fn opposite(&self) -> TeamSide {
+        match self {
+            TeamSide::Home => TeamSide::Away,
+            TeamSide::Away => TeamSide::Home,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct SeriesContext {
     pub prev_winner_picks: Vec<u8>,
@@ -190,6 +213,36 @@ pub struct SeriesContext {
     pub away_score: u8,
     pub game_number: u8,
     pub wins_needed: u8,
+    /// 主队本局的局间战术
+    pub home_tactics: GameTactics,
+    /// 客队本局的局间战术
+    pub away_tactics: GameTactics,
+}
+
+impl SeriesContext {
+    /// 主客视角互换（主队执红方时按蓝方先手运行 BP）
+    pub fn swapped(&self) -> SeriesContext {
+        SeriesContext {
+            prev_winner_picks: self.prev_winner_picks.clone(),
+            prev_loser_side: self.prev_loser_side.map(|s| s.opposite()),
+            prev_home_comp: self.prev_away_comp,
+            prev_away_comp: self.prev_home_comp,
+            home_score: self.away_score,
+            away_score: self.home_score,
+            game_number: self.game_number,
+            wins_needed: self.wins_needed,
+            home_tactics: self.away_tactics.clone(),
+            away_tactics: self.home_tactics.clone(),
+        }
+    }
+
+    /// 某一方本局的局间战术
+    pub fn tactics_for(&self, side: TeamSide) -> &GameTactics {
+        match side {
+            TeamSide::Home => &self.home_tactics,
+            TeamSide::Away => &self.away_tactics,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -230,6 +283,22 @@ impl DraftState {
     }
 }
 
+/// 单手选人时的局面：己方/对方英雄池与已选英雄、不可选英雄、阵容规划与手序
+#[derive(Debug, Clone, Copy)]
+struct PickContext<'a> {
+    team_players: &'a [PlayerChampionPool],
+    opponent_players: &'a [PlayerChampionPool],
+    team_picks: &'a [PickEntry],
+    opponent_picks: &'a [PickEntry],
+    banned_champions: &'a HashSet<u8>,
+    picked_champions: &'a HashSet<u8>,
+    version_tiers: &'a HashMap<u8, VersionTier>,
+    plan: &'a DraftPlan,
+    pick_index: usize,
+    /// 系列赛规则限制英雄复用（全局BP），需要考虑英雄池深度
+    reuse_restricted: bool,
+}
+
 const BAN_ORDER: [(TeamSide, u8); 10] = [
     (TeamSide::Home, 1),
     (TeamSide::Away, 1),
@@ -271,6 +340,9 @@ pub struct PickEntry {
     pub mastery_tier: MasteryTier,
     #[serde(skip)]
     pub traits: Vec<TraitType>,
+    /// 摇摆位选出（选人阶段对手无法确定其分路）
+    #[serde(default)]
+    pub flex: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -297,6 +369,38 @@ pub struct DraftResult {
     pub narrative: Option<DraftNarrative>,
 }
 
+impl DraftResult {
+    /// 主客视角互换
+    pub fn swap_sides(&mut self) {
+        for ban in &mut self.bans {
+            ban.team_side = ban.team_side.opposite();
+        }
+        std::mem::swap(&mut self.home_picks, &mut self.away_picks);
+        std::mem::swap(&mut self.home_comp, &mut self.away_comp);
+        std::mem::swap(&mut self.home_bp_modifiers, &mut self.away_bp_modifiers);
+        if let Some(narrative) = self.narrative.as_mut() {
+            std::mem::swap(&mut narrative.home_entries, &mut narrative.away_entries);
+        }
+    }
+
+    /// 在 BP 解说开头记录选边
+    pub fn record_side_choice(&mut self, choice: &SideChoice) {
+        if let Some(narrative) = self.narrative.as_mut() {
+            let entries = match choice.chooser {
+                TeamSide::Home => &mut narrative.home_entries,
+                TeamSide::Away => &mut narrative.away_entries,
+            };
+            entries.insert(
+                0,
+                NarrativeEntry {
+                    phase: "side".to_string(),
+                    message: choice.reason.clone(),
+                },
+            );
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PlayerChampionPool {
     pub player_id: u64,
@@ -311,16 +415,72 @@ pub struct PlayerChampionPool {
 pub struct BpEngine;
 
 impl BpEngine {
+    /// 按主队所在地图边运行 BP：蓝色方第一手选人，结果仍以主客视角返回
+    #[allow(clippy::too_many_arguments)]
+    pub fn run_draft_on_side(
+        home_side: MapSide,
+        home_players: &[PlayerChampionPool],
+        away_players: &[PlayerChampionPool],
+        version_tiers: &HashMap<u8, VersionTier>,
+        meta_type: MetaType,
+        rng: &mut StdRng,
+        home_scouting: &DraftScoutingReport,
+        away_scouting: &DraftScoutingReport,
+        series_ctx: Option<&SeriesContext>,
+        draft_rules: &SeriesDraftRules,
+    ) -> DraftResult {
+        if home_side == MapSide::Blue {
+            return Self::run_draft(
+                home_players,
+                away_players,
+                version_tiers,
+                meta_type,
+                rng,
+                home_scouting,
+                away_scouting,
+                series_ctx,
+                draft_rules,
+            );
+        }
+
+        let swapped_ctx = series_ctx.map(SeriesContext::swapped);
+        let mut draft = Self::run_draft(
+            away_players,
+            home_players,
+            version_tiers,
+            meta_type,
+            rng,
+            away_scouting,
+            home_scouting,
+            swapped_ctx.as_ref(),
+            &draft_rules.swapped(),
+        );
+        draft.swap_sides();
+        draft
+    }
+
     pub fn run_draft(
         home_players: &[PlayerChampionPool],
         away_players: &[PlayerChampionPool],
         version_tiers: &HashMap<u8, VersionTier>,
         meta_type: MetaType,
         rng: &mut StdRng,
-        home_team_comp_history: &[(CompType, u32)],
-        away_team_comp_history: &[(CompType, u32)],
+        home_scouting: &DraftScoutingReport,
+        away_scouting: &DraftScoutingReport,
         series_ctx: Option<&SeriesContext>,
+        draft_rules: &SeriesDraftRules,
     ) -> DraftResult {
+        // 系列赛规则锁定的英雄从双方英雄池中剔除，规划与禁选都不再考虑
+        let home_locked = draft_rules.locked_for(TeamSide::Home);
+        let away_locked = draft_rules.locked_for(TeamSide::Away);
+        let home_available = without_locked(home_players, &home_locked);
+        let away_available = without_locked(away_players, &away_locked);
+        let home_players = home_available.as_slice();
+        let away_players = away_available.as_slice();
+        let reuse_restricted = draft_rules.rule.restricts_reuse();
+        let home_team_comp_history = home_scouting.comp_history();
+        let away_team_comp_history = away_scouting.comp_history();
+
         let mut bans = Vec::with_capacity(BAN_ORDER.len());
         let mut draft_state = DraftState::new();
         let phase_one_pick_count = BAN_ORDER.iter().filter(|(_, phase)| *phase == 1).count();
@@ -328,8 +488,9 @@ impl BpEngine {
         let mut home_plan = plan_comp(
             home_players,
             version_tiers,
-            home_team_comp_history,
+            &home_team_comp_history,
             meta_type,
+            &home_scouting.region_style,
             series_ctx,
             TeamSide::Home,
             rng,
@@ -337,8 +498,9 @@ impl BpEngine {
         let mut away_plan = plan_comp(
             away_players,
             version_tiers,
-            away_team_comp_history,
+            &away_team_comp_history,
             meta_type,
+            &away_scouting.region_style,
             series_ctx,
             TeamSide::Away,
             rng,
@@ -365,37 +527,52 @@ impl BpEngine {
                 comp_display_name(away_plan.backup_comp)
             ),
         });
+        if !home_locked.is_empty() {
+            narrative.home_entries.push(NarrativeEntry {
+                phase: "rule".to_string(),
+                message: format!("{}：本局有{}名英雄不可选", draft_rules.rule.name(), home_locked.len()),
+            });
+        }
+        if !away_locked.is_empty() {
+            narrative.away_entries.push(NarrativeEntry {
+                phase: "rule".to_string(),
+                message: format!("{}：本局有{}名英雄不可选", draft_rules.rule.name(), away_locked.len()),
+            });
+        }
 
         for (team_side, ban_phase) in BAN_ORDER {
             if ban_phase != 1 {
                 continue;
             }
 
-            let (target_players, target_comp_history, target_picks, my_plan, my_picks) =
+            let (target_players, target_report, target_picks, my_plan, my_picks, target_locked) =
                 match team_side {
                     TeamSide::Home => (
                         away_players,
-                        away_team_comp_history,
+                        away_scouting,
                         draft_state.away_picks.as_slice(),
                         &home_plan,
                         draft_state.home_picks.as_slice(),
+                        &away_locked,
                     ),
                     TeamSide::Away => (
                         home_players,
-                        home_team_comp_history,
+                        home_scouting,
                         draft_state.home_picks.as_slice(),
                         &away_plan,
                         draft_state.away_picks.as_slice(),
+                        &home_locked,
                     ),
                 };
+            let target_comp_history = target_report.comp_history();
 
             if let Some(champion_id) = select_best_ban(
                 target_players,
                 version_tiers,
-                &draft_state.banned,
+                &merge_locked(&draft_state.banned, target_locked),
                 &draft_state.picked,
                 rng,
-                target_comp_history,
+                target_report,
                 ban_phase,
                 target_picks,
                 team_side,
@@ -410,7 +587,8 @@ impl BpEngine {
                     ban_phase,
                 });
 
-                let ban_msg = comp_history_ban_narrative(champion_id, target_comp_history)
+                let ban_msg = scouting_ban_narrative(champion_id, target_report, target_players)
+                    .or_else(|| comp_history_ban_narrative(champion_id, &target_comp_history))
                     .unwrap_or_else(|| {
                         generate_ban_narrative(
                             champion_id,
@@ -438,13 +616,14 @@ impl BpEngine {
 
         let mut pick_index = 0usize;
         for team_side in PICK_ORDER.iter().copied().take(phase_one_pick_count) {
-            let (plan, players, opponent_players, team_picks, opponent_picks) = match team_side {
+            let (plan, players, opponent_players, team_picks, opponent_picks, locked) = match team_side {
                 TeamSide::Home => (
                     &home_plan,
                     home_players,
                     away_players,
                     &draft_state.home_picks,
                     &draft_state.away_picks,
+                    &home_locked,
                 ),
                 TeamSide::Away => (
                     &away_plan,
@@ -452,19 +631,24 @@ impl BpEngine {
                     home_players,
                     &draft_state.away_picks,
                     &draft_state.home_picks,
+                    &away_locked,
                 ),
             };
+            let banned = merge_locked(&draft_state.banned, locked);
             let next_pick = select_best_pick(
-                players,
-                opponent_players,
-                team_picks,
-                opponent_picks,
-                &draft_state.banned,
-                &draft_state.picked,
-                version_tiers,
+                &PickContext {
+                    team_players: players,
+                    opponent_players,
+                    team_picks,
+                    opponent_picks,
+                    banned_champions: &banned,
+                    picked_champions: &draft_state.picked,
+                    version_tiers,
+                    plan,
+                    pick_index,
+                    reuse_restricted,
+                },
                 rng,
-                plan,
-                pick_index,
             );
 
             if let Some(pick) = next_pick {
@@ -542,31 +726,34 @@ impl BpEngine {
                 continue;
             }
 
-            let (target_players, target_comp_history, target_picks, my_plan, my_picks) =
+            let (target_players, target_report, target_picks, my_plan, my_picks, target_locked) =
                 match team_side {
                     TeamSide::Home => (
                         away_players,
-                        away_team_comp_history,
+                        away_scouting,
                         draft_state.away_picks.as_slice(),
                         &home_plan,
                         draft_state.home_picks.as_slice(),
+                        &away_locked,
                     ),
                     TeamSide::Away => (
                         home_players,
-                        home_team_comp_history,
+                        home_scouting,
                         draft_state.home_picks.as_slice(),
                         &away_plan,
                         draft_state.away_picks.as_slice(),
+                        &home_locked,
                     ),
                 };
+            let target_comp_history = target_report.comp_history();
 
             if let Some(champion_id) = select_best_ban(
                 target_players,
                 version_tiers,
-                &draft_state.banned,
+                &merge_locked(&draft_state.banned, target_locked),
                 &draft_state.picked,
                 rng,
-                target_comp_history,
+                target_report,
                 ban_phase,
                 target_picks,
                 team_side,
@@ -581,7 +768,8 @@ impl BpEngine {
                     ban_phase,
                 });
 
-                let ban_msg = comp_history_ban_narrative(champion_id, target_comp_history)
+                let ban_msg = scouting_ban_narrative(champion_id, target_report, target_players)
+                    .or_else(|| comp_history_ban_narrative(champion_id, &target_comp_history))
                     .unwrap_or_else(|| {
                         generate_ban_narrative(
                             champion_id,
@@ -653,13 +841,14 @@ impl BpEngine {
                 }
             }
 
-            let (plan, players, opponent_players, team_picks, opponent_picks) = match team_side {
+            let (plan, players, opponent_players, team_picks, opponent_picks, locked) = match team_side {
                 TeamSide::Home => (
                     &home_plan,
                     home_players,
                     away_players,
                     &draft_state.home_picks,
                     &draft_state.away_picks,
+                    &home_locked,
                 ),
                 TeamSide::Away => (
                     &away_plan,
@@ -667,19 +856,24 @@ impl BpEngine {
                     home_players,
                     &draft_state.away_picks,
                     &draft_state.home_picks,
+                    &away_locked,
                 ),
             };
+            let banned = merge_locked(&draft_state.banned, locked);
             let next_pick = select_best_pick(
-                players,
-                opponent_players,
-                team_picks,
-                opponent_picks,
-                &draft_state.banned,
-                &draft_state.picked,
-                version_tiers,
+                &PickContext {
+                    team_players: players,
+                    opponent_players,
+                    team_picks,
+                    opponent_picks,
+                    banned_champions: &banned,
+                    picked_champions: &draft_state.picked,
+                    version_tiers,
+                    plan,
+                    pick_index,
+                    reuse_restricted,
+                },
                 rng,
-                plan,
-                pick_index,
             );
 
             if let Some(pick) = next_pick {
@@ -717,18 +911,31 @@ impl BpEngine {
         fill_missing_positions(
             home_players,
             &mut draft_state.home_picks,
-            &draft_state.banned,
+            &merge_locked(&draft_state.banned, &home_locked),
             &mut draft_state.picked,
             rng,
         );
         fill_missing_positions(
             away_players,
             &mut draft_state.away_picks,
-            &draft_state.banned,
+            &merge_locked(&draft_state.banned, &away_locked),
             &mut draft_state.picked,
             rng,
         );
 
+        for (swap_a, swap_b) in resolve_flex_swaps(home_players, &mut draft_state.home_picks) {
+            narrative.home_entries.push(NarrativeEntry {
+                phase: "swap".to_string(),
+                message: flex_swap_narrative(&swap_a, &swap_b),
+            });
+        }
+        for (swap_a, swap_b) in resolve_flex_swaps(away_players, &mut draft_state.away_picks) {
+            narrative.away_entries.push(NarrativeEntry {
+                phase: "swap".to_string(),
+                message: flex_swap_narrative(&swap_a, &swap_b),
+            });
+        }
+
         let home_comp =
             detect_comp_with_plan(&picks_to_comp_view(&draft_state.home_picks), &home_plan);
         let away_comp =
@@ -794,7 +1001,7 @@ fn comp_history_ban_narrative(
     let champion = champion::get_champion(champion_id)?;
 
     let mut sorted_history: Vec<(CompType, u32)> = opponent_comp_history.to_vec();
-    sorted_history.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted_history.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
     for (comp, usage_count) in sorted_history.into_iter().take(2) {
         if usage_count < 3 {
@@ -817,6 +1024,19 @@ fn comp_history_ban_narrative(
     None
 }
 
+fn player_ids(players: &[PlayerChampionPool]) -> Vec<u64> {
+    players.iter().map(|p| p.player_id).collect()
+}
+
+/// 球探报告中对手选手的舒适/常用英雄
+fn scouting_ban_narrative(
+    champion_id: u8,
+    opponent_report: &DraftScoutingReport,
+    opponent_players: &[PlayerChampionPool],
+) -> Option<String> {
+    opponent_report.ban_reason(champion_id, &player_ids(opponent_players))
+}
+
 fn generate_ban_narrative(
     champion_id: u8,
     ban_phase: u8,
@@ -828,7 +1048,7 @@ fn generate_ban_narrative(
     opponent_picks: &[PickEntry],
 ) -> String {
     let champion_name = champion::get_champion(champion_id)
-        .map(|c| c.name_cn)
+        .map(|c| c.name_cn.as_str())
         .unwrap_or("未知");
 
     if ban_phase == 2 {
@@ -891,7 +1111,7 @@ fn generate_pick_narrative(
     version_tiers: &HashMap<u8, VersionTier>,
 ) -> String {
     let champion_name = champion::get_champion(pick.champion_id)
-        .map(|c| c.name_cn)
+        .map(|c| c.name_cn.as_str())
         .unwrap_or("未知");
     let position_name = format!("{:?}", pick.position);
 
@@ -917,14 +1137,20 @@ fn generate_pick_narrative(
         reasons.push("高熟练度");
     }
 
+    if pick.flex {
+        reasons.push("摇摆位隐藏分路");
+    }
+
     if opponent_picks
         .iter()
         .any(|entry| entry.position == pick.position)
     {
         if let Some(my_champ) = champion::get_champion(pick.champion_id) {
-            let matchup = lane_matchup_bonus(my_champ.position, my_champ.archetype, opponent_picks);
+            let matchup = lane_matchup_bonus(pick.position, my_champ.archetype, opponent_picks);
             if matchup >= 2 {
-                reasons.push(if pick_index >= 4 {
+                reasons.push(if pick_index >= COUNTER_PICK_SLOT_START {
+                    "counter位克制对线"
+                } else if pick_index >= 4 {
                     "后手对线克制"
                 } else {
                     "对线克制"
@@ -962,8 +1188,11 @@ fn generate_pick_narrative(
         reasons.join("、")
     };
 
+    let off_role = champion::get_champion(pick.champion_id)
+        .is_some_and(|c| c.position != pick.position);
+
     format!(
-        "{}位选{}（{}），{}",
+        "{}位选{}（{}{}），{}",
         position_name,
         champion_name,
         match pick.mastery_tier {
@@ -972,10 +1201,26 @@ fn generate_pick_narrative(
             MasteryTier::A => "A",
             MasteryTier::B => "B",
         },
+        if off_role { "，副位置" } else { "" },
         reason_str
     )
 }
 
+fn flex_swap_narrative(a: &PickEntry, b: &PickEntry) -> String {
+    let name = |id: u8| {
+        champion::get_champion(id)
+            .map(|c| c.name_cn.as_str())
+            .unwrap_or("未知")
+    };
+    format!(
+        "摇摆位换线：{}去{:?}位、{}去{:?}位",
+        name(a.champion_id),
+        a.position,
+        name(b.champion_id),
+        b.position
+    )
+}
+
 pub fn detect_comp(picks: &[(Position, Archetype)]) -> Option<CompType> {
     let snapshot = CompSnapshot::from_picks(picks);
     if snapshot.total != POSITIONS.len() {
@@ -1273,7 +1518,7 @@ fn select_best_ban(
     banned_champions: &HashSet<u8>,
     picked_champions: &HashSet<u8>,
     rng: &mut StdRng,
-    opponent_comp_history: &[(CompType, u32)],
+    opponent_report: &DraftScoutingReport,
     ban_phase: u8,
     opponent_picks: &[PickEntry],
     banning_team_side: TeamSide,
@@ -1283,7 +1528,7 @@ fn select_best_ban(
 ) -> Option<u8> {
     let mut candidate_set = HashSet::new();
     for player in opponent_players {
-        for champion in get_champions_by_position(player.position) {
+        for champion in available_champions_for_position(player.position) {
             candidate_set.insert(champion.id);
         }
         for champion_id in player.masteries.keys() {
@@ -1321,7 +1566,7 @@ fn select_best_ban(
                     opponent_players,
                     version_tiers,
                     rng,
-                    opponent_comp_history,
+                    opponent_report,
                     ban_phase,
                     opponent_picks,
                     banning_team_side,
@@ -1333,7 +1578,7 @@ fn select_best_ban(
         })
         .collect();
 
-    scored.sort_by(|left, right| right.1.cmp(&left.1));
+    scored.sort_by_key(|entry| std::cmp::Reverse(entry.1));
     let best_score = match scored.first() {
         Some((_, score)) => *score,
         None => return None,
@@ -1353,7 +1598,7 @@ fn calculate_ban_score(
     opponent_players: &[PlayerChampionPool],
     version_tiers: &HashMap<u8, VersionTier>,
     rng: &mut StdRng,
-    opponent_comp_history: &[(CompType, u32)],
+    opponent_report: &DraftScoutingReport,
     ban_phase: u8,
     opponent_picks: &[PickEntry],
     banning_team_side: TeamSide,
@@ -1365,7 +1610,8 @@ fn calculate_ban_score(
     let mut has_ss_master = false;
     let mut usage_score = 0i32;
     let mut comp_target_score = 0i32;
-    let core_positions = top_comp_core_positions(opponent_comp_history);
+    let opponent_comp_history = opponent_report.comp_history();
+    let core_positions = top_comp_core_positions(&opponent_comp_history);
 
     for player in opponent_players {
         let mastery = mastery_for_player(player, champion_id);
@@ -1398,7 +1644,12 @@ fn calculate_ban_score(
     } else {
         0
     };
-    let comp_history_score = comp_history_ban_bonus(champion_id, opponent_comp_history);
+    let comp_history_score = comp_history_ban_bonus(champion_id, &opponent_comp_history);
+    let scouting_score = opponent_report.ban_priority(champion_id, &player_ids(opponent_players));
+    let flex_threat_score = flex_threat_ban_bonus(champion_id, opponent_picks, opponent_players);
+    let target_score = series_ctx
+        .map(|ctx| ctx.tactics_for(banning_team_side).target_ban_bonus(champion_id, opponent_players))
+        .unwrap_or(0);
     let random_noise = rng.gen_range(0..3) as i32;
 
     threat_score
@@ -1410,9 +1661,39 @@ fn calculate_ban_score(
         + series_revenge_score
         + self_protection_score
         + comp_history_score
+        + scouting_score
+        + flex_threat_score
+        + target_score
         + random_noise
 }
 
+/// 对手多个未锁定位置都能熟练使用的摇摆位英雄更值得禁用
+fn flex_threat_ban_bonus(
+    champion_id: u8,
+    opponent_picks: &[PickEntry],
+    opponent_players: &[PlayerChampionPool],
+) -> i32 {
+    let Some(champ) = champion::get_champion(champion_id) else {
+        return 0;
+    };
+    let threatened = opponent_players
+        .iter()
+        .filter(|player| {
+            !is_position_filled(opponent_picks, player.position)
+                && champ.can_play(player.position)
+                && matches!(
+                    mastery_for_player(player, champion_id),
+                    MasteryTier::SS | MasteryTier::S | MasteryTier::A
+                )
+        })
+        .count();
+    if threatened >= 2 {
+        3
+    } else {
+        0
+    }
+}
+
 fn ability_factor(ability: u8) -> f64 {
     1.0 + f64::from(ability.saturating_sub(50)) / 100.0
 }
@@ -1528,7 +1809,7 @@ fn comp_history_ban_bonus(champion_id: u8, opponent_comp_history: &[(CompType, u
     };
 
     let mut sorted_history: Vec<(CompType, u32)> = opponent_comp_history.to_vec();
-    sorted_history.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted_history.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
     let mut bonus = 0i32;
     for (comp, usage_count) in sorted_history.iter().take(2) {
@@ -1595,7 +1876,7 @@ fn top_comp_core_positions(opponent_comp_history: &[(CompType, u32)]) -> Vec<Pos
         .copied()
         .filter(|(_, count)| *count > 0)
         .collect();
-    sorted_history.sort_by(|left, right| right.1.cmp(&left.1));
+    sorted_history.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
     let mut core_positions = Vec::new();
     for (comp, _) in sorted_history.into_iter().take(2) {
@@ -1634,11 +1915,13 @@ fn comp_core_positions(comp: CompType) -> &'static [Position] {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn plan_comp(
     players: &[PlayerChampionPool],
     version_tiers: &HashMap<u8, VersionTier>,
     comp_history: &[(CompType, u32)],
     meta_type: MetaType,
+    region_style: &RegionStyleProfile,
     series_ctx: Option<&SeriesContext>,
     team_side: TeamSide,
     rng: &mut StdRng,
@@ -1649,13 +1932,13 @@ fn plan_comp(
         .map(|comp| {
             let player_pool_score = player_pool_fit_score(comp, players);
             let version_score = version_fit_score(comp, version_tiers);
-            let meta_score = if comp.is_meta_favored(meta_type) {
-                15
-            } else {
-                0
-            };
+            let meta_score = region_style.meta_plan_bonus(comp, meta_type);
+            let style_score = region_style.comp_plan_bonus(comp);
             let history_score = history_proficiency_score(comp, comp_history);
             let rotation_score = tactical_rotation_score(comp, series_ctx, team_side);
+            let tactics_score = series_ctx
+                .map(|ctx| ctx.tactics_for(team_side).comp_plan_bonus(comp))
+                .unwrap_or(0);
             let random_noise = rng.gen_range(0..8) as i32;
 
             (
@@ -1663,14 +1946,16 @@ fn plan_comp(
                 player_pool_score
                     + version_score
                     + meta_score
+                    + style_score
                     + history_score
                     + rotation_score
+                    + tactics_score
                     + random_noise,
             )
         })
         .collect();
 
-    scored.sort_by(|left, right| right.1.cmp(&left.1));
+    scored.sort_by_key(|entry| std::cmp::Reverse(entry.1));
 
     let fallback = CompType::all().first().copied().unwrap_or(CompType::Flex);
     let primary_comp = scored.first().map(|(comp, _)| *comp).unwrap_or(fallback);
@@ -2080,7 +2365,10 @@ fn lane_matchup_bonus(
     champion_archetype: Archetype,
     opponent_picks: &[PickEntry],
 ) -> i32 {
-    let opponent_pick = opponent_picks.iter().find(|pick| pick.position == position);
+    // 摇摆位英雄在选人阶段分路未知，无法针对
+    let opponent_pick = opponent_picks
+        .iter()
+        .find(|pick| pick.position == position && !pick.flex);
     let Some(opp_pick) = opponent_pick else {
         return 0;
     };
@@ -2134,18 +2422,19 @@ fn should_switch_to_backup(
     compatible_count * 2 >= my_picks.len()
 }
 
-fn select_best_pick(
-    team_players: &[PlayerChampionPool],
-    opponent_players: &[PlayerChampionPool],
-    team_picks: &[PickEntry],
-    opponent_picks: &[PickEntry],
-    banned_champions: &HashSet<u8>,
-    picked_champions: &HashSet<u8>,
-    version_tiers: &HashMap<u8, VersionTier>,
-    rng: &mut StdRng,
-    plan: &DraftPlan,
-    pick_index: usize,
-) -> Option<PickEntry> {
+fn select_best_pick(ctx: &PickContext, rng: &mut StdRng) -> Option<PickEntry> {
+    let PickContext {
+        team_players,
+        opponent_players,
+        team_picks,
+        opponent_picks,
+        banned_champions,
+        picked_champions,
+        version_tiers,
+        plan,
+        pick_index,
+        reuse_restricted,
+    } = *ctx;
     let current_comp_view = picks_to_comp_view(team_picks);
     let opponent_comp_view = picks_to_comp_view(opponent_picks);
     let target_comp = plan.active_comp();
@@ -2161,6 +2450,11 @@ fn select_best_pick(
                 continue;
             }
 
+            let flex_positions = team_players
+                .iter()
+                .filter(|p| !is_position_filled(team_picks, p.position) && champion.can_play(p.position))
+                .count();
+            let is_flex = flex_positions >= 2 && pick_index < COUNTER_PICK_SLOT_START;
             let mastery = mastery_for_player(player, champion.id);
             let version_tier = version_tier_for(champion.id, version_tiers);
 
@@ -2201,6 +2495,19 @@ fn select_best_pick(
             );
             let denial_pick_score =
                 denial_pick_bonus(champion.id, player.position, version_tier, opponent_players);
+            let role_strength_score = role_strength_pick_score(champion, player.position);
+            let flex_score = if is_flex { FLEX_PICK_BONUS } else { 0 };
+            let pool_depth_score = if reuse_restricted {
+                pool_depth_pick_penalty(player, champion.id, banned_champions, picked_champions)
+            } else {
+                0
+            };
+            let counter_slot_score = counter_slot_bonus(
+                pick_index,
+                player.position,
+                champion.archetype,
+                opponent_picks,
+            );
             let random_noise = rng.gen_range(0..3) as i32;
 
             let total_score = mastery_score
@@ -2214,6 +2521,10 @@ fn select_best_pick(
                 + usage_confidence_score
                 + counter_pick_score
                 + denial_pick_score
+                + role_strength_score
+                + flex_score
+                + counter_slot_score
+                + pool_depth_score
                 + random_noise;
             candidates.push((
                 PickEntry {
@@ -2222,6 +2533,7 @@ fn select_best_pick(
                     position: player.position,
                     mastery_tier: mastery,
                     traits: player.traits.clone(),
+                    flex: is_flex,
                 },
                 total_score,
             ));
@@ -2238,7 +2550,7 @@ fn select_best_pick(
         );
     }
 
-    candidates.sort_by(|left, right| right.1.cmp(&left.1));
+    candidates.sort_by_key(|entry| std::cmp::Reverse(entry.1));
     let best_score = match candidates.first() {
         Some((_, score)) => *score,
         None => return None,
@@ -2305,6 +2617,7 @@ fn fallback_pick(
             position: player.position,
             mastery_tier: mastery_for_player(player, champion.id),
             traits: player.traits.clone(),
+            flex: false,
         });
     }
 
@@ -2322,6 +2635,7 @@ fn fallback_pick(
         position: player.position,
         mastery_tier: mastery_for_player(player, champion.id),
         traits: player.traits.clone(),
+        flex: false,
     })
 }
 
@@ -2334,8 +2648,136 @@ fn all_team_positions_filled(
         .all(|player| is_position_filled(team_picks, player.position))
 }
 
+/// 剔除系列赛规则锁定英雄后的英雄池
+fn without_locked(players: &[PlayerChampionPool], locked: &HashSet<u8>) -> Vec<PlayerChampionPool> {
+    players
+        .iter()
+        .cloned()
+        .map(|mut player| {
+            player.masteries.retain(|id, _| !locked.contains(id));
+            player
+        })
+        .collect()
+}
+
+fn merge_locked(banned: &HashSet<u8>, locked: &HashSet<u8>) -> HashSet<u8> {
+    banned.union(locked).copied().collect()
+}
+
+/// 全局BP下英雄用一次就少一个：选手最后一个高熟练度英雄尽量留到后面的小局
+fn pool_depth_pick_penalty(
+    player: &PlayerChampionPool,
+    champion_id: u8,
+    banned_champions: &HashSet<u8>,
+    picked_champions: &HashSet<u8>,
+) -> i32 {
+    let is_strong = |tier: &MasteryTier| matches!(tier, MasteryTier::SS | MasteryTier::S);
+    if !player.masteries.get(&champion_id).is_some_and(is_strong) {
+        return 0;
+    }
+    let remaining = player
+        .masteries
+        .iter()
+        .filter(|(id, tier)| {
+            **id != champion_id
+                && is_strong(tier)
+                && !banned_champions.contains(id)
+                && !picked_champions.contains(id)
+        })
+        .count();
+    if remaining == 0 {
+        -3
+    } else {
+        0
+    }
+}
+
+/// 可在该位置使用的英雄（含副位置）
 fn available_champions_for_position(position: Position) -> Vec<&'static Champion> {
-    get_champions_by_position(position)
+    champion::CHAMPIONS
+        .iter()
+        .filter(|champion| champion.can_play(position))
+        .collect()
+}
+
+fn role_strength_pick_score(champion: &Champion, position: Position) -> i32 {
+    champion
+        .role_strength(position)
+        .map(|s| (s * ROLE_STRENGTH_PICK_SCALE).round() as i32)
+        .unwrap_or(0)
+}
+
+/// counter 位额外重视对线克制（对手对应分路已亮出时）
+fn counter_slot_bonus(
+    pick_index: usize,
+    position: Position,
+    champion_archetype: Archetype,
+    opponent_picks: &[PickEntry],
+) -> i32 {
+    if pick_index < COUNTER_PICK_SLOT_START {
+        return 0;
+    }
+    lane_matchup_bonus(position, champion_archetype, opponent_picks) * 2
+}
+
+/// 按选手熟练度与位置强度评估某英雄放在某位置的价值
+fn flex_assignment_value(player: Option<&PlayerChampionPool>, champion_id: u8, position: Position) -> i32 {
+    let mastery = player
+        .map(|p| mastery_for_player(p, champion_id))
+        .unwrap_or(MasteryTier::B);
+    let role = champion::get_champion(champion_id)
+        .map(|c| role_strength_pick_score(c, position))
+        .unwrap_or(0);
+    i32::from(mastery.pick_score()) + role
+}
+
+/// 选人结束后交换摇摆位英雄的分路，返回发生交换的两条选人记录
+fn resolve_flex_swaps(
+    team_players: &[PlayerChampionPool],
+    team_picks: &mut [PickEntry],
+) -> Vec<(PickEntry, PickEntry)> {
+    let player_of = |player_id: u64| team_players.iter().find(|p| p.player_id == player_id);
+    let can_play = |champion_id: u8, position: Position| {
+        champion::get_champion(champion_id).is_some_and(|c| c.can_play(position))
+    };
+
+    let mut swaps = Vec::new();
+    for _ in 0..team_picks.len() {
+        let mut best: Option<(usize, usize, i32)> = None;
+        for i in 0..team_picks.len() {
+            for j in (i + 1)..team_picks.len() {
+                let (a, b) = (&team_picks[i], &team_picks[j]);
+                if !(a.flex || b.flex)
+                    || !can_play(a.champion_id, b.position)
+                    || !can_play(b.champion_id, a.position)
+                {
+                    continue;
+                }
+                let (pa, pb) = (player_of(a.player_id), player_of(b.player_id));
+                let gain = flex_assignment_value(pa, b.champion_id, a.position)
+                    + flex_assignment_value(pb, a.champion_id, b.position)
+                    - flex_assignment_value(pa, a.champion_id, a.position)
+                    - flex_assignment_value(pb, b.champion_id, b.position);
+                if gain > 0 && best.map_or(true, |(_, _, g)| gain > g) {
+                    best = Some((i, j, gain));
+                }
+            }
+        }
+
+        let Some((i, j, _)) = best else {
+            break;
+        };
+        let (champ_i, champ_j) = (team_picks[i].champion_id, team_picks[j].champion_id);
+        for (idx, champion_id) in [(i, champ_j), (j, champ_i)] {
+            let pick = &mut team_picks[idx];
+            pick.champion_id = champion_id;
+            pick.mastery_tier = player_of(pick.player_id)
+                .map(|p| mastery_for_player(p, champion_id))
+                .unwrap_or(MasteryTier::B);
+        }
+        swaps.push((team_picks[i].clone(), team_picks[j].clone()));
+    }
+    swaps
 }
 
 fn is_position_filled(team_picks: &[PickEntry], position: Position) -> bool {
@@ -2817,13 +3259,26 @@ fn calculate_team_bp_modifiers(
         let version_tier = version_tier_for(pick.champion_id, version_tiers);
         let personal_mastery_mod = mastery_modifier(pick.mastery_tier, &pick.traits);
         let personal_version_mod = version_modifier_for_player(pick.mastery_tier, version_tier);
-        let total_modifier = personal_mastery_mod + personal_version_mod + comp_bonus + counter_mod;
+        let role_mod = role_strength_modifier(pick.champion_id, pick.position);
+        let total_modifier =
+            personal_mastery_mod + personal_version_mod + role_mod + comp_bonus + counter_mod;
         result.insert(pick.player_id, total_modifier);
     }
 
     result
 }
 
+/// 副位置英雄的战力修正；无法胜任该位置的兜底选择按两倍默认修正计
+fn role_strength_modifier(champion_id: u8, position: Position) -> f64 {
+    let strength = champion::get_champion(champion_id)
+        .map(|c| {
+            c.role_strength(position)
+                .unwrap_or(2.0 * champion::DEFAULT_OFF_ROLE_STRENGTH)
+        })
+        .unwrap_or(0.0);
+    strength * ROLE_STRENGTH_MODIFIER_SCALE
+}
+
 fn calculate_team_comp_bonus(team_comp: Option<CompType>, meta_type: MetaType) -> f64 {
     match team_comp {
         Some(comp_type) => {
@@ -2945,4 +3400,145 @@ mod tests {
         let version_mod = version_modifier_for_player(MasteryTier::SS, VersionTier::T3);
         assert!((version_mod - 0.0).abs() < f64::EPSILON);
     }
+
+    fn test_pool(player_id: u64, position: Position, masteries: &[(u8, MasteryTier)]) -> PlayerChampionPool {
+        PlayerChampionPool {
+            player_id,
+            position,
+            ability: 70,
+            masteries: masteries.iter().copied().collect(),
+            games_played: HashMap::new(),
+            games_won: HashMap::new(),
+            traits: Vec::new(),
+        }
+    }
+
+    fn test_pick(player_id: u64, champion_id: u8, position: Position, flex: bool) -> PickEntry {
+        PickEntry {
+            player_id,
+            champion_id,
+            position,
+            mastery_tier: MasteryTier::B,
+            traits: Vec::new(),
+            flex,
+        }
+    }
+
+    #[test]
+    fn test_flex_swap_moves_champions_to_better_roles() {
+        // 阿卡丽(27) 主中副上，雷克顿(1) 主上副中
+        let players = vec![
+            test_pool(1, Position::Top, &[(1, MasteryTier::SS)]),
+            test_pool(2, Position::Mid, &[(27, MasteryTier::SS)]),
+        ];
+        let mut picks = vec![
+            test_pick(1, 27, Position::Top, true),
+            test_pick(2, 1, Position::Mid, false),
+        ];
+        let swaps = resolve_flex_swaps(&players, &mut picks);
+
+        assert_eq!(swaps.len(), 1);
+        assert_eq!((picks[0].champion_id, picks[0].mastery_tier), (1, MasteryTier::SS));
+        assert_eq!((picks[1].champion_id, picks[1].mastery_tier), (27, MasteryTier::SS));
+    }
+
+    #[test]
+    fn test_flex_swap_requires_flex_pick() {
+        let players = vec![
+            test_pool(1, Position::Top, &[(1, MasteryTier::SS)]),
+            test_pool(2, Position::Mid, &[(27, MasteryTier::SS)]),
+        ];
+        let mut picks = vec![
+            test_pick(1, 27, Position::Top, false),
+            test_pick(2, 1, Position::Mid, false),
+        ];
+        assert!(resolve_flex_swaps(&players, &mut picks).is_empty());
+        assert_eq!(picks[0].champion_id, 27);
+    }
+
+    #[test]
+    fn test_flex_pick_hides_lane_and_counter_slot_doubles_matchup() {
+        // 雷克顿(1) Aggressive 对 贾克斯(3) Scaling 为对线克制
+        let revealed = vec![test_pick(9, 3, Position::Top, false)];
+        let hidden = vec![test_pick(9, 3, Position::Top, true)];
+
+        assert_eq!(lane_matchup_bonus(Position::Top, Archetype::Aggressive, &revealed), 3);
+        assert_eq!(lane_matchup_bonus(Position::Top, Archetype::Aggressive, &hidden), 0);
+        assert_eq!(counter_slot_bonus(2, Position::Top, Archetype::Aggressive, &revealed), 0);
+        assert_eq!(
+            counter_slot_bonus(COUNTER_PICK_SLOT_START, Position::Top, Archetype::Aggressive, &revealed),
+            6
+        );
+    }
+
+    #[test]
+    fn test_off_role_pick_is_penalized() {
+        assert!((role_strength_modifier(1, Position::Top) - 0.0).abs() < f64::EPSILON);
+        assert!(role_strength_modifier(1, Position::Mid) < 0.0);
+        assert!(role_strength_modifier(1, Position::Adc) < role_strength_modifier(1, Position::Mid));
+    }
+
+    #[test]
+    fn test_fearless_locks_champions_for_both_teams() {
+        use crate::models::DraftRule;
+
+        let home = vec![test_pick(1, 1, Position::Top, false)];
+        let away = vec![test_pick(2, 27, Position::Mid, false)];
+
+        let mut fearless = SeriesDraftRules::new(DraftRule::Fearless, Vec::new());
+        fearless.record_game(&home, &away);
+        assert_eq!(fearless.locked_for(TeamSide::Home), HashSet::from([1, 27]));
+        assert_eq!(fearless.locked_for(TeamSide::Away), HashSet::from([1, 27]));
+
+        let mut soft = SeriesDraftRules::new(DraftRule::SoftFearless, Vec::new());
+        soft.record_game(&home, &away);
+        assert_eq!(soft.locked_for(TeamSide::Home), HashSet::from([1]));
+        assert_eq!(soft.locked_for(TeamSide::Away), HashSet::from([27]));
+
+        let global = SeriesDraftRules::new(DraftRule::GlobalBanPool, vec![5, 6]);
+        assert_eq!(global.locked_for(TeamSide::Home), HashSet::from([5, 6]));
+        assert!(SeriesDraftRules::default().locked_for(TeamSide::Away).is_empty());
+    }
+
+    #[test]
+    fn test_draft_never_picks_locked_champions() {
+        use crate::models::DraftRule;
+        use rand::SeedableRng;
+
+        let positions = [Position::Top, Position::Jug, Position::Mid, Position::Adc, Position::Sup];
+        let team = |offset: u64| -> Vec<PlayerChampionPool> {
+            positions
+                .iter()
+                .enumerate()
+                .map(|(i, pos)| {
+                    let masteries: Vec<(u8, MasteryTier)> = get_champions_by_position(*pos)
+                        .iter()
+                        .map(|c| (c.id, MasteryTier::S))
+                        .collect();
+                    test_pool(offset + i as u64, *pos, &masteries)
+                })
+                .collect()
+        };
+        let (home, away) = (team(1), team(10));
+        let tiers: HashMap<u8, VersionTier> = champion::calculate_version_tiers(MetaType::Balanced)
+            .into_iter()
+            .collect();
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut rules = SeriesDraftRules::new(DraftRule::Fearless, Vec::new());
+
+        for _ in 0..3 {
+            let locked = rules.locked_for(TeamSide::Home);
+            let draft = BpEngine::run_draft(
+                &home, &away, &tiers, MetaType::Balanced, &mut rng,
+                &DraftScoutingReport::default(), &DraftScoutingReport::default(), None, &rules,
+            );
+            assert_eq!(draft.home_picks.len(), 5);
+            assert_eq!(draft.away_picks.len(), 5);
+            for pick in draft.home_picks.iter().chain(&draft.away_picks) {
+                assert!(!locked.contains(&pick.champion_id));
+            }
+            rules.record_game(&draft.home_picks, &draft.away_picks);
+        }
+        assert_eq!(rules.locked_for(TeamSide::Away).len(), 30);
+    }
 }
@@ -1,5 +1,7 @@
 use crate::models::player::Position;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Archetype {
@@ -131,372 +133,113 @@ impl VersionTier {
     }
 }
 
+/// 英雄数据（由 `champions.json` 加载）
 #[derive(Debug, Clone)]
 pub struct Champion {
     pub id: u8,
-    pub name_cn: &'static str,
-    pub name_en: &'static str,
+    pub name_cn: String,
+    pub name_en: String,
+    /// 主位置（positions 第一项）
     pub position: Position,
+    /// 主定位（tags 第一项）
     pub archetype: Archetype,
+    /// 可胜任的全部位置，主位置在前
+    pub positions: Vec<Position>,
+    /// 全部定位标签，主定位在前
+    pub tags: Vec<Archetype>,
+    /// 赛季初始强度，版本补丁在此基础上增减
+    pub base_strength: f64,
+    /// 非主位置的强度修正（未列出的副位置取默认值）
+    pub role_strength: Vec<(Position, f64)>,
 }
 
-pub const CHAMPIONS: &[Champion] = &[
-    // ===== TOP (10) =====
-    Champion {
-        id: 1,
-        name_cn: "雷克顿",
-        name_en: "Renekton",
-        position: Position::Top,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 2,
-        name_cn: "菲奥娜",
-        name_en: "Fiora",
-        position: Position::Top,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 3,
-        name_cn: "贾克斯",
-        name_en: "Jax",
-        position: Position::Top,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 4,
-        name_cn: "艾瑞莉娅",
-        name_en: "Irelia",
-        position: Position::Top,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 5,
-        name_cn: "慎",
-        name_en: "Shen",
-        position: Position::Top,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 6,
-        name_cn: "奥恩",
-        name_en: "Ornn",
-        position: Position::Top,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 7,
-        name_cn: "剑魔",
-        name_en: "Aatrox",
-        position: Position::Top,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 8,
-        name_cn: "格温",
-        name_en: "Gwen",
-        position: Position::Top,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 9,
-        name_cn: "墨菲特",
-        name_en: "Malphite",
-        position: Position::Top,
-        archetype: Archetype::Teamfight,
-    },
-    Champion {
-        id: 10,
-        name_cn: "莫德凯撒",
-        name_en: "Mordekaiser",
-        position: Position::Top,
-        archetype: Archetype::Teamfight,
-    },
-    // ===== JUG (10) =====
-    Champion {
-        id: 11,
-        name_cn: "李青",
-        name_en: "Lee Sin",
-        position: Position::Jug,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 12,
-        name_cn: "雷恩加尔",
-        name_en: "Rengar",
-        position: Position::Jug,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 13,
-        name_cn: "莉莉娅",
-        name_en: "Lillia",
-        position: Position::Jug,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 14,
-        name_cn: "卡尔萨斯",
-        name_en: "Karthus",
-        position: Position::Jug,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 15,
-        name_cn: "伊芙琳",
-        name_en: "Evelynn",
-        position: Position::Jug,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 16,
-        name_cn: "赵信",
-        name_en: "Xin Zhao",
-        position: Position::Jug,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 17,
-        name_cn: "奥拉夫",
-        name_en: "Olaf",
-        position: Position::Jug,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 18,
-        name_cn: "特朗德尔",
-        name_en: "Trundle",
-        position: Position::Jug,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 19,
-        name_cn: "阿木木",
-        name_en: "Amumu",
-        position: Position::Jug,
-        archetype: Archetype::Teamfight,
-    },
-    Champion {
-        id: 20,
-        name_cn: "斯卡纳",
-        name_en: "Skarner",
-        position: Position::Jug,
-        archetype: Archetype::Teamfight,
-    },
-    // ===== MID (10) =====
-    Champion {
-        id: 21,
-        name_cn: "劫",
-        name_en: "Zed",
-        position: Position::Mid,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 22,
-        name_cn: "泰隆",
-        name_en: "Talon",
-        position: Position::Mid,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 23,
-        name_cn: "阿兹尔",
-        name_en: "Azir",
-        position: Position::Mid,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 24,
-        name_cn: "维克托",
-        name_en: "Viktor",
-        position: Position::Mid,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 25,
-        name_cn: "卡尔玛",
-        name_en: "Karma",
-        position: Position::Mid,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 26,
-        name_cn: "加里奥",
-        name_en: "Galio",
-        position: Position::Mid,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 27,
-        name_cn: "阿卡丽",
-        name_en: "Akali",
-        position: Position::Mid,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 28,
-        name_cn: "杰斯",
-        name_en: "Jayce",
-        position: Position::Mid,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 29,
-        name_cn: "奥莉安娜",
-        name_en: "Orianna",
-        position: Position::Mid,
-        archetype: Archetype::Teamfight,
-    },
-    Champion {
-        id: 30,
-        name_cn: "卡萨丁",
-        name_en: "Kassadin",
-        position: Position::Mid,
-        archetype: Archetype::Teamfight,
-    },
-    // ===== ADC (10) =====
-    Champion {
-        id: 31,
-        name_cn: "德莱文",
-        name_en: "Draven",
-        position: Position::Adc,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 32,
-        name_cn: "卢锡安",
-        name_en: "Lucian",
-        position: Position::Adc,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 33,
-        name_cn: "薇恩",
-        name_en: "Vayne",
-        position: Position::Adc,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 34,
-        name_cn: "凯莎",
-        name_en: "Kai'Sa",
-        position: Position::Adc,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 35,
-        name_cn: "艾希",
-        name_en: "Ashe",
-        position: Position::Adc,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 36,
-        name_cn: "赛娜",
-        name_en: "Senna",
-        position: Position::Adc,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 37,
-        name_cn: "厄运小姐",
-        name_en: "Miss Fortune",
-        position: Position::Adc,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 38,
-        name_cn: "泽丽",
-        name_en: "Zeri",
-        position: Position::Adc,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 39,
-        name_cn: "克格莫",
-        name_en: "Kog'Maw",
-        position: Position::Adc,
-        archetype: Archetype::Teamfight,
-    },
-    Champion {
-        id: 40,
-        name_cn: "金克斯",
-        name_en: "Jinx",
-        position: Position::Adc,
-        archetype: Archetype::Teamfight,
-    },
-    // ===== SUP (10) =====
-    Champion {
-        id: 41,
-        name_cn: "潘森",
-        name_en: "Pantheon",
-        position: Position::Sup,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 42,
-        name_cn: "派克",
-        name_en: "Pyke",
-        position: Position::Sup,
-        archetype: Archetype::Aggressive,
-    },
-    Champion {
-        id: 43,
-        name_cn: "塞拉菲妮",
-        name_en: "Seraphine",
-        position: Position::Sup,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 44,
-        name_cn: "索拉卡",
-        name_en: "Soraka",
-        position: Position::Sup,
-        archetype: Archetype::Scaling,
-    },
-    Champion {
-        id: 45,
-        name_cn: "璐璐",
-        name_en: "Lulu",
-        position: Position::Sup,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 46,
-        name_cn: "娜美",
-        name_en: "Nami",
-        position: Position::Sup,
-        archetype: Archetype::Utility,
-    },
-    Champion {
-        id: 47,
-        name_cn: "巴德",
-        name_en: "Bard",
-        position: Position::Sup,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 48,
-        name_cn: "莫甘娜",
-        name_en: "Morgana",
-        position: Position::Sup,
-        archetype: Archetype::Splitpush,
-    },
-    Champion {
-        id: 49,
-        name_cn: "蕾欧娜",
-        name_en: "Leona",
-        position: Position::Sup,
-        archetype: Archetype::Teamfight,
-    },
-    Champion {
-        id: 50,
-        name_cn: "芮尔",
-        name_en: "Rell",
-        position: Position::Sup,
-        archetype: Archetype::Teamfight,
-    },
-];
+/// 副位置默认强度修正
+pub const DEFAULT_OFF_ROLE_STRENGTH: f64 = -0.5;
+
+impl Champion {
+    pub fn has_tag(&self, archetype: Archetype) -> bool {
+        self.tags.contains(&archetype)
+    }
+
+    pub fn can_play(&self, position: Position) -> bool {
+        self.positions.contains(&position)
+    }
+
+    /// 可打多个位置的英雄（摇摆位）
+    pub fn is_flex(&self) -> bool {
+        self.positions.len() > 1
+    }
+
+    /// 该英雄在指定位置的强度修正：主位置为 0，副位置为负数，无法胜任返回 None
+    pub fn role_strength(&self, position: Position) -> Option<f64> {
+        if position == self.position {
+            return Some(0.0);
+        }
+        if !self.can_play(position) {
+            return None;
+        }
+        Some(
+            self.role_strength
+                .iter()
+                .find(|(p, _)| *p == position)
+                .map(|(_, s)| *s)
+                .unwrap_or(DEFAULT_OFF_ROLE_STRENGTH),
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChampionData {
+    id: u8,
+    name_cn: String,
+    name_en: String,
+    positions: Vec<String>,
+    tags: Vec<Archetype>,
+    #[serde(default)]
+    base_strength: f64,
+    #[serde(default)]
+    role_strength: HashMap<String, f64>,
+}
+
+fn load_champions() -> Vec<Champion> {
+    let data: Vec<ChampionData> = serde_json::from_str(include_str!("../../champions.json"))
+        .expect("英雄数据 champions.json 格式错误");
+    data.into_iter()
+        .map(|d| {
+            let positions: Vec<Position> = d.positions.iter().map(|p| Position::from_str(p)).collect();
+            Champion {
+                id: d.id,
+                name_cn: d.name_cn,
+                name_en: d.name_en,
+                position: positions.first().copied().unwrap_or(Position::Mid),
+                archetype: d.tags.first().copied().unwrap_or(Archetype::Teamfight),
+                positions,
+                tags: d.tags,
+                base_strength: d.base_strength,
+                role_strength: d
+                    .role_strength
+                    .iter()
+                    .map(|(p, s)| (Position::from_str(p), *s))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+static CHAMPION_DATA: OnceLock<Vec<Champion>> = OnceLock::new();
+
+/// 英雄池（首次访问时从 `champions.json` 加载），可直接当作 `[Champion]` 使用
+pub struct ChampionPool;
+
+impl std::ops::Deref for ChampionPool {
+    type Target = [Champion];
+
+    fn deref(&self) -> &[Champion] {
+        CHAMPION_DATA.get_or_init(load_champions)
+    }
+}
+
+pub static CHAMPIONS: ChampionPool = ChampionPool;
 
 pub fn get_champion(id: u8) -> Option<&'static Champion> {
     CHAMPIONS.iter().find(|c| c.id == id)
@@ -532,67 +275,78 @@ fn position_weight_id(position: Position) -> &'static str {
     }
 }
 
-/// 根据当前 Meta 计算每个英雄的版本 Tier
-pub fn calculate_version_tiers(meta: MetaType) -> Vec<(u8, VersionTier)> {
+/// 副定位受 Meta 影响的评分（不足以单独改变版本 Tier）
+const SECONDARY_TAG_SCORE: f64 = 0.25;
+
+/// Meta 对英雄的版本评分：主定位受青睐 +1/+2、受压制 -1/-2，副定位只做微调
+fn meta_tier_score(champ: &Champion, meta: MetaType) -> f64 {
     let favored = meta.favored_archetypes();
+    if favored.is_empty() {
+        return 0.0;
+    }
     let disfavored = meta.disfavored_archetypes();
-    let weights = get_meta_weights(meta);
+    let pos_weight = get_meta_weights(meta).weight_for_position(position_weight_id(champ.position));
 
+    let primary = if favored.contains(&champ.archetype) {
+        if pos_weight >= 1.1 { 2.0 } else { 1.0 }
+    } else if disfavored.contains(&champ.archetype) {
+        if pos_weight <= 0.85 { -2.0 } else { -1.0 }
+    } else {
+        0.0
+    };
+    let secondary: f64 = champ
+        .tags
+        .iter()
+        .skip(1)
+        .map(|t| {
+            if favored.contains(t) {
+                SECONDARY_TAG_SCORE
+            } else if disfavored.contains(t) {
+                -SECONDARY_TAG_SCORE
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    primary + secondary
+}
+
+/// 版本评分 → 版本 Tier（2 为 T1，-2 为 T5）
+pub fn tier_from_score(score: f64) -> VersionTier {
+    match score.round().clamp(-2.0, 2.0) as i8 {
+        2 => VersionTier::T1,
+        1 => VersionTier::T2,
+        0 => VersionTier::T3,
+        -1 => VersionTier::T4,
+        _ => VersionTier::T5,
+    }
+}
+
+/// 根据当前 Meta 计算每个英雄的版本 Tier（不含版本补丁）
+pub fn calculate_version_tiers(meta: MetaType) -> Vec<(u8, VersionTier)> {
+    calculate_version_tiers_with_patch(meta, &HashMap::new())
+}
+
+/// 根据 Meta 与当前补丁强度计算版本 Tier
+///
+/// `strengths` 为英雄当前补丁强度，未列出的英雄取数据文件中的初始强度
+pub fn calculate_version_tiers_with_patch(
+    meta: MetaType,
+    strengths: &HashMap<u8, f64>,
+) -> Vec<(u8, VersionTier)> {
     CHAMPIONS
         .iter()
         .map(|c| {
-            let tier = if favored.is_empty() {
-                VersionTier::T3
-            } else if favored.contains(&c.archetype) {
-                let pos_weight = weights.weight_for_position(position_weight_id(c.position));
-                if pos_weight >= 1.1 {
-                    VersionTier::T1
-                } else {
-                    VersionTier::T2
-                }
-            } else if disfavored.contains(&c.archetype) {
-                let pos_weight = weights.weight_for_position(position_weight_id(c.position));
-                if pos_weight <= 0.85 {
-                    VersionTier::T5
-                } else {
-                    VersionTier::T4
-                }
-            } else {
-                VersionTier::T3
-            };
-            (c.id, tier)
+            let strength = strengths.get(&c.id).copied().unwrap_or(c.base_strength);
+            (c.id, tier_from_score(meta_tier_score(c, meta) + strength))
         })
         .collect()
 }
 
 pub fn get_version_tier(champion_id: u8, meta: MetaType) -> VersionTier {
-    let favored = meta.favored_archetypes();
-    let disfavored = meta.disfavored_archetypes();
-    let weights = get_meta_weights(meta);
-
-    let champ = match get_champion(champion_id) {
-        Some(c) => c,
-        None => return VersionTier::T3,
-    };
-
-    if favored.is_empty() {
-        VersionTier::T3
-    } else if favored.contains(&champ.archetype) {
-        let pos_weight = weights.weight_for_position(position_weight_id(champ.position));
-        if pos_weight >= 1.1 {
-            VersionTier::T1
-        } else {
-            VersionTier::T2
-        }
-    } else if disfavored.contains(&champ.archetype) {
-        let pos_weight = weights.weight_for_position(position_weight_id(champ.position));
-        if pos_weight <= 0.85 {
-            VersionTier::T5
-        } else {
-            VersionTier::T4
-        }
-    } else {
-        VersionTier::T3
+    match get_champion(champion_id) {
+        Some(c) => tier_from_score(meta_tier_score(c, meta) + c.base_strength),
+        None => VersionTier::T3,
     }
 }
 
@@ -611,6 +365,22 @@ pub fn evolve_mastery(
     games_won: u32,
     rng: &mut impl rand::Rng,
 ) -> MasteryTier {
+    evolve_mastery_with_patch(current, games_played, games_won, 0.0, rng)
+}
+
+/// 考虑版本补丁的熟练度演变：版本强势英雄更容易练成、弱势英雄更容易生疏
+///
+/// `patch_strength` 为英雄赛季末补丁强度（-2 ~ 2），升级概率按 1 + 0.25×强度 缩放，降级概率反向缩放
+pub fn evolve_mastery_with_patch(
+    current: MasteryTier,
+    games_played: u32,
+    games_won: u32,
+    patch_strength: f64,
+    rng: &mut impl rand::Rng,
+) -> MasteryTier {
+    let factor = 1.0 + 0.25 * patch_strength.clamp(-2.0, 2.0);
+    let up = |p: f64| p * factor;
+    let down = |p: f64| p * (2.0 - factor);
     let win_rate = if games_played > 0 {
         games_won as f64 / games_played as f64
     } else {
@@ -620,7 +390,7 @@ pub fn evolve_mastery(
     match current {
         MasteryTier::SS => {
             if games_played == 0 {
-                if rng.gen::<f64>() < 0.10 {
+                if rng.gen::<f64>() < down(0.10) {
                     MasteryTier::S
                 } else {
                     MasteryTier::SS
@@ -631,13 +401,13 @@ pub fn evolve_mastery(
         }
         MasteryTier::S => {
             if games_played == 0 {
-                if rng.gen::<f64>() < 0.30 {
+                if rng.gen::<f64>() < down(0.30) {
                     MasteryTier::A
                 } else {
                     MasteryTier::S
                 }
             } else if games_played >= 50 && win_rate >= 0.55 {
-                if rng.gen::<f64>() < 0.10 {
+                if rng.gen::<f64>() < up(0.10) {
                     MasteryTier::SS
                 } else {
                     MasteryTier::S
@@ -648,13 +418,13 @@ pub fn evolve_mastery(
         }
         MasteryTier::A => {
             if games_played == 0 {
-                if rng.gen::<f64>() < 0.25 {
+                if rng.gen::<f64>() < down(0.25) {
                     MasteryTier::B
                 } else {
                     MasteryTier::A
                 }
             } else if games_played >= 35 && win_rate >= 0.50 {
-                if rng.gen::<f64>() < 0.15 {
+                if rng.gen::<f64>() < up(0.15) {
                     MasteryTier::S
                 } else {
                     MasteryTier::A
@@ -665,7 +435,7 @@ pub fn evolve_mastery(
         }
         MasteryTier::B => {
             if games_played >= 25 {
-                if rng.gen::<f64>() < 0.30 {
+                if rng.gen::<f64>() < up(0.30) {
                     MasteryTier::A
                 } else {
                     MasteryTier::B
@@ -763,4 +533,21 @@ mod tests {
         assert!(get_champion_by_name_en("Zed").is_some());
         assert!(get_champion_by_name_en("FakeChamp").is_none());
     }
+
+    #[test]
+    fn test_role_strength() {
+        let karma = get_champion_by_name_en("Karma").unwrap();
+        assert!(karma.is_flex());
+        assert_eq!(karma.role_strength(Position::Mid), Some(0.0));
+        assert_eq!(karma.role_strength(Position::Sup), Some(-0.25));
+        assert_eq!(karma.role_strength(Position::Adc), None);
+
+        for champ in CHAMPIONS.iter() {
+            assert_eq!(champ.positions[0], champ.position);
+            for &(pos, strength) in &champ.role_strength {
+                assert!(champ.can_play(pos) && pos != champ.position, "{} 的位置修正无效", champ.name_en);
+                assert!(strength <= 0.0);
+            }
+        }
+    }
 }
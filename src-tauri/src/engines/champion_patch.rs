@@ -0,0 +1,423 @@
+//! 英雄版本补丁引擎 (Champion Patch Engine)
+//!
+//! - 英雄初始强度来自 `champions.json`，每个赛季从初始强度开始
+//! - 春季常规赛、MSI、夏季常规赛、世界赛结束时各发布一个补丁：出场多且胜率高的英雄被削弱，冷门英雄被加强
+//! - 补丁强度叠加到 Meta 评分上决定版本 Tier，进而影响 BP 禁用优先级与赛季末熟练度演变
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::champion::{self, VersionTier, CHAMPIONS};
+use crate::engines::meta_engine::MetaType;
+use crate::models::{ChampionPatch, ChampionPatchChange, SeasonPhase};
+
+/// 单次补丁削弱的英雄数
+pub const PATCH_NERF_COUNT: usize = 3;
+/// 单次补丁加强的英雄数
+pub const PATCH_BUFF_COUNT: usize = 3;
+/// 单次调整幅度
+pub const PATCH_STEP: f64 = 0.5;
+/// 英雄强度上下限
+pub const MAX_PATCH_STRENGTH: f64 = 2.0;
+/// 进入削弱名单所需的最少出场次数
+const MIN_PICKS_FOR_NERF: u32 = 5;
+/// 加强名单从出场最少的若干英雄中随机挑选
+const BUFF_CANDIDATE_POOL: usize = 10;
+
+/// 英雄版本补丁引擎
+pub struct ChampionPatchEngine;
+
+impl ChampionPatchEngine {
+    /// 该阶段结束时发布的补丁序号；非补丁阶段返回 None
+    pub fn patch_number(phase: SeasonPhase) -> Option<u32> {
+        match phase {
+            SeasonPhase::SpringRegular => Some(1),
+            SeasonPhase::Msi => Some(2),
+            SeasonPhase::SummerRegular => Some(3),
+            SeasonPhase::WorldChampionship => Some(4),
+            _ => None,
+        }
+    }
+
+    fn strength_of(strengths: &HashMap<u8, f64>, champion_id: u8) -> f64 {
+        strengths.get(&champion_id).copied().unwrap_or_else(|| {
+            champion::get_champion(champion_id)
+                .map(|c| c.base_strength)
+                .unwrap_or(0.0)
+        })
+    }
+
+    /// 根据本赛季英雄使用数据规划补丁
+    ///
+    /// `usage` 为 英雄 → (出场次数, 胜场)，`strengths` 为当前补丁强度
+    pub fn plan_patch(
+        usage: &HashMap<u8, (u32, u32)>,
+        strengths: &HashMap<u8, f64>,
+        rng: &mut impl Rng,
+    ) -> Vec<ChampionPatchChange> {
+        if usage.values().all(|(picks, _)| *picks == 0) {
+            return Vec::new();
+        }
+        let picks_of = |id: u8| usage.get(&id).copied().unwrap_or((0, 0));
+
+        // 削弱：出场最多且胜率过半的英雄
+        let mut nerf_candidates: Vec<(u8, u32, f64)> = CHAMPIONS
+            .iter()
+            .filter_map(|c| {
+                let (picks, wins) = picks_of(c.id);
+                let win_rate = if picks > 0 { wins as f64 / picks as f64 } else { 0.0 };
+                let can_nerf = picks >= MIN_PICKS_FOR_NERF
+                    && win_rate > 0.5
+                    && Self::strength_of(strengths, c.id) > -MAX_PATCH_STRENGTH;
+                can_nerf.then_some((c.id, picks, win_rate))
+            })
+            .collect();
+        nerf_candidates.sort_by(|a, b| {
+            b.1.cmp(&a.1)
+                .then(b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        let mut changes = Vec::new();
+        for (id, picks, win_rate) in nerf_candidates.into_iter().take(PATCH_NERF_COUNT) {
+            changes.push(Self::make_change(
+                id,
+                -PATCH_STEP,
+                Self::strength_of(strengths, id),
+                format!("版本强势：出场 {} 次，胜率 {:.0}%", picks, win_rate * 100.0),
+            ));
+        }
+
+        // 加强：出场最少的英雄中随机挑选
+        let mut buff_candidates: Vec<(u8, u32)> = CHAMPIONS
+            .iter()
+            .filter(|c| !changes.iter().any(|ch| ch.champion_id == c.id))
+            .filter(|c| Self::strength_of(strengths, c.id) < MAX_PATCH_STRENGTH)
+            .map(|c| (c.id, picks_of(c.id).0))
+            .collect();
+        buff_candidates.sort_by_key(|(id, picks)| (*picks, *id));
+        buff_candidates.truncate(BUFF_CANDIDATE_POOL);
+        buff_candidates.shuffle(rng);
+        for (id, picks) in buff_candidates.into_iter().take(PATCH_BUFF_COUNT) {
+            changes.push(Self::make_change(
+                id,
+                PATCH_STEP,
+                Self::strength_of(strengths, id),
+                format!("出场率低：本赛季仅出场 {} 次", picks),
+            ));
+        }
+
+        changes
+    }
+
+    fn make_change(champion_id: u8, delta: f64, current: f64, reason: String) -> ChampionPatchChange {
+        ChampionPatchChange {
+            champion_id,
+            champion_name: champion::get_champion(champion_id)
+                .map(|c| c.name_cn.clone())
+                .unwrap_or_default(),
+            delta,
+            new_strength: (current + delta).clamp(-MAX_PATCH_STRENGTH, MAX_PATCH_STRENGTH),
+            reason,
+        }
+    }
+
+    // ==================== 数据库 ====================
+
+    /// 赛季当前的英雄强度（初始强度叠加本赛季已发布补丁）
+    pub async fn load_strengths(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+    ) -> HashMap<u8, f64> {
+        let mut strengths: HashMap<u8, f64> =
+            CHAMPIONS.iter().map(|c| (c.id, c.base_strength)).collect();
+
+        let rows = sqlx::query(
+            r#"
+            SELECT champion_id, new_strength
+            FROM champion_patch_changes
+            WHERE save_id = ? AND season_id = ?
+            ORDER BY patch_number ASC
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+        .unwrap_or_default();
+
+        for row in &rows {
+            let champion_id = row.get::<i64, _>("champion_id") as u8;
+            strengths.insert(champion_id, row.get("new_strength"));
+        }
+        strengths
+    }
+
+    /// 考虑版本补丁的英雄版本 Tier
+    pub async fn load_version_tiers(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        meta: MetaType,
+    ) -> HashMap<u8, VersionTier> {
+        let strengths = Self::load_strengths(pool, save_id, season_id).await;
+        champion::calculate_version_tiers_with_patch(meta, &strengths)
+            .into_iter()
+            .collect()
+    }
+
+    /// 本赛季各英雄的出场次数与胜场
+    pub async fn load_season_usage(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+    ) -> Result<HashMap<u8, (u32, u32)>, String> {
+        #[derive(Deserialize)]
+        struct StoredPick {
+            champion_id: u8,
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT d.home_picks_json, d.away_picks_json, m.home_team_id, g.winner_team_id
+            FROM game_draft_results d
+            JOIN match_games g ON g.save_id = d.save_id
+                 AND g.match_id = d.match_id AND g.game_number = d.game_number
+            JOIN matches m ON m.save_id = d.save_id AND m.id = d.match_id
+            JOIN tournaments t ON m.tournament_id = t.id AND t.save_id = d.save_id
+            WHERE d.save_id = ? AND t.season_id = ?
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询英雄使用数据失败: {}", e))?;
+
+        let mut usage: HashMap<u8, (u32, u32)> = HashMap::new();
+        for row in &rows {
+            let home_team_id: i64 = row.get("home_team_id");
+            let winner_team_id: i64 = row.get("winner_team_id");
+            let sides = [
+                (row.get::<String, _>("home_picks_json"), home_team_id == winner_team_id),
+                (row.get::<String, _>("away_picks_json"), home_team_id != winner_team_id),
+            ];
+            for (json, won) in sides {
+                let picks: Vec<StoredPick> = serde_json::from_str(&json).unwrap_or_default();
+                for pick in picks {
+                    let entry = usage.entry(pick.champion_id).or_default();
+                    entry.0 += 1;
+                    if won {
+                        entry.1 += 1;
+                    }
+                }
+            }
+        }
+        Ok(usage)
+    }
+
+    /// 阶段结束时发布补丁；非补丁阶段或该阶段已发布过补丁时返回 None
+    pub async fn apply_phase_patch(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        phase: SeasonPhase,
+    ) -> Result<Option<ChampionPatch>, String> {
+        let Some(patch_number) = Self::patch_number(phase) else {
+            return Ok(None);
+        };
+        let phase_str = format!("{:?}", phase);
+
+        let existing: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM champion_patch_changes WHERE save_id = ? AND season_id = ? AND phase = ?",
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(&phase_str)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询版本补丁失败: {}", e))?;
+        if existing > 0 {
+            return Ok(None);
+        }
+
+        let usage = Self::load_season_usage(pool, save_id, season_id).await?;
+        let strengths = Self::load_strengths(pool, save_id, season_id).await;
+        let changes = {
+            let mut rng = StdRng::from_entropy();
+            Self::plan_patch(&usage, &strengths, &mut rng)
+        };
+        if changes.is_empty() {
+            return Ok(None);
+        }
+
+        for change in &changes {
+            sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO champion_patch_changes
+                    (save_id, season_id, patch_number, phase, champion_id, delta, new_strength, reason)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(save_id)
+            .bind(season_id)
+            .bind(patch_number as i64)
+            .bind(&phase_str)
+            .bind(change.champion_id as i64)
+            .bind(change.delta)
+            .bind(change.new_strength)
+            .bind(&change.reason)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("保存版本补丁失败: {}", e))?;
+        }
+
+        log::info!(
+            "🩹 S{} 第{}版本补丁：{}",
+            season_id,
+            patch_number,
+            changes
+                .iter()
+                .map(|c| format!("{}{}", c.champion_name, if c.is_buff() { "↑" } else { "↓" }))
+                .collect::<Vec<_>>()
+                .join(" ")
+        );
+
+        Ok(Some(ChampionPatch {
+            season_id: season_id as u64,
+            patch_number,
+            phase: phase_str,
+            label: format!("S{} 第{}版本", season_id, patch_number),
+            changes,
+        }))
+    }
+
+    /// 补丁记录；`season_id` 为空时返回全部赛季
+    pub async fn load_patch_notes(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: Option<i64>,
+    ) -> Result<Vec<ChampionPatch>, String> {
+        let mut sql = String::from(
+            "SELECT season_id, patch_number, phase, champion_id, delta, new_strength, reason
+             FROM champion_patch_changes
+             WHERE save_id = ?",
+        );
+        if season_id.is_some() {
+            sql.push_str(" AND season_id = ?");
+        }
+        sql.push_str(" ORDER BY season_id ASC, patch_number ASC, delta ASC, champion_id ASC");
+
+        let mut query = sqlx::query(&sql).bind(save_id);
+        if let Some(sid) = season_id {
+            query = query.bind(sid);
+        }
+        let rows = query
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("查询版本补丁失败: {}", e))?;
+
+        let mut patches: Vec<ChampionPatch> = Vec::new();
+        for row in &rows {
+            let season_id = row.get::<i64, _>("season_id") as u64;
+            let patch_number = row.get::<i64, _>("patch_number") as u32;
+            let champion_id = row.get::<i64, _>("champion_id") as u8;
+            let change = ChampionPatchChange {
+                champion_id,
+                champion_name: champion::get_champion(champion_id)
+                    .map(|c| c.name_cn.clone())
+                    .unwrap_or_default(),
+                delta: row.get("delta"),
+                new_strength: row.get("new_strength"),
+                reason: row.get("reason"),
+            };
+            match patches.last_mut() {
+                Some(p) if p.season_id == season_id && p.patch_number == patch_number => {
+                    p.changes.push(change)
+                }
+                _ => patches.push(ChampionPatch {
+                    season_id,
+                    patch_number,
+                    phase: row.get("phase"),
+                    label: format!("S{} 第{}版本", season_id, patch_number),
+                    changes: vec![change],
+                }),
+            }
+        }
+        Ok(patches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_strengths() -> HashMap<u8, f64> {
+        CHAMPIONS.iter().map(|c| (c.id, c.base_strength)).collect()
+    }
+
+    #[test]
+    fn test_plan_patch_nerfs_dominant_and_buffs_unpicked() {
+        let mut usage: HashMap<u8, (u32, u32)> = HashMap::new();
+        usage.insert(1, (30, 20));
+        usage.insert(2, (25, 16));
+        usage.insert(3, (20, 12));
+        usage.insert(4, (40, 10)); // 出场多但胜率低，不削弱
+        for id in 5..=40u8 {
+            usage.insert(id, (8, 4));
+        }
+        let mut rng = StdRng::seed_from_u64(7);
+        let changes = ChampionPatchEngine::plan_patch(&usage, &base_strengths(), &mut rng);
+
+        let nerfed: Vec<u8> = changes.iter().filter(|c| !c.is_buff()).map(|c| c.champion_id).collect();
+        assert_eq!(nerfed, vec![1, 2, 3]);
+        let buffed: Vec<&ChampionPatchChange> = changes.iter().filter(|c| c.is_buff()).collect();
+        assert_eq!(buffed.len(), PATCH_BUFF_COUNT);
+        assert!(buffed.iter().all(|c| !usage.contains_key(&c.champion_id)));
+    }
+
+    #[test]
+    fn test_plan_patch_respects_strength_bounds() {
+        let mut usage: HashMap<u8, (u32, u32)> = HashMap::new();
+        usage.insert(1, (30, 20));
+        let mut strengths = base_strengths();
+        strengths.insert(1, -MAX_PATCH_STRENGTH);
+        for c in CHAMPIONS.iter().filter(|c| c.id != 1) {
+            strengths.insert(c.id, MAX_PATCH_STRENGTH);
+        }
+        let mut rng = StdRng::seed_from_u64(1);
+        let changes = ChampionPatchEngine::plan_patch(&usage, &strengths, &mut rng);
+
+        // 已到下限的英雄不再削弱，已到上限的英雄不再加强
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].champion_id, 1);
+        assert!(changes[0].is_buff());
+        assert_eq!(changes[0].new_strength, -MAX_PATCH_STRENGTH + PATCH_STEP);
+    }
+
+    #[test]
+    fn test_no_usage_means_no_patch() {
+        let mut rng = StdRng::seed_from_u64(3);
+        assert!(ChampionPatchEngine::plan_patch(&HashMap::new(), &base_strengths(), &mut rng).is_empty());
+    }
+
+    #[test]
+    fn test_patch_strength_shifts_version_tier() {
+        let champ = &CHAMPIONS[0];
+        let base = champion::calculate_version_tiers(MetaType::Balanced);
+        let base_tier = base.iter().find(|(id, _)| *id == champ.id).unwrap().1;
+
+        let mut strengths = HashMap::new();
+        strengths.insert(champ.id, MAX_PATCH_STRENGTH);
+        let buffed = champion::calculate_version_tiers_with_patch(MetaType::Balanced, &strengths);
+        let buffed_tier = buffed.iter().find(|(id, _)| *id == champ.id).unwrap().1;
+
+        assert_eq!(base_tier, VersionTier::T3);
+        assert_eq!(buffed_tier, VersionTier::T1);
+    }
+}
@@ -0,0 +1,502 @@
+//! 英雄熟练度训练引擎 (Champion Training Engine)
+//!
+//! - 每个比赛阶段结束后，选手可把训练时间分配给英雄池中的英雄
+//! - 训练累积熟练度进度，满 100 升一级；时长收益递减，等级越高越难提升
+//! - 训练会消耗状态：降低势头并累积疲劳
+//! - 未手动安排计划的队伍由 AI 按当前版本强度自动安排
+
+use std::collections::HashMap;
+
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::champion::{self, MasteryTier, VersionTier};
+use crate::engines::champion_patch::ChampionPatchEngine;
+use crate::engines::meta_engine::MetaType;
+use crate::models::player::Position;
+use crate::models::{ChampionTrainingEntry, SeasonPhase, TrainingAllocation, TrainingPlanSummary};
+
+/// 每名选手每阶段可用的训练时长
+pub const TRAINING_HOURS_PER_PHASE: u32 = 12;
+/// 单小时训练的基础进度
+const PROGRESS_PER_HOUR: f64 = 20.0;
+/// 时长收益递减指数（进度 ∝ 时长^0.7）
+const DIMINISHING_EXPONENT: f64 = 0.7;
+/// 升一级所需进度
+const PROGRESS_PER_TIER: f64 = 100.0;
+/// 升级后最多保留的溢出进度
+const MAX_CARRY_OVER: f64 = 50.0;
+/// 每训练这么多小时损失 1 点势头
+const HOURS_PER_MOMENTUM_LOSS: u32 = 8;
+/// 每训练这么多小时相当于连续出场 1 局的疲劳
+const HOURS_PER_FATIGUE_GAME: u32 = 4;
+
+/// 英雄熟练度训练引擎
+pub struct ChampionTrainingEngine;
+
+impl ChampionTrainingEngine {
+    /// 当前等级的训练效率（等级越高越难提升，SS 已满级）
+    fn tier_factor(tier: MasteryTier) -> f64 {
+        match tier {
+            MasteryTier::B => 1.0,
+            MasteryTier::A => 0.6,
+            MasteryTier::S => 0.3,
+            MasteryTier::SS => 0.0,
+        }
+    }
+
+    pub fn next_tier(tier: MasteryTier) -> Option<MasteryTier> {
+        match tier {
+            MasteryTier::B => Some(MasteryTier::A),
+            MasteryTier::A => Some(MasteryTier::S),
+            MasteryTier::S => Some(MasteryTier::SS),
+            MasteryTier::SS => None,
+        }
+    }
+
+    /// 一次训练获得的熟练度进度
+    pub fn progress_gain(tier: MasteryTier, hours: u32) -> f64 {
+        PROGRESS_PER_HOUR * f64::from(hours).powf(DIMINISHING_EXPONENT) * Self::tier_factor(tier)
+    }
+
+    /// 累加进度，满 100 升一级（单次最多升一级）
+    pub fn apply_progress(tier: MasteryTier, progress: f64, gain: f64) -> (MasteryTier, f64) {
+        let total = progress + gain;
+        match Self::next_tier(tier) {
+            Some(next) if total >= PROGRESS_PER_TIER => {
+                let carry = (total - PROGRESS_PER_TIER).min(MAX_CARRY_OVER);
+                let carry = if Self::next_tier(next).is_some() { carry } else { 0.0 };
+                (next, carry)
+            }
+            Some(_) => (tier, total),
+            None => (tier, 0.0),
+        }
+    }
+
+    /// 训练带来的状态消耗：(势头损失, 疲劳局数)
+    pub fn training_fatigue(total_hours: u32) -> (i8, u32) {
+        (
+            (total_hours / HOURS_PER_MOMENTUM_LOSS) as i8,
+            total_hours / HOURS_PER_FATIGUE_GAME,
+        )
+    }
+
+    /// 校验训练计划：总时长不超上限，且只能训练英雄池中未满级的英雄
+    pub fn validate_plan(
+        allocations: &[TrainingAllocation],
+        masteries: &HashMap<u8, MasteryTier>,
+    ) -> Result<(), String> {
+        let total: u32 = allocations.iter().map(|a| a.hours).sum();
+        if total > TRAINING_HOURS_PER_PHASE {
+            return Err(format!("训练时长超出上限: {} > {}", total, TRAINING_HOURS_PER_PHASE));
+        }
+        for a in allocations {
+            if a.hours == 0 {
+                return Err("训练时长必须大于0".to_string());
+            }
+            match masteries.get(&a.champion_id) {
+                None => return Err(format!("英雄不在选手英雄池中: {}", a.champion_id)),
+                Some(MasteryTier::SS) => return Err(format!("英雄熟练度已满级: {}", a.champion_id)),
+                Some(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// AI 自动训练：优先练版本强势、且仍有提升空间的本位置英雄
+    pub fn auto_plan(
+        position: Position,
+        masteries: &HashMap<u8, MasteryTier>,
+        version_tiers: &HashMap<u8, VersionTier>,
+    ) -> Vec<TrainingAllocation> {
+        let mut candidates: Vec<(u8, f64)> = masteries
+            .iter()
+            .filter(|(_, tier)| Self::next_tier(**tier).is_some())
+            .filter(|(id, _)| champion::get_champion(**id).is_some_and(|c| c.can_play(position)))
+            .filter_map(|(id, tier)| {
+                let version = version_tiers.get(id).copied().unwrap_or(VersionTier::T3);
+                if matches!(version, VersionTier::T4 | VersionTier::T5) {
+                    return None;
+                }
+                let score = f64::from(version.modifier()) * 2.0 + Self::tier_factor(*tier) * 3.0;
+                Some((*id, score))
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        match candidates.as_slice() {
+            [] => Vec::new(),
+            [(only, _)] => vec![TrainingAllocation {
+                champion_id: *only,
+                hours: TRAINING_HOURS_PER_PHASE,
+            }],
+            [(first, _), (second, _), ..] => vec![
+                TrainingAllocation {
+                    champion_id: *first,
+                    hours: TRAINING_HOURS_PER_PHASE * 2 / 3,
+                },
+                TrainingAllocation {
+                    champion_id: *second,
+                    hours: TRAINING_HOURS_PER_PHASE / 3,
+                },
+            ],
+        }
+    }
+
+    fn parse_position(s: &str) -> Position {
+        match s.to_uppercase().as_str() {
+            "TOP" => Position::Top,
+            "JUG" | "JUNGLE" => Position::Jug,
+            "ADC" | "BOT" => Position::Adc,
+            "SUP" | "SUPPORT" => Position::Sup,
+            _ => Position::Mid,
+        }
+    }
+
+    /// 选手英雄池熟练度
+    pub async fn load_player_masteries(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        player_id: u64,
+    ) -> Result<HashMap<u8, MasteryTier>, String> {
+        let rows = sqlx::query(
+            "SELECT champion_id, mastery_tier FROM player_champion_mastery WHERE save_id = ? AND player_id = ?",
+        )
+        .bind(save_id)
+        .bind(player_id as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询英雄熟练度失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|r| {
+                let tier = MasteryTier::from_id(&r.get::<String, _>("mastery_tier"))?;
+                Some((r.get::<i64, _>("champion_id") as u8, tier))
+            })
+            .collect())
+    }
+
+    /// 保存选手本阶段的训练计划（覆盖未结算的旧计划）
+    #[allow(clippy::too_many_arguments)]
+    pub async fn save_plan(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        phase: &str,
+        team_id: u64,
+        player_id: u64,
+        allocations: &[TrainingAllocation],
+        is_auto: bool,
+    ) -> Result<(), String> {
+        Self::clear_plan(pool, save_id, season_id, phase, player_id).await?;
+        for a in allocations {
+            sqlx::query(
+                r#"
+                INSERT INTO champion_training_plans
+                    (save_id, season_id, phase, team_id, player_id, champion_id, hours, is_auto)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(save_id)
+            .bind(season_id)
+            .bind(phase)
+            .bind(team_id as i64)
+            .bind(player_id as i64)
+            .bind(a.champion_id as i64)
+            .bind(a.hours as i64)
+            .bind(is_auto)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("保存训练计划失败: {}", e))?;
+        }
+        Ok(())
+    }
+
+    pub async fn clear_plan(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        phase: &str,
+        player_id: u64,
+    ) -> Result<(), String> {
+        sqlx::query(
+            "DELETE FROM champion_training_plans
+             WHERE save_id = ? AND season_id = ? AND phase = ? AND player_id = ? AND applied = 0",
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(phase)
+        .bind(player_id as i64)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("清除训练计划失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 队伍某阶段的训练计划与结算结果
+    pub async fn load_plan_summary(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        phase: &str,
+        team_id: u64,
+    ) -> Result<TrainingPlanSummary, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT team_id, player_id, champion_id, hours, is_auto, applied, progress_gain, tier_after
+            FROM champion_training_plans
+            WHERE save_id = ? AND season_id = ? AND phase = ? AND team_id = ?
+            ORDER BY player_id, hours DESC
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(phase)
+        .bind(team_id as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询训练计划失败: {}", e))?;
+
+        let entries = rows
+            .iter()
+            .map(|r| {
+                let champion_id = r.get::<i64, _>("champion_id") as u8;
+                ChampionTrainingEntry {
+                    team_id: r.get::<i64, _>("team_id") as u64,
+                    player_id: r.get::<i64, _>("player_id") as u64,
+                    champion_id,
+                    champion_name: champion::get_champion(champion_id)
+                        .map(|c| c.name_cn.clone())
+                        .unwrap_or_default(),
+                    hours: r.get::<i64, _>("hours") as u32,
+                    is_auto: r.get::<i64, _>("is_auto") != 0,
+                    applied: r.get::<i64, _>("applied") != 0,
+                    progress_gain: r.get("progress_gain"),
+                    tier_after: r.get("tier_after"),
+                }
+            })
+            .collect();
+
+        Ok(TrainingPlanSummary {
+            season_id: season_id as u64,
+            phase: phase.to_string(),
+            hours_per_player: TRAINING_HOURS_PER_PHASE,
+            entries,
+        })
+    }
+
+    /// 阶段结束时结算训练：手动计划优先，其余队伍由 AI 自动安排（每阶段只结算一次）
+    pub async fn run_phase_training(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        phase: SeasonPhase,
+    ) -> Result<u32, String> {
+        let phase_str = format!("{:?}", phase);
+
+        let applied: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM champion_training_plans WHERE save_id = ? AND season_id = ? AND phase = ? AND applied = 1",
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(&phase_str)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询训练计划失败: {}", e))?;
+        if applied > 0 {
+            return Ok(0);
+        }
+
+        let meta_type: Option<String> = sqlx::query_scalar(
+            "SELECT meta_type FROM meta_versions WHERE save_id = ? AND season_id = ? ORDER BY patch_number DESC LIMIT 1",
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("加载Meta类型失败: {}", e))?;
+        let meta = meta_type
+            .and_then(|s| MetaType::from_id(&s))
+            .unwrap_or(MetaType::Balanced);
+        let version_tiers = ChampionPatchEngine::load_version_tiers(pool, save_id, season_id, meta).await;
+
+        // 已安排计划的选手不再自动安排，同队其他选手仍由 AI 安排
+        let planned_players: Vec<i64> = sqlx::query_scalar(
+            "SELECT DISTINCT player_id FROM champion_training_plans WHERE save_id = ? AND season_id = ? AND phase = ?",
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(&phase_str)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询训练计划失败: {}", e))?;
+
+        let players = sqlx::query(
+            "SELECT id, team_id, position FROM players WHERE save_id = ? AND status = 'Active' AND team_id IS NOT NULL",
+        )
+        .bind(save_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询选手失败: {}", e))?;
+
+        for row in &players {
+            let team_id: i64 = row.get("team_id");
+            if planned_players.contains(&row.get::<i64, _>("id")) {
+                continue;
+            }
+            let player_id = row.get::<i64, _>("id") as u64;
+            let position = Self::parse_position(&row.get::<String, _>("position"));
+            let masteries = Self::load_player_masteries(pool, save_id, player_id).await?;
+            let plan = Self::auto_plan(position, &masteries, &version_tiers);
+            if !plan.is_empty() {
+                Self::save_plan(pool, save_id, season_id, &phase_str, team_id as u64, player_id, &plan, true)
+                    .await?;
+            }
+        }
+
+        let plans = sqlx::query(
+            r#"
+            SELECT p.id, p.player_id, p.champion_id, p.hours, m.mastery_tier, m.training_progress
+            FROM champion_training_plans p
+            JOIN player_champion_mastery m
+                ON m.save_id = p.save_id AND m.player_id = p.player_id AND m.champion_id = p.champion_id
+            WHERE p.save_id = ? AND p.season_id = ? AND p.phase = ? AND p.applied = 0
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(&phase_str)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询训练计划失败: {}", e))?;
+
+        let mut hours_by_player: HashMap<i64, u32> = HashMap::new();
+        for row in &plans {
+            let player_id: i64 = row.get("player_id");
+            let champion_id: i64 = row.get("champion_id");
+            let hours = row.get::<i64, _>("hours") as u32;
+            let tier = MasteryTier::from_id(&row.get::<String, _>("mastery_tier")).unwrap_or(MasteryTier::B);
+            let gain = Self::progress_gain(tier, hours);
+            let (new_tier, new_progress) =
+                Self::apply_progress(tier, row.get("training_progress"), gain);
+
+            sqlx::query(
+                "UPDATE player_champion_mastery SET mastery_tier = ?, training_progress = ?
+                 WHERE save_id = ? AND player_id = ? AND champion_id = ?",
+            )
+            .bind(new_tier.id())
+            .bind(new_progress)
+            .bind(save_id)
+            .bind(player_id)
+            .bind(champion_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新英雄熟练度失败: {}", e))?;
+
+            sqlx::query(
+                "UPDATE champion_training_plans SET applied = 1, progress_gain = ?, tier_after = ? WHERE id = ?",
+            )
+            .bind(gain)
+            .bind(new_tier.id())
+            .bind(row.get::<i64, _>("id"))
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新训练计划失败: {}", e))?;
+
+            *hours_by_player.entry(player_id).or_insert(0) += hours;
+        }
+
+        for (player_id, hours) in &hours_by_player {
+            let (momentum_loss, fatigue_games) = Self::training_fatigue(*hours);
+            sqlx::query(
+                "UPDATE player_form_factors
+                 SET momentum = MAX(momentum - ?, -5), games_since_rest = games_since_rest + ?
+                 WHERE save_id = ? AND player_id = ?",
+            )
+            .bind(momentum_loss as i64)
+            .bind(fatigue_games as i64)
+            .bind(save_id)
+            .bind(player_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新训练疲劳失败: {}", e))?;
+        }
+
+        Ok(plans.len() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_gain_diminishing_returns() {
+        let four = ChampionTrainingEngine::progress_gain(MasteryTier::B, 4);
+        let eight = ChampionTrainingEngine::progress_gain(MasteryTier::B, 8);
+        assert!(eight > four);
+        assert!(eight < four * 2.0, "时长翻倍收益应不足翻倍");
+
+        // 等级越高越难提升，SS 不再获得进度
+        assert!(ChampionTrainingEngine::progress_gain(MasteryTier::S, 8) < eight);
+        assert_eq!(ChampionTrainingEngine::progress_gain(MasteryTier::SS, 8), 0.0);
+    }
+
+    #[test]
+    fn test_apply_progress_levels_up_once() {
+        let (tier, progress) = ChampionTrainingEngine::apply_progress(MasteryTier::B, 90.0, 300.0);
+        assert_eq!(tier, MasteryTier::A);
+        assert_eq!(progress, MAX_CARRY_OVER);
+
+        let (tier, progress) = ChampionTrainingEngine::apply_progress(MasteryTier::A, 10.0, 20.0);
+        assert_eq!(tier, MasteryTier::A);
+        assert_eq!(progress, 30.0);
+
+        let (tier, progress) = ChampionTrainingEngine::apply_progress(MasteryTier::S, 95.0, 10.0);
+        assert_eq!(tier, MasteryTier::SS);
+        assert_eq!(progress, 0.0);
+    }
+
+    #[test]
+    fn test_validate_plan() {
+        let masteries: HashMap<u8, MasteryTier> =
+            [(1, MasteryTier::A), (2, MasteryTier::SS)].into_iter().collect();
+        let plan = |champion_id, hours| vec![TrainingAllocation { champion_id, hours }];
+
+        assert!(ChampionTrainingEngine::validate_plan(&plan(1, 6), &masteries).is_ok());
+        assert!(ChampionTrainingEngine::validate_plan(&plan(1, TRAINING_HOURS_PER_PHASE + 1), &masteries).is_err());
+        assert!(ChampionTrainingEngine::validate_plan(&plan(2, 6), &masteries).is_err());
+        assert!(ChampionTrainingEngine::validate_plan(&plan(3, 6), &masteries).is_err());
+    }
+
+    #[test]
+    fn test_auto_plan_follows_version_tiers() {
+        let mid: Vec<u8> = champion::CHAMPIONS
+            .iter()
+            .filter(|c| c.can_play(Position::Mid))
+            .map(|c| c.id)
+            .take(3)
+            .collect();
+        let masteries: HashMap<u8, MasteryTier> = mid.iter().map(|id| (*id, MasteryTier::A)).collect();
+        let version_tiers: HashMap<u8, VersionTier> = [
+            (mid[0], VersionTier::T5),
+            (mid[1], VersionTier::T1),
+            (mid[2], VersionTier::T3),
+        ]
+        .into_iter()
+        .collect();
+
+        let plan = ChampionTrainingEngine::auto_plan(Position::Mid, &masteries, &version_tiers);
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].champion_id, mid[1]);
+        assert_eq!(plan[1].champion_id, mid[2]);
+        assert_eq!(plan.iter().map(|a| a.hours).sum::<u32>(), TRAINING_HOURS_PER_PHASE);
+        assert!(ChampionTrainingEngine::validate_plan(&plan, &masteries).is_ok());
+    }
+
+    #[test]
+    fn test_training_fatigue() {
+        assert_eq!(ChampionTrainingEngine::training_fatigue(0), (0, 0));
+        assert_eq!(ChampionTrainingEngine::training_fatigue(TRAINING_HOURS_PER_PHASE), (1, 3));
+    }
+}
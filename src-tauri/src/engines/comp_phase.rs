@@ -0,0 +1,316 @@
+//! 阵容强势期引擎 (Comp Phase Engine)
+//!
+//! - 每种阵容体系有前期 / 中期 / 后期三段战力曲线，数值来自规则数据 `comp_phase_curves.json`，可按存档覆盖
+//! - 双方单局发挥各抽取一次（与 SimulationCore 同一分布），再按阶段叠加阵容曲线依次结算：
+//!   前期差距超过阈值直接结束，否则进入中期，再否则拖到后期按发挥高低定胜负
+//! - 无阵容体系时各阶段发挥差相同，胜率与 SimulationCore 完全一致，阶段只决定比赛时长
+//! - 因此前期阵容更容易赢短局，后期阵容更容易赢长局；比赛时长取决于决出胜负的阶段
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::bp_engine::CompType;
+use crate::engines::sim_core::SimulationCore;
+
+/// 决出胜负的比赛阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GamePhase {
+    Early,
+    Mid,
+    Late,
+}
+
+impl GamePhase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GamePhase::Early => "Early",
+            GamePhase::Mid => "Mid",
+            GamePhase::Late => "Late",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GamePhase::Early => "前期",
+            GamePhase::Mid => "中期",
+            GamePhase::Late => "后期",
+        }
+    }
+}
+
+/// 单个阵容体系的三段战力修正
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PhaseCurve {
+    pub early: f64,
+    pub mid: f64,
+    pub late: f64,
+}
+
+impl PhaseCurve {
+    pub fn at(&self, phase: GamePhase) -> f64 {
+        match phase {
+            GamePhase::Early => self.early,
+            GamePhase::Mid => self.mid,
+            GamePhase::Late => self.late,
+        }
+    }
+}
+
+/// 阵容强势期规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompPhaseRuleset {
+    /// 前期发挥差超过该值时比赛在前期结束
+    pub early_decisive_margin: f64,
+    /// 中期发挥差超过该值时比赛在中期结束
+    pub mid_decisive_margin: f64,
+    /// 各阶段结束比赛的时长区间（分钟，闭区间）
+    pub early_minutes: (u32, u32),
+    pub mid_minutes: (u32, u32),
+    pub late_minutes: (u32, u32),
+    /// 阵容体系 → 战力曲线（键为 CompType 名称）
+    pub curves: HashMap<String, PhaseCurve>,
+}
+
+static BUILTIN_RULESET: OnceLock<CompPhaseRuleset> = OnceLock::new();
+
+impl CompPhaseRuleset {
+    /// 内置规则数据
+    pub fn builtin() -> &'static CompPhaseRuleset {
+        BUILTIN_RULESET.get_or_init(|| {
+            serde_json::from_str(include_str!("../../comp_phase_curves.json"))
+                .expect("内置阵容强势期规则数据格式错误")
+        })
+    }
+
+    /// 阵容的战力曲线；未知阵容或未成型阵容为平线
+    pub fn curve(&self, comp: Option<CompType>) -> PhaseCurve {
+        comp.and_then(|c| self.curves.get(&format!("{:?}", c)).copied())
+            .unwrap_or_default()
+    }
+
+    pub fn minutes(&self, phase: GamePhase) -> (u32, u32) {
+        match phase {
+            GamePhase::Early => self.early_minutes,
+            GamePhase::Mid => self.mid_minutes,
+            GamePhase::Late => self.late_minutes,
+        }
+    }
+
+    /// 校验规则数据是否可用
+    pub fn validate(&self) -> Result<(), String> {
+        if self.early_decisive_margin <= 0.0 || self.mid_decisive_margin <= 0.0 {
+            return Err("决胜阈值必须大于 0".to_string());
+        }
+        let ranges = [self.early_minutes, self.mid_minutes, self.late_minutes];
+        if ranges.iter().any(|(lo, hi)| lo > hi) {
+            return Err("阶段时长区间下限不能大于上限".to_string());
+        }
+        if !(self.early_minutes.1 < self.mid_minutes.0 && self.mid_minutes.1 < self.late_minutes.0) {
+            return Err("前期、中期、后期时长区间必须依次递增且不重叠".to_string());
+        }
+        if let Some(name) = self.curves.keys().find(|k| CompType::from_id(k).is_none()) {
+            return Err(format!("未知阵容体系: {}", name));
+        }
+        Ok(())
+    }
+}
+
+impl Default for CompPhaseRuleset {
+    fn default() -> Self {
+        Self::builtin().clone()
+    }
+}
+
+/// 分阶段结算的单局结果
+#[derive(Debug, Clone, Copy)]
+pub struct PhaseOutcome {
+    pub phase: GamePhase,
+    pub home_performance: f64,
+    pub away_performance: f64,
+    pub home_won: bool,
+    pub duration_minutes: u32,
+}
+
+/// 阵容强势期引擎
+pub struct CompPhaseEngine;
+
+impl CompPhaseEngine {
+    /// 按阶段依次结算一局比赛：双方发挥只抽取一次，各阶段叠加对应的阵容曲线
+    pub fn resolve_game(
+        ruleset: &CompPhaseRuleset,
+        home_power: f64,
+        away_power: f64,
+        home_comp: Option<CompType>,
+        away_comp: Option<CompType>,
+        std_dev: f64,
+        rng: &mut impl Rng,
+    ) -> PhaseOutcome {
+        let home_curve = ruleset.curve(home_comp);
+        let away_curve = ruleset.curve(away_comp);
+        let home_roll = SimulationCore::team_performance(home_power, std_dev, rng);
+        let away_roll = SimulationCore::team_performance(away_power, std_dev, rng);
+
+        let phases = [
+            (GamePhase::Early, Some(ruleset.early_decisive_margin)),
+            (GamePhase::Mid, Some(ruleset.mid_decisive_margin)),
+            (GamePhase::Late, None),
+        ];
+        let mut outcome = None;
+        for (phase, margin) in phases {
+            let home_perf = home_roll + home_curve.at(phase);
+            let away_perf = away_roll + away_curve.at(phase);
+            let decided = match margin {
+                Some(m) => (home_perf - away_perf).abs() >= m,
+                None => true,
+            };
+            if decided {
+                outcome = Some((phase, home_perf, away_perf));
+                break;
+            }
+        }
+        let (phase, home_performance, away_performance) = outcome.expect("后期必定决出胜负");
+        let (lo, hi) = ruleset.minutes(phase);
+
+        PhaseOutcome {
+            phase,
+            home_performance,
+            away_performance,
+            home_won: home_performance > away_performance,
+            duration_minutes: rng.gen_range(lo..=hi),
+        }
+    }
+
+    // ==================== 数据库 ====================
+
+    /// 读取存档的阵容强势期规则；未覆盖时使用内置规则
+    pub async fn load_ruleset(pool: &Pool<Sqlite>, save_id: &str) -> CompPhaseRuleset {
+        let row = sqlx::query("SELECT ruleset_json FROM comp_phase_rulesets WHERE save_id = ?")
+            .bind(save_id)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten();
+
+        row.and_then(|r| {
+            let json: String = r.get("ruleset_json");
+            match serde_json::from_str::<CompPhaseRuleset>(&json) {
+                Ok(ruleset) => Some(ruleset),
+                Err(e) => {
+                    log::warn!("存档阵容强势期规则解析失败，使用内置规则: {}", e);
+                    None
+                }
+            }
+        })
+        .unwrap_or_default()
+    }
+
+    pub async fn save_ruleset(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        ruleset: &CompPhaseRuleset,
+    ) -> Result<(), String> {
+        ruleset.validate()?;
+        let json = serde_json::to_string(ruleset).map_err(|e| format!("序列化规则失败: {}", e))?;
+        sqlx::query(
+            r#"
+            INSERT INTO comp_phase_rulesets (save_id, ruleset_json, updated_at)
+            VALUES (?, ?, CURRENT_TIMESTAMP)
+            ON CONFLICT(save_id) DO UPDATE SET
+                ruleset_json = excluded.ruleset_json,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(save_id)
+        .bind(&json)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("保存阵容强势期规则失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 删除存档覆盖，恢复内置规则
+    pub async fn reset_ruleset(pool: &Pool<Sqlite>, save_id: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM comp_phase_rulesets WHERE save_id = ?")
+            .bind(save_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("重置阵容强势期规则失败: {}", e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_builtin_ruleset_is_valid() {
+        let ruleset = CompPhaseRuleset::builtin();
+        assert!(ruleset.validate().is_ok());
+        for comp in CompType::all() {
+            assert!(ruleset.curves.contains_key(&format!("{:?}", comp)), "缺少 {:?}", comp);
+        }
+        assert_eq!(ruleset.curve(None), PhaseCurve::default());
+    }
+
+    #[test]
+    fn test_duration_follows_decisive_phase() {
+        let ruleset = CompPhaseRuleset::builtin();
+        let mut rng = StdRng::seed_from_u64(1);
+        for _ in 0..500 {
+            let o = CompPhaseEngine::resolve_game(ruleset, 70.0, 70.0, None, None, 6.0, &mut rng);
+            let (lo, hi) = ruleset.minutes(o.phase);
+            assert!((lo..=hi).contains(&o.duration_minutes));
+            assert_eq!(o.home_won, o.home_performance > o.away_performance);
+        }
+    }
+
+    #[test]
+    fn test_early_comps_win_short_games_scaling_comps_win_long_games() {
+        let ruleset = CompPhaseRuleset::builtin();
+        let mut rng = StdRng::seed_from_u64(42);
+        let (mut early_wins, mut early_games) = (0, 0);
+        let (mut late_wins, mut late_games) = (0, 0);
+        for _ in 0..4000 {
+            let o = CompPhaseEngine::resolve_game(
+                ruleset,
+                70.0,
+                70.0,
+                Some(CompType::Rush),
+                Some(CompType::LateGame),
+                6.0,
+                &mut rng,
+            );
+            match o.phase {
+                GamePhase::Early => {
+                    early_games += 1;
+                    early_wins += o.home_won as u32;
+                }
+                GamePhase::Late => {
+                    late_games += 1;
+                    late_wins += o.home_won as u32;
+                }
+                GamePhase::Mid => {}
+            }
+        }
+        assert!(early_wins as f64 / early_games as f64 > 0.6);
+        assert!((late_wins as f64 / late_games as f64) < 0.4);
+    }
+
+    #[test]
+    fn test_validate_rejects_overlapping_phases() {
+        let mut ruleset = CompPhaseRuleset::builtin().clone();
+        ruleset.mid_minutes = (25, 40);
+        assert!(ruleset.validate().is_err());
+
+        let mut ruleset = CompPhaseRuleset::builtin().clone();
+        ruleset.curves.insert("Unknown".to_string(), PhaseCurve::default());
+        assert!(ruleset.validate().is_err());
+    }
+}
@@ -33,6 +33,8 @@ pub struct MatchContext {
     pub game_number: u8,
     pub is_decider: bool,
     pub score_diff: i8,
+    /// 宿敌之战（见 RivalryEngine）
+    pub is_rivalry: bool,
 }
 
 impl MatchContext {
@@ -42,6 +44,7 @@ impl MatchContext {
             "msi" | "worlds" | "masters" | "shanghai" | "claude"
         ) || self.round == "final"
             || self.is_decider
+            || self.is_rivalry
     }
 }
 
@@ -150,6 +153,10 @@ impl ConditionEngine {
             penalty -= 0.5;
         }
 
+        if match_ctx.is_rivalry {
+            penalty -= 1.0;
+        }
+
         if match_ctx.score_diff < 0 {
             penalty -= 0.5;
         }
@@ -434,6 +441,7 @@ mod tests {
             game_number: 5,
             is_decider: true,
             score_diff: -1,
+            is_rivalry: false,
         };
 
         let rookie_ctx = ConditionContext {
@@ -457,6 +465,27 @@ mod tests {
         assert!(rookie_condition < veteran_condition);
     }
 
+    #[test]
+    fn test_rivalry_raises_pressure() {
+        let regular = MatchContext {
+            tournament_type: "spring".to_string(),
+            round: "regular".to_string(),
+            ..Default::default()
+        };
+        let rivalry = MatchContext {
+            is_rivalry: true,
+            ..regular.clone()
+        };
+        assert!(!regular.is_high_pressure());
+        assert!(rivalry.is_high_pressure());
+
+        let ctx = ConditionContext::default();
+        assert!(
+            ConditionEngine::calculate_pressure_penalty(&rivalry, &ctx)
+                < ConditionEngine::calculate_pressure_penalty(&regular, &ctx)
+        );
+    }
+
     #[test]
     fn test_reset_form_factors_clears_perf_history() {
         let factors = ConditionEngine::reset_form_factors(42);
@@ -0,0 +1,191 @@
+//! 系列赛 BP 规则引擎 (Draft Rules Engine)
+//!
+//! - 规则按赛事阶段配置，未配置阶段取赛事默认（stage 为空），再否则为标准 BP
+//! - 全局BP：系列赛中用过的英雄双方都不能再选；软全局BP：只限制同一队伍
+//! - 全局禁用池：赛事阶段统一禁用一批英雄，每局都不可选
+
+use std::collections::HashSet;
+
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::bp_engine::{PickEntry, TeamSide};
+use crate::engines::champion;
+use crate::models::{DraftRule, TournamentDraftRule};
+
+/// 单个系列赛的 BP 规则与已使用英雄
+#[derive(Debug, Clone, Default)]
+pub struct SeriesDraftRules {
+    pub rule: DraftRule,
+    pub global_bans: Vec<u8>,
+    /// 主队本系列赛已使用的英雄
+    pub home_used: Vec<u8>,
+    /// 客队本系列赛已使用的英雄
+    pub away_used: Vec<u8>,
+}
+
+impl SeriesDraftRules {
+    pub fn new(rule: DraftRule, global_bans: Vec<u8>) -> Self {
+        Self {
+            rule,
+            global_bans,
+            home_used: Vec::new(),
+            away_used: Vec::new(),
+        }
+    }
+
+    /// 某一方本局不可选的英雄
+    pub fn locked_for(&self, side: TeamSide) -> HashSet<u8> {
+        let own = match side {
+            TeamSide::Home => &self.home_used,
+            TeamSide::Away => &self.away_used,
+        };
+        match self.rule {
+            DraftRule::Standard => HashSet::new(),
+            DraftRule::Fearless => self.home_used.iter().chain(&self.away_used).copied().collect(),
+            DraftRule::SoftFearless => own.iter().copied().collect(),
+            DraftRule::GlobalBanPool => self.global_bans.iter().copied().collect(),
+        }
+    }
+
+    /// 主客视角互换（主队执红方时按蓝方先手运行 BP）
+    pub fn swapped(&self) -> Self {
+        Self {
+            rule: self.rule,
+            global_bans: self.global_bans.clone(),
+            home_used: self.away_used.clone(),
+            away_used: self.home_used.clone(),
+        }
+    }
+
+    /// 记录一局的选人，供后续小局判断
+    pub fn record_game(&mut self, home_picks: &[PickEntry], away_picks: &[PickEntry]) {
+        self.home_used.extend(home_picks.iter().map(|p| p.champion_id));
+        self.away_used.extend(away_picks.iter().map(|p| p.champion_id));
+    }
+}
+
+/// 系列赛 BP 规则引擎
+pub struct DraftRulesEngine;
+
+impl DraftRulesEngine {
+    fn validate(rule: &TournamentDraftRule) -> Result<(), String> {
+        if let Some(id) = rule
+            .global_bans
+            .iter()
+            .find(|id| champion::get_champion(**id).is_none())
+        {
+            return Err(format!("未知英雄: {}", id));
+        }
+        if rule.rule == DraftRule::GlobalBanPool && rule.global_bans.is_empty() {
+            return Err("全局禁用池不能为空".to_string());
+        }
+        Ok(())
+    }
+
+    /// 读取某场比赛适用的 BP 规则：阶段配置优先，其次赛事默认，否则标准 BP
+    pub async fn load_series_rules(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        tournament_id: u64,
+        stage: &str,
+    ) -> SeriesDraftRules {
+        let row = sqlx::query(
+            r#"
+            SELECT rule, global_bans_json FROM tournament_draft_rules
+            WHERE save_id = ? AND tournament_id = ? AND (stage = ? OR stage = '')
+            ORDER BY CASE WHEN stage = '' THEN 1 ELSE 0 END
+            LIMIT 1
+            "#,
+        )
+        .bind(save_id)
+        .bind(tournament_id as i64)
+        .bind(stage)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten();
+
+        match row {
+            Some(r) => {
+                let rule = DraftRule::from_id(&r.get::<String, _>("rule")).unwrap_or_default();
+                let global_bans: Vec<u8> =
+                    serde_json::from_str(&r.get::<String, _>("global_bans_json")).unwrap_or_default();
+                SeriesDraftRules::new(rule, global_bans)
+            }
+            None => SeriesDraftRules::default(),
+        }
+    }
+
+    /// 赛事已配置的全部 BP 规则
+    pub async fn list_rules(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        tournament_id: u64,
+    ) -> Result<Vec<TournamentDraftRule>, String> {
+        let rows = sqlx::query(
+            "SELECT stage, rule, global_bans_json FROM tournament_draft_rules
+             WHERE save_id = ? AND tournament_id = ? ORDER BY stage",
+        )
+        .bind(save_id)
+        .bind(tournament_id as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询BP规则失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|r| TournamentDraftRule {
+                tournament_id,
+                stage: r.get("stage"),
+                rule: DraftRule::from_id(&r.get::<String, _>("rule")).unwrap_or_default(),
+                global_bans: serde_json::from_str(&r.get::<String, _>("global_bans_json"))
+                    .unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    pub async fn save_rule(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        rule: &TournamentDraftRule,
+    ) -> Result<(), String> {
+        Self::validate(rule)?;
+        let global_bans_json =
+            serde_json::to_string(&rule.global_bans).map_err(|e| format!("序列化禁用池失败: {}", e))?;
+        sqlx::query(
+            r#"
+            INSERT INTO tournament_draft_rules (save_id, tournament_id, stage, rule, global_bans_json, updated_at)
+            VALUES (?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+            ON CONFLICT(save_id, tournament_id, stage) DO UPDATE SET
+                rule = excluded.rule,
+                global_bans_json = excluded.global_bans_json,
+                updated_at = excluded.updated_at
+            "#,
+        )
+        .bind(save_id)
+        .bind(rule.tournament_id as i64)
+        .bind(&rule.stage)
+        .bind(rule.rule.as_str())
+        .bind(&global_bans_json)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("保存BP规则失败: {}", e))?;
+        Ok(())
+    }
+
+    pub async fn delete_rule(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        tournament_id: u64,
+        stage: &str,
+    ) -> Result<(), String> {
+        sqlx::query("DELETE FROM tournament_draft_rules WHERE save_id = ? AND tournament_id = ? AND stage = ?")
+            .bind(save_id)
+            .bind(tournament_id as i64)
+            .bind(stage)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("删除BP规则失败: {}", e))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+//! BP 球探报告引擎 (Draft Scouting Engine)
+//!
+//! 从 `game_draft_results` 中学习对手的 BP 习惯：
+//! - 每名选手近期最常用的英雄与舒适英雄（场次多、胜率高）
+//! - 队伍的阵容体系偏好
+//! - 队伍所属赛区的风格档案（BP 规划时叠加赛区风格偏好）
+//!
+//! 报告只统计最近的若干局，AI 会据此挑选针对性禁用。
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::bp_engine::{CompType, PickEntry};
+use crate::engines::champion;
+use crate::engines::regional_meta::{RegionStyleProfile, RegionalMetaEngine};
+use crate::models::player::Position;
+
+/// 报告统计的最近局数
+const REPORT_GAME_LIMIT: i64 = 40;
+/// 每名选手展示的常用英雄数
+const MOST_PLAYED_LIMIT: usize = 3;
+/// 舒适英雄：至少使用的局数
+const COMFORT_MIN_GAMES: u32 = 3;
+/// 舒适英雄：胜率门槛或占该选手出场的比例门槛
+const COMFORT_MIN_WINRATE: f64 = 0.6;
+const COMFORT_MIN_SHARE: f64 = 0.3;
+/// 针对舒适英雄的禁用加分
+const COMFORT_BAN_BONUS: i32 = 8;
+/// 针对常用英雄的禁用加分
+const MOST_PLAYED_BAN_BONUS: i32 = 4;
+
+/// 报告中的单个英雄使用记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoutedChampion {
+    pub champion_id: u8,
+    pub champion_name: String,
+    pub games: u32,
+    pub wins: u32,
+}
+
+/// 单名选手的 BP 倾向
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerDraftTendency {
+    pub player_id: u64,
+    pub position: Position,
+    pub games: u32,
+    /// 最常用的英雄（按局数排序）
+    pub most_played: Vec<ScoutedChampion>,
+    /// 舒适英雄：场次足够且胜率高或出场占比大
+    pub comfort_picks: Vec<u8>,
+}
+
+/// 队伍的阵容体系偏好
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompPreference {
+    pub comp_type: CompType,
+    pub games: u32,
+    pub wins: u32,
+}
+
+/// 队伍的 BP 球探报告
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DraftScoutingReport {
+    pub team_id: u64,
+    pub games_analyzed: u32,
+    pub comp_preferences: Vec<CompPreference>,
+    pub players: Vec<PlayerDraftTendency>,
+    /// 所属赛区的风格档案
+    #[serde(default)]
+    pub region_style: RegionStyleProfile,
+}
+
+impl DraftScoutingReport {
+    /// 体系使用次数（BP 规划与禁用评估使用）
+    pub fn comp_history(&self) -> Vec<(CompType, u32)> {
+        self.comp_preferences
+            .iter()
+            .map(|p| (p.comp_type, p.games))
+            .collect()
+    }
+
+    pub fn player(&self, player_id: u64) -> Option<&PlayerDraftTendency> {
+        self.players.iter().find(|p| p.player_id == player_id)
+    }
+
+    /// 按报告针对某名英雄的禁用加分（只看当前在场的选手）
+    pub fn ban_priority(&self, champion_id: u8, player_ids: &[u64]) -> i32 {
+        player_ids
+            .iter()
+            .filter_map(|id| self.player(*id))
+            .map(|p| {
+                if p.comfort_picks.contains(&champion_id) {
+                    COMFORT_BAN_BONUS
+                } else if p.most_played.iter().any(|c| c.champion_id == champion_id) {
+                    MOST_PLAYED_BAN_BONUS
+                } else {
+                    0
+                }
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// 禁用依据说明（用于 BP 解说）
+    pub fn ban_reason(&self, champion_id: u8, player_ids: &[u64]) -> Option<String> {
+        let name = champion::get_champion(champion_id)?.name_cn.as_str();
+        player_ids.iter().filter_map(|id| self.player(*id)).find_map(|p| {
+            let usage = p.most_played.iter().find(|c| c.champion_id == champion_id);
+            if p.comfort_picks.contains(&champion_id) {
+                let (games, wins) = usage.map(|c| (c.games, c.wins)).unwrap_or((0, 0));
+                Some(format!(
+                    "Ban {}，球探报告：对手{:?}位的舒适英雄（{}局{}胜）",
+                    name, p.position, games, wins
+                ))
+            } else {
+                usage.map(|c| format!("Ban {}，球探报告：对手{:?}位近期常用（{}局）", name, p.position, c.games))
+            }
+        })
+    }
+}
+
+/// 报告统计用的一局 BP 记录（已取该队一方视角）
+#[derive(Debug, Clone)]
+pub struct ScoutedGame {
+    pub picks: Vec<PickEntry>,
+    pub comp: Option<CompType>,
+    pub won: bool,
+}
+
+/// 统计中的单名选手：位置、出场局数、各英雄 (局数, 胜场)
+struct PlayerUsage {
+    position: Position,
+    games: u32,
+    champions: HashMap<u8, (u32, u32)>,
+}
+
+/// BP 球探报告引擎
+pub struct DraftScoutingEngine;
+
+impl DraftScoutingEngine {
+    /// 由历史对局构建报告
+    pub fn build_report(team_id: u64, games: &[ScoutedGame]) -> DraftScoutingReport {
+        let mut comps: HashMap<CompType, (u32, u32)> = HashMap::new();
+        let mut players: HashMap<u64, PlayerUsage> = HashMap::new();
+
+        for game in games {
+            if let Some(comp) = game.comp {
+                let entry = comps.entry(comp).or_default();
+                entry.0 += 1;
+                entry.1 += u32::from(game.won);
+            }
+            for pick in &game.picks {
+                let entry = players.entry(pick.player_id).or_insert_with(|| PlayerUsage {
+                    position: pick.position,
+                    games: 0,
+                    champions: HashMap::new(),
+                });
+                entry.games += 1;
+                let usage = entry.champions.entry(pick.champion_id).or_default();
+                usage.0 += 1;
+                usage.1 += u32::from(game.won);
+            }
+        }
+
+        let mut comp_preferences: Vec<CompPreference> = comps
+            .into_iter()
+            .map(|(comp_type, (games, wins))| CompPreference {
+                comp_type,
+                games,
+                wins,
+            })
+            .collect();
+        comp_preferences.sort_by(|a, b| b.games.cmp(&a.games).then(b.wins.cmp(&a.wins)));
+
+        let mut players: Vec<PlayerDraftTendency> = players
+            .into_iter()
+            .map(|(player_id, stats)| {
+                let total = stats.games;
+                let mut usage: Vec<(u8, u32, u32)> =
+                    stats.champions.into_iter().map(|(id, (g, w))| (id, g, w)).collect();
+                usage.sort_by(|a, b| b.1.cmp(&a.1).then(b.2.cmp(&a.2)).then(a.0.cmp(&b.0)));
+
+                let comfort_picks = usage
+                    .iter()
+                    .filter(|(_, g, w)| {
+                        *g >= COMFORT_MIN_GAMES
+                            && (f64::from(*w) / f64::from(*g) >= COMFORT_MIN_WINRATE
+                                || f64::from(*g) / f64::from(total) >= COMFORT_MIN_SHARE)
+                    })
+                    .map(|(id, _, _)| *id)
+                    .collect();
+                let most_played = usage
+                    .iter()
+                    .take(MOST_PLAYED_LIMIT)
+                    .map(|(id, g, w)| ScoutedChampion {
+                        champion_id: *id,
+                        champion_name: champion::get_champion(*id)
+                            .map(|c| c.name_cn.clone())
+                            .unwrap_or_default(),
+                        games: *g,
+                        wins: *w,
+                    })
+                    .collect();
+
+                PlayerDraftTendency {
+                    player_id,
+                    position: stats.position,
+                    games: total,
+                    most_played,
+                    comfort_picks,
+                }
+            })
+            .collect();
+        players.sort_by_key(|p| p.player_id);
+
+        DraftScoutingReport {
+            team_id,
+            games_analyzed: games.len() as u32,
+            comp_preferences,
+            players,
+            region_style: RegionStyleProfile::default(),
+        }
+    }
+
+    /// 从 `game_draft_results` 读取队伍最近的 BP 记录并生成报告
+    pub async fn load_report(pool: &Pool<Sqlite>, save_id: &str, team_id: u64) -> DraftScoutingReport {
+        let rows = sqlx::query(
+            r#"
+            SELECT d.home_picks_json, d.away_picks_json, d.home_comp, d.away_comp,
+                   m.home_team_id, g.winner_team_id
+            FROM game_draft_results d
+            JOIN matches m ON m.id = d.match_id
+            LEFT JOIN match_games g
+                ON g.save_id = d.save_id AND g.match_id = d.match_id AND g.game_number = d.game_number
+            WHERE d.save_id = ? AND (m.home_team_id = ? OR m.away_team_id = ?)
+            ORDER BY d.match_id DESC, d.game_number DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(save_id)
+        .bind(team_id as i64)
+        .bind(team_id as i64)
+        .bind(REPORT_GAME_LIMIT)
+        .fetch_all(pool)
+        .await
+        .unwrap_or_default();
+
+        let games: Vec<ScoutedGame> = rows
+            .iter()
+            .map(|row| {
+                let is_home = row.get::<i64, _>("home_team_id") as u64 == team_id;
+                let (picks_col, comp_col) = if is_home {
+                    ("home_picks_json", "home_comp")
+                } else {
+                    ("away_picks_json", "away_comp")
+                };
+                let winner: Option<i64> = row.get("winner_team_id");
+                ScoutedGame {
+                    picks: serde_json::from_str(&row.get::<String, _>(picks_col)).unwrap_or_default(),
+                    comp: row
+                        .get::<Option<String>, _>(comp_col)
+                        .and_then(|c| CompType::from_id(c.trim())),
+                    won: winner == Some(team_id as i64),
+                }
+            })
+            .collect();
+
+        let mut report = Self::build_report(team_id, &games);
+        report.region_style = RegionalMetaEngine::load_team_profile(pool, save_id, team_id).await;
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::champion::MasteryTier;
+
+    fn pick(player_id: u64, champion_id: u8) -> PickEntry {
+        PickEntry {
+            player_id,
+            champion_id,
+            position: Position::Mid,
+            mastery_tier: MasteryTier::S,
+            traits: Vec::new(),
+            flex: false,
+        }
+    }
+
+    fn game(champion_id: u8, won: bool) -> ScoutedGame {
+        ScoutedGame {
+            picks: vec![pick(7, champion_id)],
+            comp: Some(CompType::Rush),
+            won,
+        }
+    }
+
+    #[test]
+    fn test_build_report_finds_comfort_picks() {
+        let mut games = vec![game(1, true), game(1, true), game(1, false), game(1, true)];
+        games.extend((2..=9).map(|id| game(id, false)));
+
+        let report = DraftScoutingEngine::build_report(100, &games);
+        assert_eq!(report.games_analyzed, 12);
+        assert_eq!(report.comp_preferences[0].games, 12);
+
+        let player = report.player(7).unwrap();
+        assert_eq!(player.games, 12);
+        assert_eq!(player.most_played[0].champion_id, 1);
+        assert_eq!(player.most_played[0].games, 4);
+        assert_eq!(player.comfort_picks, vec![1]);
+    }
+
+    #[test]
+    fn test_ban_priority_only_targets_current_roster() {
+        let games = vec![game(1, true), game(1, true), game(1, true), game(2, false)];
+        let report = DraftScoutingEngine::build_report(100, &games);
+
+        assert_eq!(report.ban_priority(1, &[7]), COMFORT_BAN_BONUS);
+        assert_eq!(report.ban_priority(2, &[7]), MOST_PLAYED_BAN_BONUS);
+        assert_eq!(report.ban_priority(3, &[7]), 0);
+        // 选手已不在阵中则不再针对
+        assert_eq!(report.ban_priority(1, &[8]), 0);
+        assert!(report.ban_reason(1, &[7]).unwrap().contains("舒适英雄"));
+    }
+}
@@ -0,0 +1,793 @@
+//! 局内时间线引擎 (Game Timeline Engine)
+//!
+//! - 对线期：按位置对位比较发挥值，阵容前期强度影响对线，拉开初始经济差并产生一血/单杀
+//! - 对线期后逐分钟推演：小龙/先锋/大龙争夺、团战、推塔，倾向由发挥差、阵容强势期与克制关系决定
+//! - 胜方已由局内发挥决出：败方最多推到高地前，时间线一定以胜方推掉基地收尾
+//! - 比赛时长、经济曲线与选手击杀/死亡/助攻/经济/补刀全部由事件推导，彼此一致
+
+use rand::Rng;
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::bp_engine::{calculate_counter_modifier, CompType};
+use crate::engines::comp_phase::CompPhaseRuleset;
+use crate::engines::sim_core::SimulationCore;
+use crate::models::{
+    GameTimeline, GoldPoint, LaneOutcome, SavedGameTimeline, TimelineEvent, TimelineEventKind,
+    TimelineKill, TimelinePlayerLine,
+};
+
+/// 最短比赛时长（分钟）
+pub const MIN_GAME_MINUTES: u32 = 20;
+/// 最长比赛时长（分钟），到点强制进行决胜团战
+pub const MAX_GAME_MINUTES: u32 = 55;
+/// 对线期结束时间
+const LANING_END_MINUTE: u32 = 14;
+/// 每队每分钟自然经济
+const PASSIVE_GOLD_PER_MINUTE: i64 = 1900;
+const KILL_GOLD: i64 = 300;
+const ASSIST_GOLD: i64 = 150;
+const TOWER_GOLD: i64 = 550;
+const DRAGON_GOLD: i64 = 200;
+const HERALD_GOLD: i64 = 400;
+const BARON_GOLD: i64 = 1500;
+/// 单队防御塔总数
+const TOTAL_TOWERS: u32 = 11;
+/// 推掉该数量的防御塔后高地水晶暴露
+const TOWERS_TO_OPEN_BASE: u32 = 8;
+/// 拿到该数量小龙获得龙魂
+const DRAGONS_FOR_SOUL: u32 = 4;
+const DRAGON_RESPAWN: u32 = 5;
+const BARON_SPAWN: u32 = 20;
+const BARON_RESPAWN: u32 = 6;
+/// 强势期曲线（战力修正）换算为局势倾向的比例
+const PHASE_CURVE_SCALE: f64 = 2.5;
+/// 终局经济领先达到该值时，胜方可直接推进基地
+const SIEGE_GOLD_LEAD: i64 = 8000;
+
+/// 参与时间线推演的选手
+#[derive(Debug, Clone)]
+pub struct TimelinePlayer {
+    pub player_id: u64,
+    pub position: String,
+    /// 本局实际发挥值
+    pub performance: f64,
+}
+
+/// 时间线推演输入
+#[derive(Debug, Clone)]
+pub struct TimelineInput {
+    pub home_team_id: u64,
+    pub away_team_id: u64,
+    pub home_players: Vec<TimelinePlayer>,
+    pub away_players: Vec<TimelinePlayer>,
+    pub home_comp: Option<CompType>,
+    pub away_comp: Option<CompType>,
+    /// 胜负已由局内发挥决出
+    pub home_won: bool,
+}
+
+/// 位置参数：(击杀权重, 经济分配占比, 每分钟补刀)
+fn position_profile(position: &str) -> (f64, f64, f64) {
+    match lane_key(position) {
+        "TOP" => (1.0, 0.21, 8.0),
+        "JUG" => (1.0, 0.19, 5.5),
+        "MID" => (1.3, 0.23, 8.5),
+        "ADC" => (1.4, 0.25, 9.0),
+        "SUP" => (0.4, 0.12, 1.2),
+        _ => (1.0, 0.20, 6.0),
+    }
+}
+
+fn lane_key(position: &str) -> &'static str {
+    match position.trim().to_uppercase().as_str() {
+        "TOP" => "TOP",
+        "JUG" | "JUNGLE" => "JUG",
+        "MID" | "MIDDLE" => "MID",
+        "ADC" | "BOT" | "BOTTOM" => "ADC",
+        "SUP" | "SUPPORT" => "SUP",
+        _ => "FLEX",
+    }
+}
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn pick_weighted(rng: &mut impl Rng, weights: &[f64]) -> usize {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return rng.gen_range(0..weights.len());
+    }
+    let mut roll = rng.r#gen::<f64>() * total;
+    for (i, w) in weights.iter().enumerate() {
+        if roll < *w {
+            return i;
+        }
+        roll -= w;
+    }
+    weights.len() - 1
+}
+
+fn avg_performance(players: &[TimelinePlayer]) -> f64 {
+    if players.is_empty() {
+        return 0.0;
+    }
+    players.iter().map(|p| p.performance).sum::<f64>() / players.len() as f64
+}
+
+/// 推演过程中的可变状态
+struct TimelineState {
+    home_gold: i64,
+    away_gold: i64,
+    home_towers: u32,
+    away_towers: u32,
+    home_dragons: u32,
+    away_dragons: u32,
+    events: Vec<TimelineEvent>,
+    kills: Vec<TimelineKill>,
+}
+
+impl TimelineState {
+    fn gold_diff(&self) -> i64 {
+        self.home_gold - self.away_gold
+    }
+
+    fn add_gold(&mut self, home: bool, amount: i64) {
+        if home {
+            self.home_gold += amount;
+        } else {
+            self.away_gold += amount;
+        }
+    }
+
+    fn towers(&self, home: bool) -> u32 {
+        if home { self.home_towers } else { self.away_towers }
+    }
+}
+
+/// 局内时间线引擎
+pub struct GameTimelineEngine;
+
+impl GameTimelineEngine {
+    /// 阵容强势期：(前期强度, 后期强度)，取自阵容强势期规则的前期/后期曲线
+    pub fn comp_phase_profile(comp: Option<CompType>) -> (f64, f64) {
+        let curve = CompPhaseRuleset::builtin().curve(comp);
+        (curve.early / PHASE_CURVE_SCALE, curve.late / PHASE_CURVE_SCALE)
+    }
+
+    /// 无时间线时按发挥差估算比赛时长：碾压局更短，势均力敌的比赛更长
+    pub fn estimate_duration(performance_margin: f64, rng: &mut impl Rng) -> u32 {
+        let base = 38.0 - performance_margin.abs().min(15.0) * 0.8;
+        let minutes = (base + SimulationCore::standard_normal(rng) * 4.0).round();
+        (minutes.max(0.0) as u32).clamp(MIN_GAME_MINUTES, MAX_GAME_MINUTES)
+    }
+
+    /// 推演一局比赛的完整时间线
+    pub fn simulate(input: &TimelineInput, rng: &mut impl Rng) -> GameTimeline {
+        let winner_home = input.home_won;
+        let winner_id = if winner_home { input.home_team_id } else { input.away_team_id };
+        let winner_sign = if winner_home { 1.0 } else { -1.0 };
+        let perf_edge = avg_performance(&input.home_players) - avg_performance(&input.away_players);
+        let counter = match (input.home_comp, input.away_comp) {
+            (Some(h), Some(a)) => calculate_counter_modifier(&h, &a),
+            _ => 0.0,
+        };
+        let (home_early, home_late) = Self::comp_phase_profile(input.home_comp);
+        let (away_early, away_late) = Self::comp_phase_profile(input.away_comp);
+
+        let mut state = TimelineState {
+            home_gold: 500,
+            away_gold: 500,
+            home_towers: 0,
+            away_towers: 0,
+            home_dragons: 0,
+            away_dragons: 0,
+            events: Vec::new(),
+            kills: Vec::new(),
+        };
+
+        // === 对线期 ===
+        let mut lanes = Vec::new();
+        let mut lane_edges = Vec::new();
+        for (i, home) in input.home_players.iter().enumerate() {
+            let key = lane_key(&home.position);
+            let away = input
+                .away_players
+                .iter()
+                .position(|a| lane_key(&a.position) == key)
+                .or(if i < input.away_players.len() { Some(i) } else { None });
+            let Some(away_idx) = away else { continue };
+            let away = &input.away_players[away_idx];
+
+            let edge = home.performance - away.performance
+                + (home_early - away_early) * 3.0
+                + SimulationCore::standard_normal(rng) * 4.0;
+            let gold_diff = ((edge * 60.0).round() as i64).clamp(-1500, 1500);
+            let winner_team_id = if edge >= 3.0 {
+                Some(input.home_team_id)
+            } else if edge <= -3.0 {
+                Some(input.away_team_id)
+            } else {
+                None
+            };
+            lanes.push(LaneOutcome {
+                position: key.to_string(),
+                home_player_id: home.player_id,
+                away_player_id: away.player_id,
+                winner_team_id,
+                gold_diff,
+            });
+            lane_edges.push((i, away_idx, edge));
+        }
+        let home_lane_gold: i64 = lanes.iter().map(|l| l.gold_diff.max(0)).sum();
+        let away_lane_gold: i64 = lanes.iter().map(|l| (-l.gold_diff).max(0)).sum();
+
+        // 一血与单杀时间点
+        let total_edge: f64 = lane_edges.iter().map(|(_, _, e)| e).sum();
+        let first_blood_minute = rng.gen_range(2..=6);
+        let first_blood_home = rng.r#gen::<f64>() < logistic(total_edge / 10.0);
+        let mut solo_kills: Vec<(u32, usize, usize, bool)> = lane_edges
+            .iter()
+            .filter(|(_, _, e)| e.abs() >= 9.0)
+            .map(|(h, a, e)| {
+                let minute = rng.gen_range(first_blood_minute + 1..LANING_END_MINUTE);
+                (minute, *h, *a, *e > 0.0)
+            })
+            .collect();
+        solo_kills.sort_by_key(|s| s.0);
+
+        // === 逐分钟推演 ===
+        let mut gold_curve = Vec::new();
+        let mut next_dragon = DRAGON_RESPAWN;
+        let mut next_baron = BARON_SPAWN;
+        let mut herald_taken = false;
+        let mut duration = MAX_GAME_MINUTES;
+
+        for minute in 1..=MAX_GAME_MINUTES {
+            state.home_gold += PASSIVE_GOLD_PER_MINUTE + rng.gen_range(-100..=100);
+            state.away_gold += PASSIVE_GOLD_PER_MINUTE + rng.gen_range(-100..=100);
+            if minute <= LANING_END_MINUTE {
+                state.home_gold += home_lane_gold / LANING_END_MINUTE as i64;
+                state.away_gold += away_lane_gold / LANING_END_MINUTE as i64;
+            }
+
+            // 主队视角的局势倾向
+            let t = (minute as f64 / 35.0).min(1.0);
+            let comp_bias = (home_early - away_early) * (1.0 - t) + (home_late - away_late) * t;
+            let ramp = (minute as f64 / 30.0).min(1.5);
+            let pressure = perf_edge * 0.1
+                + comp_bias * 0.5
+                + counter * 0.3
+                + winner_sign * ramp * 0.8
+                + state.gold_diff() as f64 / 5000.0;
+            let home_prob = logistic(pressure);
+            let drift = (pressure * 100.0) as i64;
+            state.add_gold(drift > 0, drift.abs());
+
+            if minute == first_blood_minute {
+                Self::record_kills(&mut state, input, rng, minute, first_blood_home, 1, true);
+                if let Some(k) = state.kills.last() {
+                    state.events.push(TimelineEvent {
+                        minute,
+                        kind: TimelineEventKind::FirstBlood,
+                        team_id: k.team_id,
+                        description: "拿下一血".to_string(),
+                        gold_swing: KILL_GOLD,
+                    });
+                }
+            }
+            while let Some(&(m, h, a, home_side)) = solo_kills.first() {
+                if m != minute {
+                    break;
+                }
+                solo_kills.remove(0);
+                let (killer, victim) = if home_side {
+                    (&input.home_players[h], &input.away_players[a])
+                } else {
+                    (&input.away_players[a], &input.home_players[h])
+                };
+                let team_id = if home_side { input.home_team_id } else { input.away_team_id };
+                state.kills.push(TimelineKill {
+                    minute,
+                    team_id,
+                    killer_id: killer.player_id,
+                    victim_id: victim.player_id,
+                    assist_ids: Vec::new(),
+                });
+                state.add_gold(home_side, KILL_GOLD);
+                state.events.push(TimelineEvent {
+                    minute,
+                    kind: TimelineEventKind::SoloKill,
+                    team_id,
+                    description: format!("{}路单杀", lane_key(&killer.position)),
+                    gold_swing: KILL_GOLD,
+                });
+            }
+
+            // 小龙
+            let soul_claimed = state.home_dragons >= DRAGONS_FOR_SOUL || state.away_dragons >= DRAGONS_FOR_SOUL;
+            if minute >= next_dragon && !soul_claimed {
+                let home_side = rng.r#gen::<f64>() < home_prob;
+                let count = if home_side {
+                    state.home_dragons += 1;
+                    state.home_dragons
+                } else {
+                    state.away_dragons += 1;
+                    state.away_dragons
+                };
+                state.add_gold(home_side, DRAGON_GOLD);
+                let team_id = if home_side { input.home_team_id } else { input.away_team_id };
+                state.events.push(TimelineEvent {
+                    minute,
+                    kind: TimelineEventKind::Dragon,
+                    team_id,
+                    description: format!("击杀第{}条小龙", count),
+                    gold_swing: DRAGON_GOLD,
+                });
+                if count == DRAGONS_FOR_SOUL {
+                    state.events.push(TimelineEvent {
+                        minute,
+                        kind: TimelineEventKind::DragonSoul,
+                        team_id,
+                        description: "获得龙魂".to_string(),
+                        gold_swing: 0,
+                    });
+                }
+                next_dragon = minute + DRAGON_RESPAWN;
+            }
+
+            // 峡谷先锋
+            if minute == LANING_END_MINUTE && !herald_taken {
+                herald_taken = true;
+                let home_side = rng.r#gen::<f64>() < home_prob;
+                state.add_gold(home_side, HERALD_GOLD);
+                state.events.push(TimelineEvent {
+                    minute,
+                    kind: TimelineEventKind::Herald,
+                    team_id: if home_side { input.home_team_id } else { input.away_team_id },
+                    description: "拿下峡谷先锋".to_string(),
+                    gold_swing: HERALD_GOLD,
+                });
+                if rng.r#gen::<f64>() < 0.6 {
+                    Self::take_tower(&mut state, input, minute, home_side);
+                }
+            }
+
+            // 团战
+            let mut winner_closed_out = false;
+            let fight_chance = if minute < LANING_END_MINUTE {
+                0.06
+            } else if minute >= next_baron {
+                0.3
+            } else {
+                0.2
+            };
+            if rng.r#gen::<f64>() < fight_chance {
+                let home_side = rng.r#gen::<f64>() < home_prob;
+                let (ally_count, enemy_count) = if home_side {
+                    (input.home_players.len(), input.away_players.len())
+                } else {
+                    (input.away_players.len(), input.home_players.len())
+                };
+                let win_kills = rng.gen_range(2..=5).min(enemy_count as u32);
+                let lose_kills = rng.gen_range(0..=2).min(win_kills.saturating_sub(1)).min(ally_count as u32);
+                Self::record_kills(&mut state, input, rng, minute, home_side, win_kills, false);
+                Self::record_kills(&mut state, input, rng, minute, !home_side, lose_kills, false);
+                let team_id = if home_side { input.home_team_id } else { input.away_team_id };
+                state.events.push(TimelineEvent {
+                    minute,
+                    kind: TimelineEventKind::Teamfight,
+                    team_id,
+                    description: format!("团战 {} 换 {}", win_kills, lose_kills),
+                    gold_swing: (win_kills as i64 - lose_kills as i64) * KILL_GOLD,
+                });
+
+                if minute >= next_baron && rng.r#gen::<f64>() < 0.6 {
+                    state.add_gold(home_side, BARON_GOLD);
+                    state.events.push(TimelineEvent {
+                        minute,
+                        kind: TimelineEventKind::Baron,
+                        team_id,
+                        description: "击杀大龙".to_string(),
+                        gold_swing: BARON_GOLD,
+                    });
+                    next_baron = minute + BARON_RESPAWN;
+                    Self::take_tower(&mut state, input, minute, home_side);
+                }
+                if rng.r#gen::<f64>() < 0.7 {
+                    Self::take_tower(&mut state, input, minute, home_side);
+                }
+                winner_closed_out = home_side == winner_home;
+            }
+
+            // 经济领先方持续推进
+            let lead = state.gold_diff();
+            if minute > 10 && lead.abs() > 1500 && rng.r#gen::<f64>() < 0.2 {
+                Self::take_tower(&mut state, input, minute, lead > 0);
+            }
+
+            // 胜方推掉基地
+            let winner_lead = state.gold_diff() * winner_sign as i64;
+            let base_open = state.towers(winner_home) >= TOWERS_TO_OPEN_BASE;
+            if minute >= MIN_GAME_MINUTES
+                && base_open
+                && (winner_closed_out || winner_lead >= SIEGE_GOLD_LEAD)
+            {
+                Self::push_nexus(&mut state, winner_id, minute);
+                duration = minute;
+                gold_curve.push(Self::gold_point(&state, minute));
+                break;
+            }
+
+            if minute == MAX_GAME_MINUTES {
+                // 拖到最长时长：胜方赢下决胜团战直接终结比赛
+                let enemy_count = if winner_home { input.away_players.len() } else { input.home_players.len() };
+                let kills = rng.gen_range(4..=5).min(enemy_count as u32);
+                Self::record_kills(&mut state, input, rng, minute, winner_home, kills, false);
+                state.events.push(TimelineEvent {
+                    minute,
+                    kind: TimelineEventKind::Teamfight,
+                    team_id: winner_id,
+                    description: format!("决胜团战 {} 换 0", kills),
+                    gold_swing: kills as i64 * KILL_GOLD,
+                });
+                while state.towers(winner_home) < TOWERS_TO_OPEN_BASE {
+                    Self::take_tower(&mut state, input, minute, winner_home);
+                }
+                Self::push_nexus(&mut state, winner_id, minute);
+            }
+            gold_curve.push(Self::gold_point(&state, minute));
+        }
+
+        state.kills.sort_by_key(|k| k.minute);
+        state.events.sort_by_key(|e| e.minute);
+        let players = Self::build_player_lines(input, &state, duration, rng);
+
+        GameTimeline {
+            home_team_id: input.home_team_id,
+            away_team_id: input.away_team_id,
+            winner_id,
+            duration_minutes: duration,
+            home_comp: input.home_comp.map(|c| format!("{:?}", c)),
+            away_comp: input.away_comp.map(|c| format!("{:?}", c)),
+            lanes,
+            events: state.events,
+            kills: state.kills,
+            gold_curve,
+            players,
+            home_dragons: state.home_dragons,
+            away_dragons: state.away_dragons,
+            home_towers: state.home_towers,
+            away_towers: state.away_towers,
+        }
+    }
+
+    fn gold_point(state: &TimelineState, minute: u32) -> GoldPoint {
+        GoldPoint {
+            minute,
+            home_gold: state.home_gold,
+            away_gold: state.away_gold,
+            gold_diff: state.gold_diff(),
+        }
+    }
+
+    fn push_nexus(state: &mut TimelineState, winner_id: u64, minute: u32) {
+        state.events.push(TimelineEvent {
+            minute,
+            kind: TimelineEventKind::Nexus,
+            team_id: winner_id,
+            description: "推掉基地，赢下比赛".to_string(),
+            gold_swing: 0,
+        });
+    }
+
+    /// 推掉对方一座防御塔；败方最多推到高地前
+    fn take_tower(state: &mut TimelineState, input: &TimelineInput, minute: u32, home_side: bool) {
+        let taken = state.towers(home_side);
+        let limit = if home_side == input.home_won { TOTAL_TOWERS } else { TOWERS_TO_OPEN_BASE - 1 };
+        if taken >= limit {
+            return;
+        }
+        let first_tower = state.home_towers + state.away_towers == 0;
+        if home_side {
+            state.home_towers += 1;
+        } else {
+            state.away_towers += 1;
+        }
+        state.add_gold(home_side, TOWER_GOLD);
+        let team_id = if home_side { input.home_team_id } else { input.away_team_id };
+        let (kind, description) = if first_tower {
+            (TimelineEventKind::FirstTower, "推掉一塔".to_string())
+        } else if taken + 1 == TOWERS_TO_OPEN_BASE {
+            (TimelineEventKind::Inhibitor, "攻破高地水晶".to_string())
+        } else {
+            (TimelineEventKind::Tower, format!("推掉第{}座防御塔", taken + 1))
+        };
+        state.events.push(TimelineEvent {
+            minute,
+            kind,
+            team_id,
+            description,
+            gold_swing: TOWER_GOLD,
+        });
+    }
+
+    /// 记录一方的若干次击杀：击杀者按位置与发挥加权，阵亡者偏向发挥差的选手
+    fn record_kills(
+        state: &mut TimelineState,
+        input: &TimelineInput,
+        rng: &mut impl Rng,
+        minute: u32,
+        home_side: bool,
+        count: u32,
+        early: bool,
+    ) {
+        let (allies, enemies, team_id) = if home_side {
+            (&input.home_players, &input.away_players, input.home_team_id)
+        } else {
+            (&input.away_players, &input.home_players, input.away_team_id)
+        };
+        if allies.is_empty() || enemies.is_empty() {
+            return;
+        }
+        let killer_weights: Vec<f64> = allies
+            .iter()
+            .map(|p| position_profile(&p.position).0 * p.performance.max(1.0))
+            .collect();
+        let victim_weights: Vec<f64> = enemies.iter().map(|p| (110.0 - p.performance).max(1.0)).collect();
+
+        for _ in 0..count {
+            let killer = pick_weighted(rng, &killer_weights);
+            let victim = pick_weighted(rng, &victim_weights);
+            let assist_chance = if early { 0.3 } else { 0.6 };
+            let assist_ids: Vec<u64> = allies
+                .iter()
+                .enumerate()
+                .filter(|(i, p)| {
+                    let bonus = if matches!(lane_key(&p.position), "JUG" | "SUP") { 0.2 } else { 0.0 };
+                    *i != killer && rng.r#gen::<f64>() < assist_chance + bonus
+                })
+                .map(|(_, p)| p.player_id)
+                .collect();
+            state.add_gold(home_side, KILL_GOLD + ASSIST_GOLD * assist_ids.len() as i64);
+            state.kills.push(TimelineKill {
+                minute,
+                team_id,
+                killer_id: allies[killer].player_id,
+                victim_id: enemies[victim].player_id,
+                assist_ids,
+            });
+        }
+    }
+
+    /// 由击杀记录和终局经济推导选手数据：击杀/助攻赏金归个人，其余经济按位置占比分配
+    fn build_player_lines(
+        input: &TimelineInput,
+        state: &TimelineState,
+        duration: u32,
+        rng: &mut impl Rng,
+    ) -> Vec<TimelinePlayerLine> {
+        let mut lines = Vec::new();
+        for (players, team_id, team_gold) in [
+            (&input.home_players, input.home_team_id, state.home_gold),
+            (&input.away_players, input.away_team_id, state.away_gold),
+        ] {
+            let mut team_lines: Vec<TimelinePlayerLine> = players
+                .iter()
+                .map(|p| {
+                    let kills = state.kills.iter().filter(|k| k.killer_id == p.player_id).count() as u32;
+                    let deaths = state.kills.iter().filter(|k| k.victim_id == p.player_id).count() as u32;
+                    let assists = state
+                        .kills
+                        .iter()
+                        .filter(|k| k.assist_ids.contains(&p.player_id))
+                        .count() as u32;
+                    let (_, _, cs_per_min) = position_profile(&p.position);
+                    let cs_factor = 0.85 + (p.performance / 100.0) * 0.3 + rng.gen_range(-0.05..0.05);
+                    TimelinePlayerLine {
+                        player_id: p.player_id,
+                        team_id,
+                        kills,
+                        deaths,
+                        assists,
+                        cs: (cs_per_min * duration as f64 * cs_factor).max(0.0) as u32,
+                        gold: 0,
+                    }
+                })
+                .collect();
+
+            let bounty: i64 = team_lines
+                .iter()
+                .map(|l| l.kills as i64 * KILL_GOLD + l.assists as i64 * ASSIST_GOLD)
+                .sum();
+            let shared = (team_gold - bounty).max(0);
+            let share_total: f64 = players.iter().map(|p| position_profile(&p.position).1).sum();
+            let mut distributed = 0i64;
+            let last = team_lines.len().saturating_sub(1);
+            for (i, (line, p)) in team_lines.iter_mut().zip(players.iter()).enumerate() {
+                let share = if i == last {
+                    shared - distributed
+                } else {
+                    (shared as f64 * position_profile(&p.position).1 / share_total) as i64
+                };
+                distributed += share;
+                let personal = line.kills as i64 * KILL_GOLD + line.assists as i64 * ASSIST_GOLD;
+                line.gold = (share + personal).max(0) as u64;
+            }
+            lines.append(&mut team_lines);
+        }
+        lines
+    }
+
+    // ==================== 数据库 ====================
+
+    /// 存档是否启用局内时间线
+    pub async fn is_enabled(pool: &Pool<Sqlite>, save_id: &str) -> bool {
+        sqlx::query_scalar::<_, i64>("SELECT game_timeline_enabled FROM saves WHERE id = ?")
+            .bind(save_id)
+            .fetch_optional(pool)
+            .await
+            .ok()
+            .flatten()
+            .map(|v| v != 0)
+            .unwrap_or(false)
+    }
+
+    pub async fn set_enabled(pool: &Pool<Sqlite>, save_id: &str, enabled: bool) -> Result<(), String> {
+        sqlx::query("UPDATE saves SET game_timeline_enabled = ? WHERE id = ?")
+            .bind(enabled as i64)
+            .bind(save_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新时间线开关失败: {}", e))?;
+        Ok(())
+    }
+
+    pub async fn save_timeline(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_id: u64,
+        game_number: u32,
+        timeline: &GameTimeline,
+    ) -> Result<(), String> {
+        let json = serde_json::to_string(timeline).map_err(|e| format!("序列化时间线失败: {}", e))?;
+        sqlx::query(
+            r#"
+            INSERT OR REPLACE INTO game_timelines
+                (save_id, match_id, game_number, winner_id, duration_minutes, final_gold_diff, timeline_json)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(save_id)
+        .bind(match_id as i64)
+        .bind(game_number as i64)
+        .bind(timeline.winner_id as i64)
+        .bind(timeline.duration_minutes as i64)
+        .bind(timeline.final_gold_diff())
+        .bind(&json)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("保存时间线失败: {}", e))?;
+        Ok(())
+    }
+
+    pub async fn load_match_timelines(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_id: u64,
+    ) -> Result<Vec<SavedGameTimeline>, String> {
+        let rows = sqlx::query(
+            "SELECT game_number, timeline_json FROM game_timelines WHERE save_id = ? AND match_id = ? ORDER BY game_number",
+        )
+        .bind(save_id)
+        .bind(match_id as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询时间线失败: {}", e))?;
+
+        let mut timelines = Vec::new();
+        for row in rows {
+            let json: String = row.get("timeline_json");
+            let timeline: GameTimeline =
+                serde_json::from_str(&json).map_err(|e| format!("解析时间线失败: {}", e))?;
+            timelines.push(SavedGameTimeline {
+                match_id,
+                game_number: row.get::<i64, _>("game_number") as u32,
+                timeline,
+            });
+        }
+        Ok(timelines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn team(base_id: u64, perf: f64) -> Vec<TimelinePlayer> {
+        ["TOP", "JUG", "MID", "ADC", "SUP"]
+            .iter()
+            .enumerate()
+            .map(|(i, pos)| TimelinePlayer {
+                player_id: base_id + i as u64,
+                position: pos.to_string(),
+                performance: perf,
+            })
+            .collect()
+    }
+
+    fn input(home_perf: f64, away_perf: f64, home_won: bool) -> TimelineInput {
+        TimelineInput {
+            home_team_id: 1,
+            away_team_id: 2,
+            home_players: team(100, home_perf),
+            away_players: team(200, away_perf),
+            home_comp: Some(CompType::Rush),
+            away_comp: Some(CompType::LateGame),
+            home_won,
+        }
+    }
+
+    #[test]
+    fn test_timeline_ends_with_winner_nexus() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for seed_case in 0..200 {
+            let home_won = seed_case % 2 == 0;
+            let timeline = GameTimelineEngine::simulate(&input(70.0, 75.0, home_won), &mut rng);
+            let winner = if home_won { 1 } else { 2 };
+            assert_eq!(timeline.winner_id, winner);
+            let last = timeline.events.last().unwrap();
+            assert_eq!(last.kind, TimelineEventKind::Nexus);
+            assert_eq!(last.team_id, winner);
+            assert_eq!(last.minute, timeline.duration_minutes);
+            assert!((MIN_GAME_MINUTES..=MAX_GAME_MINUTES).contains(&timeline.duration_minutes));
+            assert_eq!(timeline.gold_curve.len() as u32, timeline.duration_minutes);
+            let loser_towers = if home_won { timeline.away_towers } else { timeline.home_towers };
+            assert!(loser_towers < TOWERS_TO_OPEN_BASE);
+        }
+    }
+
+    #[test]
+    fn test_player_lines_match_events() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let timeline = GameTimelineEngine::simulate(&input(80.0, 65.0, true), &mut rng);
+
+        let kills: u32 = timeline.players.iter().map(|p| p.kills).sum();
+        let deaths: u32 = timeline.players.iter().map(|p| p.deaths).sum();
+        assert_eq!(kills as usize, timeline.kills.len());
+        assert_eq!(deaths as usize, timeline.kills.len());
+
+        let last = timeline.gold_curve.last().unwrap();
+        let home_gold: u64 = timeline.players.iter().filter(|p| p.team_id == 1).map(|p| p.gold).sum();
+        let away_gold: u64 = timeline.players.iter().filter(|p| p.team_id == 2).map(|p| p.gold).sum();
+        assert_eq!(home_gold as i64, last.home_gold);
+        assert_eq!(away_gold as i64, last.away_gold);
+        assert!(timeline.events.iter().any(|e| e.kind == TimelineEventKind::FirstBlood));
+    }
+
+    #[test]
+    fn test_comp_phase_shapes_early_game() {
+        // 前期阵容对后期阵容：同等发挥下 10 分钟经济领先更多出现在前期阵容一方
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut early_leads = 0;
+        for _ in 0..200 {
+            let timeline = GameTimelineEngine::simulate(&input(72.0, 72.0, false), &mut rng);
+            if let Some(p) = timeline.gold_curve.iter().find(|g| g.minute == 10) {
+                if p.gold_diff > 0 {
+                    early_leads += 1;
+                }
+            }
+        }
+        assert!(early_leads > 100, "early_leads = {}", early_leads);
+    }
+
+    #[test]
+    fn test_estimate_duration_shorter_for_stomps() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let stomp: u32 = (0..200).map(|_| GameTimelineEngine::estimate_duration(14.0, &mut rng)).sum();
+        let close: u32 = (0..200).map(|_| GameTimelineEngine::estimate_duration(0.5, &mut rng)).sum();
+        assert!(stomp < close);
+    }
+}
@@ -0,0 +1,675 @@
+//! 名人堂引擎 (Hall of Fame Engine)
+//!
+//! 年度颁奖典礼上评选本年度名人堂入选者：
+//! - 选手退役满 HOF_WAITING_SEASONS 个赛季后才具备资格
+//! - 按荣誉（冠军、MVP、年度最佳阵容）、年度 Top20 名次与生涯数据计算积分，达到门槛入选
+//! - 每届入选人数有上限，积分高者优先，其余候选人顺延到下一届
+//! - 入选选手效力赛季最多的队伍为其退役球衣；战队按团队荣誉积分入选战队名人堂
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+/// 退役后需等待的赛季数
+pub const HOF_WAITING_SEASONS: i64 = 2;
+/// 每届入选选手上限
+pub const MAX_CLASS_SIZE: usize = 5;
+/// 选手入选门槛（名人堂 / 传奇）
+const PLAYER_HOF_SCORE: i64 = 200;
+const PLAYER_LEGEND_SCORE: i64 = 300;
+/// 战队入选门槛（名人堂 / 传奇）
+const TEAM_HOF_SCORE: i64 = 100;
+const TEAM_LEGEND_SCORE: i64 = 200;
+/// 球衣退役：在同一队伍效力的最少赛季数
+const JERSEY_MIN_SEASONS: i64 = 3;
+/// 长青生涯与巅峰实力加分门槛
+const LONG_CAREER_SEASONS: i64 = 8;
+const HIGH_PEAK_ABILITY: i64 = 70;
+
+/// 名人堂等级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HofTier {
+    HallOfFame,
+    Legend,
+}
+
+impl HofTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HofTier::HallOfFame => "HallOfFame",
+            HofTier::Legend => "Legend",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HofTier::HallOfFame => "名人堂",
+            HofTier::Legend => "传奇殿堂",
+        }
+    }
+
+    fn for_score(score: i64, hof: i64, legend: i64) -> Option<Self> {
+        if score >= legend {
+            Some(HofTier::Legend)
+        } else if score >= hof {
+            Some(HofTier::HallOfFame)
+        } else {
+            None
+        }
+    }
+}
+
+/// 选手生涯荣誉统计
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerHonorTally {
+    pub super_champion: i64,
+    pub international_champion: i64,
+    pub league_champion: i64,
+    pub international_runner_up: i64,
+    pub mvp: i64,
+    pub annual_mvp: i64,
+    pub all_pro_1st: i64,
+    pub all_pro_2nd: i64,
+    pub all_pro_3rd: i64,
+    pub annual_special: i64,
+    /// 每次入选年度 Top20 的名次
+    pub top20_ranks: Vec<u32>,
+    pub career_seasons: i64,
+    pub peak_ability: i64,
+}
+
+/// 战队荣誉统计
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TeamHonorTally {
+    pub super_champion: i64,
+    pub international_champion: i64,
+    pub league_champion: i64,
+    pub international_runner_up: i64,
+    pub regular_season_first: i64,
+}
+
+/// 积分明细与入选理由
+#[derive(Debug, Clone, PartialEq)]
+pub struct HofScore {
+    pub total: i64,
+    pub tier: Option<HofTier>,
+    /// (荣誉类别, 次数)，写入 honors_json 供前端展示
+    pub categories: Vec<(&'static str, i64)>,
+    pub reasoning: Vec<String>,
+}
+
+/// 本届入选的选手
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HofInductee {
+    pub player_id: i64,
+    pub player_name: String,
+    pub position: String,
+    pub tier: HofTier,
+    pub total_score: i64,
+    pub reasoning: Vec<String>,
+}
+
+/// 本届入选的战队（或晋升传奇）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamHofInductee {
+    pub team_id: i64,
+    pub team_name: String,
+    pub tier: HofTier,
+    pub total_score: i64,
+    pub promoted: bool,
+    pub reasoning: Vec<String>,
+}
+
+/// 退役球衣
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetiredJersey {
+    pub team_id: i64,
+    pub team_name: String,
+    pub player_id: i64,
+    pub player_name: String,
+    pub seasons_with_team: i64,
+}
+
+/// 一届名人堂评选结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InductionClass {
+    pub season_id: i64,
+    pub players: Vec<HofInductee>,
+    pub teams: Vec<TeamHofInductee>,
+    pub jerseys: Vec<RetiredJersey>,
+    /// 达到门槛但因名额所限顺延的候选人数
+    pub deferred: usize,
+}
+
+struct ScoreBuilder {
+    total: i64,
+    categories: Vec<(&'static str, i64)>,
+    reasoning: Vec<String>,
+}
+
+impl ScoreBuilder {
+    fn new() -> Self {
+        Self { total: 0, categories: Vec::new(), reasoning: Vec::new() }
+    }
+
+    fn add(&mut self, category: &'static str, label: &str, count: i64, points_each: i64) {
+        if count <= 0 {
+            return;
+        }
+        let points = count * points_each;
+        self.total += points;
+        self.categories.push((category, count));
+        self.reasoning.push(format!("{}×{} +{}", label, count, points));
+    }
+
+    fn finish(self, hof: i64, legend: i64) -> HofScore {
+        HofScore {
+            total: self.total,
+            tier: HofTier::for_score(self.total, hof, legend),
+            categories: self.categories,
+            reasoning: self.reasoning,
+        }
+    }
+}
+
+fn is_international(tournament_type: &str) -> bool {
+    matches!(
+        tournament_type,
+        "Msi" | "WorldChampionship" | "MadridMasters" | "ShanghaiMasters" | "ClaudeIntercontinental" | "IcpIntercontinental"
+    )
+}
+
+fn is_league_playoffs(tournament_type: &str) -> bool {
+    matches!(tournament_type, "SpringPlayoffs" | "SummerPlayoffs")
+}
+
+/// 年度 Top20 名次（荣誉名称为"年度Top{名次}"）
+fn top20_rank(tournament_name: &str) -> u32 {
+    tournament_name
+        .strip_prefix("年度Top")
+        .and_then(|r| r.parse().ok())
+        .unwrap_or(20)
+}
+
+/// 名人堂引擎
+pub struct HallOfFameEngine;
+
+impl HallOfFameEngine {
+    /// 汇总选手荣誉，rows 为 (honor_type, tournament_type, tournament_name)
+    pub fn tally_player_honors(rows: &[(String, String, String)]) -> PlayerHonorTally {
+        let mut tally = PlayerHonorTally::default();
+        for (honor_type, tournament_type, tournament_name) in rows {
+            match honor_type.as_str() {
+                "PLAYER_CHAMPION" => {
+                    if tournament_type == "SuperIntercontinental" {
+                        tally.super_champion += 1;
+                    } else if is_international(tournament_type) {
+                        tally.international_champion += 1;
+                    } else if is_league_playoffs(tournament_type) {
+                        tally.league_champion += 1;
+                    }
+                }
+                "PLAYER_RUNNER_UP" if is_international(tournament_type) || tournament_type == "SuperIntercontinental" => {
+                    tally.international_runner_up += 1;
+                }
+                "TOURNAMENT_MVP" | "FINALS_MVP" | "REGULAR_SEASON_MVP" | "PLAYOFFS_FMVP" => tally.mvp += 1,
+                "ANNUAL_MVP" => tally.annual_mvp += 1,
+                "ANNUAL_ALL_PRO_1ST" => tally.all_pro_1st += 1,
+                "ANNUAL_ALL_PRO_2ND" => tally.all_pro_2nd += 1,
+                "ANNUAL_ALL_PRO_3RD" => tally.all_pro_3rd += 1,
+                "ANNUAL_MOST_CONSISTENT" | "ANNUAL_MOST_DOMINANT" | "ANNUAL_ROOKIE" | "ANNUAL_MOST_IMPROVED"
+                | "ANNUAL_COMEBACK" | "ANNUAL_SIXTH_MAN" | "ANNUAL_BEST_IMPORT" | "ANNUAL_REGIONAL_MVP" => {
+                    tally.annual_special += 1
+                }
+                "ANNUAL_TOP20" => tally.top20_ranks.push(top20_rank(tournament_name)),
+                _ => {}
+            }
+        }
+        tally
+    }
+
+    /// 汇总战队荣誉，rows 为 (honor_type, tournament_type)
+    pub fn tally_team_honors(rows: &[(String, String)]) -> TeamHonorTally {
+        let mut tally = TeamHonorTally::default();
+        for (honor_type, tournament_type) in rows {
+            match honor_type.as_str() {
+                "TEAM_CHAMPION" => {
+                    if tournament_type == "SuperIntercontinental" {
+                        tally.super_champion += 1;
+                    } else if is_international(tournament_type) {
+                        tally.international_champion += 1;
+                    } else if is_league_playoffs(tournament_type) {
+                        tally.league_champion += 1;
+                    }
+                }
+                "TEAM_RUNNER_UP" if is_international(tournament_type) || tournament_type == "SuperIntercontinental" => {
+                    tally.international_runner_up += 1;
+                }
+                "REGULAR_SEASON_FIRST" => tally.regular_season_first += 1,
+                _ => {}
+            }
+        }
+        tally
+    }
+
+    /// 选手名人堂积分
+    pub fn score_player(tally: &PlayerHonorTally) -> HofScore {
+        let mut score = ScoreBuilder::new();
+        score.add("super_champion", "Super洲际赛冠军", tally.super_champion, 20);
+        score.add("international_champion", "国际赛冠军", tally.international_champion, 15);
+        score.add("league_champion", "联赛冠军", tally.league_champion, 10);
+        score.add("international_runner_up", "国际赛亚军", tally.international_runner_up, 5);
+        score.add("tournament_mvp", "赛事MVP", tally.mvp, 8);
+        score.add("annual_mvp", "年度MVP", tally.annual_mvp, 15);
+        score.add("annual_all_pro", "年度最佳阵容一阵", tally.all_pro_1st, 10);
+        score.add("annual_all_pro", "年度最佳阵容二阵", tally.all_pro_2nd, 7);
+        score.add("annual_all_pro", "年度最佳阵容三阵", tally.all_pro_3rd, 5);
+        score.add("annual_special", "年度单项奖", tally.annual_special, 5);
+
+        let top5 = tally.top20_ranks.iter().filter(|r| **r <= 5).count() as i64;
+        let top10 = tally.top20_ranks.iter().filter(|r| (6..=10).contains(*r)).count() as i64;
+        let top20 = tally.top20_ranks.len() as i64 - top5 - top10;
+        score.add("annual_top20", "年度Top5", top5, 8);
+        score.add("annual_top20", "年度Top6-10", top10, 6);
+        score.add("annual_top20", "年度Top11-20", top20, 4);
+
+        if tally.career_seasons >= LONG_CAREER_SEASONS {
+            score.add("long_career", &format!("{}赛季长青生涯", tally.career_seasons), 1, 5);
+        }
+        if tally.peak_ability >= HIGH_PEAK_ABILITY {
+            score.add("high_peak", &format!("巅峰能力{}", tally.peak_ability), 1, 5);
+        }
+
+        let mut result = score.finish(PLAYER_HOF_SCORE, PLAYER_LEGEND_SCORE);
+        // 同一类别合并次数，供前端按类别展示
+        let mut merged: Vec<(&'static str, i64)> = Vec::new();
+        for (category, count) in result.categories {
+            match merged.iter_mut().find(|(c, _)| *c == category) {
+                Some(entry) => entry.1 += count,
+                None => merged.push((category, count)),
+            }
+        }
+        result.categories = merged;
+        result
+    }
+
+    /// 战队名人堂积分
+    pub fn score_team(tally: &TeamHonorTally) -> HofScore {
+        let mut score = ScoreBuilder::new();
+        score.add("super_champion", "Super洲际赛冠军", tally.super_champion, 20);
+        score.add("international_champion", "国际赛冠军", tally.international_champion, 15);
+        score.add("league_champion", "联赛冠军", tally.league_champion, 10);
+        score.add("international_runner_up", "国际赛亚军", tally.international_runner_up, 5);
+        score.add("regular_season_first", "常规赛第一", tally.regular_season_first, 3);
+        score.finish(TEAM_HOF_SCORE, TEAM_LEGEND_SCORE)
+    }
+
+    /// 从达到门槛的候选人中按积分选出本届入选者，返回 (入选, 顺延)
+    pub fn select_class<T>(mut candidates: Vec<(T, HofScore)>) -> (Vec<(T, HofScore)>, usize) {
+        candidates.retain(|(_, s)| s.tier.is_some());
+        candidates.sort_by_key(|c| std::cmp::Reverse(c.1.total));
+        let deferred = candidates.len().saturating_sub(MAX_CLASS_SIZE);
+        candidates.truncate(MAX_CLASS_SIZE);
+        (candidates, deferred)
+    }
+
+    // ==================== 数据库操作 ====================
+
+    async fn load_player_tally(pool: &Pool<Sqlite>, save_id: &str, player_id: i64, ability: i64) -> Result<PlayerHonorTally, String> {
+        let rows = sqlx::query(
+            "SELECT honor_type, COALESCE(tournament_type, '') AS tournament_type, COALESCE(tournament_name, '') AS tournament_name FROM honors WHERE save_id = ? AND player_id = ?",
+        )
+        .bind(save_id)
+        .bind(player_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询选手荣誉失败: {}", e))?;
+        let honors: Vec<(String, String, String)> = rows
+            .iter()
+            .map(|r| (r.get("honor_type"), r.get("tournament_type"), r.get("tournament_name")))
+            .collect();
+
+        let career = sqlx::query(
+            "SELECT COUNT(DISTINCT season_id) AS seasons, MAX(best_performance) AS peak FROM player_season_stats WHERE save_id = ? AND player_id = ?",
+        )
+        .bind(save_id)
+        .bind(player_id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询选手生涯数据失败: {}", e))?;
+
+        let mut tally = Self::tally_player_honors(&honors);
+        tally.career_seasons = career.get("seasons");
+        let historical_peak = career.get::<Option<f64>, _>("peak").unwrap_or(0.0).round() as i64;
+        tally.peak_ability = ability.max(historical_peak);
+        Ok(tally)
+    }
+
+    /// 年度颁奖典礼：评选本届名人堂（选手、退役球衣、战队）
+    pub async fn run_induction(pool: &Pool<Sqlite>, save_id: &str, season_id: i64) -> Result<InductionClass, String> {
+        let mut class = InductionClass { season_id, ..Default::default() };
+
+        // 退役满等待期且尚未入选的选手（旧存档的退役赛季由 022 迁移补齐，未记录的不参评）
+        let rows = sqlx::query(
+            r#"
+            SELECT p.id, p.game_id, p.position, p.home_region_id, p.ability, p.retire_season
+            FROM players p
+            WHERE p.save_id = ? AND p.status IN ('Retired', 'RETIRED')
+              AND p.retire_season IS NOT NULL AND p.retire_season <= ?
+              AND NOT EXISTS (SELECT 1 FROM hall_of_fame h WHERE h.save_id = p.save_id AND h.player_id = p.id)
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id - HOF_WAITING_SEASONS)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询名人堂候选人失败: {}", e))?;
+
+        let mut candidates = Vec::new();
+        for row in &rows {
+            let player_id: i64 = row.get("id");
+            let tally = Self::load_player_tally(pool, save_id, player_id, row.get("ability")).await?;
+            let score = Self::score_player(&tally);
+            candidates.push(((row, tally), score));
+        }
+        let (selected, deferred) = Self::select_class(candidates);
+        class.deferred = deferred;
+
+        for ((row, tally), score) in selected {
+            let Some(tier) = score.tier else { continue };
+            let player_id: i64 = row.get("id");
+            let player_name: String = row.get("game_id");
+            let position: String = row.get("position");
+            let honors_json = serde_json::to_string(
+                &score
+                    .categories
+                    .iter()
+                    .map(|(category, count)| serde_json::json!({ "category": category, "count": count }))
+                    .collect::<Vec<_>>(),
+            )
+            .unwrap_or_default();
+            let mut reasoning = score.reasoning.clone();
+            reasoning.push(format!("总积分{}，达到{}门槛", score.total, tier.name()));
+
+            sqlx::query(
+                r#"
+                INSERT OR IGNORE INTO hall_of_fame (
+                    save_id, player_id, player_name, position, region_id, induction_season, total_score, tier,
+                    peak_ability, career_seasons, honors_json, retire_season, reasoning
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(save_id)
+            .bind(player_id)
+            .bind(&player_name)
+            .bind(&position)
+            .bind(row.get::<Option<i64>, _>("home_region_id"))
+            .bind(season_id)
+            .bind(score.total)
+            .bind(tier.as_str())
+            .bind(tally.peak_ability)
+            .bind(tally.career_seasons)
+            .bind(&honors_json)
+            .bind(row.get::<Option<i64>, _>("retire_season"))
+            .bind(serde_json::to_string(&reasoning).unwrap_or_default())
+            .execute(pool)
+            .await
+            .map_err(|e| format!("写入名人堂失败: {}", e))?;
+
+            if let Some(jersey) = Self::retire_jersey(pool, save_id, season_id, player_id, &player_name).await? {
+                reasoning.push(format!("{} 为其退役球衣（效力{}个赛季）", jersey.team_name, jersey.seasons_with_team));
+                class.jerseys.push(jersey);
+            }
+
+            class.players.push(HofInductee {
+                player_id,
+                player_name,
+                position,
+                tier,
+                total_score: score.total,
+                reasoning,
+            });
+        }
+
+        class.teams = Self::induct_teams(pool, save_id, season_id).await?;
+        Ok(class)
+    }
+
+    /// 入选选手效力赛季最多的队伍为其退役球衣
+    async fn retire_jersey(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        player_id: i64,
+        player_name: &str,
+    ) -> Result<Option<RetiredJersey>, String> {
+        let row = sqlx::query(
+            r#"
+            SELECT s.team_id, t.name AS team_name, COUNT(DISTINCT s.season_id) AS seasons
+            FROM player_season_stats s
+            JOIN teams t ON t.id = s.team_id AND t.save_id = s.save_id
+            WHERE s.save_id = ? AND s.player_id = ?
+            GROUP BY s.team_id
+            ORDER BY seasons DESC, MAX(s.season_id) DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(save_id)
+        .bind(player_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询选手效力记录失败: {}", e))?;
+
+        let Some(row) = row else { return Ok(None) };
+        let seasons_with_team: i64 = row.get("seasons");
+        if seasons_with_team < JERSEY_MIN_SEASONS {
+            return Ok(None);
+        }
+        let jersey = RetiredJersey {
+            team_id: row.get("team_id"),
+            team_name: row.get("team_name"),
+            player_id,
+            player_name: player_name.to_string(),
+            seasons_with_team,
+        };
+
+        sqlx::query(
+            "INSERT OR IGNORE INTO retired_jerseys (save_id, team_id, player_id, player_name, seasons_with_team, retired_season) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(save_id)
+        .bind(jersey.team_id)
+        .bind(player_id)
+        .bind(player_name)
+        .bind(seasons_with_team)
+        .bind(season_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("写入退役球衣失败: {}", e))?;
+        Ok(Some(jersey))
+    }
+
+    /// 战队名人堂：新达到门槛的战队入选，已入选的战队达到传奇门槛时晋升
+    async fn induct_teams(pool: &Pool<Sqlite>, save_id: &str, season_id: i64) -> Result<Vec<TeamHofInductee>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT h.team_id, COALESCE(h.tournament_type, '') AS tournament_type, h.honor_type, t.name AS team_name
+            FROM honors h
+            JOIN teams t ON t.id = h.team_id AND t.save_id = h.save_id
+            WHERE h.save_id = ? AND h.player_id IS NULL AND h.team_id IS NOT NULL
+            "#,
+        )
+        .bind(save_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询战队荣誉失败: {}", e))?;
+
+        let mut honors: HashMap<i64, (String, Vec<(String, String)>)> = HashMap::new();
+        for row in &rows {
+            let entry = honors
+                .entry(row.get("team_id"))
+                .or_insert_with(|| (row.get("team_name"), Vec::new()));
+            entry.1.push((row.get("honor_type"), row.get("tournament_type")));
+        }
+
+        let existing: HashMap<i64, String> = sqlx::query("SELECT team_id, tier FROM team_hall_of_fame WHERE save_id = ?")
+            .bind(save_id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("查询战队名人堂失败: {}", e))?
+            .iter()
+            .map(|r| (r.get("team_id"), r.get("tier")))
+            .collect();
+        let legends: HashSet<i64> = existing
+            .iter()
+            .filter(|(_, tier)| tier.as_str() == HofTier::Legend.as_str())
+            .map(|(id, _)| *id)
+            .collect();
+
+        let mut inducted = Vec::new();
+        let mut team_ids: Vec<i64> = honors.keys().copied().collect();
+        team_ids.sort_unstable();
+        for team_id in team_ids {
+            let (team_name, team_honors) = &honors[&team_id];
+            let score = Self::score_team(&Self::tally_team_honors(team_honors));
+            let Some(tier) = score.tier else { continue };
+            let promoted = existing.contains_key(&team_id);
+            if legends.contains(&team_id) || (promoted && tier != HofTier::Legend) {
+                continue;
+            }
+            let mut reasoning = score.reasoning.clone();
+            reasoning.push(format!("总积分{}，达到{}门槛", score.total, tier.name()));
+            let reasoning_json = serde_json::to_string(&reasoning).unwrap_or_default();
+
+            sqlx::query(
+                r#"
+                INSERT INTO team_hall_of_fame (save_id, team_id, team_name, induction_season, tier, total_score, reasoning)
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT(save_id, team_id) DO UPDATE SET
+                    tier = excluded.tier,
+                    total_score = excluded.total_score,
+                    reasoning = excluded.reasoning,
+                    promoted_season = excluded.induction_season
+                "#,
+            )
+            .bind(save_id)
+            .bind(team_id)
+            .bind(team_name)
+            .bind(season_id)
+            .bind(tier.as_str())
+            .bind(score.total)
+            .bind(&reasoning_json)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("写入战队名人堂失败: {}", e))?;
+
+            inducted.push(TeamHofInductee {
+                team_id,
+                team_name: team_name.clone(),
+                tier,
+                total_score: score.total,
+                promoted,
+                reasoning,
+            });
+        }
+        Ok(inducted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn honor(honor_type: &str, tournament_type: &str) -> (String, String, String) {
+        (honor_type.to_string(), tournament_type.to_string(), String::new())
+    }
+
+    #[test]
+    fn test_tally_player_honors() {
+        let rows = vec![
+            honor("PLAYER_CHAMPION", "WorldChampionship"),
+            honor("PLAYER_CHAMPION", "SpringPlayoffs"),
+            honor("PLAYER_CHAMPION", "SuperIntercontinental"),
+            honor("PLAYER_RUNNER_UP", "Msi"),
+            honor("PLAYER_RUNNER_UP", "SummerPlayoffs"),
+            honor("FINALS_MVP", "WorldChampionship"),
+            honor("ANNUAL_ALL_PRO_2ND", "ANNUAL"),
+            ("ANNUAL_TOP20".to_string(), "ANNUAL".to_string(), "年度Top3".to_string()),
+            ("ANNUAL_TOP20".to_string(), "ANNUAL".to_string(), "年度Top14".to_string()),
+        ];
+        let tally = HallOfFameEngine::tally_player_honors(&rows);
+        assert_eq!(tally.international_champion, 1);
+        assert_eq!(tally.league_champion, 1);
+        assert_eq!(tally.super_champion, 1);
+        assert_eq!(tally.international_runner_up, 1);
+        assert_eq!(tally.mvp, 1);
+        assert_eq!(tally.all_pro_2nd, 1);
+        assert_eq!(tally.top20_ranks, vec![3, 14]);
+    }
+
+    #[test]
+    fn test_score_player_tiers_and_reasoning() {
+        let tally = PlayerHonorTally {
+            international_champion: 4,
+            league_champion: 5,
+            annual_mvp: 2,
+            top20_ranks: vec![1, 2, 8, 15],
+            career_seasons: 9,
+            peak_ability: 72,
+            ..Default::default()
+        };
+        let score = HallOfFameEngine::score_player(&tally);
+        // 60 + 50 + 30 + 16 + 6 + 4 + 5 + 5
+        assert_eq!(score.total, 176);
+        assert_eq!(score.tier, None);
+        assert!(score.reasoning.iter().any(|r| r.starts_with("国际赛冠军×4")));
+        assert_eq!(score.categories.iter().find(|(c, _)| *c == "annual_top20"), Some(&("annual_top20", 4)));
+
+        let legend = PlayerHonorTally { international_champion: 20, ..Default::default() };
+        assert_eq!(HallOfFameEngine::score_player(&legend).tier, Some(HofTier::Legend));
+        let hof = PlayerHonorTally { international_champion: 14, ..Default::default() };
+        assert_eq!(HallOfFameEngine::score_player(&hof).tier, Some(HofTier::HallOfFame));
+    }
+
+    #[test]
+    fn test_select_class_caps_and_defers() {
+        let candidates: Vec<(i64, HofScore)> = (0..8)
+            .map(|i| {
+                let tally = PlayerHonorTally { international_champion: 10 + i, ..Default::default() };
+                (i, HallOfFameEngine::score_player(&tally))
+            })
+            .collect();
+        // 10、11、12、13 个国际赛冠军未达门槛（150~195 分）
+        let (selected, deferred) = HallOfFameEngine::select_class(candidates);
+        assert_eq!(selected.len(), 4);
+        assert_eq!(deferred, 0);
+        assert_eq!(selected[0].0, 7);
+
+        let many: Vec<(i64, HofScore)> = (0..8)
+            .map(|i| {
+                let tally = PlayerHonorTally { international_champion: 14 + i, ..Default::default() };
+                (i, HallOfFameEngine::score_player(&tally))
+            })
+            .collect();
+        let (selected, deferred) = HallOfFameEngine::select_class(many);
+        assert_eq!(selected.len(), MAX_CLASS_SIZE);
+        assert_eq!(deferred, 3);
+    }
+
+    #[test]
+    fn test_score_team() {
+        let rows = vec![
+            ("TEAM_CHAMPION".to_string(), "WorldChampionship".to_string()),
+            ("TEAM_CHAMPION".to_string(), "SummerPlayoffs".to_string()),
+            ("REGULAR_SEASON_FIRST".to_string(), "SummerRegular".to_string()),
+        ];
+        let tally = HallOfFameEngine::tally_team_honors(&rows);
+        let score = HallOfFameEngine::score_team(&tally);
+        assert_eq!(score.total, 28);
+        assert_eq!(score.tier, None);
+
+        let dynasty = TeamHonorTally { international_champion: 7, league_champion: 1, ..Default::default() };
+        assert_eq!(HallOfFameEngine::score_team(&dynasty).tier, Some(HofTier::HallOfFame));
+    }
+}
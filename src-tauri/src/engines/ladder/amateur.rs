@@ -0,0 +1,239 @@
+//! 天梯路人/主播选手池 (Ladder Amateur Pool)
+//!
+//! 巅峰赛除职业选手和自由球员外，还有一批由 RookieGenerator 生成的路人高手与主播：
+//! - 路人能力低于职业选手，潜力分布更宽，年龄跨度也更大
+//! - 每届天梯赛开赛前把选手池补足到固定人数，长期未被发掘的路人退出选手池
+//! - 赛事结束后全场排名靠前的路人被职业圈发掘：年轻且有潜力的进入选秀池，其余以自由球员身份入市
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use crate::engines::rookie_generator::{GeneratedRookie, RookieGenerationConfig, RookieGenerator};
+
+/// 选手池人数（每届天梯赛开赛前补足）
+pub const AMATEUR_POOL_SIZE: usize = 40;
+/// 路人选手使用国服（LPL）的 ID 池、姓名与国籍
+pub const AMATEUR_CONFIG_REGION_ID: u64 = 1;
+/// 在选手池中停留满该赛季数仍未被发掘的路人退出
+pub const AMATEUR_MAX_SEASONS: i64 = 3;
+/// 每届天梯赛最多发掘的路人数
+pub const MAX_DISCOVERIES_PER_EVENT: usize = 2;
+
+const AMATEUR_ABILITY_RANGE: (u8, u8) = (50, 66);
+const AMATEUR_POTENTIAL_RANGE: (u8, u8) = (58, 84);
+/// 路人比同龄新秀最多年长的岁数
+const AMATEUR_MAX_EXTRA_AGE: u8 = 5;
+const STREAMER_RATE: f64 = 0.3;
+/// 发掘条件：本届全场排名前 25%，且至少参赛 6 场
+const DISCOVERY_RANK_SHARE: f64 = 0.25;
+const DISCOVERY_MIN_GAMES: i32 = 6;
+/// 进入选秀池的最大年龄与最低潜力，其余以自由球员身份入市
+const DRAFT_MAX_AGE: u8 = 18;
+const DRAFT_MIN_POTENTIAL: u8 = 65;
+
+/// 路人类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AmateurKind {
+    /// 路人高手
+    Amateur,
+    /// 直播平台主播
+    Streamer,
+}
+
+impl AmateurKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AmateurKind::Amateur => "Amateur",
+            AmateurKind::Streamer => "Streamer",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Amateur" => Some(AmateurKind::Amateur),
+            "Streamer" => Some(AmateurKind::Streamer),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmateurKind::Amateur => "路人王",
+            AmateurKind::Streamer => "主播",
+        }
+    }
+}
+
+/// 路人被发掘后的去向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AmateurOutcome {
+    /// 进入选秀池
+    DraftPool,
+    /// 以自由球员身份入市（转会期第4轮参与争夺）
+    FreeAgent,
+}
+
+impl AmateurOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AmateurOutcome::DraftPool => "DraftPool",
+            AmateurOutcome::FreeAgent => "FreeAgent",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AmateurOutcome::DraftPool => "进入选秀池",
+            AmateurOutcome::FreeAgent => "以自由球员身份入市",
+        }
+    }
+}
+
+/// 新生成的路人选手
+#[derive(Debug, Clone)]
+pub struct GeneratedAmateur {
+    pub rookie: GeneratedRookie,
+    pub kind: AmateurKind,
+}
+
+/// 赛事结束时路人的本届成绩
+#[derive(Debug, Clone)]
+pub struct AmateurStanding {
+    pub player_id: i64,
+    /// 全场排名（从 1 开始）
+    pub rank: usize,
+    pub rating: i32,
+    pub games_played: i32,
+    pub age: u8,
+    pub potential: u8,
+}
+
+/// 路人发掘记录（同时作为事件详情）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmateurDiscovery {
+    pub player_id: i64,
+    pub game_id: String,
+    pub kind: AmateurKind,
+    pub outcome: AmateurOutcome,
+    pub event_name: String,
+    pub rank: i32,
+    pub field_size: i32,
+    pub rating: i32,
+    pub ability: u8,
+    pub potential: u8,
+    pub age: u8,
+}
+
+pub struct LadderAmateurEngine;
+
+impl LadderAmateurEngine {
+    /// 生成 count 名路人选手（同一种子结果一致），game_id 不与 existing_ids 重复
+    pub fn generate(seed: u64, count: usize, existing_ids: &HashSet<String>) -> Vec<GeneratedAmateur> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let config = RookieGenerationConfig {
+            ability_range: Some(AMATEUR_ABILITY_RANGE),
+            potential_range: Some(AMATEUR_POTENTIAL_RANGE),
+            ..Default::default()
+        };
+
+        RookieGenerator::new(rng.gen())
+            .generate_rookies_with_config(AMATEUR_CONFIG_REGION_ID, count, existing_ids, &config)
+            .into_iter()
+            .map(|mut rookie| {
+                rookie.age += rng.gen_range(0..=AMATEUR_MAX_EXTRA_AGE);
+                let kind = if rng.gen_bool(STREAMER_RATE) {
+                    AmateurKind::Streamer
+                } else {
+                    AmateurKind::Amateur
+                };
+                GeneratedAmateur { rookie, kind }
+            })
+            .collect()
+    }
+
+    /// 从本届路人成绩中选出被发掘的选手（按全场排名，最多 MAX_DISCOVERIES_PER_EVENT 名）
+    pub fn select_standouts(standings: &[AmateurStanding], field_size: usize) -> Vec<AmateurStanding> {
+        let cutoff = ((field_size as f64 * DISCOVERY_RANK_SHARE).ceil() as usize).max(1);
+        let mut standouts: Vec<AmateurStanding> = standings
+            .iter()
+            .filter(|s| s.rank <= cutoff && s.games_played >= DISCOVERY_MIN_GAMES)
+            .cloned()
+            .collect();
+        standouts.sort_by_key(|s| s.rank);
+        standouts.truncate(MAX_DISCOVERIES_PER_EVENT);
+        standouts
+    }
+
+    /// 被发掘路人的去向：年轻且有潜力的进入选秀池，其余以自由球员身份入市
+    pub fn discovery_outcome(age: u8, potential: u8) -> AmateurOutcome {
+        if age <= DRAFT_MAX_AGE && potential >= DRAFT_MIN_POTENTIAL {
+            AmateurOutcome::DraftPool
+        } else {
+            AmateurOutcome::FreeAgent
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standing(player_id: i64, rank: usize, games_played: i32) -> AmateurStanding {
+        AmateurStanding { player_id, rank, rating: 1400, games_played, age: 20, potential: 70 }
+    }
+
+    #[test]
+    fn test_generate_is_deterministic_and_in_range() {
+        let existing: HashSet<String> = HashSet::new();
+        let a = LadderAmateurEngine::generate(7, 30, &existing);
+        let b = LadderAmateurEngine::generate(7, 30, &existing);
+        assert_eq!(a.len(), 30);
+
+        let ids: HashSet<&str> = a.iter().map(|p| p.rookie.game_id.as_str()).collect();
+        assert_eq!(ids.len(), 30);
+        for (x, y) in a.iter().zip(&b) {
+            assert_eq!(x.rookie.game_id, y.rookie.game_id);
+            assert_eq!(x.kind, y.kind);
+            assert!((AMATEUR_ABILITY_RANGE.0..=AMATEUR_ABILITY_RANGE.1).contains(&x.rookie.ability));
+            assert!(x.rookie.potential >= x.rookie.ability);
+        }
+    }
+
+    #[test]
+    fn test_generate_avoids_existing_ids() {
+        let first = LadderAmateurEngine::generate(11, 10, &HashSet::new());
+        let existing: HashSet<String> = first.iter().map(|p| p.rookie.game_id.clone()).collect();
+        let second = LadderAmateurEngine::generate(11, 10, &existing);
+        assert!(second.iter().all(|p| !existing.contains(&p.rookie.game_id)));
+    }
+
+    #[test]
+    fn test_select_standouts() {
+        // 全场 40 人，前 25% 为前 10 名
+        let standings = vec![
+            standing(1, 12, 12),
+            standing(2, 3, 12),
+            standing(3, 9, 4),
+            standing(4, 7, 10),
+            standing(5, 1, 12),
+        ];
+        let picked: Vec<i64> = LadderAmateurEngine::select_standouts(&standings, 40)
+            .iter()
+            .map(|s| s.player_id)
+            .collect();
+        assert_eq!(picked, vec![5, 2]);
+
+        assert!(LadderAmateurEngine::select_standouts(&[standing(1, 20, 12)], 40).is_empty());
+    }
+
+    #[test]
+    fn test_discovery_outcome() {
+        assert_eq!(LadderAmateurEngine::discovery_outcome(17, 75), AmateurOutcome::DraftPool);
+        assert_eq!(LadderAmateurEngine::discovery_outcome(17, 60), AmateurOutcome::FreeAgent);
+        assert_eq!(LadderAmateurEngine::discovery_outcome(22, 80), AmateurOutcome::FreeAgent);
+        assert_eq!(AmateurKind::parse(AmateurKind::Streamer.as_str()), Some(AmateurKind::Streamer));
+    }
+}
@@ -1,6 +1,30 @@
+//! 天梯匹配器 (Ladder Matchmaker)
+//!
+//! 按位置排队（Top/Jug/Mid/Adc/Sup 各两名组成一个对局），并满足公平约束：
+//! - 轮空按历史轮空次数轮换，轮空少的选手优先上场
+//! - 同位置按积分排序分组，相邻对局之间交换选手以减少近几轮的重复相遇
+//! - 对局内 32 种分边方案中选择两队平均积分差最小的一种，
+//!   同一职业队的队友尽量分到两边，近几轮做过队友的选手尽量不再同队
+//! - 为每场对局给出匹配质量评分和模拟排队时长
+
 use rand::seq::SliceRandom;
 use rand::Rng;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+const POSITIONS: [&str; 5] = ["Top", "Jug", "Mid", "Adc", "Sup"];
+
+/// 同一职业队队友被分在同一边的惩罚（折算为积分差）
+const STACK_PENALTY: f64 = 60.0;
+/// 近几轮做过队友的选手再次同队的惩罚
+const REPEAT_TEAMMATE_PENALTY: f64 = 20.0;
+/// 交换相邻对局选手时允许的最大积分差
+const MAX_SWAP_RATING_GAP: i32 = 150;
+/// 统计重复相遇的轮数窗口
+pub const REPEAT_MEMORY_ROUNDS: i32 = 3;
+
+/// 排队时长模拟：基础时长（秒）与高/低分段的额外等待
+const BASE_QUEUE_SECS: f64 = 45.0;
+const RATING_EXTREMITY_SCALE: f64 = 300.0;
 
 #[derive(Debug, Clone)]
 pub struct LadderPlayer {
@@ -23,126 +47,405 @@ pub struct LadderMatch {
     pub red_team: LadderTeam,
     pub blue_avg_rating: i32,
     pub red_avg_rating: i32,
+    /// 匹配质量 0.0 ~ 1.0
+    pub quality: f64,
+    /// 模拟排队时长（对局内等待最久的选手）
+    pub queue_time_secs: u32,
+}
+
+/// 本届天梯赛之前各轮的匹配历史
+#[derive(Debug, Clone, Default)]
+pub struct MatchmakingHistory {
+    /// 每名选手已轮空的次数
+    pub bye_counts: HashMap<i64, u32>,
+    /// 近几轮在同一对局中相遇过的选手对（小 id 在前）
+    pub met_pairs: HashSet<(i64, i64)>,
+    /// 近几轮做过队友的选手对（小 id 在前）
+    pub teammate_pairs: HashSet<(i64, i64)>,
+}
+
+impl MatchmakingHistory {
+    fn pair(a: i64, b: i64) -> (i64, i64) {
+        (a.min(b), a.max(b))
+    }
+
+    /// 记录一场对局的相遇与队友关系
+    pub fn record_match(&mut self, blue: &[i64], red: &[i64]) {
+        let all: Vec<i64> = blue.iter().chain(red).copied().collect();
+        for (i, a) in all.iter().enumerate() {
+            for b in &all[i + 1..] {
+                self.met_pairs.insert(Self::pair(*a, *b));
+            }
+        }
+        for side in [blue, red] {
+            for (i, a) in side.iter().enumerate() {
+                for b in &side[i + 1..] {
+                    self.teammate_pairs.insert(Self::pair(*a, *b));
+                }
+            }
+        }
+    }
+
+    fn has_met(&self, a: i64, b: i64) -> bool {
+        self.met_pairs.contains(&Self::pair(a, b))
+    }
+
+    fn were_teammates(&self, a: i64, b: i64) -> bool {
+        self.teammate_pairs.contains(&Self::pair(a, b))
+    }
 }
 
 pub struct LadderMatchmaker;
 
 impl LadderMatchmaker {
-    /// 按位置配对选手，确保每支队伍包含 Top/Jug/Mid/Adc/Sup 各一名。
-    /// 同位置内按 rating 排序后相邻配对，随机分蓝红方。
+    /// 生成一轮对局，未能上场的选手放入 bye_players
     pub fn create_round_matches(
         players: Vec<LadderPlayer>,
         bye_players: &mut Vec<LadderPlayer>,
+        history: &MatchmakingHistory,
     ) -> Vec<LadderMatch> {
         let mut rng = rand::thread_rng();
 
-        // 1. 按位置分池
-        let mut players_by_position: HashMap<String, Vec<LadderPlayer>> = HashMap::new();
+        // 1. 按位置排队
+        let mut queues: HashMap<&str, Vec<LadderPlayer>> = HashMap::new();
         for player in players {
-            players_by_position
-                .entry(player.position.clone())
-                .or_insert_with(Vec::new)
-                .push(player);
-        }
-
-        let positions = vec!["Top", "Jug", "Mid", "Adc", "Sup"];
-
-        // 2. 每个位置内按 rating 排序，相邻配对，分蓝红队列
-        let mut blue_by_pos: HashMap<String, Vec<LadderPlayer>> = HashMap::new();
-        let mut red_by_pos: HashMap<String, Vec<LadderPlayer>> = HashMap::new();
-
-        for position in &positions {
-            let pos_key = position.to_string();
-            if let Some(pool) = players_by_position.get_mut(&pos_key) {
-                pool.shuffle(&mut rng);
-                pool.sort_by_key(|p| -(p.rating as i32));
-
-                let blue_list = blue_by_pos.entry(pos_key.clone()).or_insert_with(Vec::new);
-                let red_list = red_by_pos.entry(pos_key.clone()).or_insert_with(Vec::new);
-
-                while pool.len() >= 2 {
-                    let p1 = pool.remove(0);
-                    let mut p2_idx = 0;
-
-                    if let Some(ref t1) = p1.team_name {
-                        for (i, candidate) in pool.iter().enumerate() {
-                            if candidate.team_name.as_ref() != Some(t1) {
-                                p2_idx = i;
-                                break;
-                            }
-                        }
-                    }
+            match POSITIONS.iter().find(|p| **p == player.position) {
+                Some(pos) => queues.entry(*pos).or_default().push(player),
+                None => bye_players.push(player),
+            }
+        }
 
-                    let p2 = pool.remove(p2_idx);
+        let queue_sizes: HashMap<&str, usize> = POSITIONS
+            .iter()
+            .map(|pos| (*pos, queues.get(pos).map_or(0, |q| q.len())))
+            .collect();
+        let match_count = queue_sizes.values().map(|n| n / 2).min().unwrap_or(0);
 
-                    if rng.gen_bool(0.5) {
-                        blue_list.push(p1);
-                        red_list.push(p2);
-                    } else {
-                        blue_list.push(p2);
-                        red_list.push(p1);
-                    }
+        // 2. 轮空轮换：轮空次数少的优先上场，同次数随机
+        let mut lobbies_by_pos: HashMap<&str, Vec<LadderPlayer>> = HashMap::new();
+        for pos in POSITIONS {
+            let mut queue = queues.remove(pos).unwrap_or_default();
+            queue.shuffle(&mut rng);
+            queue.sort_by_key(|p| history.bye_counts.get(&p.player_id).copied().unwrap_or(0));
+            let sitting_out = queue.split_off((match_count * 2).min(queue.len()));
+            bye_players.extend(sitting_out);
+
+            // 上场选手按积分从高到低，每两人进入同一对局
+            queue.sort_by_key(|p| std::cmp::Reverse(p.rating));
+            lobbies_by_pos.insert(pos, queue);
+        }
+
+        let mut lobbies: Vec<Vec<LadderPlayer>> = (0..match_count)
+            .map(|k| {
+                POSITIONS
+                    .iter()
+                    .flat_map(|pos| lobbies_by_pos[pos][k * 2..k * 2 + 2].iter().cloned())
+                    .collect()
+            })
+            .collect();
+
+        // 3. 减少重复相遇：相邻对局之间交换同位置、积分相近的选手
+        Self::reduce_repeats(&mut lobbies, history);
+
+        let median_rating = {
+            let mut ratings: Vec<i32> = lobbies.iter().flatten().map(|p| p.rating).collect();
+            ratings.sort_unstable();
+            ratings.get(ratings.len() / 2).copied().unwrap_or(1200)
+        };
+        let avg_queue = queue_sizes.values().sum::<usize>() as f64 / POSITIONS.len() as f64;
+
+        // 4. 对局内分边
+        lobbies
+            .into_iter()
+            .map(|lobby| {
+                let mut ladder_match = Self::split_lobby(lobby, history, &mut rng);
+                ladder_match.queue_time_secs = Self::queue_time(
+                    &ladder_match,
+                    &queue_sizes,
+                    avg_queue,
+                    median_rating,
+                    &mut rng,
+                );
+                ladder_match
+            })
+            .collect()
+    }
+
+    /// 一个对局内近几轮已相遇过的选手对数
+    fn repeat_count(lobby: &[LadderPlayer], history: &MatchmakingHistory) -> usize {
+        lobby
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| lobby[i + 1..].iter().map(move |b| (a, b)))
+            .filter(|(a, b)| history.has_met(a.player_id, b.player_id))
+            .count()
+    }
+
+    fn reduce_repeats(lobbies: &mut [Vec<LadderPlayer>], history: &MatchmakingHistory) {
+        if history.met_pairs.is_empty() {
+            return;
+        }
+        for k in 0..lobbies.len().saturating_sub(1) {
+            for i in 0..lobbies[k].len() {
+                let before = Self::repeat_count(&lobbies[k], history) + Self::repeat_count(&lobbies[k + 1], history);
+                if before == 0 {
+                    break;
                 }
 
-                // 奇数个选手，最后一个轮空
-                if !pool.is_empty() {
-                    bye_players.push(pool.remove(0));
+                let mut best: Option<(usize, usize)> = None;
+                for j in 0..lobbies[k + 1].len() {
+                    let (a, b) = (&lobbies[k][i], &lobbies[k + 1][j]);
+                    if a.position != b.position || (a.rating - b.rating).abs() > MAX_SWAP_RATING_GAP {
+                        continue;
+                    }
+                    Self::swap(lobbies, k, i, j);
+                    let after = Self::repeat_count(&lobbies[k], history) + Self::repeat_count(&lobbies[k + 1], history);
+                    Self::swap(lobbies, k, i, j);
+                    if after < best.map_or(before, |(_, c)| c) {
+                        best = Some((j, after));
+                    }
+                }
+                if let Some((j, _)) = best {
+                    Self::swap(lobbies, k, i, j);
                 }
             }
         }
+    }
 
-        // 3. 计算可组成的完整队伍数（取各位置最小配对数）
-        let match_count = positions
-            .iter()
-            .map(|pos| blue_by_pos.get(*pos).map_or(0, |v| v.len()))
-            .min()
-            .unwrap_or(0);
-
-        // 4. 逐场组队：每场从每个位置各取一名蓝方/红方选手
-        let mut matches = Vec::with_capacity(match_count);
-        for _ in 0..match_count {
-            let mut blue_players = Vec::with_capacity(5);
-            let mut red_players = Vec::with_capacity(5);
-
-            for position in &positions {
-                let pos_key = position.to_string();
-                if let Some(blue_list) = blue_by_pos.get_mut(&pos_key) {
-                    if !blue_list.is_empty() {
-                        blue_players.push(blue_list.remove(0));
+    fn swap(lobbies: &mut [Vec<LadderPlayer>], k: usize, i: usize, j: usize) {
+        let (left, right) = lobbies.split_at_mut(k + 1);
+        std::mem::swap(&mut left[k][i], &mut right[0][j]);
+    }
+
+    /// 分边代价：平均积分差 + 职业队友同边惩罚 + 重复队友惩罚
+    fn split_cost(blue: &[&LadderPlayer], red: &[&LadderPlayer], history: &MatchmakingHistory) -> (f64, u32, u32) {
+        let avg = |team: &[&LadderPlayer]| team.iter().map(|p| p.rating as f64).sum::<f64>() / team.len().max(1) as f64;
+        let mut stacked = 0u32;
+        let mut repeats = 0u32;
+        for team in [blue, red] {
+            for (i, a) in team.iter().enumerate() {
+                for b in &team[i + 1..] {
+                    if a.team_name.is_some() && a.team_name == b.team_name {
+                        stacked += 1;
                     }
-                }
-                if let Some(red_list) = red_by_pos.get_mut(&pos_key) {
-                    if !red_list.is_empty() {
-                        red_players.push(red_list.remove(0));
+                    if history.were_teammates(a.player_id, b.player_id) {
+                        repeats += 1;
                     }
                 }
             }
+        }
+        let cost = (avg(blue) - avg(red)).abs()
+            + stacked as f64 * STACK_PENALTY
+            + repeats as f64 * REPEAT_TEAMMATE_PENALTY;
+        (cost, stacked, repeats)
+    }
+
+    /// 在 2^5 种分边方案中选择代价最小的一种（lobby 为按位置排列的 10 名选手）
+    fn split_lobby(lobby: Vec<LadderPlayer>, history: &MatchmakingHistory, rng: &mut impl Rng) -> LadderMatch {
+        let mut best: Option<(u32, f64, u32, u32)> = None;
+        // 第一位置固定，另外 4 个位置枚举，镜像方案等价
+        for mask in 0..16u32 {
+            let mask = mask << 1;
+            let (blue, red) = Self::apply_mask(&lobby, mask);
+            let (cost, stacked, repeats) = Self::split_cost(&blue, &red, history);
+            if best.map_or(true, |(_, c, _, _)| cost < c) {
+                best = Some((mask, cost, stacked, repeats));
+            }
+        }
+
+        let (mask, _, stacked, repeats) = best.unwrap_or((0, 0.0, 0, 0));
+        let (blue, red) = Self::apply_mask(&lobby, mask);
+        let (mut blue, mut red): (Vec<LadderPlayer>, Vec<LadderPlayer>) =
+            (blue.into_iter().cloned().collect(), red.into_iter().cloned().collect());
+        if rng.gen_bool(0.5) {
+            std::mem::swap(&mut blue, &mut red);
+        }
+
+        let blue_avg_rating = blue.iter().map(|p| p.rating).sum::<i32>() / blue.len().max(1) as i32;
+        let red_avg_rating = red.iter().map(|p| p.rating).sum::<i32>() / red.len().max(1) as i32;
+        let spread = lobby.iter().map(|p| p.rating).max().unwrap_or(0) - lobby.iter().map(|p| p.rating).min().unwrap_or(0);
+
+        LadderMatch {
+            quality: Self::match_quality(blue_avg_rating, red_avg_rating, spread, stacked, repeats),
+            blue_team: LadderTeam { players: blue },
+            red_team: LadderTeam { players: red },
+            blue_avg_rating,
+            red_avg_rating,
+            queue_time_secs: 0,
+        }
+    }
+
+    /// mask 第 i 位为 1 表示第 i 个位置的第二名选手去蓝方
+    fn apply_mask(lobby: &[LadderPlayer], mask: u32) -> (Vec<&LadderPlayer>, Vec<&LadderPlayer>) {
+        let mut blue = Vec::with_capacity(5);
+        let mut red = Vec::with_capacity(5);
+        for (i, pair) in lobby.chunks(2).enumerate() {
+            if pair.len() < 2 {
+                continue;
+            }
+            if mask & (1 << i) == 0 {
+                blue.push(&pair[0]);
+                red.push(&pair[1]);
+            } else {
+                blue.push(&pair[1]);
+                red.push(&pair[0]);
+            }
+        }
+        (blue, red)
+    }
+
+    /// 匹配质量：胜率越接近五五开、对局内分差越小、约束违反越少则越高
+    pub fn match_quality(blue_avg: i32, red_avg: i32, spread: i32, stacked: u32, repeats: u32) -> f64 {
+        let expected = 1.0 / (1.0 + 10f64.powf(-(blue_avg - red_avg) as f64 / 400.0));
+        let balance = 1.0 - (2.0 * expected - 1.0).abs();
+        let cohesion = 1.0 / (1.0 + spread.max(0) as f64 / 400.0);
+        let quality = balance * cohesion * 0.95f64.powi(stacked as i32) * 0.97f64.powi(repeats as i32);
+        (quality * 1000.0).round() / 1000.0
+    }
+
+    /// 模拟排队时长：位置越稀缺、积分越偏离中位数，等待越久
+    fn queue_time(
+        ladder_match: &LadderMatch,
+        queue_sizes: &HashMap<&str, usize>,
+        avg_queue: f64,
+        median_rating: i32,
+        rng: &mut impl Rng,
+    ) -> u32 {
+        ladder_match
+            .blue_team
+            .players
+            .iter()
+            .chain(&ladder_match.red_team.players)
+            .map(|p| {
+                let supply = queue_sizes.get(p.position.as_str()).copied().unwrap_or(1).max(1) as f64;
+                let scarcity = (avg_queue / supply).max(0.5);
+                let extremity = 1.0 + (p.rating - median_rating).abs() as f64 / RATING_EXTREMITY_SCALE;
+                let jitter: f64 = rng.gen_range(0.8..1.25);
+                (BASE_QUEUE_SECS * scarcity * extremity * jitter).round() as u32
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
 
-            if blue_players.len() == 5 && red_players.len() == 5 {
-                let blue_avg_rating = blue_players.iter().map(|p| p.rating).sum::<i32>() / 5;
-                let red_avg_rating = red_players.iter().map(|p| p.rating).sum::<i32>() / 5;
-
-                matches.push(LadderMatch {
-                    blue_team: LadderTeam {
-                        players: blue_players,
-                    },
-                    red_team: LadderTeam {
-                        players: red_players,
-                    },
-                    blue_avg_rating,
-                    red_avg_rating,
-                });
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(id: i64, position: &str, rating: i32, team: Option<&str>) -> LadderPlayer {
+        LadderPlayer {
+            player_id: id,
+            player_name: format!("P{}", id),
+            game_id: format!("G{}", id),
+            position: position.to_string(),
+            team_name: team.map(|t| t.to_string()),
+            rating,
+        }
+    }
+
+    /// 每个位置 n 名选手，积分分布较宽
+    fn field(per_role: i64) -> Vec<LadderPlayer> {
+        let mut players = Vec::new();
+        for (r, pos) in POSITIONS.iter().enumerate() {
+            for i in 0..per_role {
+                let id = r as i64 * 100 + i;
+                players.push(player(id, pos, 900 + (i as i32 * 37 + r as i32 * 53) % 700, None));
             }
         }
+        players
+    }
+
+    #[test]
+    fn test_role_queue_and_balance() {
+        let mut byes = Vec::new();
+        let matches = LadderMatchmaker::create_round_matches(field(8), &mut byes, &MatchmakingHistory::default());
+        assert_eq!(matches.len(), 4);
+        assert!(byes.is_empty());
+
+        for m in &matches {
+            for team in [&m.blue_team, &m.red_team] {
+                let mut positions: Vec<&str> = team.players.iter().map(|p| p.position.as_str()).collect();
+                positions.sort_unstable();
+                let mut expected = POSITIONS.to_vec();
+                expected.sort_unstable();
+                assert_eq!(positions, expected);
+            }
+            assert!((m.blue_avg_rating - m.red_avg_rating).abs() <= 40, "gap {} vs {}", m.blue_avg_rating, m.red_avg_rating);
+            assert!((0.0..=1.0).contains(&m.quality));
+            assert!(m.queue_time_secs > 0);
+        }
+    }
+
+    #[test]
+    fn test_pro_teammates_are_split() {
+        let players: Vec<LadderPlayer> = POSITIONS
+            .iter()
+            .enumerate()
+            .flat_map(|(r, pos)| {
+                let r = r as i64;
+                vec![player(r * 10, pos, 1200, Some("T1")), player(r * 10 + 1, pos, 1200, Some("GEN"))]
+            })
+            .collect();
+        let mut byes = Vec::new();
+        let matches = LadderMatchmaker::create_round_matches(players, &mut byes, &MatchmakingHistory::default());
+        assert_eq!(matches.len(), 1);
 
-        // 5. 剩余未配对的选手也加入轮空
-        for (_pos, remaining) in blue_by_pos.drain() {
-            bye_players.extend(remaining);
+        for team in [&matches[0].blue_team, &matches[0].red_team] {
+            let t1 = team.players.iter().filter(|p| p.team_name.as_deref() == Some("T1")).count();
+            assert!(t1 <= 3, "stacked {} T1 players on one side", t1);
         }
-        for (_pos, remaining) in red_by_pos.drain() {
-            bye_players.extend(remaining);
+    }
+
+    #[test]
+    fn test_byes_rotate_to_players_with_fewer_byes() {
+        let mut players = field(2);
+        players.push(player(999, "Mid", 1200, None));
+
+        let mut history = MatchmakingHistory::default();
+        history.bye_counts.insert(999, 0);
+        history.bye_counts.insert(200, 2);
+        history.bye_counts.insert(201, 1);
+
+        let mut byes = Vec::new();
+        let matches = LadderMatchmaker::create_round_matches(players, &mut byes, &history);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(byes.len(), 1);
+        assert_eq!(byes[0].player_id, 200);
+    }
+
+    #[test]
+    fn test_repeat_meetings_are_reduced() {
+        let players = field(4);
+        let mut byes = Vec::new();
+        let first = LadderMatchmaker::create_round_matches(players.clone(), &mut byes, &MatchmakingHistory::default());
+
+        let mut history = MatchmakingHistory::default();
+        for m in &first {
+            let blue: Vec<i64> = m.blue_team.players.iter().map(|p| p.player_id).collect();
+            let red: Vec<i64> = m.red_team.players.iter().map(|p| p.player_id).collect();
+            history.record_match(&blue, &red);
         }
 
-        matches
+        let second = LadderMatchmaker::create_round_matches(players, &mut byes, &history);
+        let repeats: usize = second
+            .iter()
+            .map(|m| {
+                let lobby: Vec<LadderPlayer> =
+                    m.blue_team.players.iter().chain(&m.red_team.players).cloned().collect();
+                LadderMatchmaker::repeat_count(&lobby, &history)
+            })
+            .sum();
+        // 不做交换时两场对局的 90 对相遇会全部重复
+        assert!(repeats < 90, "repeats = {}", repeats);
+    }
+
+    #[test]
+    fn test_match_quality() {
+        let even = LadderMatchmaker::match_quality(1200, 1200, 0, 0, 0);
+        assert_eq!(even, 1.0);
+        assert!(LadderMatchmaker::match_quality(1300, 1200, 0, 0, 0) < even);
+        assert!(LadderMatchmaker::match_quality(1200, 1200, 400, 0, 0) < even);
+        assert!(LadderMatchmaker::match_quality(1200, 1200, 0, 2, 0) < even);
+        assert!(LadderMatchmaker::match_quality(1200, 1200, 0, 0, 3) < even);
     }
 }
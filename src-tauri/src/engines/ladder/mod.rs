@@ -1,7 +1,11 @@
+pub mod amateur;
 pub mod matchmaker;
+pub mod profile;
 pub mod rating;
 pub mod simulator;
 
-pub use matchmaker::LadderMatchmaker;
-pub use rating::LadderRatingEngine;
+pub use amateur::{AmateurDiscovery, AmateurKind, AmateurOutcome, LadderAmateurEngine};
+pub use matchmaker::{LadderMatchmaker, MatchmakingHistory};
+pub use profile::{LadderEventSync, LadderProfile, LadderProfileEngine, LadderRank, LadderSeasonRecord, LadderTier};
+pub use rating::{LadderRatingEngine, PlayerRating, RatingSystem};
 pub use simulator::LadderSimulator;
@@ -0,0 +1,668 @@
+//! 天梯档案引擎 (Ladder Profile Engine)
+//!
+//! 每名选手/新秀拥有一个跨赛事、跨赛季的天梯档案：
+//! - 天梯赛开赛时以档案积分作为起始积分，赛事结束后写回档案
+//! - 新赛季开始时软重置：积分向 1200 回归，积分偏差放大
+//! - 积分映射为段位与小段（黑铁 IV ~ 最强王者）
+//! - 赛事积分涨跌转化为少量状态动量；公开的天梯数据为自由球员和新秀提供基础球探情报
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use super::rating::{PlayerRating, INITIAL_DEVIATION, INITIAL_RATING, INITIAL_VOLATILITY};
+use crate::models::ScoutSubject;
+
+/// 软重置后保留的积分差（相对 1200）比例
+const SOFT_RESET_RETENTION: f64 = 0.6;
+/// 每个休赛期积分偏差的增长量
+const SEASON_DEVIATION_GROWTH: f64 = 120.0;
+/// 每 100 分赛事积分变化折算 1 点状态动量，上限 ±2
+const RATING_PER_MOMENTUM: f64 = 100.0;
+const MAX_LADDER_MOMENTUM: i8 = 2;
+/// 天梯数据能提供的最大球探了解程度（积分偏差为 0 时）
+const LADDER_KNOWLEDGE_MAX: f64 = 0.3;
+/// 新秀路人局积分：每点能力对应的积分差与随机幅度
+const PROSPECT_RATING_PER_ABILITY: f64 = 10.0;
+const PROSPECT_RATING_JITTER: f64 = 60.0;
+const PROSPECT_DEVIATION: f64 = 220.0;
+
+/// 天梯段位
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum LadderTier {
+    Iron,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Diamond,
+    Master,
+    Grandmaster,
+    Challenger,
+}
+
+/// (段位, 起始积分, 分小段的积分跨度；0 表示不分小段)
+const TIER_TABLE: [(LadderTier, i32, i32); 9] = [
+    (LadderTier::Iron, 600, 200),
+    (LadderTier::Bronze, 800, 200),
+    (LadderTier::Silver, 1000, 150),
+    (LadderTier::Gold, 1150, 150),
+    (LadderTier::Platinum, 1300, 150),
+    (LadderTier::Diamond, 1450, 150),
+    (LadderTier::Master, 1600, 0),
+    (LadderTier::Grandmaster, 1750, 0),
+    (LadderTier::Challenger, 1900, 0),
+];
+
+impl LadderTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LadderTier::Iron => "Iron",
+            LadderTier::Bronze => "Bronze",
+            LadderTier::Silver => "Silver",
+            LadderTier::Gold => "Gold",
+            LadderTier::Platinum => "Platinum",
+            LadderTier::Diamond => "Diamond",
+            LadderTier::Master => "Master",
+            LadderTier::Grandmaster => "Grandmaster",
+            LadderTier::Challenger => "Challenger",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LadderTier::Iron => "黑铁",
+            LadderTier::Bronze => "青铜",
+            LadderTier::Silver => "白银",
+            LadderTier::Gold => "黄金",
+            LadderTier::Platinum => "铂金",
+            LadderTier::Diamond => "钻石",
+            LadderTier::Master => "大师",
+            LadderTier::Grandmaster => "宗师",
+            LadderTier::Challenger => "最强王者",
+        }
+    }
+}
+
+/// 段位 + 小段（I 最高，IV 最低；大师及以上不分小段）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LadderRank {
+    pub tier: LadderTier,
+    pub division: Option<u8>,
+}
+
+impl LadderRank {
+    pub fn from_rating(rating: i32) -> Self {
+        let (tier, floor, span) = TIER_TABLE
+            .iter()
+            .rev()
+            .find(|(_, floor, _)| rating >= *floor)
+            .copied()
+            .unwrap_or(TIER_TABLE[0]);
+
+        let division = (span > 0).then(|| {
+            let step = ((rating - floor).max(0) * 4 / span).min(3);
+            (4 - step) as u8
+        });
+        Self { tier, division }
+    }
+
+    /// 展示名，如 "黄金 II"
+    pub fn label(&self) -> String {
+        match self.division {
+            Some(d) => {
+                let roman = ["I", "II", "III", "IV"][(d.clamp(1, 4) - 1) as usize];
+                format!("{} {}", self.tier.name(), roman)
+            }
+            None => self.tier.name().to_string(),
+        }
+    }
+}
+
+/// 天梯档案
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderProfile {
+    pub subject_type: ScoutSubject,
+    pub subject_id: u64,
+    pub rating: i32,
+    pub rating_deviation: f64,
+    pub volatility: f64,
+    pub peak_rating: i32,
+    pub games_played: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub season_games: i64,
+    pub season_peak: i32,
+    pub updated_season: i64,
+    pub rank: LadderRank,
+    pub rank_label: String,
+}
+
+/// 往届赛季的天梯定级
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderSeasonRecord {
+    pub season: i64,
+    pub final_rating: i32,
+    pub peak_rating: i32,
+    pub rank: LadderRank,
+    pub rank_label: String,
+    pub games_played: i64,
+    pub wins: i64,
+}
+
+/// 天梯赛事结束后写回档案的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LadderEventSync {
+    pub profiles_updated: u32,
+    /// 获得状态动量调整的选手数
+    pub momentum_adjusted: u32,
+}
+
+pub struct LadderProfileEngine;
+
+impl LadderProfileEngine {
+    /// 赛季软重置：积分向 1200 回归，积分偏差放大，波动率回到初始值附近
+    pub fn soft_reset(rating: PlayerRating) -> PlayerRating {
+        PlayerRating {
+            rating: INITIAL_RATING + (rating.rating - INITIAL_RATING) * SOFT_RESET_RETENTION,
+            deviation: (rating.deviation.powi(2) + SEASON_DEVIATION_GROWTH.powi(2))
+                .sqrt()
+                .min(INITIAL_DEVIATION),
+            volatility: (rating.volatility + INITIAL_VOLATILITY) / 2.0,
+        }
+    }
+
+    /// 一届天梯赛的积分涨跌转化为状态动量（±2 以内）
+    pub fn form_momentum(event_rating_change: i32) -> i8 {
+        let steps = (event_rating_change as f64 / RATING_PER_MOMENTUM).trunc() as i64;
+        steps.clamp(-(MAX_LADDER_MOMENTUM as i64), MAX_LADDER_MOMENTUM as i64) as i8
+    }
+
+    /// 公开天梯数据带来的球探了解程度：场次越多、积分偏差越小越可信
+    pub fn public_knowledge(rating_deviation: f64, games_played: i64) -> f64 {
+        if games_played <= 0 {
+            return 0.0;
+        }
+        let certainty = (1.0 - rating_deviation / INITIAL_DEVIATION).clamp(0.0, 1.0);
+        LADDER_KNOWLEDGE_MAX * certainty
+    }
+
+    /// 新秀的路人局积分：由能力决定，带确定性的个体偏移
+    pub fn prospect_rating(ability: u8, prospect_id: u64) -> PlayerRating {
+        let mixed = prospect_id
+            .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            .rotate_left(17)
+            .wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        let jitter = ((mixed >> 11) % 2001) as f64 / 1000.0 - 1.0;
+        PlayerRating {
+            rating: INITIAL_RATING
+                + (ability as f64 - 60.0) * PROSPECT_RATING_PER_ABILITY
+                + jitter * PROSPECT_RATING_JITTER,
+            deviation: PROSPECT_DEVIATION,
+            volatility: INITIAL_VOLATILITY,
+        }
+    }
+
+    fn profile_from_row(row: &sqlx::sqlite::SqliteRow) -> Option<LadderProfile> {
+        let subject: String = row.get("subject_type");
+        let subject_type = match subject.as_str() {
+            "Player" => ScoutSubject::Player,
+            "Prospect" => ScoutSubject::Prospect,
+            _ => return None,
+        };
+        let rating: i64 = row.get("rating");
+        let rank = LadderRank::from_rating(rating as i32);
+        Some(LadderProfile {
+            subject_type,
+            subject_id: row.get::<i64, _>("subject_id") as u64,
+            rating: rating as i32,
+            rating_deviation: row.get("rating_deviation"),
+            volatility: row.get("volatility"),
+            peak_rating: row.get::<i64, _>("peak_rating") as i32,
+            games_played: row.get("games_played"),
+            wins: row.get("wins"),
+            losses: row.get("losses"),
+            season_games: row.get("season_games"),
+            season_peak: row.get::<i64, _>("season_peak") as i32,
+            updated_season: row.get("updated_season"),
+            rank,
+            rank_label: rank.label(),
+        })
+    }
+
+    // ==================== 数据库操作 ====================
+
+    /// 读取单个天梯档案
+    pub async fn load_profile(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        subject: ScoutSubject,
+        subject_id: u64,
+    ) -> Result<Option<LadderProfile>, String> {
+        let row = sqlx::query(
+            "SELECT * FROM ladder_profile WHERE save_id = ? AND subject_type = ? AND subject_id = ?"
+        )
+        .bind(save_id)
+        .bind(subject.as_str())
+        .bind(subject_id as i64)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询天梯档案失败: {}", e))?;
+
+        Ok(row.as_ref().and_then(Self::profile_from_row))
+    }
+
+    /// 天梯总榜（按积分排序）
+    pub async fn leaderboard(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        subject: ScoutSubject,
+        limit: u32,
+    ) -> Result<Vec<LadderProfile>, String> {
+        let rows = sqlx::query(
+            "SELECT * FROM ladder_profile WHERE save_id = ? AND subject_type = ? AND games_played > 0 ORDER BY rating DESC, wins DESC LIMIT ?"
+        )
+        .bind(save_id)
+        .bind(subject.as_str())
+        .bind(limit as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询天梯总榜失败: {}", e))?;
+
+        Ok(rows.iter().filter_map(Self::profile_from_row).collect())
+    }
+
+    /// 往届赛季定级记录
+    pub async fn season_history(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        subject: ScoutSubject,
+        subject_id: u64,
+    ) -> Result<Vec<LadderSeasonRecord>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT season, final_rating, peak_rating, games_played, wins
+            FROM ladder_profile_season
+            WHERE save_id = ? AND subject_type = ? AND subject_id = ?
+            ORDER BY season
+            "#
+        )
+        .bind(save_id)
+        .bind(subject.as_str())
+        .bind(subject_id as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询天梯赛季记录失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|r| {
+                let final_rating = r.get::<i64, _>("final_rating") as i32;
+                let rank = LadderRank::from_rating(final_rating);
+                LadderSeasonRecord {
+                    season: r.get("season"),
+                    final_rating,
+                    peak_rating: r.get::<i64, _>("peak_rating") as i32,
+                    rank,
+                    rank_label: rank.label(),
+                    games_played: r.get("games_played"),
+                    wins: r.get("wins"),
+                }
+            })
+            .collect())
+    }
+
+    /// 某类主体的公开天梯情报（已换算为了解程度）
+    pub async fn knowledge_map(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        subject: ScoutSubject,
+    ) -> Result<HashMap<u64, f64>, String> {
+        let rows = sqlx::query(
+            "SELECT subject_id, rating_deviation, games_played FROM ladder_profile WHERE save_id = ? AND subject_type = ?"
+        )
+        .bind(save_id)
+        .bind(subject.as_str())
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询天梯档案失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|r| {
+                let id = r.get::<i64, _>("subject_id") as u64;
+                (id, Self::public_knowledge(r.get("rating_deviation"), r.get("games_played")))
+            })
+            .collect())
+    }
+
+    /// 天梯赛开赛：以档案积分作为本届起始积分
+    pub async fn carry_into_event(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        tournament_id: i64,
+    ) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            UPDATE ladder_rating SET
+                rating = COALESCE((SELECT lp.rating FROM ladder_profile lp
+                    WHERE lp.save_id = ladder_rating.save_id AND lp.subject_type = 'Player' AND lp.subject_id = ladder_rating.player_id), rating),
+                rating_deviation = COALESCE((SELECT lp.rating_deviation FROM ladder_profile lp
+                    WHERE lp.save_id = ladder_rating.save_id AND lp.subject_type = 'Player' AND lp.subject_id = ladder_rating.player_id), rating_deviation),
+                volatility = COALESCE((SELECT lp.volatility FROM ladder_profile lp
+                    WHERE lp.save_id = ladder_rating.save_id AND lp.subject_type = 'Player' AND lp.subject_id = ladder_rating.player_id), volatility)
+            WHERE save_id = ? AND ladder_tournament_id = ?
+            "#
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("读取天梯档案积分失败: {}", e))?;
+
+        sqlx::query(
+            r#"
+            UPDATE ladder_rating
+            SET max_rating = rating, start_rating = rating, start_deviation = rating_deviation
+            WHERE save_id = ? AND ladder_tournament_id = ?
+            "#
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("初始化天梯起始积分失败: {}", e))?;
+
+        Ok(())
+    }
+
+    /// 天梯赛结束：把本届结果写回档案，并按积分涨跌调整状态动量（每届只结算一次，整体在同一事务内完成）
+    pub async fn sync_from_event(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        tournament_id: i64,
+    ) -> Result<LadderEventSync, String> {
+        let mut tx = pool.begin().await.map_err(|e| format!("开启事务失败: {}", e))?;
+        let tournament: Option<(i64, i64)> = sqlx::query_as(
+            "SELECT season, profile_synced FROM ladder_tournament WHERE save_id = ? AND id = ?"
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("查询天梯赛失败: {}", e))?;
+
+        let mut result = LadderEventSync { profiles_updated: 0, momentum_adjusted: 0 };
+        let Some((season, synced)) = tournament else {
+            return Ok(result);
+        };
+        if synced != 0 {
+            return Ok(result);
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT player_id, rating, rating_deviation, volatility, max_rating,
+                   games_played, wins, losses, start_rating
+            FROM ladder_rating
+            WHERE save_id = ? AND ladder_tournament_id = ? AND games_played > 0
+            "#
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| format!("查询天梯积分失败: {}", e))?;
+
+        for row in &rows {
+            let player_id: i64 = row.get("player_id");
+            let rating: i64 = row.get("rating");
+            let max_rating: i64 = row.get("max_rating");
+
+            sqlx::query(
+                r#"
+                INSERT INTO ladder_profile (
+                    save_id, subject_type, subject_id, rating, rating_deviation, volatility,
+                    peak_rating, games_played, wins, losses, season_games, season_peak, updated_season
+                ) VALUES (?, 'Player', ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ON CONFLICT(save_id, subject_type, subject_id) DO UPDATE SET
+                    rating = excluded.rating,
+                    rating_deviation = excluded.rating_deviation,
+                    volatility = excluded.volatility,
+                    peak_rating = MAX(ladder_profile.peak_rating, excluded.peak_rating),
+                    games_played = ladder_profile.games_played + excluded.games_played,
+                    wins = ladder_profile.wins + excluded.wins,
+                    losses = ladder_profile.losses + excluded.losses,
+                    season_games = ladder_profile.season_games + excluded.season_games,
+                    season_peak = MAX(ladder_profile.season_peak, excluded.season_peak),
+                    updated_season = excluded.updated_season
+                "#
+            )
+            .bind(save_id)
+            .bind(player_id)
+            .bind(rating)
+            .bind(row.get::<f64, _>("rating_deviation"))
+            .bind(row.get::<f64, _>("volatility"))
+            .bind(max_rating)
+            .bind(row.get::<i64, _>("games_played"))
+            .bind(row.get::<i64, _>("wins"))
+            .bind(row.get::<i64, _>("losses"))
+            .bind(row.get::<i64, _>("games_played"))
+            .bind(max_rating)
+            .bind(season)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("写入天梯档案失败: {}", e))?;
+            result.profiles_updated += 1;
+
+            let momentum = Self::form_momentum((rating - row.get::<i64, _>("start_rating")) as i32);
+            if momentum != 0 {
+                let updated = sqlx::query(
+                    "UPDATE player_form_factors SET momentum = MAX(-5, MIN(5, momentum + ?)) WHERE save_id = ? AND player_id = ?"
+                )
+                .bind(momentum as i64)
+                .bind(save_id)
+                .bind(player_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("更新状态动量失败: {}", e))?;
+                if updated.rows_affected() > 0 {
+                    result.momentum_adjusted += 1;
+                }
+            }
+        }
+
+        sqlx::query("UPDATE ladder_tournament SET profile_synced = 1 WHERE id = ?")
+            .bind(tournament_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("更新天梯赛状态失败: {}", e))?;
+        tx.commit().await.map_err(|e| format!("提交事务失败: {}", e))?;
+
+        Ok(result)
+    }
+
+    /// 为尚无档案的选秀新秀生成路人局天梯档案，返回新建数量
+    pub async fn seed_prospect_profiles(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        current_season: i64,
+    ) -> Result<u32, String> {
+        let prospects: Vec<(i64, i64)> = sqlx::query_as(
+            r#"
+            SELECT dp.id, dp.ability FROM draft_pool dp
+            WHERE dp.save_id = ? AND dp.status = 'available'
+              AND NOT EXISTS (
+                  SELECT 1 FROM ladder_profile lp
+                  WHERE lp.save_id = dp.save_id AND lp.subject_type = 'Prospect' AND lp.subject_id = dp.id
+              )
+            "#
+        )
+        .bind(save_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询选秀池失败: {}", e))?;
+
+        for (prospect_id, ability) in &prospects {
+            let rating = Self::prospect_rating(*ability as u8, *prospect_id as u64);
+            let rounded = rating.rating.round() as i64;
+            sqlx::query(
+                r#"
+                INSERT INTO ladder_profile (
+                    save_id, subject_type, subject_id, rating, rating_deviation, volatility,
+                    peak_rating, games_played, wins, losses, season_games, season_peak, updated_season
+                ) VALUES (?, 'Prospect', ?, ?, ?, ?, ?, ?, ?, ?, 0, ?, ?)
+                "#
+            )
+            .bind(save_id)
+            .bind(prospect_id)
+            .bind(rounded)
+            .bind(rating.deviation)
+            .bind(rating.volatility)
+            .bind(rounded)
+            // 路人局场次只用于判断数据可信度，胜负按五五开记录
+            .bind(40i64)
+            .bind(20i64)
+            .bind(20i64)
+            .bind(rounded)
+            .bind(current_season)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("生成新秀天梯档案失败: {}", e))?;
+        }
+
+        Ok(prospects.len() as u32)
+    }
+
+    /// 新赛季软重置：归档上赛季定级，积分回归并放大积分偏差，返回重置的档案数
+    pub async fn season_soft_reset(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        new_season: i64,
+    ) -> Result<u32, String> {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO ladder_profile_season (
+                save_id, subject_type, subject_id, season, final_rating, peak_rating, games_played, wins
+            )
+            SELECT save_id, subject_type, subject_id, updated_season, rating, season_peak, season_games, wins
+            FROM ladder_profile
+            WHERE save_id = ? AND subject_type = 'Player' AND season_games > 0 AND updated_season < ?
+            "#
+        )
+        .bind(save_id)
+        .bind(new_season)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("归档天梯赛季记录失败: {}", e))?;
+
+        let rows = sqlx::query(
+            "SELECT id, rating, rating_deviation, volatility FROM ladder_profile WHERE save_id = ? AND subject_type = 'Player' AND updated_season < ?"
+        )
+        .bind(save_id)
+        .bind(new_season)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询天梯档案失败: {}", e))?;
+
+        for row in &rows {
+            let reset = Self::soft_reset(PlayerRating {
+                rating: row.get::<i64, _>("rating") as f64,
+                deviation: row.get("rating_deviation"),
+                volatility: row.get("volatility"),
+            });
+            let rating = reset.rating.round() as i64;
+
+            sqlx::query(
+                r#"
+                UPDATE ladder_profile
+                SET rating = ?, rating_deviation = ?, volatility = ?,
+                    season_games = 0, season_peak = ?, updated_season = ?
+                WHERE id = ?
+                "#
+            )
+            .bind(rating)
+            .bind(reset.deviation)
+            .bind(reset.volatility)
+            .bind(rating)
+            .bind(new_season)
+            .bind(row.get::<i64, _>("id"))
+            .execute(pool)
+            .await
+            .map_err(|e| format!("天梯软重置失败: {}", e))?;
+        }
+
+        Ok(rows.len() as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rank_from_rating() {
+        assert_eq!(LadderRank::from_rating(400), LadderRank { tier: LadderTier::Iron, division: Some(4) });
+        assert_eq!(LadderRank::from_rating(1200).label(), "黄金 III");
+        assert_eq!(LadderRank::from_rating(1299).label(), "黄金 I");
+        assert_eq!(LadderRank::from_rating(1300).label(), "铂金 IV");
+        assert_eq!(LadderRank::from_rating(1650).label(), "大师");
+        assert_eq!(LadderRank::from_rating(2400).tier, LadderTier::Challenger);
+
+        let mut last = LadderRank::from_rating(0);
+        for rating in (0..2500).step_by(10) {
+            let rank = LadderRank::from_rating(rating);
+            assert!(rank.tier >= last.tier);
+            if rank.tier == last.tier {
+                assert!(rank.division <= last.division);
+            }
+            last = rank;
+        }
+    }
+
+    #[test]
+    fn test_soft_reset_regresses_and_widens() {
+        let high = PlayerRating { rating: 1800.0, deviation: 80.0, volatility: 0.09 };
+        let reset = LadderProfileEngine::soft_reset(high);
+        assert!((reset.rating - 1560.0).abs() < 1e-9);
+        assert!(reset.deviation > high.deviation && reset.deviation <= INITIAL_DEVIATION);
+        assert!((reset.volatility - 0.075).abs() < 1e-9);
+
+        let low = LadderProfileEngine::soft_reset(PlayerRating { rating: 900.0, ..high });
+        assert!(low.rating > 900.0 && low.rating < INITIAL_RATING);
+
+        let fresh = LadderProfileEngine::soft_reset(PlayerRating::default());
+        assert_eq!(fresh.deviation, INITIAL_DEVIATION);
+    }
+
+    #[test]
+    fn test_form_momentum_is_small() {
+        assert_eq!(LadderProfileEngine::form_momentum(0), 0);
+        assert_eq!(LadderProfileEngine::form_momentum(99), 0);
+        assert_eq!(LadderProfileEngine::form_momentum(150), 1);
+        assert_eq!(LadderProfileEngine::form_momentum(-220), -2);
+        assert_eq!(LadderProfileEngine::form_momentum(900), 2);
+    }
+
+    #[test]
+    fn test_public_knowledge_grows_with_certainty() {
+        assert_eq!(LadderProfileEngine::public_knowledge(100.0, 0), 0.0);
+        let uncertain = LadderProfileEngine::public_knowledge(300.0, 10);
+        let certain = LadderProfileEngine::public_knowledge(60.0, 60);
+        assert!(certain > uncertain);
+        assert!(certain <= LADDER_KNOWLEDGE_MAX);
+    }
+
+    #[test]
+    fn test_prospect_rating_tracks_ability() {
+        let a = LadderProfileEngine::prospect_rating(70, 5);
+        assert_eq!(a, LadderProfileEngine::prospect_rating(70, 5));
+        for id in 0..50 {
+            let strong = LadderProfileEngine::prospect_rating(75, id);
+            let weak = LadderProfileEngine::prospect_rating(55, id);
+            assert!(strong.rating > weak.rating);
+            assert!((strong.rating - 1350.0).abs() <= PROSPECT_RATING_JITTER);
+        }
+    }
+}
@@ -1,7 +1,83 @@
-use rand::rngs::StdRng;
-use rand::Rng;
-use rand::SeedableRng;
+//! 天梯积分引擎
+//!
+//! 支持两种积分系统（按天梯赛事选择）：
+//! - Glicko-2：积分 + 积分偏差(RD) + 波动率，预期胜率由双方平均积分与对手 RD 计算
+//! - TrueSkill：μ/σ 双参数，按两队 μ 之和与总方差计算预期胜率
+//!
+//! 两者都会随对局数增加收缩不确定度；冷门胜利得分更多，爆冷失利扣分更多，
+//! 同分对局胜负两方的积分变化大小相等，积分不再单向膨胀。
+
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::f64::consts::PI;
+
+use crate::engines::sim_core::SimulationCore;
+
+/// 初始积分
+pub const INITIAL_RATING: f64 = 1200.0;
+/// 初始积分偏差（两种系统共用同一刻度）
+pub const INITIAL_DEVIATION: f64 = 350.0;
+/// Glicko-2 初始波动率
+pub const INITIAL_VOLATILITY: f64 = 0.06;
+
+/// Glicko-2 刻度换算系数
+const GLICKO_SCALE: f64 = 173.7178;
+/// Glicko-2 系统常数 τ（约束波动率变化）
+const GLICKO_TAU: f64 = 0.5;
+const GLICKO_EPSILON: f64 = 0.000001;
+
+/// TrueSkill 单人发挥标准差 β（积分刻度）
+const TRUESKILL_BETA: f64 = INITIAL_DEVIATION / 2.0;
+/// TrueSkill 每局动态因子 τ，防止 σ 收缩到 0
+const TRUESKILL_TAU: f64 = INITIAL_DEVIATION / 100.0;
+
+/// 影响力加成上限：只在队内重新分配积分，队内合计约为 0
+const PERFORMANCE_BONUS_CAP: f64 = 5.0;
+
+/// 积分系统
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RatingSystem {
+    #[default]
+    Glicko2,
+    TrueSkill,
+}
+
+impl RatingSystem {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RatingSystem::Glicko2 => "Glicko2",
+            RatingSystem::TrueSkill => "TrueSkill",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Glicko2" => Some(RatingSystem::Glicko2),
+            "TrueSkill" => Some(RatingSystem::TrueSkill),
+            _ => None,
+        }
+    }
+}
+
+/// 选手积分状态
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlayerRating {
+    pub rating: f64,
+    /// Glicko-2 的 RD / TrueSkill 的 σ
+    pub deviation: f64,
+    /// Glicko-2 波动率（TrueSkill 不使用，原样保留）
+    pub volatility: f64,
+}
+
+impl Default for PlayerRating {
+    fn default() -> Self {
+        Self {
+            rating: INITIAL_RATING,
+            deviation: INITIAL_DEVIATION,
+            volatility: INITIAL_VOLATILITY,
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct RatingUpdate {
@@ -11,70 +87,329 @@ pub struct RatingUpdate {
     pub rating_change: i32,
     pub performance_bonus: i32,
     pub influence: f64,
+    pub new_deviation: f64,
+    pub new_volatility: f64,
+    /// 赛前预期胜率
+    pub expected_score: f64,
 }
 
 pub struct LadderRatingEngine;
 
 impl LadderRatingEngine {
+    /// 计算一局天梯对局后双方全部选手的积分变化
     pub fn calculate_rating_changes(
-        blue_players: &[(i64, i32)],
-        red_players: &[(i64, i32)],
-        blue_performances: &HashMap<i64, f64>,
-        red_performances: &HashMap<i64, f64>,
+        system: RatingSystem,
+        blue_players: &[(i64, PlayerRating)],
+        red_players: &[(i64, PlayerRating)],
+        blue_avg_rating: i32,
+        red_avg_rating: i32,
+        performances: &HashMap<i64, f64>,
         winner_side: &str,
     ) -> Vec<RatingUpdate> {
-        let mut rng = StdRng::from_entropy();
-        let mut updates = Vec::new();
+        let blue_won = winner_side == "blue";
+        let (blue_new, red_new, blue_expected) = match system {
+            RatingSystem::Glicko2 => {
+                let blue_expected =
+                    Self::glicko_expected(blue_avg_rating as f64, red_avg_rating as f64, red_players);
+                let red_expected =
+                    Self::glicko_expected(red_avg_rating as f64, blue_avg_rating as f64, blue_players);
+                let blue_new = Self::glicko_update(blue_players, red_players, blue_expected, blue_won);
+                let red_new = Self::glicko_update(red_players, blue_players, red_expected, !blue_won);
+                (blue_new, red_new, blue_expected)
+            }
+            RatingSystem::TrueSkill => {
+                Self::trueskill_update(blue_players, red_players, blue_avg_rating, red_avg_rating, blue_won)
+            }
+        };
+
+        let mut updates = Vec::with_capacity(blue_players.len() + red_players.len());
+        Self::collect_updates(&mut updates, blue_players, &blue_new, performances, blue_expected);
+        Self::collect_updates(&mut updates, red_players, &red_new, performances, 1.0 - blue_expected);
+        updates
+    }
 
-        let blue_avg_perf = blue_performances.values().copied().sum::<f64>()
-            / blue_performances.len().max(1) as f64;
-        let red_avg_perf =
-            red_performances.values().copied().sum::<f64>() / red_performances.len().max(1) as f64;
+    fn collect_updates(
+        updates: &mut Vec<RatingUpdate>,
+        players: &[(i64, PlayerRating)],
+        new_ratings: &[PlayerRating],
+        performances: &HashMap<i64, f64>,
+        expected_score: f64,
+    ) {
+        let team_perfs: Vec<f64> = players
+            .iter()
+            .map(|(id, _)| performances.get(id).copied().unwrap_or(0.0))
+            .collect();
+        let team_avg_perf = team_perfs.iter().sum::<f64>() / team_perfs.len().max(1) as f64;
 
-        for (player_id, rating) in blue_players {
-            let is_winner = winner_side == "blue";
-            let perf = blue_performances.get(player_id).copied().unwrap_or(0.0);
-            let influence = perf - blue_avg_perf;
-            let change = Self::calc_change(is_winner, influence, &mut rng);
-            let new_rating = (*rating + change).max(0);
+        for (((player_id, old), new), perf) in players.iter().zip(new_ratings).zip(team_perfs) {
+            let influence = perf - team_avg_perf;
+            let performance_bonus = (influence * 0.5)
+                .clamp(-PERFORMANCE_BONUS_CAP, PERFORMANCE_BONUS_CAP)
+                .round() as i32;
+            let old_rating = old.rating.round() as i32;
+            let new_rating = ((new.rating.round() as i32) + performance_bonus).max(0);
 
             updates.push(RatingUpdate {
                 player_id: *player_id,
-                old_rating: *rating,
+                old_rating,
                 new_rating,
-                rating_change: change,
-                performance_bonus: (influence * 0.8).clamp(-6.0, 6.0).round() as i32,
+                rating_change: new_rating - old_rating,
+                performance_bonus,
                 influence,
+                new_deviation: new.deviation,
+                new_volatility: new.volatility,
+                expected_score,
             });
         }
+    }
 
-        for (player_id, rating) in red_players {
-            let is_winner = winner_side == "red";
-            let perf = red_performances.get(player_id).copied().unwrap_or(0.0);
-            let influence = perf - red_avg_perf;
-            let change = Self::calc_change(is_winner, influence, &mut rng);
-            let new_rating = (*rating + change).max(0);
+    /// Glicko-2 的 g(φ)
+    fn glicko_g(phi: f64) -> f64 {
+        1.0 / (1.0 + 3.0 * phi * phi / (PI * PI)).sqrt()
+    }
 
-            updates.push(RatingUpdate {
-                player_id: *player_id,
-                old_rating: *rating,
-                new_rating,
-                rating_change: change,
-                performance_bonus: (influence * 0.8).clamp(-6.0, 6.0).round() as i32,
-                influence,
-            });
+    /// 对手队伍的合成 φ：队员 φ² 的均值开方
+    fn composite_phi(opponents: &[(i64, PlayerRating)]) -> f64 {
+        let n = opponents.len().max(1) as f64;
+        let sum_sq: f64 = opponents
+            .iter()
+            .map(|(_, r)| (r.deviation / GLICKO_SCALE).powi(2))
+            .sum();
+        (sum_sq / n).sqrt()
+    }
+
+    /// Glicko-2 预期胜率：以己方平均积分对阵对方平均积分，按对方 RD 折减
+    fn glicko_expected(own_avg: f64, opp_avg: f64, opponents: &[(i64, PlayerRating)]) -> f64 {
+        let mu = (own_avg - INITIAL_RATING) / GLICKO_SCALE;
+        let mu_opp = (opp_avg - INITIAL_RATING) / GLICKO_SCALE;
+        let g = Self::glicko_g(Self::composite_phi(opponents));
+        1.0 / (1.0 + (-g * (mu - mu_opp)).exp())
+    }
+
+    /// Glicko-2 单局更新：每名队员各自以自身 RD/波动率对阵对手合成选手
+    fn glicko_update(
+        team: &[(i64, PlayerRating)],
+        opponents: &[(i64, PlayerRating)],
+        expected: f64,
+        won: bool,
+    ) -> Vec<PlayerRating> {
+        let score = if won { 1.0 } else { 0.0 };
+        let g = Self::glicko_g(Self::composite_phi(opponents));
+        let v = 1.0 / (g * g * expected * (1.0 - expected)).max(GLICKO_EPSILON);
+        let delta = v * g * (score - expected);
+
+        team.iter()
+            .map(|(_, r)| {
+                let phi = r.deviation / GLICKO_SCALE;
+                let sigma = Self::glicko_volatility(phi, r.volatility, v, delta);
+                let phi_star = (phi * phi + sigma * sigma).sqrt();
+                let new_phi = 1.0 / (1.0 / (phi_star * phi_star) + 1.0 / v).sqrt();
+                let new_mu = (r.rating - INITIAL_RATING) / GLICKO_SCALE + new_phi * new_phi * g * (score - expected);
+
+                PlayerRating {
+                    rating: new_mu * GLICKO_SCALE + INITIAL_RATING,
+                    deviation: (new_phi * GLICKO_SCALE).min(INITIAL_DEVIATION),
+                    volatility: sigma,
+                }
+            })
+            .collect()
+    }
+
+    /// Glicko-2 第 5 步：Illinois 迭代求新波动率
+    fn glicko_volatility(phi: f64, sigma: f64, v: f64, delta: f64) -> f64 {
+        let a = (sigma * sigma).ln();
+        let tau2 = GLICKO_TAU * GLICKO_TAU;
+        let f = |x: f64| {
+            let ex = x.exp();
+            let denom = phi * phi + v + ex;
+            ex * (delta * delta - phi * phi - v - ex) / (2.0 * denom * denom) - (x - a) / tau2
+        };
+
+        let mut lo = a;
+        let mut hi = if delta * delta > phi * phi + v {
+            (delta * delta - phi * phi - v).ln()
+        } else {
+            let mut k = 1.0;
+            while f(a - k * GLICKO_TAU) < 0.0 {
+                k += 1.0;
+            }
+            a - k * GLICKO_TAU
+        };
+
+        let mut f_lo = f(lo);
+        let mut f_hi = f(hi);
+        while (hi - lo).abs() > GLICKO_EPSILON {
+            let mid = lo + (lo - hi) * f_lo / (f_hi - f_lo);
+            let f_mid = f(mid);
+            if f_mid * f_hi <= 0.0 {
+                lo = hi;
+                f_lo = f_hi;
+            } else {
+                f_lo /= 2.0;
+            }
+            hi = mid;
+            f_hi = f_mid;
         }
 
-        updates
+        (lo / 2.0).exp()
+    }
+
+    /// TrueSkill 两队更新，返回 (蓝方新积分, 红方新积分, 蓝方预期胜率)
+    fn trueskill_update(
+        blue: &[(i64, PlayerRating)],
+        red: &[(i64, PlayerRating)],
+        blue_avg_rating: i32,
+        red_avg_rating: i32,
+        blue_won: bool,
+    ) -> (Vec<PlayerRating>, Vec<PlayerRating>, f64) {
+        let tau2 = TRUESKILL_TAU * TRUESKILL_TAU;
+        let variance = |team: &[(i64, PlayerRating)]| -> f64 {
+            team.iter().map(|(_, r)| r.deviation * r.deviation + tau2).sum()
+        };
+
+        let player_count = (blue.len() + red.len()) as f64;
+        let c = (variance(blue) + variance(red) + player_count * TRUESKILL_BETA * TRUESKILL_BETA).sqrt();
+        // 队伍 μ 之和 = 平均积分 × 人数
+        let blue_mu = blue_avg_rating as f64 * blue.len() as f64;
+        let red_mu = red_avg_rating as f64 * red.len() as f64;
+        let blue_expected = SimulationCore::normal_cdf((blue_mu - red_mu) / c);
+
+        let t = if blue_won { blue_mu - red_mu } else { red_mu - blue_mu } / c;
+        let cdf = SimulationCore::normal_cdf(t);
+        let v = if cdf > 1e-12 {
+            (-t * t / 2.0).exp() / (2.0 * PI).sqrt() / cdf
+        } else {
+            -t
+        };
+        let w = (v * (v + t)).clamp(0.0, 1.0 - 1e-6);
+
+        let update = |team: &[(i64, PlayerRating)], won: bool| -> Vec<PlayerRating> {
+            let sign = if won { 1.0 } else { -1.0 };
+            team.iter()
+                .map(|(_, r)| {
+                    let var = r.deviation * r.deviation + tau2;
+                    PlayerRating {
+                        rating: r.rating + sign * var / c * v,
+                        deviation: (var * (1.0 - var / (c * c) * w)).sqrt(),
+                        volatility: r.volatility,
+                    }
+                })
+                .collect()
+        };
+
+        (update(blue, blue_won), update(red, !blue_won), blue_expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn team(base_id: i64, rating: f64, deviation: f64) -> Vec<(i64, PlayerRating)> {
+        (0..5)
+            .map(|i| {
+                (base_id + i, PlayerRating { rating, deviation, volatility: INITIAL_VOLATILITY })
+            })
+            .collect()
+    }
+
+    fn avg(players: &[(i64, PlayerRating)]) -> i32 {
+        (players.iter().map(|(_, r)| r.rating).sum::<f64>() / players.len() as f64).round() as i32
+    }
+
+    fn play(system: RatingSystem, blue: &[(i64, PlayerRating)], red: &[(i64, PlayerRating)], winner: &str) -> Vec<RatingUpdate> {
+        LadderRatingEngine::calculate_rating_changes(system, blue, red, avg(blue), avg(red), &HashMap::new(), winner)
+    }
+
+    fn change_of(updates: &[RatingUpdate], player_id: i64) -> i32 {
+        updates.iter().find(|u| u.player_id == player_id).unwrap().rating_change
+    }
+
+    #[test]
+    fn test_equal_match_is_zero_sum() {
+        for system in [RatingSystem::Glicko2, RatingSystem::TrueSkill] {
+            let blue = team(0, 1200.0, 200.0);
+            let red = team(10, 1200.0, 200.0);
+            let updates = play(system, &blue, &red, "blue");
+
+            let total: i32 = updates.iter().map(|u| u.rating_change).sum();
+            assert!(total.abs() <= updates.len() as i32, "{:?} total change {}", system, total);
+            assert!(change_of(&updates, 0) > 0);
+            assert!(change_of(&updates, 10) < 0);
+            assert!((updates[0].expected_score - 0.5).abs() < 1e-9);
+        }
     }
 
-    fn calc_change(is_winner: bool, influence: f64, rng: &mut StdRng) -> i32 {
-        // 基础分: 赢 +22, 输 -14 (非对称，赢的收益大于输的损失)
-        let base = if is_winner { 22.0 } else { -14.0 };
-        // 影响力加权: influence 范围大约 -8~+8, 乘 0.8 得 -6.4~+6.4
-        let influence_bonus = (influence * 0.8).clamp(-6.0, 6.0);
-        // 随机因子: ±3
-        let noise: f64 = rng.gen_range(-3.0..=3.0);
-        (base + influence_bonus + noise).round() as i32
+    #[test]
+    fn test_upset_gains_more() {
+        for system in [RatingSystem::Glicko2, RatingSystem::TrueSkill] {
+            let strong = team(0, 1400.0, 150.0);
+            let weak = team(10, 1100.0, 150.0);
+
+            let favourite_win = play(system, &strong, &weak, "blue");
+            let upset = play(system, &strong, &weak, "red");
+            assert!(favourite_win[0].expected_score > 0.5);
+            assert!(
+                change_of(&upset, 10) > change_of(&favourite_win, 0),
+                "{:?}: upset {} vs favourite {}",
+                system,
+                change_of(&upset, 10),
+                change_of(&favourite_win, 0)
+            );
+            assert!(change_of(&upset, 0) < 0);
+        }
+    }
+
+    #[test]
+    fn test_deviation_shrinks_with_games() {
+        for system in [RatingSystem::Glicko2, RatingSystem::TrueSkill] {
+            let mut blue = team(0, INITIAL_RATING, INITIAL_DEVIATION);
+            let mut red = team(10, INITIAL_RATING, INITIAL_DEVIATION);
+            let mut last_deviation = INITIAL_DEVIATION;
+            let mut first_change = None;
+
+            for game in 0..12 {
+                let winner = if game % 2 == 0 { "blue" } else { "red" };
+                let updates = play(system, &blue, &red, winner);
+                first_change.get_or_insert(change_of(&updates, 0).abs());
+
+                for (id, rating) in blue.iter_mut().chain(red.iter_mut()) {
+                    let u = updates.iter().find(|u| u.player_id == *id).unwrap();
+                    *rating = PlayerRating {
+                        rating: u.new_rating as f64,
+                        deviation: u.new_deviation,
+                        volatility: u.new_volatility,
+                    };
+                }
+                assert!(blue[0].1.deviation < last_deviation, "{:?} game {}", system, game);
+                last_deviation = blue[0].1.deviation;
+            }
+
+            let latest = play(system, &blue, &red, "blue");
+            assert!(change_of(&latest, 0) < first_change.unwrap());
+        }
+    }
+
+    #[test]
+    fn test_performance_bonus_redistributes_within_team() {
+        let blue = team(0, 1200.0, 100.0);
+        let red = team(10, 1200.0, 100.0);
+        let performances: HashMap<i64, f64> = [(0, 90.0), (1, 70.0), (2, 80.0), (3, 80.0), (4, 80.0)].into_iter().collect();
+        let updates = LadderRatingEngine::calculate_rating_changes(
+            RatingSystem::Glicko2, &blue, &red, 1200, 1200, &performances, "blue",
+        );
+
+        assert_eq!(updates[0].performance_bonus, 5);
+        assert_eq!(updates[1].performance_bonus, -5);
+        assert!(change_of(&updates, 0) > change_of(&updates, 2));
+        assert!(change_of(&updates, 1) < change_of(&updates, 2));
+    }
+
+    #[test]
+    fn test_rating_system_roundtrip() {
+        for system in [RatingSystem::Glicko2, RatingSystem::TrueSkill] {
+            assert_eq!(RatingSystem::parse(system.as_str()), Some(system));
+        }
+        assert_eq!(RatingSystem::parse("Elo"), None);
     }
 }
@@ -1,9 +1,13 @@
 use super::matchmaker::{LadderMatch, LadderPlayer, LadderTeam};
 use crate::engines::bp_engine::{BpEngine, PlayerChampionPool};
 use crate::engines::champion::VersionTier;
+use crate::engines::comp_phase::CompPhaseRuleset;
+use crate::engines::draft_rules::SeriesDraftRules;
+use crate::engines::draft_scouting::DraftScoutingReport;
 use crate::engines::match_simulation::MatchSimulationEngine;
-use crate::engines::meta_engine::MetaType;
-use crate::engines::traits::TraitType;
+use crate::engines::meta_engine::{MetaType, MetaWeights};
+use crate::engines::sim_core::{PerformanceInput, SimulationCore};
+use crate::engines::traits::{TraitContext, TraitType};
 use crate::models::player::Position;
 use rand::rngs::StdRng;
 use rand::Rng;
@@ -50,6 +54,13 @@ impl LadderSimulator {
         }
     }
 
+    /// 使用指定的阵容强势期规则
+    pub fn with_phase_ruleset(ruleset: CompPhaseRuleset) -> Self {
+        Self {
+            match_sim_engine: MatchSimulationEngine::default().with_phase_ruleset(ruleset),
+        }
+    }
+
     pub fn simulate_match(
         &self,
         ladder_match: &LadderMatch,
@@ -81,52 +92,58 @@ impl LadderSimulator {
                 version_tiers,
                 MetaType::Balanced,
                 &mut rng,
-                &[],
-                &[],
+                &DraftScoutingReport::default(),
+                &DraftScoutingReport::default(),
                 None,
+                &SeriesDraftRules::default(),
             )
         } else {
+            log::warn!(
+                "天梯第{}轮第{}场缺少选手数据（蓝方 {}/{}，红方 {}/{}），本场判为无效",
+                round_number,
+                match_number,
+                blue_pools.len(),
+                ladder_match.blue_team.players.len(),
+                red_pools.len(),
+                ladder_match.red_team.players.len()
+            );
             return self.create_error_result(ladder_match, round_number, match_number);
         };
 
-        let blue_power = self.calculate_team_power(
+        // 选手发挥走统一模拟核心：特性修正 + BP 加成 + 稳定性波动，队伍战力按 Meta 加权
+        let blue_rolls = self.roll_team(
             &ladder_match.blue_team,
             player_full_data,
             &draft_result.home_bp_modifiers,
+            &mut rng,
         );
-        let red_power = self.calculate_team_power(
+        let red_rolls = self.roll_team(
             &ladder_match.red_team,
             player_full_data,
             &draft_result.away_bp_modifiers,
+            &mut rng,
         );
+        let blue_power = Self::team_power(&blue_rolls);
+        let red_power = Self::team_power(&red_rolls);
 
-        let (blue_perf, red_perf, winner_id) = self
-            .match_sim_engine
-            .simulate_game(blue_power, red_power, 1, 2);
+        let (blue_perf, red_perf, winner_id, game_duration) = self.match_sim_engine.simulate_phased_game(
+            blue_power,
+            red_power,
+            draft_result.home_comp,
+            draft_result.away_comp,
+            1,
+            2,
+        );
 
         let winner_side = if winner_id == 1 { "blue" } else { "red" };
 
-        // 给每个选手基于 ability 的独立 performance，而非共享同一个值
-        // 否则 influence = player_perf - team_avg 永远为 0
+        // 选手表现 = 个人发挥 + 本局队伍发挥相对战力的偏移，influence 体现个人相对队伍的差异
         let mut performances = HashMap::new();
-        let mut rng_perf = StdRng::from_entropy();
-        for player in &ladder_match.blue_team.players {
-            let ability = player_full_data
-                .get(&player.player_id)
-                .map(|d| d.ability as f64)
-                .unwrap_or(50.0);
-            let noise: f64 = (rng_perf.gen::<f64>() - 0.5) * 6.0;
-            let player_perf = blue_perf + (ability - blue_power) + noise;
-            performances.insert(player.player_id, player_perf);
+        for (player_id, actual, _) in &blue_rolls {
+            performances.insert(*player_id, actual + (blue_perf - blue_power));
         }
-        for player in &ladder_match.red_team.players {
-            let ability = player_full_data
-                .get(&player.player_id)
-                .map(|d| d.ability as f64)
-                .unwrap_or(50.0);
-            let noise: f64 = (rng_perf.gen::<f64>() - 0.5) * 6.0;
-            let player_perf = red_perf + (ability - red_power) + noise;
-            performances.insert(player.player_id, player_perf);
+        for (player_id, actual, _) in &red_rolls {
+            performances.insert(*player_id, actual + (red_perf - red_power));
         }
 
         let (mvp_player_id, mvp_player_name) = self.find_mvp(
@@ -172,7 +189,7 @@ impl LadderSimulator {
             winner_side: winner_side.to_string(),
             mvp_player_id,
             mvp_player_name,
-            game_duration: 30 + (rand::random::<i32>() % 20),
+            game_duration: game_duration as i32,
             performances,
             draft_result: Some(serde_json::to_string(&draft_result).unwrap_or_default()),
         }
@@ -196,32 +213,63 @@ impl LadderSimulator {
         })
     }
 
-    fn calculate_team_power(
+    /// 天梯对局的特性上下文（非正式赛事）
+    fn trait_context(age: u8) -> TraitContext {
+        TraitContext {
+            tournament_type: "ladder".to_string(),
+            is_playoff: false,
+            is_international: false,
+            game_number: 1,
+            score_diff: 0,
+            age,
+            is_first_season: false,
+            games_since_rest: 0,
+        }
+    }
+
+    /// 计算队伍每名选手的单局发挥，返回 (选手ID, 实际发挥, 位置)；缺少数据的选手不计入战力并记录警告
+    fn roll_team(
         &self,
         team: &LadderTeam,
         player_full_data: &HashMap<i64, PlayerFullData>,
         bp_modifiers: &HashMap<u64, f64>,
-    ) -> f64 {
-        let mut total_power = 0.0;
-        let mut count = 0;
-
-        for player in &team.players {
-            if let Some(full_data) = player_full_data.get(&player.player_id) {
-                let base_power = full_data.ability as f64;
-                let bp_modifier = bp_modifiers
-                    .get(&(player.player_id as u64))
-                    .copied()
-                    .unwrap_or(0.0);
-                total_power += base_power + bp_modifier;
-                count += 1;
-            }
-        }
+        rng: &mut impl Rng,
+    ) -> Vec<(i64, f64, String)> {
+        team.players
+            .iter()
+            .filter_map(|player| {
+                let Some(full_data) = player_full_data.get(&player.player_id) else {
+                    log::warn!(
+                        "天梯选手 {}（ID {}）缺少能力数据，不计入队伍战力",
+                        player.game_id,
+                        player.player_id
+                    );
+                    return None;
+                };
+                let roll = SimulationCore::roll_player(
+                    &PerformanceInput {
+                        ability: full_data.ability,
+                        stability: full_data.stability,
+                        condition: 0,
+                        traits: &full_data.traits,
+                        bonus: bp_modifiers.get(&(player.player_id as u64)).copied().unwrap_or(0.0),
+                        condition_adjust: 0,
+                    },
+                    &Self::trait_context(full_data.age),
+                    rng,
+                );
+                Some((player.player_id, roll.actual_ability, player.position.clone()))
+            })
+            .collect()
+    }
 
-        if count > 0 {
-            total_power / count as f64
-        } else {
-            50.0
+    /// 队伍战力：天梯不区分版本，按均衡权重计算
+    fn team_power(rolls: &[(i64, f64, String)]) -> f64 {
+        if rolls.is_empty() {
+            return 50.0;
         }
+        let players: Vec<(f64, &str)> = rolls.iter().map(|(_, actual, pos)| (*actual, pos.as_str())).collect();
+        SimulationCore::team_power(&players, &MetaWeights::balanced())
     }
 
     fn find_mvp(
@@ -316,6 +364,8 @@ impl LadderSimulator {
 #[derive(Debug, Clone)]
 pub struct PlayerFullData {
     pub ability: u8,
+    pub stability: u8,
+    pub age: u8,
     pub masteries: HashMap<u8, crate::engines::champion::MasteryTier>,
     pub traits: Vec<TraitType>,
 }
@@ -98,6 +98,11 @@ pub enum HonorCategory {
     AnnualMostConsistent,
     AnnualMostDominant,
     AnnualRookie,
+    AnnualMostImproved,
+    AnnualComeback,
+    AnnualSixthMan,
+    AnnualBestImport,
+    AnnualRegionalMvp,
 }
 
 impl HonorCategory {
@@ -148,6 +153,11 @@ impl HonorCategory {
             HonorCategory::AnnualMostConsistent => 0.08,
             HonorCategory::AnnualMostDominant => 0.12,
             HonorCategory::AnnualRookie => 0.12,
+            HonorCategory::AnnualMostImproved => 0.08,
+            HonorCategory::AnnualComeback => 0.06,
+            HonorCategory::AnnualSixthMan => 0.05,
+            HonorCategory::AnnualBestImport => 0.10,
+            HonorCategory::AnnualRegionalMvp => 0.15,
         }
     }
 
@@ -190,6 +200,11 @@ impl HonorCategory {
             HonorCategory::AnnualMostConsistent => 1,
             HonorCategory::AnnualMostDominant => 2,
             HonorCategory::AnnualRookie => 2,
+            HonorCategory::AnnualMostImproved => 1,
+            HonorCategory::AnnualComeback => 1,
+            HonorCategory::AnnualSixthMan => 1,
+            HonorCategory::AnnualBestImport => 2,
+            HonorCategory::AnnualRegionalMvp => 2,
         }
     }
 
@@ -364,6 +379,11 @@ impl MarketValueEngine {
             "annualmostconsistent" | "annual_most_consistent" => Some(HonorCategory::AnnualMostConsistent),
             "annualmostdominant" | "annual_most_dominant" => Some(HonorCategory::AnnualMostDominant),
             "annualrookie" | "annual_rookie" => Some(HonorCategory::AnnualRookie),
+            "annualmostimproved" | "annual_most_improved" => Some(HonorCategory::AnnualMostImproved),
+            "annualcomeback" | "annual_comeback" => Some(HonorCategory::AnnualComeback),
+            "annualsixthman" | "annual_sixth_man" => Some(HonorCategory::AnnualSixthMan),
+            "annualbestimport" | "annual_best_import" => Some(HonorCategory::AnnualBestImport),
+            "annualregionalmvp" | "annual_regional_mvp" => Some(HonorCategory::AnnualRegionalMvp),
             // 兼容旧存档
             "annualbesttop" | "annual_best_top" | "annualbestjungle" | "annual_best_jungle"
             | "annualbestmid" | "annual_best_mid" | "annualbestadc" | "annual_best_adc"
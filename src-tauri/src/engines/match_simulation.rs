@@ -1,12 +1,16 @@
+use super::bp_engine::CompType;
+use super::comp_phase::{CompPhaseEngine, CompPhaseRuleset};
 use super::condition::ConditionEngine;
+use super::game_timeline::GameTimelineEngine;
 use super::lineup_engine::{LineupCandidate, LineupEngine, SubstitutionContext};
-use super::meta_engine::{MetaEngine, MetaWeights};
-use super::traits::{TraitContext, TraitEngine, TraitType};
+use super::meta_engine::MetaWeights;
+use super::series_planner::{SeriesGamePlan, SeriesGameState};
+use super::sim_core::{PerformanceInput, SimulationCore, TEAM_PERFORMANCE_SIGMA};
+use super::traits::{TraitContext, TraitType};
 use super::PlayerFormFactors;
 use crate::models::player::Position;
 use crate::models::transfer::AITeamPersonality;
 use crate::models::{Match, MatchFormat, MatchGame, MatchResult, MatchStatus};
-use rand_distr::{Distribution, Normal};
 use std::collections::HashMap;
 use std::sync::Mutex;
 
@@ -52,14 +56,13 @@ pub struct MatchSimulationEngine {
     /// 标准差 (控制发挥波动程度)
     std_dev: f64,
     last_games_played: Mutex<HashMap<u64, u8>>,
+    /// 阵容强势期规则
+    phase_ruleset: CompPhaseRuleset,
 }
 
 impl Default for MatchSimulationEngine {
     fn default() -> Self {
-        Self {
-            std_dev: 6.0,
-            last_games_played: Mutex::new(HashMap::new()),
-        }
+        Self::new(TEAM_PERFORMANCE_SIGMA)
     }
 }
 
@@ -68,18 +71,18 @@ impl MatchSimulationEngine {
         Self {
             std_dev,
             last_games_played: Mutex::new(HashMap::new()),
+            phase_ruleset: CompPhaseRuleset::default(),
         }
     }
 
-    pub fn take_last_games_played(&self) -> HashMap<u64, u8> {
-        std::mem::take(&mut *self.last_games_played.lock().unwrap())
+    /// 使用指定的阵容强势期规则（如存档覆盖的规则）
+    pub fn with_phase_ruleset(mut self, ruleset: CompPhaseRuleset) -> Self {
+        self.phase_ruleset = ruleset;
+        self
     }
 
-    /// 基于Box-Muller变换生成正态分布随机数
-    fn gaussian_random(&self, mean: f64) -> f64 {
-        let normal = Normal::new(mean, self.std_dev).unwrap();
-        let mut rng = rand::thread_rng();
-        normal.sample(&mut rng)
+    pub fn take_last_games_played(&self) -> HashMap<u64, u8> {
+        std::mem::take(&mut *self.last_games_played.lock().unwrap())
     }
 
     /// 模拟单局比赛
@@ -91,8 +94,8 @@ impl MatchSimulationEngine {
         home_team_id: u64,
         away_team_id: u64,
     ) -> (f64, f64, u64) {
-        let home_performance = self.gaussian_random(home_power);
-        let away_performance = self.gaussian_random(away_power);
+        let (home_performance, away_performance) =
+            SimulationCore::sample_game(home_power, away_power, self.std_dev, &mut rand::thread_rng());
 
         let winner_id = if home_performance > away_performance {
             home_team_id
@@ -103,6 +106,58 @@ impl MatchSimulationEngine {
         (home_performance, away_performance, winner_id)
     }
 
+    /// 按阵容强势期分阶段模拟单局
+    /// 返回: (主队发挥值, 客队发挥值, 获胜队伍ID, 比赛时长)
+    pub fn simulate_phased_game(
+        &self,
+        home_power: f64,
+        away_power: f64,
+        home_comp: Option<CompType>,
+        away_comp: Option<CompType>,
+        home_team_id: u64,
+        away_team_id: u64,
+    ) -> (f64, f64, u64, u32) {
+        self.resolve_phased_game(
+            home_power,
+            away_power,
+            home_comp,
+            away_comp,
+            self.std_dev,
+            home_team_id,
+            away_team_id,
+        )
+    }
+
+    /// 指定发挥波动的分阶段结算（局间战术会放大波动）
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_phased_game(
+        &self,
+        home_power: f64,
+        away_power: f64,
+        home_comp: Option<CompType>,
+        away_comp: Option<CompType>,
+        std_dev: f64,
+        home_team_id: u64,
+        away_team_id: u64,
+    ) -> (f64, f64, u64, u32) {
+        let outcome = CompPhaseEngine::resolve_game(
+            &self.phase_ruleset,
+            home_power,
+            away_power,
+            home_comp,
+            away_comp,
+            std_dev,
+            &mut rand::thread_rng(),
+        );
+        let winner_id = if outcome.home_won { home_team_id } else { away_team_id };
+        (
+            outcome.home_performance,
+            outcome.away_performance,
+            winner_id,
+            outcome.duration_minutes,
+        )
+    }
+
     /// 模拟BO系列赛
     pub fn simulate_match(
         &self,
@@ -143,7 +198,10 @@ impl MatchSimulationEngine {
                 home_performance: home_perf,
                 away_performance: away_perf,
                 winner_id,
-                duration_minutes: Some(30 + rand::random::<u32>() % 20), // 30-50分钟
+                duration_minutes: Some(GameTimelineEngine::estimate_duration(
+                    home_perf - away_perf,
+                    &mut rand::thread_rng(),
+                )),
             };
 
             games.push(game);
@@ -190,16 +248,15 @@ impl MatchSimulationEngine {
     /// 计算胜率 (基于战力值差距)
     /// 用于显示预测胜率
     pub fn calculate_win_probability(&self, team_power: f64, opponent_power: f64) -> f64 {
-        let diff = team_power - opponent_power;
-        // 使用简化的正态分布CDF近似
-        let z = diff / (self.std_dev * std::f64::consts::SQRT_2);
-        0.5 * (1.0 + erf_approx(z))
+        SimulationCore::win_probability(team_power, opponent_power, self.std_dev)
     }
 
     /// 特性感知的BO系列赛模拟
     ///
     /// 在每局比赛中根据当前局数、比分差、赛事类型构建 TraitContext，
     /// 对每位选手应用特性修正后计算队伍战力，再用正态分布采样决定胜负。
+    /// `game_planner` 在每局开赛前完成选边、BP 与局间战术（见 `AutoSeriesPlanner`），
+    /// 传入时按本局阵容体系分阶段结算并计入地图边与战术修正；未传入则不计 BP 与地图边影响。
     pub fn simulate_match_with_traits(
         &self,
         match_id: u64,
@@ -217,6 +274,7 @@ impl MatchSimulationEngine {
         home_personality: &AITeamPersonality,
         away_personality: &AITeamPersonality,
         current_season: u32,
+        mut game_planner: Option<&mut dyn FnMut(&SeriesGameState) -> SeriesGamePlan>,
     ) -> MatchResult {
         self.last_games_played.lock().unwrap().clear();
 
@@ -250,8 +308,31 @@ impl MatchSimulationEngine {
         let mut games = Vec::new();
         let mut game_number: u8 = 1;
         let mut games_played_series: HashMap<u64, u8> = HashMap::new();
+        let mut prev_game: Option<(bool, f64)> = None;
 
         while home_score < wins_needed && away_score < wins_needed {
+            // 逐局赛前准备（选边、BP、局间战术），BP 加成作用于本局上场选手
+            let plan = game_planner.as_deref_mut().map(|plan_game| {
+                plan_game(&SeriesGameState {
+                    game_number,
+                    home_score,
+                    away_score,
+                    wins_needed,
+                    prev_home_won: prev_game.map(|(home_won, _)| home_won),
+                    prev_performance_gap: prev_game.map(|(_, gap)| gap).unwrap_or(0.0),
+                    home_lineup: &current_home,
+                    away_lineup: &current_away,
+                })
+            });
+            if let Some(plan) = &plan {
+                for p in current_home.iter_mut() {
+                    p.bp_modifier = plan.home_bp_modifiers.get(&p.player_id).copied().unwrap_or(0.0);
+                }
+                for p in current_away.iter_mut() {
+                    p.bp_modifier = plan.away_bp_modifiers.get(&p.player_id).copied().unwrap_or(0.0);
+                }
+            }
+
             let home_has_leader = current_home
                 .iter()
                 .any(|p| p.traits.contains(&TraitType::TeamLeader));
@@ -278,8 +359,27 @@ impl MatchSimulationEngine {
                 away_has_leader,
             );
 
-            let (home_perf, away_perf, winner_id) =
-                self.simulate_game(home_power, away_power, home_team_id, away_team_id);
+            // 有赛前准备时计入地图边与局间战术修正，并按强势期分阶段结算，时长由决出胜负的阶段决定
+            let (home_perf, away_perf, winner_id, duration) = match &plan {
+                Some(plan) => self.resolve_phased_game(
+                    home_power + plan.home_power_shift,
+                    away_power,
+                    plan.home_comp,
+                    plan.away_comp,
+                    self.std_dev * plan.variance_scale,
+                    home_team_id,
+                    away_team_id,
+                ),
+                None => {
+                    let (home_perf, away_perf, winner_id) =
+                        self.simulate_game(home_power, away_power, home_team_id, away_team_id);
+                    let duration = GameTimelineEngine::estimate_duration(
+                        home_perf - away_perf,
+                        &mut rand::thread_rng(),
+                    );
+                    (home_perf, away_perf, winner_id, duration)
+                }
+            };
 
             let game = MatchGame {
                 id: 0,
@@ -298,7 +398,7 @@ impl MatchSimulationEngine {
                 home_performance: home_perf,
                 away_performance: away_perf,
                 winner_id,
-                duration_minutes: Some(30 + rand::random::<u32>() % 20),
+                duration_minutes: Some(duration),
             };
 
             games.push(game);
@@ -312,6 +412,7 @@ impl MatchSimulationEngine {
             } else {
                 away_score += 1;
             }
+            prev_game = Some((winner_id == home_team_id, home_perf - away_perf));
 
             // 局间换人（BO系列赛且比赛未结束）
             if bo_count > 1 && home_score < wins_needed && away_score < wins_needed {
@@ -541,45 +642,31 @@ impl MatchSimulationEngine {
                 games_since_rest: 0,
             };
 
-            // 计算特性综合修正
-            let modifiers = TraitEngine::calculate_combined_modifiers(&player.traits, &trait_ctx);
-
-            // 应用修正到基础属性
-            let (modified_ability, modified_stability, mut modified_condition, ability_ceiling) =
-                TraitEngine::apply_modifiers(
-                    player.ability,
-                    player.stability,
-                    player.condition,
-                    &modifiers,
-                );
-            raw_modified_abilities.push(modified_ability as f64);
+            // TeamLeader 队友加成：condition +1（对非 TeamLeader 本人）
+            let condition_adjust =
+                i8::from(team_leader_bonus && !player.traits.contains(&TraitType::TeamLeader));
+
+            // 特性修正 → 稳定性噪声 → 钳位（统一模拟核心）
+            let roll = SimulationCore::roll_player(
+                &PerformanceInput {
+                    ability: player.ability,
+                    stability: player.stability,
+                    condition: player.condition,
+                    traits: &player.traits,
+                    bonus: player.bp_modifier,
+                    condition_adjust,
+                },
+                &trait_ctx,
+                &mut rng,
+            );
+            raw_modified_abilities.push(roll.modified_ability);
             total_bp_bonus += player.bp_modifier;
             total_version_bonus += player.champion_version_score;
-
-            // TeamLeader 队友加成：condition +1（对非 TeamLeader 本人）
-            if team_leader_bonus && !player.traits.contains(&TraitType::TeamLeader) {
-                modified_condition = (modified_condition as i16 + 1).clamp(-10, 10) as i8;
-            }
-
-            // 稳定性噪声: N(0, (100 - modified_stability) / 10)
-            let sigma = (100.0 - modified_stability as f64) / 10.0;
-            let normal = Normal::new(0.0, sigma.max(0.1)).unwrap();
-            let noise: f64 = normal.sample(&mut rng);
-
-            // 实际能力 = modified_ability + modified_condition + noise
-            let raw_ability =
-                modified_ability as f64 + player.bp_modifier + modified_condition as f64 + noise;
-
-            // 钳位到 [ability - 15, ability_ceiling]
-            let min_ability = (modified_ability as f64 - 15.0).max(0.0);
-            let max_ability = (ability_ceiling as f64).min(100.0);
-            let actual_ability = raw_ability.clamp(min_ability, max_ability);
-
-            player_abilities.push((actual_ability, player.position.as_str()));
+            player_abilities.push((roll.actual_ability, player.position.as_str()));
         }
 
         // 使用 Meta 加权计算队伍战力
-        let meta_power = MetaEngine::calculate_team_power_weighted(&player_abilities, meta_weights);
+        let meta_power = SimulationCore::team_power(&player_abilities, meta_weights);
         let player_count = players.len() as f64;
         let breakdown = if player_count > 0.0 {
             TeamPowerBreakdown {
@@ -600,24 +687,6 @@ impl MatchSimulationEngine {
     }
 }
 
-/// 误差函数的近似计算
-fn erf_approx(x: f64) -> f64 {
-    let a1 = 0.254829592;
-    let a2 = -0.284496736;
-    let a3 = 1.421413741;
-    let a4 = -1.453152027;
-    let a5 = 1.061405429;
-    let p = 0.3275911;
-
-    let sign = if x < 0.0 { -1.0 } else { 1.0 };
-    let x = x.abs();
-
-    let t = 1.0 / (1.0 + p * x);
-    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
-
-    sign * y
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -736,6 +805,10 @@ mod tests {
         // 战力低时胜率应该更低
         let prob_lower = engine.calculate_win_probability(60.0, 70.0);
         assert!(prob_lower < 0.5);
+
+        // 赛前预测与实际结算同分布：战力差 6 时为 Φ(6 / 6√2) ≈ 76%（统一前为 Φ(1) ≈ 84%）
+        let prob_edge = engine.calculate_win_probability(76.0, 70.0);
+        assert!((prob_edge - 0.760).abs() < 0.002, "prob_edge = {}", prob_edge);
     }
 
     #[test]
@@ -859,6 +932,7 @@ mod tests {
             &AITeamPersonality::Balanced,
             &AITeamPersonality::Balanced,
             1,
+            None,
         );
 
         assert!(result.games.len() >= 2 && result.games.len() <= 3);
@@ -893,6 +967,7 @@ mod tests {
             &AITeamPersonality::Balanced,
             &AITeamPersonality::Balanced,
             1,
+            None,
         );
 
         assert!(result.games.len() >= 3 && result.games.len() <= 5);
@@ -929,6 +1004,7 @@ mod tests {
                 &AITeamPersonality::Balanced,
                 &AITeamPersonality::Balanced,
                 1,
+                None,
             );
             if result.winner_id == 1 {
                 home_wins += 1;
@@ -975,6 +1051,7 @@ mod tests {
                 &AITeamPersonality::Balanced,
                 &AITeamPersonality::Balanced,
                 1,
+                None,
             );
             if result.winner_id == 1 {
                 leader_wins += 1;
@@ -2,13 +2,18 @@
 //!
 //! 管理电竞赛季的版本（Meta）系统：
 //! - 20 种不同的 Meta 类型，每种定义 5 个位置的权重
-//! - 每赛季随机切换版本（S1 固定为 Balanced，不连续重复）
+//! - 版本演化：新赛季根据上赛季各阵容体系的出场与胜率决定下一个版本（S1 固定为 Balanced）
+//! - 季中补丁：马德里大师赛、夏季常规赛开始前，单一体系过于统治时调整版本
 //! - 加权平均 + carry/drag 效应的队伍战力计算
 
+use std::collections::HashMap;
+
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Row, Sqlite};
+use super::bp_engine::CompType;
 use super::champion::Archetype;
+use crate::models::SeasonPhase;
 
 /// Meta 类型枚举
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -192,6 +197,24 @@ impl MetaType {
             MetaType::MidJungleSynergy => &[Archetype::Scaling, Archetype::Splitpush],
         }
     }
+
+    /// 蓝色方战力修正（红色方取相反数）
+    ///
+    /// 前期节奏版本里先手锁定强势英雄更重要，蓝方优势更大；
+    /// 单人线 carry 与后期版本里最后的 counter 位更值钱，红方反而占优。
+    pub fn blue_side_bonus(&self) -> f64 {
+        match self {
+            MetaType::EarlyGameAggro | MetaType::ObjectiveControl => 0.8,
+            MetaType::JungleTempo | MetaType::DiveComposition => 0.7,
+            MetaType::SkirmishMeta | MetaType::PickComposition | MetaType::MidJungleSynergy => 0.6,
+            MetaType::Balanced | MetaType::VisionControl | MetaType::SupportEra => 0.4,
+            MetaType::MidKingdom | MetaType::TeamfightMeta | MetaType::BotLaneDominance => 0.3,
+            MetaType::DualCarry | MetaType::ProtectTheCarry => 0.1,
+            MetaType::LateGameScaling | MetaType::SoloLaneMeta => -0.2,
+            MetaType::SplitPushMeta | MetaType::TopJungleSynergy => -0.3,
+            MetaType::TopLaneCarry => -0.4,
+        }
+    }
 }
 
 /// 位置权重（5 个位置的权重之和 = 5.0）
@@ -253,78 +276,426 @@ pub fn get_meta_weights(meta: MetaType) -> MetaWeights {
 const CARRY_RATE: f64 = 0.3;
 const DRAG_RATE: f64 = 0.5;
 
+/// 版本演化：风格胜率向 50% 收缩的先验场次
+const TREND_PRIOR_GAMES: f64 = 20.0;
+/// 赛季更替时参考数据的最少阵容场次，不足时随机轮换
+const MIN_EVOLUTION_SAMPLE: u32 = 40;
+/// 季中调整所需的最少阵容场次
+const MIN_MID_SEASON_SAMPLE: u32 = 30;
+/// 单一风格出场占比超过该值且胜率领先时，季中补丁予以削弱
+const OVERCENTRALIZED_SHARE: f64 = 0.4;
+const OVERCENTRALIZED_WIN_RATE: f64 = 0.52;
+/// 版本主推风格的胜率低于该值时，季中补丁调整版本
+const UNDERPERFORMING_WIN_RATE: f64 = 0.46;
+/// 候选版本权重：主推崛起风格、克制统治风格的加成，以及继续主推统治风格的衰减
+const RISING_WEIGHT: f64 = 3.0;
+const COUNTER_WEIGHT: f64 = 1.5;
+const DOMINANT_DECAY: f64 = 0.2;
+
+/// 阵容体系的出场与胜场
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CompRecord {
+    pub games: u32,
+    pub wins: u32,
+}
+
+/// 按阵容核心风格聚合的走势
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArchetypeTrend {
+    pub archetype: Archetype,
+    pub games: u32,
+    /// 出场占比（按阵容方计）
+    pub share: f64,
+    /// 平滑后的胜率
+    pub win_rate: f64,
+}
+
+/// 版本更替决策
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetaShift {
+    pub meta: MetaType,
+    pub reason: String,
+}
+
+/// 版本补丁记录（meta_versions 的一行）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetaPatch {
+    pub season_id: i64,
+    pub patch_number: i64,
+    pub phase: Option<String>,
+    pub meta: MetaType,
+    pub reason: Option<String>,
+}
+
 /// Meta 引擎
 pub struct MetaEngine;
 
 impl MetaEngine {
-    /// 为新赛季生成 Meta 版本并写入数据库
-    /// S1 固定为 Balanced，S2+ 随机抽取（不连续重复）
-    pub async fn roll_new_meta(
+    /// 季中版本调整的时间点：马德里大师赛、夏季常规赛开始前
+    pub fn is_mid_season_patch_phase(phase: SeasonPhase) -> bool {
+        matches!(phase, SeasonPhase::MadridMasters | SeasonPhase::SummerRegular)
+    }
+
+    /// 按阵容核心风格聚合出场占比与胜率（全能阵容不计入任何风格）
+    pub fn archetype_trends(records: &HashMap<CompType, CompRecord>) -> Vec<ArchetypeTrend> {
+        let total: u32 = records.values().map(|r| r.games).sum();
+        Archetype::all()
+            .iter()
+            .map(|&archetype| {
+                let (games, wins) = records
+                    .iter()
+                    .filter(|(comp, _)| **comp != CompType::Flex && comp.core_archetypes().contains(&archetype))
+                    .fold((0u32, 0u32), |(g, w), (_, r)| (g + r.games, w + r.wins));
+                ArchetypeTrend {
+                    archetype,
+                    games,
+                    share: if total > 0 { games as f64 / total as f64 } else { 0.0 },
+                    win_rate: (wins as f64 + TREND_PRIOR_GAMES * 0.5) / (games as f64 + TREND_PRIOR_GAMES),
+                }
+            })
+            .collect()
+    }
+
+    /// 版本演化：上一阶段统治的风格被削弱，胜率高但出场少的风格成为新主流
+    ///
+    /// 数据不足时退化为不连续重复的随机轮换
+    pub fn evolve(current: MetaType, records: &HashMap<CompType, CompRecord>, rng: &mut impl Rng) -> MetaShift {
+        let candidates: Vec<MetaType> = MetaType::all().iter().copied().filter(|m| *m != current).collect();
+        let total: u32 = records.values().map(|r| r.games).sum();
+        if total < MIN_EVOLUTION_SAMPLE {
+            let meta = candidates[rng.gen_range(0..candidates.len())];
+            return MetaShift {
+                meta,
+                reason: format!("比赛样本不足，版本常规轮换至{}", meta.display_name()),
+            };
+        }
+
+        let trends = Self::archetype_trends(records);
+        let dominant = trends
+            .iter()
+            .copied()
+            .max_by(|a, b| a.share.total_cmp(&b.share).then(a.win_rate.total_cmp(&b.win_rate)))
+            .expect("风格列表非空");
+        let rising = trends
+            .iter()
+            .copied()
+            .filter(|t| t.archetype != dominant.archetype)
+            .max_by(|a, b| a.win_rate.total_cmp(&b.win_rate))
+            .expect("风格列表非空");
+
+        let weight = |meta: MetaType| {
+            let mut w = 1.0;
+            if meta.favored_archetypes().contains(&rising.archetype) {
+                w += RISING_WEIGHT;
+            }
+            if meta.disfavored_archetypes().contains(&dominant.archetype) {
+                w += COUNTER_WEIGHT;
+            }
+            if meta.favored_archetypes().contains(&dominant.archetype) {
+                w *= DOMINANT_DECAY;
+            }
+            w
+        };
+        let total_weight: f64 = candidates.iter().map(|m| weight(*m)).sum();
+        let mut roll = rng.gen::<f64>() * total_weight;
+        let meta = candidates
+            .iter()
+            .copied()
+            .find(|m| {
+                roll -= weight(*m);
+                roll <= 0.0
+            })
+            .unwrap_or(candidates[candidates.len() - 1]);
+
+        let summary = format!(
+            "{}体系最流行（出场{:.0}%，胜率{:.0}%）",
+            dominant.archetype.display_name(),
+            dominant.share * 100.0,
+            dominant.win_rate * 100.0
+        );
+        let reason = if meta.favored_archetypes().contains(&rising.archetype) {
+            format!(
+                "{}；{}体系胜率{:.0}%后来居上，版本转向{}",
+                summary,
+                rising.archetype.display_name(),
+                rising.win_rate * 100.0,
+                meta.display_name()
+            )
+        } else if meta.disfavored_archetypes().contains(&dominant.archetype) {
+            format!("{}；设计师削弱该体系，{}对其形成克制", summary, meta.display_name())
+        } else {
+            format!("{}；版本常规轮换至{}", summary, meta.display_name())
+        };
+        MetaShift { meta, reason }
+    }
+
+    /// 季中版本调整：单一风格过于统治或版本主推风格表现不佳时才调整
+    pub fn mid_season_shift(
+        current: MetaType,
+        records: &HashMap<CompType, CompRecord>,
+        rng: &mut impl Rng,
+    ) -> Option<MetaShift> {
+        let total: u32 = records.values().map(|r| r.games).sum();
+        if total < MIN_MID_SEASON_SAMPLE {
+            return None;
+        }
+
+        let trends = Self::archetype_trends(records);
+        let overcentralized = trends
+            .iter()
+            .find(|t| t.share >= OVERCENTRALIZED_SHARE && t.win_rate >= OVERCENTRALIZED_WIN_RATE);
+        let favored: Vec<&ArchetypeTrend> = trends
+            .iter()
+            .filter(|t| current.favored_archetypes().contains(&t.archetype) && t.games > 0)
+            .collect();
+        let favored_win_rate = if favored.is_empty() {
+            None
+        } else {
+            Some(favored.iter().map(|t| t.win_rate).sum::<f64>() / favored.len() as f64)
+        };
+
+        let trigger = if let Some(t) = overcentralized {
+            format!(
+                "季中补丁：{}体系出场{:.0}%、胜率{:.0}%过于统治",
+                t.archetype.display_name(),
+                t.share * 100.0,
+                t.win_rate * 100.0
+            )
+        } else if let Some(rate) = favored_win_rate.filter(|r| *r < UNDERPERFORMING_WIN_RATE) {
+            format!(
+                "季中补丁：{}主推体系胜率仅{:.0}%",
+                current.display_name(),
+                rate * 100.0
+            )
+        } else {
+            return None;
+        };
+
+        let shift = Self::evolve(current, records, rng);
+        Some(MetaShift {
+            meta: shift.meta,
+            reason: format!("{}。{}", trigger, shift.reason),
+        })
+    }
+
+    // ==================== 数据库操作 ====================
+
+    /// 阵容体系的出场与胜场（赛季内 game_draft_results 行号大于 since_rowid 的对局）
+    pub async fn load_comp_records(
         pool: &Pool<Sqlite>,
         save_id: &str,
         season_id: i64,
-    ) -> Result<MetaType, String> {
-        let meta = if season_id == 1 {
-            MetaType::Balanced
-        } else {
-            // 查询上一赛季的 meta
-            let prev_meta: Option<String> = sqlx::query_scalar(
-                "SELECT meta_type FROM meta_versions WHERE save_id = ? AND season_id = ? LIMIT 1"
-            )
-            .bind(save_id)
-            .bind(season_id - 1)
-            .fetch_optional(pool)
-            .await
-            .map_err(|e| format!("查询上赛季版本失败: {}", e))?;
-
-            let mut rng = rand::thread_rng();
-            let all_metas = MetaType::all();
-            loop {
-                let idx = rng.gen_range(0..all_metas.len());
-                let candidate = all_metas[idx];
-                // 不连续重复
-                if let Some(ref prev) = prev_meta {
-                    if candidate.id() == prev.as_str() {
-                        continue;
+        since_rowid: i64,
+    ) -> Result<HashMap<CompType, CompRecord>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT d.home_comp, d.away_comp, m.home_team_id, g.winner_team_id
+            FROM game_draft_results d
+            JOIN match_games g ON g.save_id = d.save_id
+                 AND g.match_id = d.match_id AND g.game_number = d.game_number
+            JOIN matches m ON m.save_id = d.save_id AND m.id = d.match_id
+            JOIN tournaments t ON m.tournament_id = t.id AND t.save_id = d.save_id
+            WHERE d.save_id = ? AND t.season_id = ? AND d.rowid > ?
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(since_rowid)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询阵容体系数据失败: {}", e))?;
+
+        let mut records: HashMap<CompType, CompRecord> = HashMap::new();
+        for row in &rows {
+            let home_won = row.get::<i64, _>("home_team_id") == row.get::<i64, _>("winner_team_id");
+            let sides = [
+                (row.get::<Option<String>, _>("home_comp"), home_won),
+                (row.get::<Option<String>, _>("away_comp"), !home_won),
+            ];
+            for (comp, won) in sides {
+                if let Some(comp) = comp.as_deref().and_then(CompType::from_id) {
+                    let entry = records.entry(comp).or_default();
+                    entry.games += 1;
+                    if won {
+                        entry.wins += 1;
                     }
                 }
-                break candidate;
             }
-        };
+        }
+        Ok(records)
+    }
 
-        // 获取权重
-        let weights = get_meta_weights(meta);
+    /// 赛季当前生效的版本补丁（最新一次）
+    pub async fn load_current_patch(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+    ) -> Result<Option<(MetaPatch, i64)>, String> {
+        let row = sqlx::query(
+            r#"
+            SELECT season_id, patch_number, phase, meta_type, reason, since_draft_rowid
+            FROM meta_versions
+            WHERE save_id = ? AND season_id = ?
+            ORDER BY patch_number DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询版本失败: {}", e))?;
+
+        Ok(row.map(|r| {
+            let patch = MetaPatch {
+                season_id: r.get("season_id"),
+                patch_number: r.get("patch_number"),
+                phase: r.get("phase"),
+                meta: MetaType::from_id(&r.get::<String, _>("meta_type")).unwrap_or(MetaType::Balanced),
+                reason: r.get("reason"),
+            };
+            (patch, r.get::<i64, _>("since_draft_rowid"))
+        }))
+    }
+
+    /// 写入一次版本补丁；since_draft_rowid 为补丁生效时 game_draft_results 的最大行号
+    async fn save_patch(pool: &Pool<Sqlite>, save_id: &str, patch: &MetaPatch) -> Result<(), String> {
+        let since_draft_rowid: i64 = sqlx::query_scalar(
+            "SELECT COALESCE(MAX(rowid), 0) FROM game_draft_results WHERE save_id = ?"
+        )
+        .bind(save_id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询BP记录失败: {}", e))?;
+        let weights = get_meta_weights(patch.meta);
 
-        // 写入数据库
         sqlx::query(
             r#"
-            INSERT INTO meta_versions (save_id, season_id, meta_type, meta_name, weight_top, weight_jug, weight_mid, weight_adc, weight_sup)
-            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
-            ON CONFLICT(save_id, season_id) DO UPDATE SET
+            INSERT INTO meta_versions (
+                save_id, season_id, patch_number, phase, meta_type, meta_name,
+                weight_top, weight_jug, weight_mid, weight_adc, weight_sup, reason, since_draft_rowid
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(save_id, season_id, patch_number) DO UPDATE SET
+                phase = excluded.phase,
                 meta_type = excluded.meta_type,
                 meta_name = excluded.meta_name,
                 weight_top = excluded.weight_top,
                 weight_jug = excluded.weight_jug,
                 weight_mid = excluded.weight_mid,
                 weight_adc = excluded.weight_adc,
-                weight_sup = excluded.weight_sup
+                weight_sup = excluded.weight_sup,
+                reason = excluded.reason,
+                since_draft_rowid = excluded.since_draft_rowid
             "#,
         )
         .bind(save_id)
-        .bind(season_id)
-        .bind(meta.id())
-        .bind(meta.display_name())
+        .bind(patch.season_id)
+        .bind(patch.patch_number)
+        .bind(&patch.phase)
+        .bind(patch.meta.id())
+        .bind(patch.meta.display_name())
         .bind(weights.top)
         .bind(weights.jug)
         .bind(weights.mid)
         .bind(weights.adc)
         .bind(weights.sup)
+        .bind(&patch.reason)
+        .bind(since_draft_rowid)
         .execute(pool)
         .await
         .map_err(|e| format!("写入 Meta 版本失败: {}", e))?;
 
-        log::info!("S{} 版本: {} ({})", season_id, meta.display_name(), meta.id());
-        Ok(meta)
+        Ok(())
+    }
+
+    /// 为新赛季生成 Meta 版本并写入数据库
+    /// S1 固定为 Balanced，S2+ 根据上赛季各阵容体系的出场与胜率演化
+    pub async fn roll_new_meta(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+    ) -> Result<MetaType, String> {
+        let shift = if season_id == 1 {
+            MetaShift {
+                meta: MetaType::Balanced,
+                reason: "首个赛季使用均衡版本".to_string(),
+            }
+        } else {
+            let prev_meta = Self::load_current_patch(pool, save_id, season_id - 1)
+                .await?
+                .map(|(patch, _)| patch.meta)
+                .unwrap_or(MetaType::Balanced);
+            let records = Self::load_comp_records(pool, save_id, season_id - 1, 0).await?;
+            Self::evolve(prev_meta, &records, &mut rand::thread_rng())
+        };
+
+        Self::save_patch(
+            pool,
+            save_id,
+            &MetaPatch {
+                season_id,
+                patch_number: 1,
+                phase: Some(format!("{:?}", SeasonPhase::SpringRegular)),
+                meta: shift.meta,
+                reason: Some(shift.reason.clone()),
+            },
+        )
+        .await?;
+
+        log::info!("S{} 版本: {} ({}) - {}", season_id, shift.meta.display_name(), shift.meta.id(), shift.reason);
+        Ok(shift.meta)
+    }
+
+    /// 季中版本调整（马德里大师赛、夏季常规赛开始前），只参考上次补丁之后的对局；
+    /// 未调整或该阶段已调整过时返回 None
+    pub async fn apply_mid_season_shift(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        phase: SeasonPhase,
+    ) -> Result<Option<MetaPatch>, String> {
+        if !Self::is_mid_season_patch_phase(phase) {
+            return Ok(None);
+        }
+        let phase_str = format!("{:?}", phase);
+        let existing: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM meta_versions WHERE save_id = ? AND season_id = ? AND phase = ?"
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(&phase_str)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询版本失败: {}", e))?;
+        if existing > 0 {
+            return Ok(None);
+        }
+
+        let Some((current, since_rowid)) = Self::load_current_patch(pool, save_id, season_id).await? else {
+            return Ok(None);
+        };
+        let records = Self::load_comp_records(pool, save_id, season_id, since_rowid).await?;
+        let Some(shift) = Self::mid_season_shift(current.meta, &records, &mut rand::thread_rng()) else {
+            log::debug!("S{} {} 前版本保持 {}", season_id, phase.name(), current.meta.display_name());
+            return Ok(None);
+        };
+
+        let patch = MetaPatch {
+            season_id,
+            patch_number: current.patch_number + 1,
+            phase: Some(phase_str),
+            meta: shift.meta,
+            reason: Some(shift.reason),
+        };
+        Self::save_patch(pool, save_id, &patch).await?;
+        log::info!(
+            "S{} 季中版本调整: {} → {}",
+            season_id,
+            current.meta.display_name(),
+            patch.meta.display_name()
+        );
+        Ok(Some(patch))
     }
 
     /// 获取当前赛季的 Meta 权重，如果没有则返回均衡权重
@@ -334,7 +705,7 @@ impl MetaEngine {
         season_id: i64,
     ) -> Result<MetaWeights, String> {
         let row = sqlx::query(
-            "SELECT weight_top, weight_jug, weight_mid, weight_adc, weight_sup FROM meta_versions WHERE save_id = ? AND season_id = ?"
+            "SELECT weight_top, weight_jug, weight_mid, weight_adc, weight_sup FROM meta_versions WHERE save_id = ? AND season_id = ? ORDER BY patch_number DESC LIMIT 1"
         )
         .bind(save_id)
         .bind(season_id)
@@ -549,4 +920,108 @@ mod tests {
         assert!((w.weight_for_position("BOT") - 0.95).abs() < 0.001);
         assert!((w.weight_for_position("SUPPORT") - 0.90).abs() < 0.001);
     }
+
+    fn records(entries: &[(CompType, u32, u32)]) -> HashMap<CompType, CompRecord> {
+        entries
+            .iter()
+            .map(|&(comp, games, wins)| (comp, CompRecord { games, wins }))
+            .collect()
+    }
+
+    #[test]
+    fn test_archetype_trends() {
+        let data = records(&[
+            (CompType::Rush, 30, 20),
+            (CompType::Teamfight, 10, 3),
+            (CompType::Flex, 10, 5),
+        ]);
+        let trends = MetaEngine::archetype_trends(&data);
+        let aggressive = trends.iter().find(|t| t.archetype == Archetype::Aggressive).unwrap();
+        assert_eq!(aggressive.games, 30);
+        assert!((aggressive.share - 0.6).abs() < 1e-9);
+        // (20 + 10) / (30 + 20)
+        assert!((aggressive.win_rate - 0.6).abs() < 1e-9);
+
+        // 没有出场的风格胜率为 50%
+        let splitpush = trends.iter().find(|t| t.archetype == Archetype::Splitpush).unwrap();
+        assert_eq!(splitpush.games, 0);
+        assert!((splitpush.win_rate - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_evolve_without_data_rotates() {
+        use rand::SeedableRng;
+        for seed in 0..50 {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            let shift = MetaEngine::evolve(MetaType::MidKingdom, &HashMap::new(), &mut rng);
+            assert_ne!(shift.meta, MetaType::MidKingdom);
+            assert!(shift.reason.contains("样本不足"));
+        }
+    }
+
+    #[test]
+    fn test_evolve_follows_previous_season() {
+        use rand::SeedableRng;
+        // 激进体系统治，团战体系出场少但胜率高
+        let data = records(&[
+            (CompType::Rush, 60, 36),
+            (CompType::Teamfight, 20, 15),
+            (CompType::Protect, 20, 9),
+        ]);
+
+        let mut favors_rising = 0;
+        let mut favors_dominant = 0;
+        for seed in 0..200 {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+            let shift = MetaEngine::evolve(MetaType::Balanced, &data, &mut rng);
+            assert_ne!(shift.meta, MetaType::Balanced);
+            assert!(!shift.reason.is_empty());
+            if shift.meta.favored_archetypes().contains(&Archetype::Teamfight) {
+                favors_rising += 1;
+            }
+            if shift.meta.favored_archetypes().contains(&Archetype::Aggressive) {
+                favors_dominant += 1;
+            }
+        }
+        assert!(favors_rising > favors_dominant, "{} vs {}", favors_rising, favors_dominant);
+
+        // 同一种子结果一致
+        let a = MetaEngine::evolve(MetaType::Balanced, &data, &mut rand::rngs::StdRng::seed_from_u64(9));
+        let b = MetaEngine::evolve(MetaType::Balanced, &data, &mut rand::rngs::StdRng::seed_from_u64(9));
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_mid_season_shift_triggers() {
+        use rand::SeedableRng;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+        // 样本不足不调整
+        let small = records(&[(CompType::Rush, 20, 18)]);
+        assert!(MetaEngine::mid_season_shift(MetaType::Balanced, &small, &mut rng).is_none());
+
+        // 各体系均衡不调整
+        let even = records(&[
+            (CompType::Rush, 10, 5),
+            (CompType::Protect, 10, 5),
+            (CompType::Control, 10, 5),
+            (CompType::Splitpush, 10, 5),
+            (CompType::Teamfight, 10, 5),
+        ]);
+        assert!(MetaEngine::mid_season_shift(MetaType::Balanced, &even, &mut rng).is_none());
+
+        // 单一体系过于统治时调整
+        let dominant = records(&[
+            (CompType::Rush, 30, 22),
+            (CompType::Protect, 10, 4),
+            (CompType::Teamfight, 10, 4),
+        ]);
+        let shift = MetaEngine::mid_season_shift(MetaType::Balanced, &dominant, &mut rng).unwrap();
+        assert_ne!(shift.meta, MetaType::Balanced);
+        assert!(shift.reason.starts_with("季中补丁"));
+
+        assert!(MetaEngine::is_mid_season_patch_phase(SeasonPhase::MadridMasters));
+        assert!(MetaEngine::is_mid_season_patch_phase(SeasonPhase::SummerRegular));
+        assert!(!MetaEngine::is_mid_season_patch_phase(SeasonPhase::SpringRegular));
+    }
 }
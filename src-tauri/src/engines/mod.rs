@@ -1,10 +1,8 @@
 pub mod match_simulation;
-pub mod player_performance;
 pub mod season_progress;
 pub mod points_calculation;
 pub mod draft;
 pub mod draft_auction;
-pub mod power_engine;
 pub mod financial;
 pub mod honor;
 pub mod event;
@@ -19,14 +17,32 @@ pub mod lineup_engine;
 pub mod champion;
 pub mod bp_engine;
 pub mod ladder;
+pub mod scouting;
+pub mod talent_pipeline;
+pub mod role_transition;
+pub mod salary_cap;
+pub mod sponsorship;
+pub mod game_timeline;
+pub mod comp_phase;
+pub mod champion_patch;
+pub mod draft_rules;
+pub mod side_selection;
+pub mod draft_scouting;
+pub mod champion_training;
+pub mod series_tactics;
+pub mod series_planner;
+pub mod sim_core;
+pub mod regional_meta;
+pub mod hall_of_fame;
+pub mod records_book;
+pub mod rivalry;
+pub mod award_voting;
 
 pub use match_simulation::*;
-pub use player_performance::*;
 pub use season_progress::*;
 pub use points_calculation::*;
 pub use draft::*;
 pub use draft_auction::*;
-pub use power_engine::*;
 pub use financial::*;
 pub use honor::*;
 pub use event::*;
@@ -39,3 +55,23 @@ pub use meta_engine::*;
 pub use rookie_generator::*;
 pub use lineup_engine::*;
 pub use ladder::*;
+pub use scouting::*;
+pub use talent_pipeline::*;
+pub use role_transition::*;
+pub use salary_cap::*;
+pub use sponsorship::*;
+pub use game_timeline::*;
+pub use comp_phase::*;
+pub use champion_patch::*;
+pub use draft_rules::*;
+pub use side_selection::*;
+pub use draft_scouting::*;
+pub use champion_training::*;
+pub use series_tactics::*;
+pub use series_planner::*;
+pub use sim_core::*;
+pub use regional_meta::*;
+pub use hall_of_fame::*;
+pub use records_book::*;
+pub use rivalry::*;
+pub use award_voting::*;
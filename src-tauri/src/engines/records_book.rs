@@ -0,0 +1,790 @@
+//! 纪录簿引擎 (Records Book Engine)
+//!
+//! 比赛结束后增量维护各项纪录：
+//! - 纪录分三个范围：全球、赛区（赛区内赛事）、联赛（同一赛事类型，如 S世界赛、LPL 春季赛常规赛）
+//! - 系列赛纪录：最长连胜、最长/最短单局、系列赛最高KDA、单局最多击杀
+//! - 赛事纪录：最多冠军、最年轻冠军（赛事结束颁发荣誉后更新）
+//! - 每次纪录被刷新都会写入历史；纪录易主（或单场类纪录被超越）时返回给调用方生成事件
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::models::{MatchResult, TournamentType};
+
+/// 纪录类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecordType {
+    /// 最长系列赛连胜
+    LongestWinStreak,
+    /// 最长单局
+    LongestGame,
+    /// 最短单局
+    ShortestGame,
+    /// 系列赛最高KDA
+    HighestSeriesKda,
+    /// 单局最多击杀
+    MostKillsInGame,
+    /// 最多冠军
+    MostTitles,
+    /// 最年轻冠军
+    YoungestChampion,
+}
+
+impl RecordType {
+    pub const ALL: [RecordType; 7] = [
+        RecordType::LongestWinStreak,
+        RecordType::LongestGame,
+        RecordType::ShortestGame,
+        RecordType::HighestSeriesKda,
+        RecordType::MostKillsInGame,
+        RecordType::MostTitles,
+        RecordType::YoungestChampion,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordType::LongestWinStreak => "LONGEST_WIN_STREAK",
+            RecordType::LongestGame => "LONGEST_GAME",
+            RecordType::ShortestGame => "SHORTEST_GAME",
+            RecordType::HighestSeriesKda => "HIGHEST_SERIES_KDA",
+            RecordType::MostKillsInGame => "MOST_KILLS_IN_GAME",
+            RecordType::MostTitles => "MOST_TITLES",
+            RecordType::YoungestChampion => "YOUNGEST_CHAMPION",
+        }
+    }
+
+    pub fn from_id(s: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|t| t.as_str() == s)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RecordType::LongestWinStreak => "最长连胜",
+            RecordType::LongestGame => "最长单局",
+            RecordType::ShortestGame => "最短单局",
+            RecordType::HighestSeriesKda => "系列赛最高KDA",
+            RecordType::MostKillsInGame => "单局最多击杀",
+            RecordType::MostTitles => "最多冠军",
+            RecordType::YoungestChampion => "最年轻冠军",
+        }
+    }
+
+    /// 纪录持有者类型：TEAM / PLAYER / MATCH
+    pub fn holder_kind(&self) -> &'static str {
+        match self {
+            RecordType::LongestWinStreak | RecordType::MostTitles => "TEAM",
+            RecordType::LongestGame | RecordType::ShortestGame => "MATCH",
+            RecordType::HighestSeriesKda | RecordType::MostKillsInGame | RecordType::YoungestChampion => "PLAYER",
+        }
+    }
+
+    /// 数值越小越好的纪录
+    pub fn lower_is_better(&self) -> bool {
+        matches!(self, RecordType::ShortestGame | RecordType::YoungestChampion)
+    }
+
+    /// 累积类纪录：持有者自己延续纪录只更新数值，不算"打破"
+    pub fn is_cumulative(&self) -> bool {
+        matches!(self, RecordType::LongestWinStreak | RecordType::MostTitles)
+    }
+
+    pub fn format_value(&self, value: f64) -> String {
+        match self {
+            RecordType::LongestWinStreak => format!("{}连胜", value as i64),
+            RecordType::LongestGame | RecordType::ShortestGame => format!("{}分钟", value as i64),
+            RecordType::HighestSeriesKda => format!("KDA {:.2}", value),
+            RecordType::MostKillsInGame => format!("{}杀", value as i64),
+            RecordType::MostTitles => format!("{}冠", value as i64),
+            RecordType::YoungestChampion => format!("{}岁", value as i64),
+        }
+    }
+
+    /// 新成绩是否打破现有纪录（持平不算）
+    pub fn beats(&self, value: f64, current: Option<f64>) -> bool {
+        match current {
+            None => true,
+            Some(current) if self.lower_is_better() => value < current,
+            Some(current) => value > current,
+        }
+    }
+}
+
+/// 赛事类型中文名（tournaments.tournament_type 存储为枚举的 Debug 名）
+fn tournament_type_name(tournament_type: &str) -> String {
+    [
+        TournamentType::SpringRegular,
+        TournamentType::SpringPlayoffs,
+        TournamentType::SummerRegular,
+        TournamentType::SummerPlayoffs,
+        TournamentType::Msi,
+        TournamentType::MadridMasters,
+        TournamentType::ClaudeIntercontinental,
+        TournamentType::WorldChampionship,
+        TournamentType::ShanghaiMasters,
+        TournamentType::IcpIntercontinental,
+        TournamentType::SuperIntercontinental,
+    ]
+    .iter()
+    .find(|t| format!("{:?}", t) == tournament_type)
+    .map(|t| t.name().to_string())
+    .unwrap_or_else(|| tournament_type.to_string())
+}
+
+/// 纪录范围
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordScope {
+    /// GLOBAL / REGION / LEAGUE
+    pub scope_type: String,
+    /// 全球为空串；赛区为 region_id；联赛为 "{赛事类型}" 或 "{赛事类型}:{region_id}"
+    pub scope_key: String,
+    pub label: String,
+}
+
+impl RecordScope {
+    pub fn global() -> Self {
+        Self { scope_type: "GLOBAL".to_string(), scope_key: String::new(), label: "全球".to_string() }
+    }
+
+    pub fn region(region_id: i64, region_name: &str) -> Self {
+        Self { scope_type: "REGION".to_string(), scope_key: region_id.to_string(), label: region_name.to_string() }
+    }
+
+    pub fn league(tournament_type: &str, region: Option<(i64, &str)>) -> Self {
+        let type_name = tournament_type_name(tournament_type);
+        match region {
+            Some((region_id, region_name)) => Self {
+                scope_type: "LEAGUE".to_string(),
+                scope_key: format!("{}:{}", tournament_type, region_id),
+                label: format!("{} {}", region_name, type_name),
+            },
+            None => Self {
+                scope_type: "LEAGUE".to_string(),
+                scope_key: tournament_type.to_string(),
+                label: type_name,
+            },
+        }
+    }
+
+    /// 一场比赛计入的纪录范围：全球 + 赛区（仅赛区内赛事）+ 联赛
+    pub fn scopes_for(tournament_type: &str, region: Option<(i64, &str)>) -> Vec<Self> {
+        let mut scopes = vec![Self::global()];
+        if let Some((region_id, region_name)) = region {
+            scopes.push(Self::region(region_id, region_name));
+        }
+        scopes.push(Self::league(tournament_type, region));
+        scopes
+    }
+}
+
+/// 一次冲击纪录的成绩
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordAttempt {
+    pub record_type: RecordType,
+    pub holder_id: i64,
+    pub holder_name: String,
+    pub value: f64,
+    pub tournament_id: Option<i64>,
+    pub match_id: Option<i64>,
+}
+
+/// 被打破的纪录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrokenRecord {
+    pub record_type: RecordType,
+    pub scope: RecordScope,
+    pub holder_kind: String,
+    pub holder_id: i64,
+    pub holder_name: String,
+    pub value: f64,
+    pub previous_holder_id: i64,
+    pub previous_holder_name: String,
+    pub previous_value: f64,
+    pub season_id: i64,
+    pub tournament_id: Option<i64>,
+    pub match_id: Option<i64>,
+}
+
+impl BrokenRecord {
+    pub fn describe(&self) -> String {
+        let by = if self.holder_id == self.previous_holder_id {
+            format!("{} 刷新了自己保持的", self.holder_name)
+        } else {
+            format!("{} 打破了 {} 保持的", self.holder_name, self.previous_holder_name)
+        };
+        format!(
+            "{}{}{}纪录：{} → {}",
+            by,
+            self.scope.label,
+            self.record_type.name(),
+            self.record_type.format_value(self.previous_value),
+            self.record_type.format_value(self.value)
+        )
+    }
+}
+
+/// 系列赛纪录输入（快速模拟与详细模拟两条路径共用）
+#[derive(Debug, Clone)]
+pub struct SeriesRecordInput {
+    pub match_id: i64,
+    pub tournament_id: i64,
+    pub home_team_id: i64,
+    pub away_team_id: i64,
+    pub winner_id: i64,
+    /// 各小局时长（分钟），无时长数据的小局不计入
+    pub durations: Vec<u32>,
+}
+
+impl SeriesRecordInput {
+    pub fn from_match_result(result: &MatchResult) -> Self {
+        Self {
+            match_id: result.match_info.id as i64,
+            tournament_id: result.match_info.tournament_id as i64,
+            home_team_id: result.match_info.home_team_id as i64,
+            away_team_id: result.match_info.away_team_id as i64,
+            winner_id: result.winner_id as i64,
+            durations: result.games.iter().filter_map(|g| g.duration_minutes).collect(),
+        }
+    }
+}
+
+/// 选手在一场系列赛中的数据汇总
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlayerSeriesLine {
+    pub player_id: i64,
+    pub player_name: String,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub max_game_kills: i64,
+}
+
+impl PlayerSeriesLine {
+    pub fn kda(&self) -> f64 {
+        (self.kills + self.assists) as f64 / self.deaths.max(1) as f64
+    }
+}
+
+/// 纪录簿引擎
+pub struct RecordsBookEngine;
+
+impl RecordsBookEngine {
+    /// 由一场系列赛的小局时长与选手数据生成冲击纪录的成绩（不含连胜）
+    pub fn series_attempts(
+        input: &SeriesRecordInput,
+        matchup_name: &str,
+        lines: &[PlayerSeriesLine],
+    ) -> Vec<RecordAttempt> {
+        let mut attempts = Vec::new();
+        let match_attempt = |record_type: RecordType, value: f64| RecordAttempt {
+            record_type,
+            holder_id: input.match_id,
+            holder_name: matchup_name.to_string(),
+            value,
+            tournament_id: Some(input.tournament_id),
+            match_id: Some(input.match_id),
+        };
+
+        if let Some(&longest) = input.durations.iter().max() {
+            attempts.push(match_attempt(RecordType::LongestGame, longest as f64));
+        }
+        if let Some(&shortest) = input.durations.iter().filter(|d| **d > 0).min() {
+            attempts.push(match_attempt(RecordType::ShortestGame, shortest as f64));
+        }
+
+        let player_attempt = |record_type: RecordType, line: &PlayerSeriesLine, value: f64| RecordAttempt {
+            record_type,
+            holder_id: line.player_id,
+            holder_name: line.player_name.clone(),
+            value,
+            tournament_id: Some(input.tournament_id),
+            match_id: Some(input.match_id),
+        };
+        let best_kda = lines
+            .iter()
+            .max_by(|a, b| a.kda().partial_cmp(&b.kda()).unwrap_or(std::cmp::Ordering::Equal));
+        if let Some(line) = best_kda {
+            attempts.push(player_attempt(RecordType::HighestSeriesKda, line, line.kda()));
+        }
+        if let Some(line) = lines.iter().max_by_key(|l| l.max_game_kills) {
+            if line.max_game_kills > 0 {
+                attempts.push(player_attempt(RecordType::MostKillsInGame, line, line.max_game_kills as f64));
+            }
+        }
+
+        attempts
+    }
+
+    /// 以新成绩冲击纪录：打破则写入纪录与历史，纪录易主或单场类纪录被超越时返回 BrokenRecord
+    pub async fn submit(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        scope: &RecordScope,
+        attempt: &RecordAttempt,
+    ) -> Result<Option<BrokenRecord>, String> {
+        let record_type = attempt.record_type;
+        let current = sqlx::query(
+            r#"
+            SELECT holder_id, holder_name, value FROM records_book
+            WHERE save_id = ? AND record_type = ? AND scope_type = ? AND scope_key = ?
+            "#,
+        )
+        .bind(save_id)
+        .bind(record_type.as_str())
+        .bind(&scope.scope_type)
+        .bind(&scope.scope_key)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询纪录失败: {}", e))?;
+
+        let current_value = current.as_ref().map(|r| r.get::<f64, _>("value"));
+        if !record_type.beats(attempt.value, current_value) {
+            return Ok(None);
+        }
+        let previous = current.map(|r| (r.get::<i64, _>("holder_id"), r.get::<String, _>("holder_name"), r.get::<f64, _>("value")));
+
+        sqlx::query(
+            r#"
+            INSERT INTO records_book (
+                save_id, record_type, scope_type, scope_key, scope_label, holder_kind, holder_id, holder_name,
+                value, season_id, tournament_id, match_id
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(save_id, record_type, scope_type, scope_key) DO UPDATE SET
+                scope_label = excluded.scope_label,
+                holder_id = excluded.holder_id,
+                holder_name = excluded.holder_name,
+                value = excluded.value,
+                season_id = excluded.season_id,
+                tournament_id = excluded.tournament_id,
+                match_id = excluded.match_id
+            "#,
+        )
+        .bind(save_id)
+        .bind(record_type.as_str())
+        .bind(&scope.scope_type)
+        .bind(&scope.scope_key)
+        .bind(&scope.label)
+        .bind(record_type.holder_kind())
+        .bind(attempt.holder_id)
+        .bind(&attempt.holder_name)
+        .bind(attempt.value)
+        .bind(season_id)
+        .bind(attempt.tournament_id)
+        .bind(attempt.match_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("写入纪录失败: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO record_history (
+                save_id, record_type, scope_type, scope_key, scope_label, holder_kind, holder_id, holder_name,
+                value, previous_holder_id, previous_holder_name, previous_value, season_id, tournament_id, match_id
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(save_id)
+        .bind(record_type.as_str())
+        .bind(&scope.scope_type)
+        .bind(&scope.scope_key)
+        .bind(&scope.label)
+        .bind(record_type.holder_kind())
+        .bind(attempt.holder_id)
+        .bind(&attempt.holder_name)
+        .bind(attempt.value)
+        .bind(previous.as_ref().map(|p| p.0))
+        .bind(previous.as_ref().map(|p| p.1.clone()))
+        .bind(previous.as_ref().map(|p| p.2))
+        .bind(season_id)
+        .bind(attempt.tournament_id)
+        .bind(attempt.match_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("写入纪录历史失败: {}", e))?;
+
+        // 首次设立的纪录不算"打破"；累积类纪录由原持有者延续时也不算
+        let Some((previous_holder_id, previous_holder_name, previous_value)) = previous else {
+            return Ok(None);
+        };
+        if record_type.is_cumulative() && previous_holder_id == attempt.holder_id {
+            return Ok(None);
+        }
+
+        Ok(Some(BrokenRecord {
+            record_type,
+            scope: scope.clone(),
+            holder_kind: record_type.holder_kind().to_string(),
+            holder_id: attempt.holder_id,
+            holder_name: attempt.holder_name.clone(),
+            value: attempt.value,
+            previous_holder_id,
+            previous_holder_name,
+            previous_value,
+            season_id,
+            tournament_id: attempt.tournament_id,
+            match_id: attempt.match_id,
+        }))
+    }
+
+    /// 更新双方在某范围内的连胜，返回胜者当前连胜场数
+    async fn advance_streaks(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        scope: &RecordScope,
+        winner_id: i64,
+        loser_id: i64,
+    ) -> Result<i64, String> {
+        sqlx::query(
+            r#"
+            INSERT INTO record_streaks (save_id, team_id, scope_type, scope_key, current_streak)
+            VALUES (?, ?, ?, ?, 1)
+            ON CONFLICT(save_id, team_id, scope_type, scope_key) DO UPDATE SET
+                current_streak = current_streak + 1
+            "#,
+        )
+        .bind(save_id)
+        .bind(winner_id)
+        .bind(&scope.scope_type)
+        .bind(&scope.scope_key)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("更新连胜失败: {}", e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO record_streaks (save_id, team_id, scope_type, scope_key, current_streak)
+            VALUES (?, ?, ?, ?, 0)
+            ON CONFLICT(save_id, team_id, scope_type, scope_key) DO UPDATE SET
+                current_streak = 0
+            "#,
+        )
+        .bind(save_id)
+        .bind(loser_id)
+        .bind(&scope.scope_type)
+        .bind(&scope.scope_key)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("更新连胜失败: {}", e))?;
+
+        sqlx::query_scalar(
+            "SELECT current_streak FROM record_streaks WHERE save_id = ? AND team_id = ? AND scope_type = ? AND scope_key = ?",
+        )
+        .bind(save_id)
+        .bind(winner_id)
+        .bind(&scope.scope_type)
+        .bind(&scope.scope_key)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询连胜失败: {}", e))
+    }
+
+    /// 加载赛事类型与所属赛区 (tournament_type, Some((region_id, 赛区名)))
+    async fn load_tournament_scope(
+        pool: &Pool<Sqlite>,
+        tournament_id: i64,
+    ) -> Result<Option<(String, Option<(i64, String)>)>, String> {
+        let row = sqlx::query(
+            r#"
+            SELECT t.tournament_type, t.region_id, r.name AS region_name
+            FROM tournaments t
+            LEFT JOIN regions r ON r.id = t.region_id
+            WHERE t.id = ?
+            "#,
+        )
+        .bind(tournament_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询赛事失败: {}", e))?;
+
+        Ok(row.map(|r| {
+            let region = match (r.get::<Option<i64>, _>("region_id"), r.get::<Option<String>, _>("region_name")) {
+                (Some(id), Some(name)) => Some((id, name)),
+                _ => None,
+            };
+            (r.get::<String, _>("tournament_type"), region)
+        }))
+    }
+
+    async fn team_name(pool: &Pool<Sqlite>, team_id: i64) -> Result<String, String> {
+        let name: Option<String> = sqlx::query_scalar("SELECT name FROM teams WHERE id = ?")
+            .bind(team_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("查询队伍失败: {}", e))?;
+        Ok(name.unwrap_or_else(|| format!("队伍{}", team_id)))
+    }
+
+    /// 汇总系列赛各选手数据（仅详细模拟会写入小局选手数据，快速模拟时为空）
+    async fn load_series_lines(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_id: i64,
+    ) -> Result<Vec<PlayerSeriesLine>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT player_id, MAX(player_name) AS player_name,
+                   SUM(COALESCE(kills, 0)) AS kills,
+                   SUM(COALESCE(deaths, 0)) AS deaths,
+                   SUM(COALESCE(assists, 0)) AS assists,
+                   MAX(COALESCE(kills, 0)) AS max_game_kills
+            FROM game_player_performances
+            WHERE save_id = ? AND game_id IN (SELECT id FROM match_games WHERE match_id = ?)
+            GROUP BY player_id
+            "#,
+        )
+        .bind(save_id)
+        .bind(match_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询系列赛选手数据失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|r| PlayerSeriesLine {
+                player_id: r.get("player_id"),
+                player_name: r.get("player_name"),
+                kills: r.get("kills"),
+                deaths: r.get("deaths"),
+                assists: r.get("assists"),
+                max_game_kills: r.get("max_game_kills"),
+            })
+            .collect())
+    }
+
+    /// 系列赛结束：更新连胜与单场类纪录
+    pub async fn check_series(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        input: &SeriesRecordInput,
+    ) -> Result<Vec<BrokenRecord>, String> {
+        let Some((tournament_type, region)) = Self::load_tournament_scope(pool, input.tournament_id).await? else {
+            return Ok(Vec::new());
+        };
+        let scopes = RecordScope::scopes_for(&tournament_type, region.as_ref().map(|(id, name)| (*id, name.as_str())));
+
+        let home_name = Self::team_name(pool, input.home_team_id).await?;
+        let away_name = Self::team_name(pool, input.away_team_id).await?;
+        let (winner_name, loser_id) = if input.winner_id == input.home_team_id {
+            (home_name.clone(), input.away_team_id)
+        } else {
+            (away_name.clone(), input.home_team_id)
+        };
+        let lines = Self::load_series_lines(pool, save_id, input.match_id).await?;
+        let attempts = Self::series_attempts(input, &format!("{} vs {}", home_name, away_name), &lines);
+
+        let mut broken = Vec::new();
+        for scope in &scopes {
+            let streak = Self::advance_streaks(pool, save_id, scope, input.winner_id, loser_id).await?;
+            let streak_attempt = RecordAttempt {
+                record_type: RecordType::LongestWinStreak,
+                holder_id: input.winner_id,
+                holder_name: winner_name.clone(),
+                value: streak as f64,
+                tournament_id: Some(input.tournament_id),
+                match_id: Some(input.match_id),
+            };
+            for attempt in std::iter::once(&streak_attempt).chain(attempts.iter()) {
+                if let Some(record) = Self::submit(pool, save_id, season_id, scope, attempt).await? {
+                    broken.push(record);
+                }
+            }
+        }
+
+        Ok(broken)
+    }
+
+    /// 赛事结束（荣誉已颁发）：更新最多冠军与最年轻冠军纪录
+    pub async fn check_titles(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        tournament_id: i64,
+    ) -> Result<Vec<BrokenRecord>, String> {
+        let Some((tournament_type, region)) = Self::load_tournament_scope(pool, tournament_id).await? else {
+            return Ok(Vec::new());
+        };
+        let champion = sqlx::query(
+            "SELECT team_id, team_name FROM honors WHERE save_id = ? AND tournament_id = ? AND honor_type = 'TEAM_CHAMPION' AND team_id IS NOT NULL LIMIT 1",
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询冠军队伍失败: {}", e))?;
+        let Some(champion) = champion else {
+            return Ok(Vec::new());
+        };
+        let team_id: i64 = champion.get("team_id");
+        let team_name: String = champion.get::<Option<String>, _>("team_name").unwrap_or_default();
+
+        let youngest = sqlx::query(
+            r#"
+            SELECT p.id, p.game_id, p.age
+            FROM honors h
+            JOIN players p ON p.id = h.player_id
+            WHERE h.save_id = ? AND h.tournament_id = ? AND h.honor_type = 'PLAYER_CHAMPION'
+            ORDER BY p.age ASC
+            LIMIT 1
+            "#,
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询冠军成员失败: {}", e))?;
+
+        let scopes = RecordScope::scopes_for(&tournament_type, region.as_ref().map(|(id, name)| (*id, name.as_str())));
+        let mut broken = Vec::new();
+        for scope in &scopes {
+            let titles: i64 = match scope.scope_type.as_str() {
+                "GLOBAL" => sqlx::query_scalar(
+                    "SELECT COUNT(*) FROM honors WHERE save_id = ? AND team_id = ? AND honor_type = 'TEAM_CHAMPION'",
+                )
+                .bind(save_id)
+                .bind(team_id)
+                .fetch_one(pool)
+                .await,
+                "REGION" => sqlx::query_scalar(
+                    r#"
+                    SELECT COUNT(*) FROM honors h JOIN tournaments t ON t.id = h.tournament_id
+                    WHERE h.save_id = ? AND h.team_id = ? AND h.honor_type = 'TEAM_CHAMPION' AND t.region_id = ?
+                    "#,
+                )
+                .bind(save_id)
+                .bind(team_id)
+                .bind(region.as_ref().map(|(id, _)| *id))
+                .fetch_one(pool)
+                .await,
+                _ => sqlx::query_scalar(
+                    r#"
+                    SELECT COUNT(*) FROM honors h JOIN tournaments t ON t.id = h.tournament_id
+                    WHERE h.save_id = ? AND h.team_id = ? AND h.honor_type = 'TEAM_CHAMPION'
+                      AND t.tournament_type = ? AND (t.region_id IS ? OR t.region_id = ?)
+                    "#,
+                )
+                .bind(save_id)
+                .bind(team_id)
+                .bind(&tournament_type)
+                .bind(region.as_ref().map(|(id, _)| *id))
+                .bind(region.as_ref().map(|(id, _)| *id))
+                .fetch_one(pool)
+                .await,
+            }
+            .map_err(|e| format!("统计冠军数失败: {}", e))?;
+
+            let mut attempts = vec![RecordAttempt {
+                record_type: RecordType::MostTitles,
+                holder_id: team_id,
+                holder_name: team_name.clone(),
+                value: titles as f64,
+                tournament_id: Some(tournament_id),
+                match_id: None,
+            }];
+            if let Some(row) = &youngest {
+                attempts.push(RecordAttempt {
+                    record_type: RecordType::YoungestChampion,
+                    holder_id: row.get("id"),
+                    holder_name: row.get("game_id"),
+                    value: row.get::<i64, _>("age") as f64,
+                    tournament_id: Some(tournament_id),
+                    match_id: None,
+                });
+            }
+            for attempt in &attempts {
+                if let Some(record) = Self::submit(pool, save_id, season_id, scope, attempt).await? {
+                    broken.push(record);
+                }
+            }
+        }
+
+        Ok(broken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(player_id: i64, kills: i64, deaths: i64, assists: i64, max_game_kills: i64) -> PlayerSeriesLine {
+        PlayerSeriesLine {
+            player_id,
+            player_name: format!("P{}", player_id),
+            kills,
+            deaths,
+            assists,
+            max_game_kills,
+        }
+    }
+
+    #[test]
+    fn test_beats_respects_direction() {
+        assert!(RecordType::LongestGame.beats(45.0, None));
+        assert!(RecordType::LongestGame.beats(46.0, Some(45.0)));
+        assert!(!RecordType::LongestGame.beats(45.0, Some(45.0)));
+        assert!(RecordType::ShortestGame.beats(18.0, Some(19.0)));
+        assert!(!RecordType::ShortestGame.beats(20.0, Some(19.0)));
+        assert!(RecordType::YoungestChampion.beats(17.0, Some(18.0)));
+    }
+
+    #[test]
+    fn test_record_type_round_trip() {
+        for t in RecordType::ALL {
+            assert_eq!(RecordType::from_id(t.as_str()), Some(t));
+        }
+        assert_eq!(RecordType::from_id("UNKNOWN"), None);
+    }
+
+    #[test]
+    fn test_scopes_for_regional_and_international() {
+        let regional = RecordScope::scopes_for("SpringRegular", Some((3, "LPL")));
+        assert_eq!(regional.len(), 3);
+        assert_eq!(regional[1].scope_key, "3");
+        assert_eq!(regional[2].scope_key, "SpringRegular:3");
+        assert_eq!(regional[2].label, "LPL 春季赛常规赛");
+
+        let international = RecordScope::scopes_for("WorldChampionship", None);
+        assert_eq!(international.len(), 2);
+        assert_eq!(international[0].scope_type, "GLOBAL");
+        assert_eq!(international[1].scope_key, "WorldChampionship");
+    }
+
+    #[test]
+    fn test_series_attempts() {
+        let input = SeriesRecordInput {
+            match_id: 7,
+            tournament_id: 1,
+            home_team_id: 10,
+            away_team_id: 20,
+            winner_id: 10,
+            durations: vec![32, 41, 24],
+        };
+        let lines = vec![line(1, 8, 0, 12, 5), line(2, 15, 3, 6, 9), line(3, 2, 6, 4, 1)];
+        let attempts = RecordsBookEngine::series_attempts(&input, "A vs B", &lines);
+
+        let find = |t: RecordType| attempts.iter().find(|a| a.record_type == t).unwrap();
+        assert_eq!(find(RecordType::LongestGame).value, 41.0);
+        assert_eq!(find(RecordType::ShortestGame).value, 24.0);
+        assert_eq!(find(RecordType::LongestGame).holder_id, 7);
+        // 0死按1死计算：(8+12)/1 = 20
+        assert_eq!(find(RecordType::HighestSeriesKda).holder_id, 1);
+        assert_eq!(find(RecordType::HighestSeriesKda).value, 20.0);
+        assert_eq!(find(RecordType::MostKillsInGame).holder_id, 2);
+        assert_eq!(find(RecordType::MostKillsInGame).value, 9.0);
+    }
+
+    #[test]
+    fn test_series_attempts_without_details() {
+        let input = SeriesRecordInput {
+            match_id: 7,
+            tournament_id: 1,
+            home_team_id: 10,
+            away_team_id: 20,
+            winner_id: 20,
+            durations: Vec::new(),
+        };
+        assert!(RecordsBookEngine::series_attempts(&input, "A vs B", &[]).is_empty());
+    }
+}
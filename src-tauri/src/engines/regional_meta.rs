@@ -0,0 +1,398 @@
+//! 赛区版本理解引擎 (Regional Meta Engine)
+//!
+//! 同一赛季的 Meta 在各赛区被解读成不同的打法：
+//! - 每个赛区有一份风格档案（对五种阵容风格的偏好），LPL 偏爱小规模团战、LCK 偏爱运营
+//! - BP 规划阵容体系时叠加所属赛区的风格偏好，版本主推体系的加成也按赛区偏好放大或打折
+//! - 国际赛事结束后，冠军队伍所在赛区的风格向其他赛区扩散；新赛季各赛区部分回归传统风格
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::bp_engine::CompType;
+use crate::engines::champion::Archetype;
+use crate::engines::meta_engine::MetaType;
+
+/// 风格偏好折算到 BP 规划的加分（偏好 1.0 时加 12 分）
+const STYLE_PLAN_WEIGHT: f64 = 12.0;
+/// 版本主推体系的基础加分（与 BP 规划原有加分一致）
+const META_FAVORED_PLAN_BONUS: f64 = 15.0;
+/// 赛区偏好对版本主推体系加分的放大倍率
+const META_INTERPRETATION_SCALE: f64 = 0.5;
+/// 国际赛冠军赛区风格的扩散比例
+const ADOPTION_RATE: f64 = 0.25;
+/// 新赛季回归传统风格的比例
+const IDENTITY_REVERT_RATE: f64 = 0.4;
+
+/// 赛区传统风格
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegionStyle {
+    /// 小规模团战（LPL）
+    Skirmish,
+    /// 运营（LCK）
+    Macro,
+    /// 团战与功能性阵容（LEC）
+    Teamfight,
+    /// 后期发育（LCS）
+    LateGame,
+}
+
+impl RegionStyle {
+    /// 按赛区名称取传统风格（赛区 ID 按存档自增，不能用于识别赛区）
+    pub fn for_region(region_name: &str) -> Option<Self> {
+        match region_name {
+            "LPL" => Some(RegionStyle::Skirmish),
+            "LCK" => Some(RegionStyle::Macro),
+            "LEC" => Some(RegionStyle::Teamfight),
+            "LCS" => Some(RegionStyle::LateGame),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RegionStyle::Skirmish => "小规模团战",
+            RegionStyle::Macro => "运营",
+            RegionStyle::Teamfight => "团战",
+            RegionStyle::LateGame => "后期发育",
+        }
+    }
+
+    /// 传统风格偏好：激进、后期、功能、分推、团战
+    fn base_affinities(&self) -> [f64; 5] {
+        match self {
+            RegionStyle::Skirmish => [0.8, -0.4, -0.3, -0.2, 0.5],
+            RegionStyle::Macro => [-0.5, 0.4, 0.5, 0.6, 0.0],
+            RegionStyle::Teamfight => [0.1, -0.1, 0.3, -0.3, 0.6],
+            RegionStyle::LateGame => [-0.3, 0.7, 0.0, -0.2, 0.2],
+        }
+    }
+}
+
+/// 赛区风格档案（各风格偏好取值 -1.0 ~ 1.0，0 为中立）
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegionStyleProfile {
+    pub region_id: u64,
+    pub region_name: String,
+    pub style: Option<RegionStyle>,
+    pub aggressive: f64,
+    pub scaling: f64,
+    pub utility: f64,
+    pub splitpush: f64,
+    pub teamfight: f64,
+    /// 最近一次吸收其风格的赛区
+    pub adopted_from: Option<String>,
+}
+
+impl RegionStyleProfile {
+    /// 赛区的传统风格档案
+    pub fn base(region_id: u64, region_name: &str) -> Self {
+        let style = RegionStyle::for_region(region_name);
+        let mut profile = RegionStyleProfile {
+            region_id,
+            region_name: region_name.to_string(),
+            style,
+            ..Default::default()
+        };
+        if let Some(style) = style {
+            profile.set_affinities(style.base_affinities());
+        }
+        profile
+    }
+
+    pub fn is_neutral(&self) -> bool {
+        self.affinities().iter().all(|a| *a == 0.0)
+    }
+
+    pub fn affinity(&self, archetype: Archetype) -> f64 {
+        match archetype {
+            Archetype::Aggressive => self.aggressive,
+            Archetype::Scaling => self.scaling,
+            Archetype::Utility => self.utility,
+            Archetype::Splitpush => self.splitpush,
+            Archetype::Teamfight => self.teamfight,
+        }
+    }
+
+    fn affinities(&self) -> [f64; 5] {
+        [self.aggressive, self.scaling, self.utility, self.splitpush, self.teamfight]
+    }
+
+    fn set_affinities(&mut self, values: [f64; 5]) {
+        let [aggressive, scaling, utility, splitpush, teamfight] = values.map(|v| v.clamp(-1.0, 1.0));
+        self.aggressive = aggressive;
+        self.scaling = scaling;
+        self.utility = utility;
+        self.splitpush = splitpush;
+        self.teamfight = teamfight;
+    }
+
+    /// 对阵容体系的平均偏好（全能阵容视为中立）
+    fn comp_affinity(&self, comp: CompType) -> f64 {
+        if comp == CompType::Flex {
+            return 0.0;
+        }
+        let archetypes = comp.core_archetypes();
+        archetypes.iter().map(|a| self.affinity(*a)).sum::<f64>() / archetypes.len() as f64
+    }
+
+    /// 最偏爱的阵容风格
+    pub fn favorite_archetype(&self) -> Option<Archetype> {
+        if self.is_neutral() {
+            return None;
+        }
+        Archetype::all()
+            .iter()
+            .copied()
+            .max_by(|a, b| self.affinity(*a).total_cmp(&self.affinity(*b)))
+    }
+
+    /// BP 规划：赛区风格对阵容体系的加分
+    pub fn comp_plan_bonus(&self, comp: CompType) -> i32 {
+        (self.comp_affinity(comp) * STYLE_PLAN_WEIGHT).round() as i32
+    }
+
+    /// BP 规划：版本主推体系的加分，赛区偏好的体系被放大、不擅长的体系打折
+    pub fn meta_plan_bonus(&self, comp: CompType, meta_type: MetaType) -> i32 {
+        if !comp.is_meta_favored(meta_type) {
+            return 0;
+        }
+        let scale = 1.0 + self.comp_affinity(comp) * META_INTERPRETATION_SCALE;
+        (META_FAVORED_PLAN_BONUS * scale).round() as i32
+    }
+
+    /// 吸收另一赛区的风格（向其偏好靠拢 rate 比例）
+    pub fn adopt(&mut self, source: &RegionStyleProfile, rate: f64) {
+        let own = self.affinities();
+        let other = source.affinities();
+        self.set_affinities(std::array::from_fn(|i| own[i] + (other[i] - own[i]) * rate));
+        self.adopted_from = Some(source.region_name.clone());
+    }
+
+    /// 向传统风格回归 rate 比例
+    pub fn revert_toward_identity(&mut self, rate: f64) {
+        let base = Self::base(self.region_id, &self.region_name).affinities();
+        let own = self.affinities();
+        self.set_affinities(std::array::from_fn(|i| own[i] + (base[i] - own[i]) * rate));
+    }
+}
+
+/// 赛区版本理解引擎
+pub struct RegionalMetaEngine;
+
+impl RegionalMetaEngine {
+    /// 存档中所有赛区的风格档案（未记录的赛区使用传统风格）
+    pub async fn load_profiles(pool: &Pool<Sqlite>, save_id: &str) -> Result<Vec<RegionStyleProfile>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT r.id, r.name, s.aggressive, s.scaling, s.utility, s.splitpush, s.teamfight, s.adopted_from
+            FROM regions r
+            LEFT JOIN region_meta_styles s ON s.save_id = r.save_id AND s.region_id = r.id
+            WHERE r.save_id = ?
+            ORDER BY r.id
+            "#,
+        )
+        .bind(save_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询赛区风格失败: {}", e))?;
+
+        Ok(rows.iter().map(Self::profile_from_row).collect())
+    }
+
+    /// 队伍所属赛区的风格档案，查询失败或无赛区时为中立
+    pub async fn load_team_profile(pool: &Pool<Sqlite>, save_id: &str, team_id: u64) -> RegionStyleProfile {
+        let row = sqlx::query(
+            r#"
+            SELECT r.id, r.name, s.aggressive, s.scaling, s.utility, s.splitpush, s.teamfight, s.adopted_from
+            FROM teams t
+            JOIN regions r ON r.id = t.region_id AND r.save_id = t.save_id
+            LEFT JOIN region_meta_styles s ON s.save_id = r.save_id AND s.region_id = r.id
+            WHERE t.save_id = ? AND t.id = ?
+            "#,
+        )
+        .bind(save_id)
+        .bind(team_id as i64)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten();
+
+        row.as_ref().map(Self::profile_from_row).unwrap_or_default()
+    }
+
+    fn profile_from_row(row: &sqlx::sqlite::SqliteRow) -> RegionStyleProfile {
+        let region_id = row.get::<i64, _>("id") as u64;
+        let region_name: String = row.get("name");
+        let mut profile = RegionStyleProfile::base(region_id, &region_name);
+        if let Some(aggressive) = row.get::<Option<f64>, _>("aggressive") {
+            profile.set_affinities([
+                aggressive,
+                row.get("scaling"),
+                row.get("utility"),
+                row.get("splitpush"),
+                row.get("teamfight"),
+            ]);
+            profile.adopted_from = row.get("adopted_from");
+        }
+        profile
+    }
+
+    async fn save_profile(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        profile: &RegionStyleProfile,
+    ) -> Result<(), String> {
+        sqlx::query(
+            r#"
+            INSERT INTO region_meta_styles (
+                save_id, region_id, aggressive, scaling, utility, splitpush, teamfight, adopted_from, updated_season
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(save_id, region_id) DO UPDATE SET
+                aggressive = excluded.aggressive,
+                scaling = excluded.scaling,
+                utility = excluded.utility,
+                splitpush = excluded.splitpush,
+                teamfight = excluded.teamfight,
+                adopted_from = excluded.adopted_from,
+                updated_season = excluded.updated_season
+            "#,
+        )
+        .bind(save_id)
+        .bind(profile.region_id as i64)
+        .bind(profile.aggressive)
+        .bind(profile.scaling)
+        .bind(profile.utility)
+        .bind(profile.splitpush)
+        .bind(profile.teamfight)
+        .bind(&profile.adopted_from)
+        .bind(season_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("保存赛区风格失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 国际赛事结束后冠军赛区的风格向其他赛区扩散，返回冠军赛区名称
+    ///
+    /// 每项赛事只扩散一次（记录在 region_meta_spreads），重复结算时返回 None
+    pub async fn spread_from_champion(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        tournament_id: u64,
+        champion_team_id: u64,
+    ) -> Result<Option<String>, String> {
+        let spread: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM region_meta_spreads WHERE save_id = ? AND tournament_id = ?",
+        )
+        .bind(save_id)
+        .bind(tournament_id as i64)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询风格扩散记录失败: {}", e))?;
+        if spread > 0 {
+            return Ok(None);
+        }
+
+        let region_id: Option<i64> = sqlx::query_scalar("SELECT region_id FROM teams WHERE save_id = ? AND id = ?")
+            .bind(save_id)
+            .bind(champion_team_id as i64)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("查询冠军队伍赛区失败: {}", e))?
+            .flatten();
+        let Some(region_id) = region_id else {
+            return Ok(None);
+        };
+
+        let mut profiles = Self::load_profiles(pool, save_id).await?;
+        let Some(source) = profiles.iter().find(|p| p.region_id == region_id as u64).cloned() else {
+            return Ok(None);
+        };
+        for profile in profiles.iter_mut().filter(|p| p.region_id != source.region_id) {
+            profile.adopt(&source, ADOPTION_RATE);
+            Self::save_profile(pool, save_id, season_id, profile).await?;
+        }
+
+        sqlx::query(
+            "INSERT OR IGNORE INTO region_meta_spreads (save_id, season_id, tournament_id, source_region_id) VALUES (?, ?, ?, ?)",
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .bind(tournament_id as i64)
+        .bind(region_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("记录风格扩散失败: {}", e))?;
+        Ok(Some(source.region_name))
+    }
+
+    /// 新赛季：各赛区部分回归传统风格
+    pub async fn revert_for_new_season(pool: &Pool<Sqlite>, save_id: &str, season_id: i64) -> Result<(), String> {
+        for mut profile in Self::load_profiles(pool, save_id).await? {
+            profile.revert_toward_identity(IDENTITY_REVERT_RATE);
+            Self::save_profile(pool, save_id, season_id, &profile).await?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_styles_prefer_their_comps() {
+        let lpl = RegionStyleProfile::base(1, "LPL");
+        let lck = RegionStyleProfile::base(2, "LCK");
+
+        assert_eq!(lpl.style, Some(RegionStyle::Skirmish));
+        assert!(lpl.comp_plan_bonus(CompType::Skirmish) > lck.comp_plan_bonus(CompType::Skirmish));
+        assert!(lck.comp_plan_bonus(CompType::Splitpush) > lpl.comp_plan_bonus(CompType::Splitpush));
+        assert_eq!(lpl.comp_plan_bonus(CompType::Flex), 0);
+        assert_eq!(lpl.favorite_archetype(), Some(Archetype::Aggressive));
+    }
+
+    #[test]
+    fn test_neutral_profile_keeps_meta_bonus() {
+        let neutral = RegionStyleProfile::default();
+        assert!(neutral.is_neutral());
+        for comp in CompType::all() {
+            assert_eq!(neutral.comp_plan_bonus(*comp), 0);
+            let expected = if comp.is_meta_favored(MetaType::Balanced) { 15 } else { 0 };
+            assert_eq!(neutral.meta_plan_bonus(*comp, MetaType::Balanced), expected);
+        }
+    }
+
+    #[test]
+    fn test_meta_interpretation_differs_by_region() {
+        let lpl = RegionStyleProfile::base(1, "LPL");
+        let lck = RegionStyleProfile::base(2, "LCK");
+        let meta = MetaType::all()
+            .iter()
+            .copied()
+            .find(|m| CompType::Rush.is_meta_favored(*m))
+            .expect("存在主推激进体系的版本");
+
+        assert!(lpl.meta_plan_bonus(CompType::Rush, meta) > 15);
+        assert!(lck.meta_plan_bonus(CompType::Rush, meta) < 15);
+    }
+
+    #[test]
+    fn test_adoption_and_revert() {
+        let lpl = RegionStyleProfile::base(1, "LPL");
+        let mut lck = RegionStyleProfile::base(2, "LCK");
+        let before = lck.aggressive;
+
+        lck.adopt(&lpl, 0.25);
+        assert!((lck.aggressive - (before + (lpl.aggressive - before) * 0.25)).abs() < 1e-9);
+        assert_eq!(lck.adopted_from.as_deref(), Some("LPL"));
+
+        let adopted = lck.aggressive;
+        lck.revert_toward_identity(1.0);
+        assert!((lck.aggressive - before).abs() < 1e-9);
+        assert!(adopted > lck.aggressive);
+    }
+}
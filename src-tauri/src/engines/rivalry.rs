@@ -0,0 +1,488 @@
+//! 宿敌引擎 (Rivalry Engine)
+//!
+//! 从已完成的比赛汇总战队之间、同位置选手之间的历史交锋：
+//! - 战队交锋：系列赛/小局胜负、决赛/季后赛/国际赛相遇次数（来自 matches）
+//! - 对位交锋：同一小局中同位置对手的胜负与数据（来自 match_games + game_player_performances）
+//! - 宿敌判定：交手频率 + 比赛分量（决赛、季后赛、国际赛）+ 胜负接近程度，积分达到门槛即为宿敌
+//! - 宿敌之战计入 `MatchContext::is_rivalry`，提高比赛压力
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::models::HeadToHeadStats;
+
+/// 宿敌积分门槛
+pub const RIVALRY_THRESHOLD: f64 = 15.0;
+/// 计入宿敌积分的权重
+const REGULAR_MEETING_WEIGHT: f64 = 0.5;
+const PLAYOFF_MEETING_WEIGHT: f64 = 3.0;
+const FINAL_MEETING_WEIGHT: f64 = 6.0;
+const INTERNATIONAL_MEETING_WEIGHT: f64 = 2.0;
+/// 胜负接近（胜率 40%~60%）且交手足够多时的加分
+const CLOSE_RECORD_BONUS: f64 = 4.0;
+const CLOSE_RECORD_MIN_SERIES: u32 = 4;
+/// 交锋详情中保留的最近交手场次
+const RECENT_MEETINGS: usize = 10;
+
+/// 一次系列赛交手
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RivalryMeeting {
+    pub match_id: i64,
+    pub season_id: i64,
+    pub tournament_name: String,
+    pub tournament_type: String,
+    pub stage: String,
+    pub home_team_id: i64,
+    pub away_team_id: i64,
+    pub home_score: i64,
+    pub away_score: i64,
+    pub winner_id: i64,
+}
+
+impl RivalryMeeting {
+    pub fn is_final(&self) -> bool {
+        matches!(self.stage.as_str(), "GRAND_FINAL" | "GRAND_FINALS" | "FINAL" | "FINALS" | "ICP_FINAL")
+            || self.stage.starts_with("FINALS_R")
+    }
+
+    /// 联赛季后赛，或国际赛的淘汰赛阶段
+    pub fn is_playoff(&self) -> bool {
+        self.tournament_type.ends_with("Playoffs") || self.stage.contains("FINAL")
+    }
+
+    pub fn is_international(&self) -> bool {
+        !matches!(
+            self.tournament_type.as_str(),
+            "SpringRegular" | "SpringPlayoffs" | "SummerRegular" | "SummerPlayoffs"
+        )
+    }
+
+    fn stakes_weight(&self) -> f64 {
+        let mut weight = REGULAR_MEETING_WEIGHT;
+        if self.is_final() {
+            weight += FINAL_MEETING_WEIGHT;
+        } else if self.is_playoff() {
+            weight += PLAYOFF_MEETING_WEIGHT;
+        }
+        if self.is_international() {
+            weight += INTERNATIONAL_MEETING_WEIGHT;
+        }
+        weight
+    }
+}
+
+/// 两支战队的历史交锋汇总（team1_id < team2_id）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamHeadToHead {
+    #[serde(flatten)]
+    pub stats: HeadToHeadStats,
+    pub team1_name: String,
+    pub team2_name: String,
+    pub final_meetings: u32,
+    pub playoff_meetings: u32,
+    pub international_meetings: u32,
+    pub last_meeting_season: Option<i64>,
+    pub rivalry_score: f64,
+    pub is_rivalry: bool,
+    pub rivalry_reasons: Vec<String>,
+    /// 最近的交手（最新在前）
+    pub recent_meetings: Vec<RivalryMeeting>,
+}
+
+/// 两名同位置选手的对位交锋汇总（以 player_id 的视角）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerHeadToHead {
+    pub player_id: i64,
+    pub player_name: String,
+    pub opponent_id: i64,
+    pub opponent_name: String,
+    pub position: String,
+    pub games: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub kills: i64,
+    pub deaths: i64,
+    pub assists: i64,
+    pub opponent_kills: i64,
+    pub opponent_deaths: i64,
+    pub opponent_assists: i64,
+    pub avg_impact: f64,
+    pub opponent_avg_impact: f64,
+}
+
+/// 宿敌引擎
+pub struct RivalryEngine;
+
+impl RivalryEngine {
+    /// 汇总一对战队的交手记录，meetings 需按时间先后排列
+    pub fn summarize(team_a: i64, team_b: i64, meetings: &[RivalryMeeting]) -> TeamHeadToHead {
+        let mut stats = HeadToHeadStats::new(String::new(), team_a as u64, team_b as u64);
+        let mut final_meetings = 0;
+        let mut playoff_meetings = 0;
+        let mut international_meetings = 0;
+        let mut stakes = 0.0;
+        let mut last_meeting_season = None;
+
+        for m in meetings {
+            let (team1_score, team2_score) = if m.home_team_id as u64 == stats.team1_id {
+                (m.home_score, m.away_score)
+            } else {
+                (m.away_score, m.home_score)
+            };
+            stats.update(m.winner_id as u64, team1_score as u8, team2_score as u8, m.match_id as u64);
+            if m.is_final() {
+                final_meetings += 1;
+            } else if m.is_playoff() {
+                playoff_meetings += 1;
+            }
+            if m.is_international() {
+                international_meetings += 1;
+            }
+            stakes += m.stakes_weight();
+            last_meeting_season = Some(m.season_id);
+        }
+
+        let (rivalry_score, rivalry_reasons) =
+            Self::rivalry_score(&stats, stakes, final_meetings, playoff_meetings, international_meetings);
+
+        TeamHeadToHead {
+            stats,
+            team1_name: String::new(),
+            team2_name: String::new(),
+            final_meetings,
+            playoff_meetings,
+            international_meetings,
+            last_meeting_season,
+            rivalry_score,
+            is_rivalry: rivalry_score >= RIVALRY_THRESHOLD,
+            rivalry_reasons,
+            recent_meetings: meetings.iter().rev().take(RECENT_MEETINGS).cloned().collect(),
+        }
+    }
+
+    /// 宿敌积分与理由：交手分量累计 + 胜负接近加分
+    fn rivalry_score(
+        stats: &HeadToHeadStats,
+        stakes: f64,
+        final_meetings: u32,
+        playoff_meetings: u32,
+        international_meetings: u32,
+    ) -> (f64, Vec<String>) {
+        let mut score = stakes;
+        let mut reasons = Vec::new();
+        if stats.total_matches > 0 {
+            reasons.push(format!("交手{}次（{}:{}）", stats.total_matches, stats.team1_wins, stats.team2_wins));
+        }
+        if final_meetings > 0 {
+            reasons.push(format!("决赛相遇{}次", final_meetings));
+        }
+        if playoff_meetings > 0 {
+            reasons.push(format!("季后赛/淘汰赛相遇{}次", playoff_meetings));
+        }
+        if international_meetings > 0 {
+            reasons.push(format!("国际赛相遇{}次", international_meetings));
+        }
+        let win_rate = stats.team1_win_rate();
+        if stats.total_matches >= CLOSE_RECORD_MIN_SERIES && (0.4..=0.6).contains(&win_rate) {
+            score += CLOSE_RECORD_BONUS;
+            reasons.push("胜负难分".to_string());
+        }
+        (score, reasons)
+    }
+
+    /// 汇总所有战队对的交锋，meetings 需按时间先后排列
+    pub fn aggregate(meetings: &[RivalryMeeting]) -> Vec<TeamHeadToHead> {
+        let mut by_pair: HashMap<(i64, i64), Vec<RivalryMeeting>> = HashMap::new();
+        for m in meetings {
+            let key = (m.home_team_id.min(m.away_team_id), m.home_team_id.max(m.away_team_id));
+            by_pair.entry(key).or_default().push(m.clone());
+        }
+        by_pair
+            .into_iter()
+            .map(|((a, b), pair_meetings)| Self::summarize(a, b, &pair_meetings))
+            .collect()
+    }
+
+    /// 加载已完成的系列赛交手（按时间先后），可限定某一对或某一支战队
+    async fn load_meetings(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        pair: Option<(i64, i64)>,
+        team_id: Option<i64>,
+    ) -> Result<Vec<RivalryMeeting>, String> {
+        let (a, b) = pair.unzip();
+        let rows = sqlx::query(
+            r#"
+            SELECT m.id, m.stage, m.home_team_id, m.away_team_id, m.home_score, m.away_score, m.winner_id,
+                   t.season_id, t.name AS tournament_name, t.tournament_type
+            FROM matches m
+            JOIN tournaments t ON t.id = m.tournament_id
+            WHERE m.save_id = ? AND m.winner_id IS NOT NULL
+              AND m.home_team_id IS NOT NULL AND m.away_team_id IS NOT NULL
+              AND (? IS NULL OR (m.home_team_id = ? AND m.away_team_id = ?) OR (m.home_team_id = ? AND m.away_team_id = ?))
+              AND (? IS NULL OR m.home_team_id = ? OR m.away_team_id = ?)
+            ORDER BY t.season_id, m.id
+            "#,
+        )
+        .bind(save_id)
+        .bind(a)
+        .bind(a)
+        .bind(b)
+        .bind(b)
+        .bind(a)
+        .bind(team_id)
+        .bind(team_id)
+        .bind(team_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询交锋记录失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|r| RivalryMeeting {
+                match_id: r.get("id"),
+                season_id: r.get("season_id"),
+                tournament_name: r.get("tournament_name"),
+                tournament_type: r.get("tournament_type"),
+                stage: r.get("stage"),
+                home_team_id: r.get("home_team_id"),
+                away_team_id: r.get("away_team_id"),
+                home_score: r.get("home_score"),
+                away_score: r.get("away_score"),
+                winner_id: r.get("winner_id"),
+            })
+            .collect())
+    }
+
+    async fn fill_team_names(pool: &Pool<Sqlite>, h2h: &mut TeamHeadToHead) -> Result<(), String> {
+        for (team_id, name) in [
+            (h2h.stats.team1_id, &mut h2h.team1_name),
+            (h2h.stats.team2_id, &mut h2h.team2_name),
+        ] {
+            let team_name: Option<String> = sqlx::query_scalar("SELECT name FROM teams WHERE id = ?")
+                .bind(team_id as i64)
+                .fetch_optional(pool)
+                .await
+                .map_err(|e| format!("查询队伍失败: {}", e))?;
+            *name = team_name.unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// 两支战队的历史交锋
+    pub async fn team_head_to_head(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_a: i64,
+        team_b: i64,
+    ) -> Result<TeamHeadToHead, String> {
+        let meetings = Self::load_meetings(pool, save_id, Some((team_a, team_b)), None).await?;
+        let mut h2h = Self::summarize(team_a, team_b, &meetings);
+        h2h.stats.save_id = save_id.to_string();
+        Self::fill_team_names(pool, &mut h2h).await?;
+        Ok(h2h)
+    }
+
+    /// 宿敌列表（可限定某支战队），按宿敌积分降序
+    pub async fn rivalries(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: Option<i64>,
+        limit: usize,
+    ) -> Result<Vec<TeamHeadToHead>, String> {
+        let meetings = Self::load_meetings(pool, save_id, None, team_id).await?;
+        let mut list: Vec<TeamHeadToHead> = Self::aggregate(&meetings).into_iter().filter(|h| h.is_rivalry).collect();
+        list.sort_by(|a, b| b.rivalry_score.partial_cmp(&a.rivalry_score).unwrap_or(std::cmp::Ordering::Equal));
+        list.truncate(limit);
+        for h2h in list.iter_mut() {
+            h2h.stats.save_id = save_id.to_string();
+            Self::fill_team_names(pool, h2h).await?;
+        }
+        Ok(list)
+    }
+
+    /// 两支战队是否为宿敌（比赛模拟时判断压力）
+    pub async fn is_rivalry(pool: &Pool<Sqlite>, save_id: &str, team_a: i64, team_b: i64) -> Result<bool, String> {
+        let meetings = Self::load_meetings(pool, save_id, Some((team_a, team_b)), None).await?;
+        Ok(Self::summarize(team_a, team_b, &meetings).is_rivalry)
+    }
+
+    /// 对位交锋：同一小局中同位置、分属两队的对局
+    const LANE_MATCHUP_SQL: &'static str = r#"
+        SELECT a.player_id, MAX(a.player_name) AS player_name,
+               b.player_id AS opponent_id, MAX(b.player_name) AS opponent_name,
+               MAX(a.position) AS position,
+               COUNT(*) AS games,
+               SUM(CASE WHEN mg.winner_team_id = a.team_id THEN 1 ELSE 0 END) AS wins,
+               SUM(COALESCE(a.kills, 0)) AS kills,
+               SUM(COALESCE(a.deaths, 0)) AS deaths,
+               SUM(COALESCE(a.assists, 0)) AS assists,
+               SUM(COALESCE(b.kills, 0)) AS opponent_kills,
+               SUM(COALESCE(b.deaths, 0)) AS opponent_deaths,
+               SUM(COALESCE(b.assists, 0)) AS opponent_assists,
+               AVG(a.impact_score) AS avg_impact,
+               AVG(b.impact_score) AS opponent_avg_impact
+        FROM game_player_performances a
+        JOIN game_player_performances b
+          ON b.save_id = a.save_id AND b.game_id = a.game_id AND b.position = a.position AND b.team_id <> a.team_id
+        JOIN match_games mg ON mg.id = a.game_id
+        WHERE a.save_id = ? AND a.player_id = ? AND (? IS NULL OR b.player_id = ?)
+        GROUP BY a.player_id, b.player_id
+        ORDER BY games DESC
+    "#;
+
+    fn row_to_player_h2h(r: &sqlx::sqlite::SqliteRow) -> PlayerHeadToHead {
+        let games: i64 = r.get("games");
+        let wins: i64 = r.get("wins");
+        PlayerHeadToHead {
+            player_id: r.get("player_id"),
+            player_name: r.get("player_name"),
+            opponent_id: r.get("opponent_id"),
+            opponent_name: r.get("opponent_name"),
+            position: r.get("position"),
+            games: games as u32,
+            wins: wins as u32,
+            losses: (games - wins) as u32,
+            kills: r.get("kills"),
+            deaths: r.get("deaths"),
+            assists: r.get("assists"),
+            opponent_kills: r.get("opponent_kills"),
+            opponent_deaths: r.get("opponent_deaths"),
+            opponent_assists: r.get("opponent_assists"),
+            avg_impact: r.get("avg_impact"),
+            opponent_avg_impact: r.get("opponent_avg_impact"),
+        }
+    }
+
+    /// 两名选手的对位交锋（未在同一局对位过则返回 None）
+    pub async fn player_head_to_head(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        player_id: i64,
+        opponent_id: i64,
+    ) -> Result<Option<PlayerHeadToHead>, String> {
+        let row = sqlx::query(Self::LANE_MATCHUP_SQL)
+            .bind(save_id)
+            .bind(player_id)
+            .bind(opponent_id)
+            .bind(opponent_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("查询对位交锋失败: {}", e))?;
+        Ok(row.as_ref().map(Self::row_to_player_h2h))
+    }
+
+    /// 选手最常交手的对位对手
+    pub async fn lane_opponents(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        player_id: i64,
+        limit: usize,
+    ) -> Result<Vec<PlayerHeadToHead>, String> {
+        let rows = sqlx::query(Self::LANE_MATCHUP_SQL)
+            .bind(save_id)
+            .bind(player_id)
+            .bind(None::<i64>)
+            .bind(None::<i64>)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("查询对位交锋失败: {}", e))?;
+        Ok(rows.iter().take(limit).map(Self::row_to_player_h2h).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meeting(match_id: i64, tournament_type: &str, stage: &str, home: i64, away: i64, home_score: i64, away_score: i64) -> RivalryMeeting {
+        RivalryMeeting {
+            match_id,
+            season_id: 1,
+            tournament_name: String::new(),
+            tournament_type: tournament_type.to_string(),
+            stage: stage.to_string(),
+            home_team_id: home,
+            away_team_id: away,
+            home_score,
+            away_score,
+            winner_id: if home_score > away_score { home } else { away },
+        }
+    }
+
+    #[test]
+    fn test_meeting_classification() {
+        let final_meeting = meeting(1, "WorldChampionship", "GRAND_FINAL", 1, 2, 3, 2);
+        assert!(final_meeting.is_final());
+        assert!(final_meeting.is_international());
+
+        let playoff = meeting(2, "SpringPlayoffs", "WINNERS_R1", 1, 2, 3, 0);
+        assert!(playoff.is_playoff());
+        assert!(!playoff.is_final());
+        assert!(!playoff.is_international());
+        assert!(meeting(4, "Msi", "SEMI_FINAL", 1, 2, 3, 1).is_playoff());
+        assert!(!meeting(5, "Msi", "GROUP_A", 1, 2, 1, 0).is_playoff());
+
+        assert!(meeting(3, "IcpIntercontinental", "FINALS_R1", 1, 2, 2, 1).is_final());
+    }
+
+    #[test]
+    fn test_summarize_normalizes_team_order() {
+        let meetings = vec![
+            meeting(1, "SpringRegular", "REGULAR", 5, 3, 2, 1),
+            meeting(2, "SpringRegular", "REGULAR", 3, 5, 2, 0),
+            meeting(3, "SpringPlayoffs", "GRAND_FINAL", 5, 3, 3, 2),
+        ];
+        let h2h = RivalryEngine::summarize(5, 3, &meetings);
+        assert_eq!(h2h.stats.team1_id, 3);
+        assert_eq!(h2h.stats.team2_id, 5);
+        assert_eq!(h2h.stats.total_matches, 3);
+        assert_eq!(h2h.stats.team1_wins, 1);
+        assert_eq!(h2h.stats.team2_wins, 2);
+        assert_eq!(h2h.stats.team1_games, 5);
+        assert_eq!(h2h.stats.team2_games, 5);
+        assert_eq!(h2h.final_meetings, 1);
+        assert_eq!(h2h.stats.last_match_id, Some(3));
+        assert_eq!(h2h.recent_meetings[0].match_id, 3);
+    }
+
+    #[test]
+    fn test_regular_meetings_alone_rarely_make_rivalry() {
+        let meetings: Vec<_> = (0..6)
+            .map(|i| meeting(i, "SummerRegular", "REGULAR", 1, 2, if i % 2 == 0 { 2 } else { 0 }, if i % 2 == 0 { 0 } else { 2 }))
+            .collect();
+        let h2h = RivalryEngine::summarize(1, 2, &meetings);
+        // 6 × 0.5 + 胜负接近 4 = 7
+        assert_eq!(h2h.rivalry_score, 7.0);
+        assert!(!h2h.is_rivalry);
+    }
+
+    #[test]
+    fn test_finals_meetings_make_rivalry() {
+        let meetings = vec![
+            meeting(1, "SpringPlayoffs", "GRAND_FINAL", 1, 2, 3, 2),
+            meeting(2, "SummerPlayoffs", "GRAND_FINAL", 2, 1, 3, 1),
+            meeting(3, "WorldChampionship", "SEMI_FINAL", 1, 2, 3, 0),
+        ];
+        let h2h = RivalryEngine::summarize(1, 2, &meetings);
+        assert!(h2h.is_rivalry);
+        assert_eq!(h2h.international_meetings, 1);
+        assert!(h2h.rivalry_reasons.iter().any(|r| r.contains("决赛相遇2次")));
+    }
+
+    #[test]
+    fn test_aggregate_groups_pairs() {
+        let meetings = vec![
+            meeting(1, "SpringRegular", "REGULAR", 1, 2, 2, 0),
+            meeting(2, "SpringRegular", "REGULAR", 2, 1, 2, 1),
+            meeting(3, "SpringRegular", "REGULAR", 1, 3, 2, 1),
+        ];
+        let mut all = RivalryEngine::aggregate(&meetings);
+        all.sort_by_key(|h| (h.stats.team1_id, h.stats.team2_id));
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].stats.total_matches, 2);
+        assert_eq!(all[1].stats.total_matches, 1);
+    }
+}
@@ -0,0 +1,487 @@
+//! 老将角色转型引擎 (Role Transition Engine)
+//!
+//! - 转位置：选手可在一个赛季内转练新位置，开始时承受临时能力惩罚并重置英雄池，
+//!   满一个赛季后恢复大部分能力（跨度大的转型会留下 1 点永久损失）
+//! - 选手兼教练：带有 Mentor 特性的高龄替补可签下兼任教练的合同
+//! - 第六人：带有 BattleTested 特性的老将替补可签下第六人合同
+//!
+//! AI 球队在转会期第 2 轮生成位置需求后，优先用阵容内的富余选手转位置填补缺口
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+
+use super::champion::{self, MasteryTier};
+use super::traits::TraitType;
+use super::transfer::CachedPlayer;
+use crate::models::{ContractRole, Position, PositionRetraining, RetrainingStatus};
+
+/// 转位置基础能力惩罚
+const BASE_RETRAIN_PENALTY: u8 = 6;
+/// 相近位置之间转型的能力惩罚
+const ADJACENT_RETRAIN_PENALTY: u8 = 4;
+/// 能力惩罚上限
+const MAX_RETRAIN_PENALTY: u8 = 10;
+/// 允许转位置的最大年龄
+pub const RETRAIN_MAX_AGE: u8 = 30;
+
+/// 选手兼教练最低年龄
+const PLAYING_COACH_MIN_AGE: u8 = 29;
+/// 第六人最低年龄
+const SIXTH_MAN_MIN_AGE: u8 = 27;
+
+/// AI 转位置时，转型后能力至少要达到需求目标减去该容差
+const AI_RETRAIN_TARGET_TOLERANCE: i64 = 3;
+
+/// 角色转型引擎
+pub struct RoleTransitionEngine;
+
+impl RoleTransitionEngine {
+    /// 是否为相近位置（技能重合度高，转型成本低）
+    pub fn is_adjacent(from: Position, to: Position) -> bool {
+        use Position::*;
+        matches!(
+            (from, to),
+            (Top, Mid) | (Mid, Top) | (Mid, Adc) | (Adc, Mid) | (Jug, Sup) | (Sup, Jug) | (Top, Jug) | (Jug, Top)
+        )
+    }
+
+    /// 转位置的临时能力惩罚：相近位置更低，25 岁后每 2 岁 +1
+    pub fn retraining_penalty(from: Position, to: Position, age: u8) -> u8 {
+        let base = if Self::is_adjacent(from, to) {
+            ADJACENT_RETRAIN_PENALTY
+        } else {
+            BASE_RETRAIN_PENALTY
+        };
+        let age_extra = age.saturating_sub(25) / 2;
+        (base + age_extra).min(MAX_RETRAIN_PENALTY)
+    }
+
+    /// 训练期满后恢复的能力值（非相近位置保留 1 点永久损失）
+    pub fn recovery_amount(from: Position, to: Position, penalty: u8) -> u8 {
+        if Self::is_adjacent(from, to) {
+            penalty
+        } else {
+            penalty.saturating_sub(1)
+        }
+    }
+
+    /// 校验是否可以转位置
+    pub fn validate_retraining(from: Position, to: Position, age: u8) -> Result<(), String> {
+        if from == to {
+            return Err("目标位置与当前位置相同".to_string());
+        }
+        if age > RETRAIN_MAX_AGE {
+            return Err(format!("选手年龄 {} 岁，超过转位置上限 {} 岁", age, RETRAIN_MAX_AGE));
+        }
+        Ok(())
+    }
+
+    /// 老将可接受的特殊合同角色：Mentor 优先兼任教练，其次 BattleTested 担任第六人
+    pub fn eligible_veteran_role(age: u8, traits: &[TraitType]) -> Option<ContractRole> {
+        if age >= PLAYING_COACH_MIN_AGE && traits.contains(&TraitType::Mentor) {
+            Some(ContractRole::PlayingCoach)
+        } else if age >= SIXTH_MAN_MIN_AGE && traits.contains(&TraitType::BattleTested) {
+            Some(ContractRole::SixthMan)
+        } else {
+            None
+        }
+    }
+
+    /// 校验选手是否可以签下指定的老将角色
+    pub fn validate_veteran_role(role: ContractRole, age: u8, traits: &[TraitType]) -> Result<(), String> {
+        let ok = match role {
+            ContractRole::PlayingCoach => age >= PLAYING_COACH_MIN_AGE && traits.contains(&TraitType::Mentor),
+            ContractRole::SixthMan => {
+                age >= SIXTH_MAN_MIN_AGE
+                    && (traits.contains(&TraitType::BattleTested) || traits.contains(&TraitType::Mentor))
+            }
+            _ => return Err(format!("{} 不是老将角色", role.as_str())),
+        };
+        if ok {
+            Ok(())
+        } else {
+            Err(format!("选手不满足 {} 合同条件", role.as_str()))
+        }
+    }
+
+    /// 老将角色的薪资系数（出场减少换取稳定合同）
+    pub fn role_salary_factor(role: ContractRole) -> f64 {
+        match role {
+            ContractRole::PlayingCoach => 0.9,
+            ContractRole::SixthMan => 0.75,
+            _ => 1.0,
+        }
+    }
+
+    /// 为缺口位置挑选转位置人选：只考虑所在位置有富余（非该位置最强）的选手，
+    /// 返回 (选手ID, 能力惩罚)，按转型后能力最高者优先
+    pub fn pick_retrain_candidate(
+        need_position: Position,
+        min_ability_target: i64,
+        roster: &[CachedPlayer],
+    ) -> Option<(i64, u8)> {
+        let need_str = format!("{:?}", need_position);
+
+        roster
+            .iter()
+            .filter(|p| p.position != need_str && p.age <= RETRAIN_MAX_AGE as i64)
+            .filter(|p| {
+                // 原位置必须仍有更强的选手
+                roster
+                    .iter()
+                    .any(|o| o.id != p.id && o.position == p.position && o.ability >= p.ability)
+            })
+            .map(|p| {
+                let from = Position::from_str(&p.position);
+                let penalty = Self::retraining_penalty(from, need_position, p.age as u8);
+                (p.id, penalty, p.ability - penalty as i64)
+            })
+            .filter(|(_, _, projected)| *projected >= min_ability_target - AI_RETRAIN_TARGET_TOLERANCE)
+            .max_by_key(|(_, _, projected)| *projected)
+            .map(|(id, penalty, _)| (id, penalty))
+    }
+
+    // ==================== 数据库操作 ====================
+
+    /// 开始转位置：立即转到新位置、扣除能力、重置英雄熟练度
+    pub async fn start_retraining(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        player_id: u64,
+        to_position: Position,
+        season: i64,
+    ) -> Result<PositionRetraining, String> {
+        let row = sqlx::query(
+            "SELECT game_id, team_id, position, ability, age FROM players WHERE id = ? AND save_id = ? AND status = 'Active'",
+        )
+        .bind(player_id as i64)
+        .bind(save_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询选手失败: {}", e))?
+        .ok_or_else(|| format!("选手 {} 不存在或已退役", player_id))?;
+
+        let game_id: String = row.get("game_id");
+        let team_id: Option<i64> = row.get("team_id");
+        let from_position = Position::from_str(&row.get::<String, _>("position"));
+        let ability: i64 = row.get("ability");
+        let age = row.get::<i64, _>("age") as u8;
+
+        Self::validate_retraining(from_position, to_position, age)?;
+
+        let in_progress: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM position_retraining WHERE save_id = ? AND player_id = ? AND status = 'InProgress'",
+        )
+        .bind(save_id)
+        .bind(player_id as i64)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询转位置记录失败: {}", e))?;
+        if in_progress > 0 {
+            return Err(format!("{} 正在转位置训练中", game_id));
+        }
+
+        let penalty = Self::retraining_penalty(from_position, to_position, age);
+        let new_ability = (ability - penalty as i64).max(1);
+
+        // 位置、英雄熟练度与转位置记录一并写入，避免中途失败留下半转位置的选手
+        let mut tx = pool.begin().await.map_err(|e| format!("开启事务失败: {}", e))?;
+
+        sqlx::query("UPDATE players SET position = ?, ability = ?, is_starter = 0 WHERE id = ?")
+            .bind(format!("{:?}", to_position))
+            .bind(new_ability)
+            .bind(player_id as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("更新选手位置失败: {}", e))?;
+
+        Self::reset_champion_mastery(&mut *tx, save_id, player_id, to_position).await?;
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO position_retraining (save_id, player_id, team_id, from_position, to_position, start_season, ability_penalty, status)
+            VALUES (?, ?, ?, ?, ?, ?, ?, 'InProgress')
+            "#,
+        )
+        .bind(save_id)
+        .bind(player_id as i64)
+        .bind(team_id)
+        .bind(format!("{:?}", from_position))
+        .bind(format!("{:?}", to_position))
+        .bind(season)
+        .bind(penalty as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("记录转位置训练失败: {}", e))?;
+
+        tx.commit().await.map_err(|e| format!("提交事务失败: {}", e))?;
+
+        Ok(PositionRetraining {
+            id: result.last_insert_rowid(),
+            player_id,
+            player_name: game_id,
+            team_id: team_id.map(|t| t as u64),
+            from_position: format!("{:?}", from_position),
+            to_position: format!("{:?}", to_position),
+            start_season: season,
+            ability_penalty: penalty,
+            status: RetrainingStatus::InProgress,
+            completed_season: None,
+        })
+    }
+
+    /// 清空旧位置的英雄熟练度，新位置只保留一个 A 级英雄
+    async fn reset_champion_mastery(
+        conn: &mut SqliteConnection,
+        save_id: &str,
+        player_id: u64,
+        position: Position,
+    ) -> Result<(), String> {
+        sqlx::query("DELETE FROM player_champion_mastery WHERE save_id = ? AND player_id = ?")
+            .bind(save_id)
+            .bind(player_id as i64)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| format!("重置英雄熟练度失败: {}", e))?;
+
+        let mut rng = rand::rngs::StdRng::from_entropy();
+        if let Some(champion) = champion::get_champions_by_position(position).choose(&mut rng) {
+            sqlx::query(
+                r#"
+                INSERT INTO player_champion_mastery (save_id, player_id, champion_id, mastery_tier, games_played, games_won)
+                VALUES (?, ?, ?, ?, 0, 0)
+                ON CONFLICT(save_id, player_id, champion_id) DO NOTHING
+                "#,
+            )
+            .bind(save_id)
+            .bind(player_id as i64)
+            .bind(champion.id as i64)
+            .bind(MasteryTier::A.id())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| format!("写入新位置英雄熟练度失败: {}", e))?;
+        }
+        Ok(())
+    }
+
+    /// 完成已满一个赛季的转位置训练，恢复临时扣除的能力
+    pub async fn complete_due_retrainings(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season: i64,
+    ) -> Result<Vec<PositionRetraining>, String> {
+        let due: Vec<PositionRetraining> = Self::list_retrainings(pool, save_id, None)
+            .await?
+            .into_iter()
+            .filter(|r| r.status == RetrainingStatus::InProgress && r.start_season < season)
+            .collect();
+
+        let mut completed = Vec::with_capacity(due.len());
+        for mut r in due {
+            let recovery = Self::recovery_amount(
+                Position::from_str(&r.from_position),
+                Position::from_str(&r.to_position),
+                r.ability_penalty,
+            );
+
+            sqlx::query("UPDATE players SET ability = MIN(100, ability + ?) WHERE id = ?")
+                .bind(recovery as i64)
+                .bind(r.player_id as i64)
+                .execute(pool)
+                .await
+                .map_err(|e| format!("恢复转位置能力失败: {}", e))?;
+
+            sqlx::query("UPDATE position_retraining SET status = 'Completed', completed_season = ? WHERE id = ?")
+                .bind(season)
+                .bind(r.id)
+                .execute(pool)
+                .await
+                .map_err(|e| format!("更新转位置记录失败: {}", e))?;
+
+            r.status = RetrainingStatus::Completed;
+            r.completed_season = Some(season);
+            completed.push(r);
+        }
+        Ok(completed)
+    }
+
+    /// 查询转位置记录（可按队伍过滤）
+    pub async fn list_retrainings(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: Option<u64>,
+    ) -> Result<Vec<PositionRetraining>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT r.*, p.game_id
+            FROM position_retraining r
+            JOIN players p ON p.id = r.player_id
+            WHERE r.save_id = ? AND (? IS NULL OR r.team_id = ?)
+            ORDER BY r.start_season DESC, r.id DESC
+            "#,
+        )
+        .bind(save_id)
+        .bind(team_id.map(|t| t as i64))
+        .bind(team_id.map(|t| t as i64))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询转位置记录失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| PositionRetraining {
+                id: row.get("id"),
+                player_id: row.get::<i64, _>("player_id") as u64,
+                player_name: row.get("game_id"),
+                team_id: row.get::<Option<i64>, _>("team_id").map(|t| t as u64),
+                from_position: row.get("from_position"),
+                to_position: row.get("to_position"),
+                start_season: row.get("start_season"),
+                ability_penalty: row.get::<i64, _>("ability_penalty") as u8,
+                status: RetrainingStatus::parse(&row.get::<String, _>("status")),
+                completed_season: row.get("completed_season"),
+            })
+            .collect())
+    }
+
+    /// 签下老将角色合同：更新合同角色并按系数调整薪资
+    pub async fn assign_veteran_role(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        player_id: u64,
+        role: ContractRole,
+    ) -> Result<i64, String> {
+        let row = sqlx::query("SELECT age, salary, contract_role FROM players WHERE id = ? AND save_id = ? AND status = 'Active'")
+            .bind(player_id as i64)
+            .bind(save_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("查询选手失败: {}", e))?
+            .ok_or_else(|| format!("选手 {} 不存在或已退役", player_id))?;
+
+        let age = row.get::<i64, _>("age") as u8;
+        let salary: i64 = row.get("salary");
+        let current_role = ContractRole::parse(&row.try_get::<String, _>("contract_role").unwrap_or_default());
+        if current_role == role {
+            return Ok(salary);
+        }
+
+        let traits = Self::player_traits(pool, save_id, player_id).await?;
+        Self::validate_veteran_role(role, age, &traits)?;
+
+        let new_salary = ((salary as f64 * Self::role_salary_factor(role)).round() as i64).max(1);
+        sqlx::query("UPDATE players SET contract_role = ?, salary = ?, is_starter = 0 WHERE id = ?")
+            .bind(role.as_str())
+            .bind(new_salary)
+            .bind(player_id as i64)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新合同角色失败: {}", e))?;
+
+        Ok(new_salary)
+    }
+
+    /// 查询选手特性
+    pub async fn player_traits(pool: &Pool<Sqlite>, save_id: &str, player_id: u64) -> Result<Vec<TraitType>, String> {
+        let rows: Vec<String> = sqlx::query_scalar("SELECT trait_type FROM player_traits WHERE save_id = ? AND player_id = ?")
+            .bind(save_id)
+            .bind(player_id as i64)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("查询特性失败: {}", e))?;
+        Ok(rows.iter().filter_map(|s| TraitType::from_str(s)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached(id: i64, position: &str, ability: i64, age: i64) -> CachedPlayer {
+        CachedPlayer {
+            id,
+            game_id: format!("P{}", id),
+            ability,
+            potential: ability,
+            age,
+            salary: 0,
+            loyalty: 70,
+            satisfaction: 70,
+            position: position.to_string(),
+            tag: "NORMAL".to_string(),
+            team_id: Some(1),
+            is_starter: false,
+            home_region_id: None,
+            region_loyalty: 70,
+            contract_end_season: None,
+            status: "Active".to_string(),
+            stability: 60,
+            growth_accumulator: 0.0,
+            contract_role: "Sub".to_string(),
+            season_games_played: 0,
+            season_games_total: 0,
+        }
+    }
+
+    #[test]
+    fn test_penalty_depends_on_adjacency_and_age() {
+        let adjacent = RoleTransitionEngine::retraining_penalty(Position::Top, Position::Mid, 22);
+        let far = RoleTransitionEngine::retraining_penalty(Position::Top, Position::Sup, 22);
+        let old_far = RoleTransitionEngine::retraining_penalty(Position::Top, Position::Sup, 30);
+        assert_eq!(adjacent, ADJACENT_RETRAIN_PENALTY);
+        assert_eq!(far, BASE_RETRAIN_PENALTY);
+        assert!(old_far > far && old_far <= MAX_RETRAIN_PENALTY);
+
+        assert_eq!(RoleTransitionEngine::recovery_amount(Position::Top, Position::Mid, 4), 4);
+        assert_eq!(RoleTransitionEngine::recovery_amount(Position::Top, Position::Sup, 6), 5);
+    }
+
+    #[test]
+    fn test_validate_retraining() {
+        assert!(RoleTransitionEngine::validate_retraining(Position::Mid, Position::Mid, 22).is_err());
+        assert!(RoleTransitionEngine::validate_retraining(Position::Mid, Position::Top, 31).is_err());
+        assert!(RoleTransitionEngine::validate_retraining(Position::Mid, Position::Top, 30).is_ok());
+    }
+
+    #[test]
+    fn test_veteran_role_eligibility() {
+        let mentor = [TraitType::Mentor];
+        let battle = [TraitType::BattleTested];
+        assert_eq!(RoleTransitionEngine::eligible_veteran_role(30, &mentor), Some(ContractRole::PlayingCoach));
+        assert_eq!(RoleTransitionEngine::eligible_veteran_role(27, &battle), Some(ContractRole::SixthMan));
+        assert_eq!(RoleTransitionEngine::eligible_veteran_role(26, &battle), None);
+        assert_eq!(RoleTransitionEngine::eligible_veteran_role(30, &[]), None);
+
+        assert!(RoleTransitionEngine::validate_veteran_role(ContractRole::PlayingCoach, 28, &mentor).is_err());
+        assert!(RoleTransitionEngine::validate_veteran_role(ContractRole::SixthMan, 28, &mentor).is_ok());
+        assert!(RoleTransitionEngine::validate_veteran_role(ContractRole::Starter, 30, &mentor).is_err());
+    }
+
+    #[test]
+    fn test_pick_retrain_candidate_uses_surplus_only() {
+        let roster = vec![
+            cached(1, "Mid", 70, 24),
+            cached(2, "Mid", 66, 23), // 中单富余（非该位置最强）
+            cached(3, "Top", 62, 25), // 唯一上单，不能转走
+            cached(4, "Adc", 64, 31), // 超龄
+            cached(5, "Adc", 68, 22),
+        ];
+
+        // Mid → Jug 非相近位置：66 - 6 = 60
+        let pick = RoleTransitionEngine::pick_retrain_candidate(Position::Jug, 58, &roster);
+        assert_eq!(pick.map(|(id, _)| id), Some(2));
+
+        // 目标过高时无人可转
+        assert!(RoleTransitionEngine::pick_retrain_candidate(Position::Jug, 70, &roster).is_none());
+    }
+
+    #[test]
+    fn test_contract_role_expected_rate() {
+        assert_eq!(ContractRole::parse("SixthMan"), ContractRole::SixthMan);
+        assert_eq!(ContractRole::parse("unknown"), ContractRole::Starter);
+        assert!(ContractRole::SixthMan.expected_play_rate() > ContractRole::Sub.expected_play_rate());
+        assert!(ContractRole::PlayingCoach.is_veteran_role());
+        assert!(!ContractRole::Sub.is_veteran_role());
+    }
+}
@@ -0,0 +1,383 @@
+//! 薪资帽引擎 (Salary Cap Engine)
+//!
+//! - 帽线由 `FinancialConfig` 按赛区资本规模换算：LCK/LEC 为硬帽，LPL/LCS 为软帽
+//! - 计入薪资帽 = 在约合同薪资 + 到期合同的续约预留额（cap hold）+ 死钱
+//! - 硬帽球队签约后不得超过帽线；软帽球队可超帽至上限线，超帽部分在第 6 轮缴纳奢侈税
+//! - 单人薪资上限对所有赛区生效，新合同薪资一律截断至该上限
+
+use std::collections::HashMap;
+
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::models::{
+    CapRules, CapSheetEntry, CapType, DeadCapEntry, FinancialConfig, RegionCode, TeamCapSheet,
+};
+
+/// 到期合同的续约预留额比例（按现薪资上浮预留）
+pub const CAP_HOLD_RATIO: f64 = 1.1;
+/// 解约后计入当赛季薪资帽的死钱比例
+pub const DEAD_CAP_RATIO: f64 = 0.5;
+/// 缺位紧急签约可豁免薪资帽的底薪合同金额
+pub const EMERGENCY_MIN_SALARY: i64 = 300_000;
+
+/// 薪资帽引擎
+pub struct SalaryCapEngine;
+
+impl SalaryCapEngine {
+    /// 赛区薪资帽规则
+    pub fn rules_for_region(region: RegionCode) -> CapRules {
+        FinancialConfig::default().cap_rules(region)
+    }
+
+    /// 合同是否在本赛季到期
+    pub fn is_expiring(contract_end_season: Option<i64>, season_id: i64) -> bool {
+        contract_end_season.is_some_and(|end| end <= season_id)
+    }
+
+    /// 单份合同计入薪资帽的金额（到期合同按续约预留额计）
+    pub fn cap_hit(salary: i64, contract_end_season: Option<i64>, season_id: i64) -> i64 {
+        if Self::is_expiring(contract_end_season, season_id) {
+            (salary as f64 * CAP_HOLD_RATIO) as i64
+        } else {
+            salary
+        }
+    }
+
+    /// 计入薪资帽总额
+    pub fn payroll<I>(contracts: I, season_id: i64, dead_cap: i64) -> i64
+    where
+        I: IntoIterator<Item = (i64, Option<i64>)>,
+    {
+        contracts
+            .into_iter()
+            .map(|(salary, end)| Self::cap_hit(salary, end, season_id))
+            .sum::<i64>()
+            + dead_cap
+    }
+
+    /// 超帽部分的奢侈税
+    pub fn luxury_tax(rules: &CapRules, payroll: i64) -> i64 {
+        let over = (payroll - rules.salary_cap).max(0);
+        (over as f64 * rules.luxury_tax_rate) as i64
+    }
+
+    /// 新合同薪资截断至单人上限
+    pub fn clamp_salary(rules: &CapRules, salary: i64) -> i64 {
+        salary.min(rules.individual_salary_cap)
+    }
+
+    /// 签约检查：incoming 为新合同计入额，outgoing 为同时移出的计入额（如续约时的预留额）
+    pub fn check_signing(rules: &CapRules, payroll: i64, incoming: i64, outgoing: i64) -> Result<(), String> {
+        if incoming > rules.individual_salary_cap {
+            return Err(format!(
+                "单人薪资{}万超过上限{}万",
+                incoming / 10000,
+                rules.individual_salary_cap / 10000
+            ));
+        }
+        let after = payroll - outgoing + incoming;
+        if incoming > outgoing && after > rules.apron {
+            return Err(match rules.cap_type {
+                CapType::Hard => format!("签约后薪资{}万超过硬帽{}万", after / 10000, rules.apron / 10000),
+                CapType::Soft => format!("签约后薪资{}万超过软帽上限{}万", after / 10000, rules.apron / 10000),
+            });
+        }
+        Ok(())
+    }
+
+    /// 缺位紧急签约：帽内按原薪资签约；超帽时降为底薪合同豁免签约，返回 (薪资, 超出上限线的金额)
+    ///
+    /// 超出金额只计本次签约带来的部分，签约前已超出上限线的薪资不重复计入
+    pub fn emergency_signing(rules: &CapRules, payroll: i64, salary: i64) -> (i64, i64) {
+        let salary = Self::clamp_salary(rules, salary);
+        if Self::check_signing(rules, payroll, salary, 0).is_ok() {
+            return (salary, 0);
+        }
+        let salary = salary.min(EMERGENCY_MIN_SALARY);
+        let overage = (payroll + salary - rules.apron).max(0).min(salary);
+        (salary, overage)
+    }
+
+    /// 剩余薪资空间（可为负）
+    pub fn cap_space(rules: &CapRules, payroll: i64) -> i64 {
+        rules.salary_cap - payroll
+    }
+
+    // ============================================
+    // 数据库
+    // ============================================
+
+    /// 各球队所属赛区的薪资帽规则
+    pub async fn load_team_rules(pool: &Pool<Sqlite>, save_id: &str) -> Result<HashMap<i64, CapRules>, String> {
+        let rows = sqlx::query(
+            "SELECT t.id, r.name as region_name FROM teams t LEFT JOIN regions r ON t.region_id = r.id WHERE t.save_id = ?",
+        )
+        .bind(save_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询球队赛区失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let region: Option<String> = row.get("region_name");
+                let code = RegionCode::from_str(region.as_deref().unwrap_or(""));
+                (row.get::<i64, _>("id"), Self::rules_for_region(code))
+            })
+            .collect())
+    }
+
+    /// 各球队本赛季死钱合计
+    pub async fn load_dead_cap_totals(pool: &Pool<Sqlite>, save_id: &str, season_id: i64) -> Result<HashMap<i64, i64>, String> {
+        let rows = sqlx::query(
+            "SELECT team_id, SUM(amount) as total FROM team_dead_cap WHERE save_id = ? AND season_id = ? GROUP BY team_id",
+        )
+        .bind(save_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询死钱失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| (row.get::<i64, _>("team_id"), row.get::<i64, _>("total")))
+            .collect())
+    }
+
+    /// 记录死钱
+    pub async fn record_dead_cap(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: i64,
+        season_id: i64,
+        player_id: i64,
+        amount: i64,
+        reason: &str,
+    ) -> Result<(), String> {
+        if amount <= 0 {
+            return Ok(());
+        }
+        sqlx::query(
+            "INSERT INTO team_dead_cap (save_id, team_id, season_id, player_id, amount, reason) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(save_id)
+        .bind(team_id)
+        .bind(season_id)
+        .bind(player_id)
+        .bind(amount)
+        .bind(reason)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("记录死钱失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 生成球队薪资表
+    pub async fn build_cap_sheet(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: i64,
+        season_id: i64,
+    ) -> Result<TeamCapSheet, String> {
+        let team = sqlx::query(
+            "SELECT t.name, r.name as region_name FROM teams t LEFT JOIN regions r ON t.region_id = r.id WHERE t.id = ? AND t.save_id = ?",
+        )
+        .bind(team_id)
+        .bind(save_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询球队失败: {}", e))?
+        .ok_or_else(|| format!("球队 {} 不存在", team_id))?;
+        let team_name: String = team.get("name");
+        let region_code: String = team.get::<Option<String>, _>("region_name").unwrap_or_default();
+        let rules = Self::rules_for_region(RegionCode::from_str(&region_code));
+
+        let player_rows = sqlx::query(
+            r#"SELECT id, game_id, position, salary, contract_end_season FROM players
+               WHERE save_id = ? AND team_id = ? AND status = 'Active'
+               ORDER BY salary DESC"#,
+        )
+        .bind(save_id)
+        .bind(team_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询球队合同失败: {}", e))?;
+
+        let entries: Vec<CapSheetEntry> = player_rows
+            .iter()
+            .map(|row| {
+                let salary: i64 = row.get("salary");
+                let end: Option<i64> = row.get("contract_end_season");
+                CapSheetEntry {
+                    player_id: row.get::<i64, _>("id") as u64,
+                    player_name: row.get("game_id"),
+                    position: row.get::<Option<String>, _>("position").unwrap_or_default(),
+                    salary,
+                    contract_end_season: end.map(|e| e as u32),
+                    is_expiring: Self::is_expiring(end, season_id),
+                    cap_hit: Self::cap_hit(salary, end, season_id),
+                }
+            })
+            .collect();
+
+        let dead_rows = sqlx::query(
+            r#"SELECT d.player_id, d.amount, d.reason, p.game_id
+               FROM team_dead_cap d LEFT JOIN players p ON d.player_id = p.id
+               WHERE d.save_id = ? AND d.team_id = ? AND d.season_id = ?
+               ORDER BY d.amount DESC"#,
+        )
+        .bind(save_id)
+        .bind(team_id)
+        .bind(season_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询死钱失败: {}", e))?;
+
+        let dead_cap: Vec<DeadCapEntry> = dead_rows
+            .iter()
+            .map(|row| DeadCapEntry {
+                player_id: row.get::<Option<i64>, _>("player_id").unwrap_or(0) as u64,
+                player_name: row.get::<Option<String>, _>("game_id").unwrap_or_default(),
+                amount: row.get("amount"),
+                reason: row.get("reason"),
+            })
+            .collect();
+
+        let committed_salary = entries.iter().filter(|e| !e.is_expiring).map(|e| e.cap_hit).sum();
+        let cap_holds = entries.iter().filter(|e| e.is_expiring).map(|e| e.cap_hit).sum();
+        let dead_cap_total = dead_cap.iter().map(|d| d.amount).sum();
+        let payroll = committed_salary + cap_holds + dead_cap_total;
+
+        Ok(TeamCapSheet {
+            team_id: team_id as u64,
+            team_name,
+            season_id: season_id as u64,
+            region_code,
+            rules,
+            entries,
+            dead_cap,
+            committed_salary,
+            cap_holds,
+            dead_cap_total,
+            payroll,
+            cap_space: Self::cap_space(&rules, payroll),
+            projected_luxury_tax: Self::luxury_tax(&rules, payroll),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_rules() {
+        let lpl = SalaryCapEngine::rules_for_region(RegionCode::LPL);
+        let lck = SalaryCapEngine::rules_for_region(RegionCode::LCK);
+        assert_eq!(lpl.cap_type, CapType::Soft);
+        assert_eq!(lck.cap_type, CapType::Hard);
+        assert!(lpl.salary_cap > lck.salary_cap);
+        assert!(lpl.apron > lpl.salary_cap);
+        assert_eq!(lck.apron, lck.salary_cap);
+    }
+
+    /// 硬帽赛区的初始阵容必须在帽线内，否则开档后转会窗口会拒绝该队的所有签约
+    #[test]
+    fn test_seeded_hard_cap_payrolls_fit_under_cap() {
+        use crate::services::player_data::get_team_players;
+        use crate::services::InitService;
+
+        for region in InitService::get_regions() {
+            let rules = SalaryCapEngine::rules_for_region(RegionCode::from_str(region.name));
+            if rules.cap_type != CapType::Hard {
+                continue;
+            }
+            for (_, short_name) in &region.team_names {
+                let players = get_team_players(short_name);
+                assert!(!players.is_empty(), "{} 缺少初始阵容", short_name);
+                // 按全部合同本赛季到期（计入续约预留额）的最坏情况计算
+                let payroll = SalaryCapEngine::payroll(
+                    players.iter().map(|p| {
+                        let tag = InitService::determine_player_tag(p.ability, p.potential, p.age);
+                        (InitService::calculate_initial_salary(p.ability, p.potential, tag) as i64, Some(1))
+                    }),
+                    1,
+                    0,
+                );
+                assert!(
+                    payroll <= rules.salary_cap,
+                    "{} {} 初始薪资 {} 超过硬帽 {}",
+                    region.name,
+                    short_name,
+                    payroll,
+                    rules.salary_cap
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cap_hold_for_expiring_contracts() {
+        assert_eq!(SalaryCapEngine::cap_hit(1_000_000, Some(3), 3), 1_100_000);
+        assert_eq!(SalaryCapEngine::cap_hit(1_000_000, Some(4), 3), 1_000_000);
+        assert_eq!(SalaryCapEngine::cap_hit(1_000_000, None, 3), 1_000_000);
+        let payroll = SalaryCapEngine::payroll(vec![(1_000_000, Some(3)), (2_000_000, Some(5))], 3, 500_000);
+        assert_eq!(payroll, 3_600_000);
+    }
+
+    #[test]
+    fn test_luxury_tax() {
+        let rules = SalaryCapEngine::rules_for_region(RegionCode::LEC);
+        assert_eq!(SalaryCapEngine::luxury_tax(&rules, rules.salary_cap), 0);
+        assert_eq!(
+            SalaryCapEngine::luxury_tax(&rules, rules.salary_cap + 1_000_000),
+            (1_000_000.0 * rules.luxury_tax_rate) as i64
+        );
+    }
+
+    #[test]
+    fn test_hard_and_soft_cap_signing() {
+        let hard = SalaryCapEngine::rules_for_region(RegionCode::LCK);
+        let soft = SalaryCapEngine::rules_for_region(RegionCode::LPL);
+
+        // 硬帽：超过帽线即拒绝
+        assert!(SalaryCapEngine::check_signing(&hard, hard.salary_cap - 500_000, 1_000_000, 0).is_err());
+        assert!(SalaryCapEngine::check_signing(&hard, hard.salary_cap - 1_000_000, 1_000_000, 0).is_ok());
+
+        // 软帽：可超帽至上限线
+        assert!(SalaryCapEngine::check_signing(&soft, soft.salary_cap, 1_000_000, 0).is_ok());
+        assert!(SalaryCapEngine::check_signing(&soft, soft.apron, 1_000_000, 0).is_err());
+
+        // 单人上限对所有赛区生效
+        assert!(SalaryCapEngine::check_signing(&soft, 0, soft.individual_salary_cap + 1, 0).is_err());
+
+        // 降薪续约（移出额大于签入额）不受帽线限制
+        assert!(SalaryCapEngine::check_signing(&hard, hard.salary_cap + 2_000_000, 1_000_000, 1_100_000).is_ok());
+    }
+
+    #[test]
+    fn test_emergency_signing_exemption() {
+        let hard = SalaryCapEngine::rules_for_region(RegionCode::LCK);
+
+        // 帽内：按原薪资签约
+        assert_eq!(SalaryCapEngine::emergency_signing(&hard, 0, 1_000_000), (1_000_000, 0));
+
+        // 超帽：降为底薪合同，超出部分单独记账
+        let payroll = hard.salary_cap - 100_000;
+        assert_eq!(
+            SalaryCapEngine::emergency_signing(&hard, payroll, 1_000_000),
+            (EMERGENCY_MIN_SALARY, EMERGENCY_MIN_SALARY - 100_000)
+        );
+
+        // 底薪合同本身仍在帽内时不产生超额
+        let payroll = hard.salary_cap - EMERGENCY_MIN_SALARY;
+        assert_eq!(SalaryCapEngine::emergency_signing(&hard, payroll, 1_000_000), (EMERGENCY_MIN_SALARY, 0));
+
+        // 签约前已超出上限线：只计本次底薪合同
+        let payroll = hard.apron + 2_000_000;
+        assert_eq!(
+            SalaryCapEngine::emergency_signing(&hard, payroll, 1_000_000),
+            (EMERGENCY_MIN_SALARY, EMERGENCY_MIN_SALARY)
+        );
+    }
+}
@@ -7,7 +7,7 @@
 
 use crate::engines::market_value::MarketValueEngine;
 use crate::models::{
-    DepartureReason, LoyaltyChange, LoyaltyChangeReason, Player, TeamSeasonPerformance,
+    ContractRole, DepartureReason, LoyaltyChange, LoyaltyChangeReason, Player, TeamSeasonPerformance,
 };
 
 /// 满意度计算引擎
@@ -41,12 +41,8 @@ impl SatisfactionEngine {
             0.0
         };
 
-        // expected_rate: Starter=0.75, Sub=0.20, Prospect=0.05
-        let expected_rate: f64 = match contract_role {
-            "Sub" => 0.20,
-            "Prospect" => 0.05,
-            _ => 0.75,
-        };
+        // expected_rate: Starter=0.75, SixthMan=0.35, Sub=0.20, PlayingCoach=0.10, Prospect=0.05
+        let expected_rate: f64 = ContractRole::parse(contract_role).expected_play_rate();
         let rate_gap = expected_rate - play_rate;
         if rate_gap > 0.0 {
             // penalty = gap × 25 × (ability / 50)
@@ -0,0 +1,673 @@
+//! 球探引擎 (Scouting Engine)
+//!
+//! 为每支队伍维护一份独立的选手评分"迷雾"：
+//! - 本队选手完全可见；同赛区选手有基础了解，外赛区选手和选秀新秀几乎未知
+//! - 派遣球探到赛区/选秀池后，每个阶段结束时提升对目标的了解程度（边际递减）
+//! - 了解程度决定能力/潜力区间的宽度以及可见特性的数量
+//! - 跨赛季情报会衰减（选手能力在变化）
+
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::ladder::LadderProfileEngine;
+use crate::models::{RatingRange, SeasonPhase, ScoutSubject, ScoutTarget, ScoutedRatings, ScoutingAssignment, ValueRange};
+
+/// 每支队伍最多同时派遣的球探数
+pub const MAX_SCOUTS_PER_TEAM: usize = 3;
+
+/// 了解程度为 0 时的区间半宽
+const ABILITY_MAX_HALF_WIDTH: f64 = 12.0;
+const POTENTIAL_MAX_HALF_WIDTH: f64 = 18.0;
+
+/// 区间中心相对真实值的最大偏移（占半宽的比例）
+const CENTER_BIAS_RATIO: f64 = 0.6;
+
+/// 每阶段情报增长（乘以剩余未知部分）
+const REGION_GAIN_PER_PHASE: f64 = 0.15;
+const DRAFT_POOL_GAIN_PER_PHASE: f64 = 0.25;
+
+/// 无球探时的基础了解程度
+const SAME_REGION_PLAYER_BASELINE: f64 = 0.35;
+const OTHER_REGION_PLAYER_BASELINE: f64 = 0.10;
+const SAME_REGION_PROSPECT_BASELINE: f64 = 0.15;
+const OTHER_REGION_PROSPECT_BASELINE: f64 = 0.0;
+
+/// 每跨一个赛季情报保留的比例
+const SEASON_DECAY: f64 = 0.8;
+
+/// 达到该了解程度后全部特性可见
+const TRAIT_FULL_REVEAL: f64 = 0.7;
+
+/// 球探引擎
+pub struct ScoutingEngine;
+
+/// 某队视角下批量计算选手情报所需的数据
+pub struct PlayerFogView {
+    pub viewer_team_id: u64,
+    viewer_region: Option<u64>,
+    team_regions: HashMap<u64, u64>,
+    stored: HashMap<u64, f64>,
+    ladder: HashMap<u64, f64>,
+}
+
+impl PlayerFogView {
+    /// 对某名选手的了解程度（与 `ScoutingEngine::player_knowledge` 口径一致）
+    pub fn knowledge(&self, player_id: u64, player_team_id: Option<u64>) -> f64 {
+        if player_team_id == Some(self.viewer_team_id) {
+            return 1.0;
+        }
+        let player_region = player_team_id.and_then(|tid| self.team_regions.get(&tid).copied());
+        let ladder = if player_team_id.is_none() {
+            self.ladder.get(&player_id).copied().unwrap_or(0.0)
+        } else {
+            0.0
+        };
+        ScoutingEngine::combine_player_knowledge(
+            self.viewer_region,
+            player_region,
+            self.stored.get(&player_id).copied().unwrap_or(0.0),
+            ladder,
+        )
+    }
+
+    /// 生成该队视角下的选手评分情报
+    pub fn scout(&self, player_id: u64, player_team_id: Option<u64>, ability: u8, potential: u8, trait_count: usize) -> ScoutedRatings {
+        ScoutingEngine::scout_ratings(
+            self.viewer_team_id,
+            ScoutSubject::Player,
+            player_id,
+            ability,
+            potential,
+            trait_count,
+            self.knowledge(player_id, player_team_id),
+        )
+    }
+}
+
+impl ScoutingEngine {
+    /// 无球探情报时的基础了解程度
+    pub fn baseline_knowledge(subject: ScoutSubject, same_region: bool) -> f64 {
+        match (subject, same_region) {
+            (ScoutSubject::Player, true) => SAME_REGION_PLAYER_BASELINE,
+            (ScoutSubject::Player, false) => OTHER_REGION_PLAYER_BASELINE,
+            (ScoutSubject::Prospect, true) => SAME_REGION_PROSPECT_BASELINE,
+            (ScoutSubject::Prospect, false) => OTHER_REGION_PROSPECT_BASELINE,
+        }
+    }
+
+    /// 一个阶段的考察带来的情报增长（越了解增长越慢）
+    pub fn knowledge_gain(current: f64, target: ScoutTarget) -> f64 {
+        let rate = match target {
+            ScoutTarget::Region => REGION_GAIN_PER_PHASE,
+            ScoutTarget::DraftPool => DRAFT_POOL_GAIN_PER_PHASE,
+        };
+        (1.0 - current.clamp(0.0, 1.0)) * rate
+    }
+
+    /// 按赛季差衰减已存储的情报
+    pub fn decayed_knowledge(stored: f64, updated_season: i64, current_season: i64) -> f64 {
+        let seasons = (current_season - updated_season).max(0);
+        stored * SEASON_DECAY.powi(seasons as i32)
+    }
+
+    /// 计算评分区间
+    ///
+    /// 区间一定包含真实值；中心带有一个由 seed 决定的固定偏移，
+    /// 同一了解程度下重复查询结果一致，无法通过多次查询取平均反推真实值。
+    pub fn rating_range(value: u8, knowledge: f64, max_half_width: f64, seed: u64) -> RatingRange {
+        let knowledge = knowledge.clamp(0.0, 1.0);
+        let half = max_half_width * (1.0 - knowledge);
+        if half < 0.5 {
+            return RatingRange::exact(value);
+        }
+
+        // [-1, 1] 的确定性偏移
+        let bias = (Self::mix(seed) % 2001) as f64 / 1000.0 - 1.0;
+        let center = value as f64 + bias * half * CENTER_BIAS_RATIO;
+
+        let min = (center - half).round().clamp(1.0, 100.0) as u8;
+        let max = (center + half).round().clamp(1.0, 100.0) as u8;
+        RatingRange {
+            min: min.min(value),
+            max: max.max(value),
+        }
+    }
+
+    /// 当前了解程度下可见的特性数量
+    pub fn visible_trait_count(total: usize, knowledge: f64) -> usize {
+        if knowledge >= TRAIT_FULL_REVEAL {
+            total
+        } else {
+            ((total as f64) * knowledge / TRAIT_FULL_REVEAL).floor() as usize
+        }
+    }
+
+    /// 生成某队视角下的评分情报
+    pub fn scout_ratings(
+        viewer_team_id: u64,
+        subject: ScoutSubject,
+        subject_id: u64,
+        ability: u8,
+        potential: u8,
+        trait_count: usize,
+        knowledge: f64,
+    ) -> ScoutedRatings {
+        // 了解程度每提升一档（0.1）重新抽取偏移，避免跨档位反推
+        let step = (knowledge.clamp(0.0, 1.0) * 10.0).floor() as u64;
+        let base_seed = viewer_team_id
+            .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+            ^ subject_id.wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+            ^ ((subject as u64) << 56)
+            ^ step;
+
+        let ability_range = Self::rating_range(ability, knowledge, ABILITY_MAX_HALF_WIDTH, base_seed);
+        let potential_range = Self::rating_range(
+            potential.max(ability),
+            knowledge,
+            POTENTIAL_MAX_HALF_WIDTH,
+            base_seed ^ 0xA5A5_A5A5,
+        );
+        let visible = Self::visible_trait_count(trait_count, knowledge);
+
+        ScoutedRatings {
+            viewer_team_id,
+            knowledge,
+            ability_range,
+            potential_range,
+            hidden_trait_count: (trait_count - visible) as u32,
+            market_value_range: None,
+        }
+    }
+
+    /// 按评分区间折算由能力/潜力派生的数值（如身价）
+    ///
+    /// `base` 给出某组能力/潜力下的基准值，真实数值按区间各端点与真实评分的基准比例缩放，
+    /// 区间与评分区间一样必然包含真实值，了解程度为满时即为真实值。
+    pub fn derived_value_range<F>(value: u64, ability: u8, potential: u8, scouted: &ScoutedRatings, base: F) -> ValueRange
+    where
+        F: Fn(u8, u8) -> u64,
+    {
+        let true_base = base(ability, potential.max(ability)).max(1) as f64;
+        let a = scouted.ability_range;
+        let p = scouted.potential_range;
+        let scaled: Vec<u64> = [(a.min, p.min), (a.min, p.max), (a.max, p.min), (a.max, p.max)]
+            .iter()
+            .map(|&(ab, pot)| (value as f64 * base(ab, pot.max(ab)) as f64 / true_base) as u64)
+            .collect();
+        ValueRange {
+            min: scaled.iter().copied().min().unwrap_or(value).min(value),
+            max: scaled.iter().copied().max().unwrap_or(value).max(value),
+        }
+    }
+
+    /// splitmix64 混合，用于生成确定性偏移
+    fn mix(seed: u64) -> u64 {
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // ==================== 数据库操作 ====================
+
+    /// 存档的玩家执教队伍（球探迷雾的默认视角）
+    pub async fn managed_team(pool: &Pool<Sqlite>, save_id: &str) -> Result<Option<u64>, String> {
+        let team: Option<Option<i64>> = sqlx::query_scalar("SELECT managed_team_id FROM saves WHERE id = ?")
+            .bind(save_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("查询执教队伍失败: {}", e))?;
+        Ok(team.flatten().map(|t| t as u64))
+    }
+
+    /// 设置存档的玩家执教队伍（None 切换为观察者视角），队伍必须属于该存档
+    pub async fn set_managed_team(pool: &Pool<Sqlite>, save_id: &str, team_id: Option<u64>) -> Result<(), String> {
+        if let Some(tid) = team_id {
+            let exists: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM teams WHERE id = ? AND save_id = ?")
+                .bind(tid as i64)
+                .bind(save_id)
+                .fetch_one(pool)
+                .await
+                .map_err(|e| format!("查询队伍失败: {}", e))?;
+            if exists == 0 {
+                return Err(format!("队伍 {} 不属于当前存档", tid));
+            }
+        }
+
+        sqlx::query("UPDATE saves SET managed_team_id = ? WHERE id = ?")
+            .bind(team_id.map(|t| t as i64))
+            .bind(save_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("设置执教队伍失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 查询队伍所属赛区
+    pub async fn team_region(pool: &Pool<Sqlite>, team_id: u64) -> Result<Option<u64>, String> {
+        let region: Option<i64> = sqlx::query_scalar("SELECT region_id FROM teams WHERE id = ?")
+            .bind(team_id as i64)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("查询队伍赛区失败: {}", e))?;
+        Ok(region.map(|r| r as u64))
+    }
+
+    /// 读取某队对某主体的已存储情报（已按赛季衰减）
+    async fn stored_knowledge(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: u64,
+        subject: ScoutSubject,
+        subject_id: u64,
+        current_season: i64,
+    ) -> Result<Option<f64>, String> {
+        let row = sqlx::query(
+            "SELECT knowledge, updated_season FROM scouting_knowledge WHERE save_id = ? AND team_id = ? AND subject_type = ? AND subject_id = ?"
+        )
+        .bind(save_id)
+        .bind(team_id as i64)
+        .bind(subject.as_str())
+        .bind(subject_id as i64)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询球探情报失败: {}", e))?;
+
+        Ok(row.map(|r| {
+            Self::decayed_knowledge(r.get("knowledge"), r.get("updated_season"), current_season)
+        }))
+    }
+
+    /// 批量读取某队对某类主体的情报（已按赛季衰减）
+    pub async fn get_knowledge_map(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: u64,
+        subject: ScoutSubject,
+        current_season: i64,
+    ) -> Result<HashMap<u64, f64>, String> {
+        let rows = sqlx::query(
+            "SELECT subject_id, knowledge, updated_season FROM scouting_knowledge WHERE save_id = ? AND team_id = ? AND subject_type = ?"
+        )
+        .bind(save_id)
+        .bind(team_id as i64)
+        .bind(subject.as_str())
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询球探情报失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|r| {
+                let id = r.get::<i64, _>("subject_id") as u64;
+                let k = Self::decayed_knowledge(r.get("knowledge"), r.get("updated_season"), current_season);
+                (id, k)
+            })
+            .collect())
+    }
+
+    /// 某队对某名职业选手的实际了解程度
+    pub async fn player_knowledge(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        viewer_team_id: u64,
+        player_id: u64,
+        player_team_id: Option<u64>,
+        current_season: i64,
+    ) -> Result<f64, String> {
+        if player_team_id == Some(viewer_team_id) {
+            return Ok(1.0);
+        }
+
+        let viewer_region = Self::team_region(pool, viewer_team_id).await?;
+        let player_region = match player_team_id {
+            Some(tid) => Self::team_region(pool, tid).await?,
+            None => None,
+        };
+
+        let stored = Self::stored_knowledge(
+            pool, save_id, viewer_team_id, ScoutSubject::Player, player_id, current_season,
+        )
+        .await?;
+
+        // 自由球员没有职业比赛数据，公开的天梯档案可作为参考
+        let ladder = if player_team_id.is_none() {
+            LadderProfileEngine::load_profile(pool, save_id, ScoutSubject::Player, player_id)
+                .await?
+                .map(|p| LadderProfileEngine::public_knowledge(p.rating_deviation, p.games_played))
+                .unwrap_or(0.0)
+        } else {
+            0.0
+        };
+
+        Ok(Self::combine_player_knowledge(viewer_region, player_region, stored.unwrap_or(0.0), ladder))
+    }
+
+    /// 非本队选手的了解程度：已存储情报、赛区基础了解、公开天梯情报取最大值
+    fn combine_player_knowledge(
+        viewer_region: Option<u64>,
+        player_region: Option<u64>,
+        stored: f64,
+        ladder: f64,
+    ) -> f64 {
+        let same_region = viewer_region.is_some() && viewer_region == player_region;
+        let baseline = Self::baseline_knowledge(ScoutSubject::Player, same_region);
+        stored.max(baseline).max(ladder)
+    }
+
+    /// 批量加载某队视角下的选手情报（选手列表、搜索等批量查询使用）
+    pub async fn player_fog_view(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        viewer_team_id: u64,
+        current_season: i64,
+    ) -> Result<PlayerFogView, String> {
+        let team_regions: HashMap<u64, u64> = sqlx::query("SELECT id, region_id FROM teams WHERE save_id = ?")
+            .bind(save_id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("查询队伍赛区失败: {}", e))?
+            .iter()
+            .filter_map(|r| {
+                let region: Option<i64> = r.get("region_id");
+                region.map(|region| (r.get::<i64, _>("id") as u64, region as u64))
+            })
+            .collect();
+
+        Ok(PlayerFogView {
+            viewer_team_id,
+            viewer_region: team_regions.get(&viewer_team_id).copied(),
+            team_regions,
+            stored: Self::get_knowledge_map(pool, save_id, viewer_team_id, ScoutSubject::Player, current_season).await?,
+            ladder: LadderProfileEngine::knowledge_map(pool, save_id, ScoutSubject::Player).await?,
+        })
+    }
+
+    /// 获取球探派遣列表
+    pub async fn list_assignments(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: Option<u64>,
+    ) -> Result<Vec<ScoutingAssignment>, String> {
+        let rows = match team_id {
+            Some(tid) => sqlx::query(
+                "SELECT * FROM scouting_assignments WHERE save_id = ? AND team_id = ? ORDER BY id"
+            )
+            .bind(save_id)
+            .bind(tid as i64)
+            .fetch_all(pool)
+            .await,
+            None => sqlx::query("SELECT * FROM scouting_assignments WHERE save_id = ? ORDER BY id")
+                .bind(save_id)
+                .fetch_all(pool)
+                .await,
+        }
+        .map_err(|e| format!("查询球探派遣失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .filter_map(|r| {
+                let target: String = r.get("target_type");
+                Some(ScoutingAssignment {
+                    id: r.get("id"),
+                    team_id: r.get::<i64, _>("team_id") as u64,
+                    target_type: ScoutTarget::parse(&target)?,
+                    region_id: r.get::<i64, _>("region_id") as u64,
+                    assigned_season: r.get("assigned_season"),
+                    phases_active: r.get("phases_active"),
+                })
+            })
+            .collect())
+    }
+
+    /// 派遣球探
+    pub async fn assign(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: u64,
+        target: ScoutTarget,
+        region_id: u64,
+        current_season: i64,
+    ) -> Result<ScoutingAssignment, String> {
+        let existing = Self::list_assignments(pool, save_id, Some(team_id)).await?;
+        if let Some(a) = existing
+            .iter()
+            .find(|a| a.target_type == target && a.region_id == region_id)
+        {
+            return Ok(a.clone());
+        }
+        if existing.len() >= MAX_SCOUTS_PER_TEAM {
+            return Err(format!("每支队伍最多同时派遣 {} 名球探", MAX_SCOUTS_PER_TEAM));
+        }
+
+        let id: i64 = sqlx::query_scalar(
+            "INSERT INTO scouting_assignments (save_id, team_id, target_type, region_id, assigned_season) VALUES (?, ?, ?, ?, ?) RETURNING id"
+        )
+        .bind(save_id)
+        .bind(team_id as i64)
+        .bind(target.as_str())
+        .bind(region_id as i64)
+        .bind(current_season)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("派遣球探失败: {}", e))?;
+
+        Ok(ScoutingAssignment {
+            id,
+            team_id,
+            target_type: target,
+            region_id,
+            assigned_season: current_season,
+            phases_active: 0,
+        })
+    }
+
+    /// 召回球探（已积累的情报保留）
+    pub async fn recall(pool: &Pool<Sqlite>, save_id: &str, assignment_id: i64) -> Result<(), String> {
+        sqlx::query("DELETE FROM scouting_assignments WHERE save_id = ? AND id = ?")
+            .bind(save_id)
+            .bind(assignment_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("召回球探失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 阶段结束时推进所有球探的考察进度，返回更新的情报条数
+    ///
+    /// 每个派遣记录最近一次推进的 (赛季, 阶段)，阶段重复结算时不会重复积累
+    pub async fn advance_phase(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        current_season: i64,
+        phase: SeasonPhase,
+    ) -> Result<u32, String> {
+        let phase_str = format!("{:?}", phase);
+        let assignments = Self::list_assignments(pool, save_id, None).await?;
+        let advanced: HashSet<i64> = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM scouting_assignments WHERE save_id = ? AND last_advanced_season = ? AND last_advanced_phase = ?"
+        )
+        .bind(save_id)
+        .bind(current_season)
+        .bind(&phase_str)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询球探派遣失败: {}", e))?
+        .into_iter()
+        .collect();
+        let mut updated = 0u32;
+
+        for assignment in assignments.iter().filter(|a| !advanced.contains(&a.id)) {
+            let subject = assignment.target_type.subject();
+            let subject_ids: Vec<i64> = match assignment.target_type {
+                ScoutTarget::Region => sqlx::query_scalar(
+                    r#"
+                    SELECT p.id FROM players p
+                    JOIN teams t ON p.team_id = t.id
+                    WHERE p.save_id = ? AND t.region_id = ? AND p.status = 'Active' AND p.team_id != ?
+                    "#,
+                )
+                .bind(save_id)
+                .bind(assignment.region_id as i64)
+                .bind(assignment.team_id as i64)
+                .fetch_all(pool)
+                .await,
+                ScoutTarget::DraftPool => sqlx::query_scalar(
+                    "SELECT id FROM draft_pool WHERE save_id = ? AND region_id = ? AND status = 'available'"
+                )
+                .bind(save_id)
+                .bind(assignment.region_id as i64)
+                .fetch_all(pool)
+                .await,
+            }
+            .map_err(|e| format!("查询考察对象失败: {}", e))?;
+
+            let same_region = Self::team_region(pool, assignment.team_id).await? == Some(assignment.region_id);
+            let baseline = Self::baseline_knowledge(subject, same_region);
+            let known = Self::get_knowledge_map(pool, save_id, assignment.team_id, subject, current_season).await?;
+
+            for subject_id in subject_ids {
+                let current = known
+                    .get(&(subject_id as u64))
+                    .copied()
+                    .unwrap_or(0.0)
+                    .max(baseline);
+                let next = (current + Self::knowledge_gain(current, assignment.target_type)).min(1.0);
+
+                sqlx::query(
+                    r#"
+                    INSERT INTO scouting_knowledge (save_id, team_id, subject_type, subject_id, knowledge, updated_season)
+                    VALUES (?, ?, ?, ?, ?, ?)
+                    ON CONFLICT(save_id, team_id, subject_type, subject_id) DO UPDATE SET
+                        knowledge = excluded.knowledge,
+                        updated_season = excluded.updated_season
+                    "#,
+                )
+                .bind(save_id)
+                .bind(assignment.team_id as i64)
+                .bind(subject.as_str())
+                .bind(subject_id)
+                .bind(next)
+                .bind(current_season)
+                .execute(pool)
+                .await
+                .map_err(|e| format!("写入球探情报失败: {}", e))?;
+                updated += 1;
+            }
+
+            sqlx::query(
+                "UPDATE scouting_assignments SET phases_active = phases_active + 1, last_advanced_season = ?, last_advanced_phase = ? WHERE id = ?"
+            )
+            .bind(current_season)
+            .bind(&phase_str)
+            .bind(assignment.id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新球探派遣失败: {}", e))?;
+        }
+
+        Ok(updated)
+    }
+}
+
+// ===== 单元测试 =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_always_contains_true_value() {
+        for value in [1u8, 30, 55, 78, 100] {
+            for step in 0..=10 {
+                let knowledge = step as f64 / 10.0;
+                for seed in 0..50u64 {
+                    let r = ScoutingEngine::rating_range(value, knowledge, POTENTIAL_MAX_HALF_WIDTH, seed);
+                    assert!(r.contains(value), "区间 {:?} 不包含真实值 {} (k={})", r, value, knowledge);
+                    assert!(r.min >= 1 && r.max <= 100);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_range_narrows_with_knowledge() {
+        let width = |k: f64| {
+            let r = ScoutingEngine::rating_range(60, k, ABILITY_MAX_HALF_WIDTH, 42);
+            r.max - r.min
+        };
+        assert!(width(0.0) > width(0.5));
+        assert!(width(0.5) > width(0.9));
+        assert!(ScoutingEngine::rating_range(60, 1.0, ABILITY_MAX_HALF_WIDTH, 42).is_exact());
+    }
+
+    #[test]
+    fn test_scout_ratings_deterministic() {
+        let a = ScoutingEngine::scout_ratings(3, ScoutSubject::Player, 17, 72, 85, 4, 0.35);
+        let b = ScoutingEngine::scout_ratings(3, ScoutSubject::Player, 17, 72, 85, 4, 0.35);
+        assert_eq!(a.ability_range, b.ability_range);
+        assert_eq!(a.potential_range, b.potential_range);
+        assert_eq!(a.hidden_trait_count, b.hidden_trait_count);
+    }
+
+    #[test]
+    fn test_derived_value_range() {
+        use crate::engines::market_value::MarketValueEngine;
+        let base = |a: u8, p: u8| MarketValueEngine::calculate_base_market_value(a, 24, p, "NORMAL", "MID");
+        let value = base(72, 85) * 2;
+
+        let fogged = ScoutingEngine::scout_ratings(3, ScoutSubject::Player, 17, 72, 85, 0, 0.1);
+        let range = ScoutingEngine::derived_value_range(value, 72, 85, &fogged, base);
+        assert!(range.min <= value && value <= range.max);
+        assert!(range.max > range.min);
+
+        let known = ScoutingEngine::scout_ratings(3, ScoutSubject::Player, 17, 72, 85, 0, 1.0);
+        let exact = ScoutingEngine::derived_value_range(value, 72, 85, &known, base);
+        assert_eq!((exact.min, exact.max), (value, value));
+    }
+
+    #[test]
+    fn test_fog_view_knowledge() {
+        let view = PlayerFogView {
+            viewer_team_id: 1,
+            viewer_region: Some(10),
+            team_regions: HashMap::from([(1, 10), (2, 10), (3, 20)]),
+            stored: HashMap::from([(300, 0.8)]),
+            ladder: HashMap::from([(400, 0.5)]),
+        };
+        // 本队选手完全可见，评分为精确值
+        assert_eq!(view.knowledge(100, Some(1)), 1.0);
+        assert!(view.scout(100, Some(1), 72, 85, 3).ability_range.is_exact());
+        // 同赛区 / 外赛区基础了解
+        assert_eq!(view.knowledge(200, Some(2)), SAME_REGION_PLAYER_BASELINE);
+        assert_eq!(view.knowledge(201, Some(3)), OTHER_REGION_PLAYER_BASELINE);
+        // 球探情报与公开天梯情报（仅自由球员）
+        assert_eq!(view.knowledge(300, Some(3)), 0.8);
+        assert_eq!(view.knowledge(400, None), 0.5);
+        assert_eq!(view.knowledge(400, Some(3)), OTHER_REGION_PLAYER_BASELINE);
+    }
+
+    #[test]
+    fn test_knowledge_gain_diminishing() {
+        let low = ScoutingEngine::knowledge_gain(0.1, ScoutTarget::Region);
+        let high = ScoutingEngine::knowledge_gain(0.8, ScoutTarget::Region);
+        assert!(low > high, "情报增长应边际递减: {} vs {}", low, high);
+        assert!(ScoutingEngine::knowledge_gain(0.5, ScoutTarget::DraftPool)
+            > ScoutingEngine::knowledge_gain(0.5, ScoutTarget::Region));
+        assert_eq!(ScoutingEngine::knowledge_gain(1.0, ScoutTarget::Region), 0.0);
+    }
+
+    #[test]
+    fn test_trait_visibility() {
+        assert_eq!(ScoutingEngine::visible_trait_count(4, 0.0), 0);
+        assert_eq!(ScoutingEngine::visible_trait_count(4, 0.4), 2);
+        assert_eq!(ScoutingEngine::visible_trait_count(4, TRAIT_FULL_REVEAL), 4);
+    }
+
+    #[test]
+    fn test_knowledge_decays_across_seasons() {
+        assert_eq!(ScoutingEngine::decayed_knowledge(0.9, 3, 3), 0.9);
+        let decayed = ScoutingEngine::decayed_knowledge(0.9, 2, 4);
+        assert!((decayed - 0.9 * 0.64).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,418 @@
+//! 系列赛逐局准备 (Series Planner)
+//!
+//! 快速模拟路径按小局推进系列赛，每局开赛前由调用方完成准备：
+//! - 按赛制决定选边方并选边
+//! - 按系列赛 BP 规则（全局BP / 软全局BP / 全局禁用池）运行本局 BP，并记录已用英雄
+//! - 第二局起双方按比分与上一局表现调整局间战术（玩家预设优先）
+//!
+//! 引擎只负责战力与胜负结算，BP 与战术结果按局记录，模拟结束后由调用方落库。
+
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+
+use crate::engines::bp_engine::{BpEngine, CompType, DraftResult, PlayerChampionPool, SeriesContext, TeamSide};
+use crate::engines::champion::VersionTier;
+use crate::engines::draft_rules::SeriesDraftRules;
+use crate::engines::draft_scouting::DraftScoutingReport;
+use crate::engines::match_simulation::MatchPlayerInfo;
+use crate::engines::meta_engine::MetaType;
+use crate::engines::series_tactics::{GameTactics, SeriesTacticsEngine};
+use crate::engines::side_selection::{MapSide, SideSelectionEngine};
+use crate::models::MatchFormat;
+
+/// 某局开赛前的系列赛局面
+#[derive(Debug, Clone, Copy)]
+pub struct SeriesGameState<'a> {
+    pub game_number: u8,
+    pub home_score: u8,
+    pub away_score: u8,
+    pub wins_needed: u8,
+    /// 上一局主队是否获胜（第一局为 None）
+    pub prev_home_won: Option<bool>,
+    /// 上一局主队视角的发挥差
+    pub prev_performance_gap: f64,
+    /// 本局双方上场选手（已完成局间换人）
+    pub home_lineup: &'a [MatchPlayerInfo],
+    pub away_lineup: &'a [MatchPlayerInfo],
+}
+
+/// 某局的赛前准备结果
+#[derive(Debug, Clone)]
+pub struct SeriesGamePlan {
+    pub home_comp: Option<CompType>,
+    pub away_comp: Option<CompType>,
+    pub home_bp_modifiers: HashMap<u64, f64>,
+    pub away_bp_modifiers: HashMap<u64, f64>,
+    /// 主队视角的战力偏移（地图边 + 局间战术）
+    pub home_power_shift: f64,
+    /// 本局发挥波动倍率（局间战术）
+    pub variance_scale: f64,
+}
+
+/// 已准备的一局：选边、BP 与双方战术
+#[derive(Debug, Clone)]
+pub struct PlannedGame {
+    pub game_number: u8,
+    pub home_side: MapSide,
+    pub draft: DraftResult,
+    pub home_tactics: GameTactics,
+    pub away_tactics: GameTactics,
+}
+
+/// AI 托管的系列赛逐局准备
+pub struct AutoSeriesPlanner<'a> {
+    format: MatchFormat,
+    meta_type: MetaType,
+    higher_seed: TeamSide,
+    /// 双方首发与替补的英雄池（按选手ID）
+    champion_pools: &'a HashMap<u64, PlayerChampionPool>,
+    version_tiers: &'a HashMap<u8, VersionTier>,
+    home_scouting: &'a DraftScoutingReport,
+    away_scouting: &'a DraftScoutingReport,
+    draft_rules: SeriesDraftRules,
+    /// 玩家为指定小局预设的战术
+    manual_tactics: HashMap<(u8, TeamSide), GameTactics>,
+    series_ctx: Option<SeriesContext>,
+    rng: &'a mut StdRng,
+    games: Vec<PlannedGame>,
+}
+
+impl<'a> AutoSeriesPlanner<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        format: MatchFormat,
+        meta_type: MetaType,
+        higher_seed: TeamSide,
+        champion_pools: &'a HashMap<u64, PlayerChampionPool>,
+        version_tiers: &'a HashMap<u8, VersionTier>,
+        home_scouting: &'a DraftScoutingReport,
+        away_scouting: &'a DraftScoutingReport,
+        draft_rules: SeriesDraftRules,
+        rng: &'a mut StdRng,
+    ) -> Self {
+        Self {
+            format,
+            meta_type,
+            higher_seed,
+            champion_pools,
+            version_tiers,
+            home_scouting,
+            away_scouting,
+            draft_rules,
+            manual_tactics: HashMap::new(),
+            series_ctx: None,
+            rng,
+            games: Vec::new(),
+        }
+    }
+
+    /// 第一局沿用已有的系列赛上下文（续打中断的系列赛）
+    pub fn with_series_context(mut self, series_ctx: Option<SeriesContext>) -> Self {
+        self.series_ctx = series_ctx;
+        self
+    }
+
+    /// 玩家预设的局间战术，对应小局优先于 AI 决策
+    pub fn with_manual_tactics(mut self, manual_tactics: HashMap<(u8, TeamSide), GameTactics>) -> Self {
+        self.manual_tactics = manual_tactics;
+        self
+    }
+
+    /// 已准备的各局
+    pub fn games(&self) -> &[PlannedGame] {
+        &self.games
+    }
+
+    fn lineup_pools(&self, lineup: &[MatchPlayerInfo]) -> Vec<PlayerChampionPool> {
+        lineup
+            .iter()
+            .filter_map(|p| self.champion_pools.get(&p.player_id).cloned())
+            .collect()
+    }
+
+    /// 上一局结束后的系列赛上下文与双方下一局战术
+    fn next_series_context(&self, state: &SeriesGameState, home_won: bool) -> Option<SeriesContext> {
+        let last = self.games.last()?;
+        let prev_winner_picks = if home_won {
+            &last.draft.home_picks
+        } else {
+            &last.draft.away_picks
+        };
+        let mut series = SeriesContext {
+            prev_winner_picks: prev_winner_picks.iter().map(|p| p.champion_id).collect(),
+            prev_loser_side: Some(if home_won { TeamSide::Away } else { TeamSide::Home }),
+            prev_home_comp: last.draft.home_comp,
+            prev_away_comp: last.draft.away_comp,
+            home_score: state.home_score,
+            away_score: state.away_score,
+            game_number: last.game_number,
+            wins_needed: state.wins_needed,
+            ..Default::default()
+        };
+        // 快速模拟不记录选手单局数据，AI 不做针对选手的调整
+        let home_tactics = match self.manual_tactics.get(&(state.game_number, TeamSide::Home)) {
+            Some(tactics) => tactics.clone(),
+            None => SeriesTacticsEngine::ai_choose(
+                TeamSide::Home,
+                &series,
+                &self.home_scouting.comp_history(),
+                None,
+                state.prev_performance_gap,
+            ),
+        };
+        let away_tactics = match self.manual_tactics.get(&(state.game_number, TeamSide::Away)) {
+            Some(tactics) => tactics.clone(),
+            None => SeriesTacticsEngine::ai_choose(
+                TeamSide::Away,
+                &series,
+                &self.away_scouting.comp_history(),
+                None,
+                -state.prev_performance_gap,
+            ),
+        };
+        series.home_tactics = home_tactics;
+        series.away_tactics = away_tactics;
+        Some(series)
+    }
+
+    /// 准备一局：选边、BP、局间战术
+    pub fn plan_game(&mut self, state: &SeriesGameState) -> SeriesGamePlan {
+        if let Some(home_won) = state.prev_home_won {
+            self.series_ctx = self.next_series_context(state, home_won);
+        }
+
+        let home_pools = self.lineup_pools(state.home_lineup);
+        let away_pools = self.lineup_pools(state.away_lineup);
+
+        let side_chooser = SideSelectionEngine::side_chooser(
+            self.format,
+            state.game_number,
+            state.home_score,
+            state.away_score,
+            self.higher_seed,
+            self.series_ctx.as_ref().and_then(|c| c.prev_loser_side),
+        );
+        let side_choice = SideSelectionEngine::choose(
+            side_chooser,
+            &home_pools,
+            &away_pools,
+            self.version_tiers,
+            self.meta_type,
+        );
+        let mut draft = BpEngine::run_draft_on_side(
+            side_choice.home_side,
+            &home_pools,
+            &away_pools,
+            self.version_tiers,
+            self.meta_type,
+            self.rng,
+            self.home_scouting,
+            self.away_scouting,
+            self.series_ctx.as_ref(),
+            &self.draft_rules,
+        );
+        draft.record_side_choice(&side_choice);
+        self.draft_rules.record_game(&draft.home_picks, &draft.away_picks);
+
+        // 第一局双方按原计划，之后按上一局结束时决定的战术
+        let (home_tactics, away_tactics) = match (&self.series_ctx, state.prev_home_won) {
+            (Some(series), Some(_)) => (series.home_tactics.clone(), series.away_tactics.clone()),
+            _ => Default::default(),
+        };
+        let (tactic_shift, variance_scale) = SeriesTacticsEngine::game_modifier(&home_tactics, &away_tactics);
+
+        let plan = SeriesGamePlan {
+            home_comp: draft.home_comp,
+            away_comp: draft.away_comp,
+            home_bp_modifiers: draft.home_bp_modifiers.clone(),
+            away_bp_modifiers: draft.away_bp_modifiers.clone(),
+            home_power_shift: SideSelectionEngine::side_bonus(self.meta_type, side_choice.home_side) + tactic_shift,
+            variance_scale,
+        };
+        self.games.push(PlannedGame {
+            game_number: state.game_number,
+            home_side: side_choice.home_side,
+            draft,
+            home_tactics,
+            away_tactics,
+        });
+        plan
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::champion::{self, get_champions_by_position, MasteryTier};
+    use crate::engines::match_simulation::{MatchSimContext, MatchSimulationEngine};
+    use crate::engines::meta_engine::MetaWeights;
+    use crate::models::player::Position;
+    use crate::models::transfer::AITeamPersonality;
+    use crate::models::DraftRule;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn make_lineup(offset: u64) -> Vec<MatchPlayerInfo> {
+        ["TOP", "JUG", "MID", "ADC", "SUP"]
+            .iter()
+            .enumerate()
+            .map(|(i, position)| MatchPlayerInfo {
+                player_id: offset + i as u64,
+                ability: 75,
+                stability: 70,
+                condition: 0,
+                age: 24,
+                position: position.to_string(),
+                traits: Vec::new(),
+                is_first_season: false,
+                is_starter: true,
+                join_season: 1,
+                potential: 75,
+                satisfaction: 60,
+                form_factors: None,
+                bp_modifier: 0.0,
+                champion_version_score: 0.0,
+            })
+            .collect()
+    }
+
+    fn make_pools(offset: u64) -> Vec<PlayerChampionPool> {
+        [Position::Top, Position::Jug, Position::Mid, Position::Adc, Position::Sup]
+            .iter()
+            .enumerate()
+            .map(|(i, position)| PlayerChampionPool {
+                player_id: offset + i as u64,
+                position: *position,
+                ability: 75,
+                masteries: get_champions_by_position(*position)
+                    .iter()
+                    .map(|c| (c.id, MasteryTier::S))
+                    .collect(),
+                games_played: HashMap::new(),
+                games_won: HashMap::new(),
+                traits: Vec::new(),
+            })
+            .collect()
+    }
+
+    fn champion_pools() -> HashMap<u64, PlayerChampionPool> {
+        make_pools(1)
+            .into_iter()
+            .chain(make_pools(10))
+            .map(|p| (p.player_id, p))
+            .collect()
+    }
+
+    fn version_tiers() -> HashMap<u8, VersionTier> {
+        champion::calculate_version_tiers(MetaType::Balanced)
+            .into_iter()
+            .collect()
+    }
+
+    fn playoff_ctx() -> MatchSimContext {
+        MatchSimContext {
+            is_playoff: true,
+            is_international: false,
+            tournament_type: "playoff".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fearless_bo5_never_repeats_champions() {
+        let (home, away) = (make_lineup(1), make_lineup(10));
+        let (champion_pools, tiers) = (champion_pools(), version_tiers());
+        let (home_scouting, away_scouting) = (DraftScoutingReport::default(), DraftScoutingReport::default());
+        let ctx = playoff_ctx();
+        let engine = MatchSimulationEngine::default();
+
+        for seed in 0..5 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let mut planner = AutoSeriesPlanner::new(
+                MatchFormat::Bo5,
+                MetaType::Balanced,
+                TeamSide::Home,
+                &champion_pools,
+                &tiers,
+                &home_scouting,
+                &away_scouting,
+                SeriesDraftRules::new(DraftRule::Fearless, Vec::new()),
+                &mut rng,
+            );
+            let result = engine.simulate_match_with_traits(
+                1,
+                1,
+                "FINAL",
+                MatchFormat::Bo5,
+                1,
+                2,
+                &home,
+                &away,
+                &[],
+                &[],
+                &ctx,
+                &MetaWeights::balanced(),
+                &AITeamPersonality::Balanced,
+                &AITeamPersonality::Balanced,
+                1,
+                Some(&mut |state: &SeriesGameState| planner.plan_game(state)),
+            );
+
+            assert_eq!(planner.games().len(), result.games.len());
+            let mut used = HashSet::new();
+            for game in planner.games() {
+                for pick in game.draft.home_picks.iter().chain(&game.draft.away_picks) {
+                    assert!(used.insert(pick.champion_id), "英雄 {} 在全局BP中被重复使用", pick.champion_id);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_manual_tactics_apply_from_second_game() {
+        let (home, away) = (make_lineup(1), make_lineup(10));
+        let (champion_pools, tiers) = (champion_pools(), version_tiers());
+        let (home_scouting, away_scouting) = (DraftScoutingReport::default(), DraftScoutingReport::default());
+        let manual = GameTactics {
+            aggressive: true,
+            is_manual: true,
+            ..Default::default()
+        };
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut planner = AutoSeriesPlanner::new(
+            MatchFormat::Bo3,
+            MetaType::Balanced,
+            TeamSide::Home,
+            &champion_pools,
+            &tiers,
+            &home_scouting,
+            &away_scouting,
+            SeriesDraftRules::default(),
+            &mut rng,
+        )
+        .with_manual_tactics(HashMap::from([((2, TeamSide::Home), manual.clone())]));
+
+        MatchSimulationEngine::default().simulate_match_with_traits(
+            1,
+            1,
+            "FINAL",
+            MatchFormat::Bo3,
+            1,
+            2,
+            &home,
+            &away,
+            &[],
+            &[],
+            &playoff_ctx(),
+            &MetaWeights::balanced(),
+            &AITeamPersonality::Balanced,
+            &AITeamPersonality::Balanced,
+            1,
+            Some(&mut |state: &SeriesGameState| planner.plan_game(state)),
+        );
+
+        let games = planner.games();
+        assert!(games[0].home_tactics.is_standard() && games[0].away_tactics.is_standard());
+        assert_eq!(games[1].home_tactics, manual);
+    }
+}
@@ -0,0 +1,421 @@
+//! 系列赛局间战术引擎 (Series Tactics Engine)
+//!
+//! 每局结束后双方可做出战术调整，作用于下一局：
+//! - 切换体系：BP 规划优先从新的阵容体系池中选择，临场换体系有磨合代价
+//! - 针对选手：BP 优先禁用对手某名选手的拿手英雄，压制其发挥
+//! - 提高侵略性：战力上移，但本局胜负波动加大
+//!
+//! 战术由 AI 按比分与上一局表现决定，也可由玩家为指定小局预先设定。
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::engines::bp_engine::{CompType, PlayerChampionPool, SeriesContext, TeamSide};
+use crate::engines::champion::MasteryTier;
+
+/// 切换体系的磨合代价
+const COMP_SWITCH_SHIFT: f64 = -0.3;
+/// 被针对选手的发挥下降（折算到对手整体战力）
+const TARGET_OPPONENT_SHIFT: f64 = -0.8;
+/// 禁用位用于针对，自身英雄保护减少
+const TARGET_OWN_SHIFT: f64 = -0.2;
+/// 提高侵略性的战力上移与波动倍率
+const AGGRESSION_SHIFT: f64 = 0.6;
+const AGGRESSION_VARIANCE: f64 = 1.4;
+/// 切换体系时最多保留的候选体系数
+const MAX_COMP_POOL: usize = 3;
+/// AI 针对选手的门槛：上一局发挥高出其队伍均值的幅度
+const STAR_MARGIN: f64 = 6.0;
+/// BP 规划中战术体系池的加分
+const COMP_POOL_PLAN_BONUS: i32 = 30;
+
+/// 一局的战术调整
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameTactics {
+    /// 切换后的阵容体系池（为空表示沿用原计划）
+    pub comp_pool: Vec<CompType>,
+    /// 重点针对的对手选手
+    pub target_player_id: Option<u64>,
+    /// 提高侵略性
+    pub aggressive: bool,
+    /// 是否由玩家设定
+    pub is_manual: bool,
+    pub reasons: Vec<String>,
+}
+
+/// 战术对本局战力分布的修正
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TacticModifier {
+    /// 本方战力偏移
+    pub power_shift: f64,
+    /// 对手战力偏移
+    pub opponent_shift: f64,
+    /// 本局波动倍率
+    pub variance_scale: f64,
+}
+
+impl GameTactics {
+    pub fn is_standard(&self) -> bool {
+        self.comp_pool.is_empty() && self.target_player_id.is_none() && !self.aggressive
+    }
+
+    pub fn modifier(&self) -> TacticModifier {
+        let mut modifier = TacticModifier {
+            power_shift: 0.0,
+            opponent_shift: 0.0,
+            variance_scale: 1.0,
+        };
+        if !self.comp_pool.is_empty() {
+            modifier.power_shift += COMP_SWITCH_SHIFT;
+        }
+        if self.target_player_id.is_some() {
+            modifier.power_shift += TARGET_OWN_SHIFT;
+            modifier.opponent_shift += TARGET_OPPONENT_SHIFT;
+        }
+        if self.aggressive {
+            modifier.power_shift += AGGRESSION_SHIFT;
+            modifier.variance_scale *= AGGRESSION_VARIANCE;
+        }
+        modifier
+    }
+
+    /// BP 规划：战术体系池内的体系加分
+    pub fn comp_plan_bonus(&self, comp: CompType) -> i32 {
+        if self.comp_pool.contains(&comp) {
+            COMP_POOL_PLAN_BONUS
+        } else {
+            0
+        }
+    }
+
+    /// BP 禁用：针对目标选手拿手英雄的加分
+    pub fn target_ban_bonus(&self, champion_id: u8, opponent_players: &[PlayerChampionPool]) -> i32 {
+        let Some(target) = self.target_player_id else {
+            return 0;
+        };
+        let Some(player) = opponent_players.iter().find(|p| p.player_id == target) else {
+            return 0;
+        };
+        match player.masteries.get(&champion_id) {
+            Some(MasteryTier::SS) => 12,
+            Some(MasteryTier::S) => 7,
+            Some(MasteryTier::A) => 2,
+            _ => 0,
+        }
+    }
+}
+
+/// 已记录的小局战术
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesTacticRecord {
+    pub game_number: u8,
+    pub team_id: u64,
+    pub tactics: GameTactics,
+    pub modifier: Option<TacticModifier>,
+    /// 是否已在模拟中生效
+    pub applied: bool,
+}
+
+/// 系列赛局间战术引擎
+pub struct SeriesTacticsEngine;
+
+impl SeriesTacticsEngine {
+    /// 双方战术合成为主队视角的战力差偏移与波动倍率
+    pub fn game_modifier(home: &GameTactics, away: &GameTactics) -> (f64, f64) {
+        let h = home.modifier();
+        let a = away.modifier();
+        let shift = (h.power_shift + a.opponent_shift) - (a.power_shift + h.opponent_shift);
+        (shift, h.variance_scale * a.variance_scale)
+    }
+
+    /// AI 根据比分与上一局表现决定下一局战术
+    ///
+    /// - 上一局输了：放弃刚失利的体系，改用历史上最熟练的其他体系
+    /// - 对手有选手上一局发挥突出：下一局针对其英雄池
+    /// - 面临淘汰且上一局发挥落后：提高侵略性搏一把
+    pub fn ai_choose(
+        team_side: TeamSide,
+        ctx: &SeriesContext,
+        comp_history: &[(CompType, u32)],
+        opponent_star: Option<(u64, f64)>,
+        performance_gap: f64,
+    ) -> GameTactics {
+        let mut tactics = GameTactics::default();
+        let (my_score, opp_score, my_prev_comp) = match team_side {
+            TeamSide::Home => (ctx.home_score, ctx.away_score, ctx.prev_home_comp),
+            TeamSide::Away => (ctx.away_score, ctx.home_score, ctx.prev_away_comp),
+        };
+
+        if ctx.prev_loser_side == Some(team_side) {
+            if let Some(lost_comp) = my_prev_comp {
+                let mut history: Vec<(CompType, u32)> =
+                    comp_history.iter().copied().filter(|(c, _)| *c != lost_comp).collect();
+                history.sort_by_key(|(_, games)| std::cmp::Reverse(*games));
+                tactics.comp_pool = history.iter().take(2).map(|(c, _)| *c).collect();
+                if !tactics.comp_pool.is_empty() {
+                    tactics
+                        .reasons
+                        .push(format!("上一局{:?}体系失利，改打{:?}", lost_comp, tactics.comp_pool));
+                }
+            }
+        }
+
+        if let Some((player_id, margin)) = opponent_star {
+            if margin >= STAR_MARGIN {
+                tactics.target_player_id = Some(player_id);
+                tactics
+                    .reasons
+                    .push(format!("对手选手上一局发挥高出队伍均值{:.1}，重点针对", margin));
+            }
+        }
+
+        let facing_elimination = ctx.wins_needed > 0 && opp_score + 1 >= ctx.wins_needed && my_score < opp_score;
+        if facing_elimination && performance_gap < 0.0 {
+            tactics.aggressive = true;
+            tactics.reasons.push("面临淘汰且发挥落后，提高侵略性".to_string());
+        }
+
+        tactics
+    }
+
+    /// 校验玩家设定的战术
+    pub fn validate(tactics: &GameTactics, game_number: u8, opponent_player_ids: &[u64]) -> Result<(), String> {
+        if game_number < 2 {
+            return Err("局间战术只能从第二局开始设定".to_string());
+        }
+        if tactics.comp_pool.len() > MAX_COMP_POOL {
+            return Err(format!("体系池最多{}个", MAX_COMP_POOL));
+        }
+        if let Some(target) = tactics.target_player_id {
+            if !opponent_player_ids.contains(&target) {
+                return Err("针对的选手不属于对手队伍".to_string());
+            }
+        }
+        Ok(())
+    }
+
+    /// 读取玩家为某局预设的战术
+    pub async fn load_manual(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_id: u64,
+        game_number: u8,
+        team_id: u64,
+    ) -> Option<GameTactics> {
+        let row = sqlx::query(
+            r#"
+            SELECT comp_pool_json, target_player_id, aggressive, reasons_json FROM series_tactics
+            WHERE save_id = ? AND match_id = ? AND game_number = ? AND team_id = ? AND is_manual = 1
+            "#,
+        )
+        .bind(save_id)
+        .bind(match_id as i64)
+        .bind(game_number as i64)
+        .bind(team_id as i64)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten()?;
+        Some(Self::tactics_from_row(&row, true))
+    }
+
+    fn tactics_from_row(row: &sqlx::sqlite::SqliteRow, is_manual: bool) -> GameTactics {
+        GameTactics {
+            comp_pool: serde_json::from_str(&row.get::<String, _>("comp_pool_json")).unwrap_or_default(),
+            target_player_id: row.get::<Option<i64>, _>("target_player_id").map(|id| id as u64),
+            aggressive: row.get::<i64, _>("aggressive") != 0,
+            is_manual,
+            reasons: serde_json::from_str(&row.get::<String, _>("reasons_json")).unwrap_or_default(),
+        }
+    }
+
+    /// 写入一局战术；`applied` 为真时同时记录生效的修正
+    pub async fn save_tactics(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_id: u64,
+        game_number: u8,
+        team_id: u64,
+        tactics: &GameTactics,
+        applied: bool,
+    ) -> Result<(), String> {
+        let comp_pool_json =
+            serde_json::to_string(&tactics.comp_pool).map_err(|e| format!("序列化体系池失败: {}", e))?;
+        let reasons_json =
+            serde_json::to_string(&tactics.reasons).map_err(|e| format!("序列化战术说明失败: {}", e))?;
+        let modifier = tactics.modifier();
+        sqlx::query(
+            r#"
+            INSERT INTO series_tactics
+                (save_id, match_id, game_number, team_id, comp_pool_json, target_player_id, aggressive,
+                 is_manual, reasons_json, power_shift, opponent_shift, variance_scale, applied)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(save_id, match_id, game_number, team_id) DO UPDATE SET
+                comp_pool_json = excluded.comp_pool_json,
+                target_player_id = excluded.target_player_id,
+                aggressive = excluded.aggressive,
+                is_manual = excluded.is_manual,
+                reasons_json = excluded.reasons_json,
+                power_shift = excluded.power_shift,
+                opponent_shift = excluded.opponent_shift,
+                variance_scale = excluded.variance_scale,
+                applied = excluded.applied
+            "#,
+        )
+        .bind(save_id)
+        .bind(match_id as i64)
+        .bind(game_number as i64)
+        .bind(team_id as i64)
+        .bind(&comp_pool_json)
+        .bind(tactics.target_player_id.map(|id| id as i64))
+        .bind(tactics.aggressive as i64)
+        .bind(tactics.is_manual as i64)
+        .bind(&reasons_json)
+        .bind(modifier.power_shift)
+        .bind(modifier.opponent_shift)
+        .bind(modifier.variance_scale)
+        .bind(applied as i64)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("保存局间战术失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 删除玩家为某局预设且尚未生效的战术
+    pub async fn clear_manual(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_id: u64,
+        game_number: u8,
+        team_id: u64,
+    ) -> Result<(), String> {
+        sqlx::query(
+            "DELETE FROM series_tactics WHERE save_id = ? AND match_id = ? AND game_number = ? AND team_id = ? AND is_manual = 1 AND applied = 0",
+        )
+        .bind(save_id)
+        .bind(match_id as i64)
+        .bind(game_number as i64)
+        .bind(team_id as i64)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("删除局间战术失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 某场比赛的全部局间战术
+    pub async fn load_match(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_id: u64,
+    ) -> Result<Vec<SeriesTacticRecord>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT game_number, team_id, comp_pool_json, target_player_id, aggressive, is_manual,
+                   reasons_json, power_shift, opponent_shift, variance_scale, applied
+            FROM series_tactics
+            WHERE save_id = ? AND match_id = ?
+            ORDER BY game_number, team_id
+            "#,
+        )
+        .bind(save_id)
+        .bind(match_id as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询局间战术失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| {
+                let applied = row.get::<i64, _>("applied") != 0;
+                SeriesTacticRecord {
+                    game_number: row.get::<i64, _>("game_number") as u8,
+                    team_id: row.get::<i64, _>("team_id") as u64,
+                    tactics: Self::tactics_from_row(row, row.get::<i64, _>("is_manual") != 0),
+                    modifier: applied.then(|| TacticModifier {
+                        power_shift: row.get("power_shift"),
+                        opponent_shift: row.get("opponent_shift"),
+                        variance_scale: row.get("variance_scale"),
+                    }),
+                    applied,
+                }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx(home_score: u8, away_score: u8, loser: TeamSide) -> SeriesContext {
+        SeriesContext {
+            prev_loser_side: Some(loser),
+            prev_home_comp: Some(CompType::Rush),
+            prev_away_comp: Some(CompType::Teamfight),
+            home_score,
+            away_score,
+            game_number: home_score + away_score,
+            wins_needed: 3,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_ai_switches_comp_after_loss() {
+        let history = [(CompType::Rush, 10), (CompType::Dive, 6), (CompType::Protect, 3)];
+        let tactics = SeriesTacticsEngine::ai_choose(TeamSide::Home, &ctx(0, 1, TeamSide::Home), &history, None, -1.0);
+        assert_eq!(tactics.comp_pool, vec![CompType::Dive, CompType::Protect]);
+        assert!(!tactics.aggressive);
+
+        // 赢下上一局的一方沿用原计划
+        let tactics = SeriesTacticsEngine::ai_choose(TeamSide::Away, &ctx(0, 1, TeamSide::Home), &history, None, 1.0);
+        assert!(tactics.is_standard());
+    }
+
+    #[test]
+    fn test_ai_targets_star_and_goes_aggressive_when_facing_elimination() {
+        let tactics =
+            SeriesTacticsEngine::ai_choose(TeamSide::Home, &ctx(1, 2, TeamSide::Home), &[], Some((42, 8.0)), -2.0);
+        assert_eq!(tactics.target_player_id, Some(42));
+        assert!(tactics.aggressive);
+
+        let tactics =
+            SeriesTacticsEngine::ai_choose(TeamSide::Home, &ctx(1, 1, TeamSide::Home), &[], Some((42, 3.0)), -2.0);
+        assert_eq!(tactics.target_player_id, None);
+        assert!(!tactics.aggressive);
+    }
+
+    #[test]
+    fn test_game_modifier_trades_mean_for_variance() {
+        let aggressive = GameTactics {
+            aggressive: true,
+            ..Default::default()
+        };
+        let (shift, variance) = SeriesTacticsEngine::game_modifier(&aggressive, &GameTactics::default());
+        assert!(shift > 0.0);
+        assert!(variance > 1.0);
+
+        // 针对选手对主队视角是正向偏移，对称时互相抵消
+        let targeting = GameTactics {
+            target_player_id: Some(1),
+            ..Default::default()
+        };
+        let (shift, _) = SeriesTacticsEngine::game_modifier(&targeting, &GameTactics::default());
+        assert!(shift > 0.0);
+        let (shift, variance) = SeriesTacticsEngine::game_modifier(&targeting, &targeting);
+        assert!(shift.abs() < f64::EPSILON);
+        assert!((variance - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_validate_manual_tactics() {
+        let tactics = GameTactics {
+            target_player_id: Some(9),
+            is_manual: true,
+            ..Default::default()
+        };
+        assert!(SeriesTacticsEngine::validate(&tactics, 1, &[9]).is_err());
+        assert!(SeriesTacticsEngine::validate(&tactics, 2, &[8]).is_err());
+        assert!(SeriesTacticsEngine::validate(&tactics, 2, &[9]).is_ok());
+    }
+}
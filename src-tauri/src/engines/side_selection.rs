@@ -0,0 +1,287 @@
+//! 选边引擎 (Side Selection Engine)
+//!
+//! - 蓝色方第一手选人，红色方握有最后的 counter 位
+//! - 版本决定蓝/红方的基础胜率修正（见 `MetaType::blue_side_bonus`）
+//! - 选边权：第一局与 BO5 决胜局由高种子选择，其余小局由上一局败方选择
+//! - AI 选边时权衡先手锁定强势英雄与后手 counter 的价值
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::engines::bp_engine::{PlayerChampionPool, TeamSide};
+use crate::engines::champion::{MasteryTier, VersionTier};
+use crate::engines::meta_engine::MetaType;
+use crate::models::MatchFormat;
+
+/// 每名掌握版本 T1 英雄（S 级以上熟练度）的选手带来的先手价值
+const FIRST_PICK_VALUE: f64 = 0.4;
+/// 先手价值最多计入的招牌数（蓝方第一轮只能锁定有限的英雄）
+const FIRST_PICK_MAX_SIGNATURES: usize = 2;
+/// 每名英雄池深厚（3 个以上 S 级英雄）的选手带来的 counter 价值
+const COUNTER_PICK_VALUE: f64 = 0.3;
+const COUNTER_PICK_MAX_DEEP_POOLS: usize = 3;
+const DEEP_POOL_SIZE: usize = 3;
+
+/// 地图边（蓝色方 / 红色方）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MapSide {
+    #[default]
+    Blue,
+    Red,
+}
+
+impl MapSide {
+    pub fn opposite(&self) -> MapSide {
+        match self {
+            MapSide::Blue => MapSide::Red,
+            MapSide::Red => MapSide::Blue,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MapSide::Blue => "Blue",
+            MapSide::Red => "Red",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MapSide::Blue => "蓝色方",
+            MapSide::Red => "红色方",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<MapSide> {
+        match id {
+            "Blue" => Some(MapSide::Blue),
+            "Red" => Some(MapSide::Red),
+            _ => None,
+        }
+    }
+}
+
+/// 一局比赛的选边结果
+#[derive(Debug, Clone)]
+pub struct SideChoice {
+    /// 拥有选边权的一方
+    pub chooser: TeamSide,
+    /// 主队所在的地图边
+    pub home_side: MapSide,
+    pub reason: String,
+}
+
+/// 系列赛选边计划：各队偏好在系列赛内不变，逐局按选边权决定主队所在边
+#[derive(Debug, Clone, Copy)]
+pub struct SidePlan {
+    pub meta_type: MetaType,
+    pub higher_seed: TeamSide,
+    pub home_preference: MapSide,
+    pub away_preference: MapSide,
+}
+
+impl SidePlan {
+    pub fn home_side(&self, chooser: TeamSide) -> MapSide {
+        match chooser {
+            TeamSide::Home => self.home_preference,
+            TeamSide::Away => self.away_preference.opposite(),
+        }
+    }
+}
+
+/// 选边引擎
+pub struct SideSelectionEngine;
+
+impl SideSelectionEngine {
+    /// 战力评级较高的一方视为高种子（相同时主队优先）
+    pub fn higher_seed(home_rating: f64, away_rating: f64) -> TeamSide {
+        if away_rating > home_rating {
+            TeamSide::Away
+        } else {
+            TeamSide::Home
+        }
+    }
+
+    /// 按赛制决定本局的选边方
+    ///
+    /// - BO1：高种子选边
+    /// - BO3：第一局高种子，之后由上一局败方选边
+    /// - BO5：第一局与 2:2 决胜局由高种子选边，其余由上一局败方选边
+    pub fn side_chooser(
+        format: MatchFormat,
+        game_number: u8,
+        home_score: u8,
+        away_score: u8,
+        higher_seed: TeamSide,
+        prev_loser: Option<TeamSide>,
+    ) -> TeamSide {
+        let decider = format == MatchFormat::Bo5 && home_score == 2 && away_score == 2;
+        if format == MatchFormat::Bo1 || game_number <= 1 || decider {
+            return higher_seed;
+        }
+        prev_loser.unwrap_or(higher_seed)
+    }
+
+    /// 主队所在边对应的主队战力修正（主队在红方时取相反数）
+    pub fn side_bonus(meta: MetaType, home_side: MapSide) -> f64 {
+        match home_side {
+            MapSide::Blue => meta.blue_side_bonus(),
+            MapSide::Red => -meta.blue_side_bonus(),
+        }
+    }
+
+    /// 队伍偏好的地图边：先手锁定版本强势招牌 vs 后手 counter
+    pub fn preferred_side(
+        pools: &[PlayerChampionPool],
+        version_tiers: &HashMap<u8, VersionTier>,
+        meta: MetaType,
+    ) -> (MapSide, String) {
+        let is_strong = |tier: &MasteryTier| matches!(tier, MasteryTier::SS | MasteryTier::S);
+
+        let signatures = pools
+            .iter()
+            .filter(|p| {
+                p.masteries.iter().any(|(id, tier)| {
+                    is_strong(tier) && version_tiers.get(id) == Some(&VersionTier::T1)
+                })
+            })
+            .count()
+            .min(FIRST_PICK_MAX_SIGNATURES);
+        let deep_pools = pools
+            .iter()
+            .filter(|p| p.masteries.values().filter(|t| is_strong(t)).count() >= DEEP_POOL_SIZE)
+            .count()
+            .min(COUNTER_PICK_MAX_DEEP_POOLS);
+
+        let blue_score = meta.blue_side_bonus() + FIRST_PICK_VALUE * signatures as f64;
+        let red_score = COUNTER_PICK_VALUE * deep_pools as f64;
+
+        if blue_score >= red_score {
+            let reason = if signatures > 0 {
+                format!("选择蓝色方：先手锁定{}个版本强势招牌", signatures)
+            } else {
+                format!("选择蓝色方：当前版本蓝方优势{:+.1}", meta.blue_side_bonus())
+            };
+            (MapSide::Blue, reason)
+        } else {
+            (
+                MapSide::Red,
+                format!("选择红色方：{}名选手英雄池深厚，握住最后的counter位", deep_pools),
+            )
+        }
+    }
+
+    /// 由选边方按自身偏好选择本局地图边
+    pub fn choose(
+        chooser: TeamSide,
+        home_pools: &[PlayerChampionPool],
+        away_pools: &[PlayerChampionPool],
+        version_tiers: &HashMap<u8, VersionTier>,
+        meta: MetaType,
+    ) -> SideChoice {
+        let chooser_pools = match chooser {
+            TeamSide::Home => home_pools,
+            TeamSide::Away => away_pools,
+        };
+        let (preferred, reason) = Self::preferred_side(chooser_pools, version_tiers, meta);
+        let home_side = match chooser {
+            TeamSide::Home => preferred,
+            TeamSide::Away => preferred.opposite(),
+        };
+        SideChoice {
+            chooser,
+            home_side,
+            reason,
+        }
+    }
+
+    /// 为系列赛生成选边计划
+    pub fn plan_series(
+        home_rating: f64,
+        away_rating: f64,
+        home_pools: &[PlayerChampionPool],
+        away_pools: &[PlayerChampionPool],
+        version_tiers: &HashMap<u8, VersionTier>,
+        meta: MetaType,
+    ) -> SidePlan {
+        SidePlan {
+            meta_type: meta,
+            higher_seed: Self::higher_seed(home_rating, away_rating),
+            home_preference: Self::preferred_side(home_pools, version_tiers, meta).0,
+            away_preference: Self::preferred_side(away_pools, version_tiers, meta).0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::player::Position;
+
+    fn pool(player_id: u64, masteries: &[(u8, MasteryTier)]) -> PlayerChampionPool {
+        PlayerChampionPool {
+            player_id,
+            position: Position::Mid,
+            ability: 70,
+            masteries: masteries.iter().copied().collect(),
+            games_played: HashMap::new(),
+            games_won: HashMap::new(),
+            traits: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_side_chooser_rules_per_format() {
+        use TeamSide::{Away, Home};
+        assert_eq!(SideSelectionEngine::side_chooser(MatchFormat::Bo1, 1, 0, 0, Away, None), Away);
+        assert_eq!(SideSelectionEngine::side_chooser(MatchFormat::Bo3, 1, 0, 0, Home, None), Home);
+        assert_eq!(
+            SideSelectionEngine::side_chooser(MatchFormat::Bo3, 2, 1, 0, Home, Some(Away)),
+            Away
+        );
+        // BO5 决胜局回到高种子
+        assert_eq!(
+            SideSelectionEngine::side_chooser(MatchFormat::Bo5, 5, 2, 2, Home, Some(Away)),
+            Home
+        );
+        assert_eq!(
+            SideSelectionEngine::side_chooser(MatchFormat::Bo5, 4, 2, 1, Home, Some(Away)),
+            Away
+        );
+    }
+
+    #[test]
+    fn test_side_bonus_is_symmetric() {
+        for meta in MetaType::all() {
+            let blue = SideSelectionEngine::side_bonus(*meta, MapSide::Blue);
+            let red = SideSelectionEngine::side_bonus(*meta, MapSide::Red);
+            assert!((blue + red).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_deep_pools_prefer_counter_pick() {
+        let tiers: HashMap<u8, VersionTier> = HashMap::new();
+        let deep = [(1, MasteryTier::S), (2, MasteryTier::S), (3, MasteryTier::SS)];
+        let pools: Vec<_> = (0..3).map(|i| pool(i, &deep)).collect();
+
+        let (side, _) = SideSelectionEngine::preferred_side(&pools, &tiers, MetaType::LateGameScaling);
+        assert_eq!(side, MapSide::Red);
+
+        // 同样的英雄池，若掌握版本 T1 英雄则更看重先手
+        let tiers: HashMap<u8, VersionTier> = [(1, VersionTier::T1)].into_iter().collect();
+        let (side, _) = SideSelectionEngine::preferred_side(&pools, &tiers, MetaType::EarlyGameAggro);
+        assert_eq!(side, MapSide::Blue);
+    }
+
+    #[test]
+    fn test_choose_mirrors_for_away_chooser() {
+        let tiers: HashMap<u8, VersionTier> = HashMap::new();
+        let shallow = vec![pool(1, &[(1, MasteryTier::A)])];
+        let choice = SideSelectionEngine::choose(TeamSide::Away, &[], &shallow, &tiers, MetaType::Balanced);
+        assert_eq!(choice.chooser, TeamSide::Away);
+        // 客队选蓝方 → 主队在红方
+        assert_eq!(choice.home_side, MapSide::Red);
+    }
+}
@@ -0,0 +1,430 @@
+//! 统一模拟核心 (Simulation Core)
+//!
+//! 所有比赛路径（快速模拟、详细模拟、天梯、胜率预测）共用同一套发挥模型：
+//! - 选手发挥 = 特性修正后能力 + 额外加成 + 状态 + N(0, σ)，σ = (100 - 稳定性) / 10
+//! - 选手发挥钳位到 [能力 - 15, 能力上限]，能力上限默认为能力 + 10（特性可调整）
+//! - 队伍战力 = Meta 加权后的选手发挥
+//! - 单局发挥 = N(队伍战力, σ=6)，发挥值高者获胜
+//!
+//! 平衡调整（统一前后对比见 docs/03-core-systems/match-simulation.md，快速模拟路径不变）：
+//! - 详细模拟单局波动由"战力差 + N(0, 3)"改为双方各自 N(战力, 6)，战力差 5 的胜率由约 95% 降至约 72%
+//! - 详细模拟的 BP 加成从仅展示改为逐选手计入发挥
+//! - 单局详细模拟（game_commands）的队伍战力由五人平均改为 Meta 加权（均衡权重，含 carry/drag）
+//! - 分阶段结算（天梯）不再逐阶段重抽发挥，战力差 5 的胜率由约 80% 降至约 72%
+//! - 赛前胜率预测由 Φ(战力差 / σ) 改为与结算一致的 Φ(战力差 / √2σ)，战力差 6 的预测由约 84% 降至约 76%
+
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+use super::meta_engine::{MetaEngine, MetaWeights};
+use super::traits::{TraitContext, TraitEngine, TraitType};
+
+/// 单局队伍发挥的标准差（策划案规定）
+pub const TEAM_PERFORMANCE_SIGMA: f64 = 6.0;
+/// 选手发挥下限：能力 - 15
+const PERFORMANCE_FLOOR_MARGIN: f64 = 15.0;
+
+/// 选手单局发挥的分解
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PerformanceRoll {
+    /// 特性修正后的能力
+    pub modified_ability: f64,
+    /// 特性修正后的状态加成
+    pub condition_bonus: f64,
+    /// 稳定性波动
+    pub stability_noise: f64,
+    /// 实际发挥
+    pub actual_ability: f64,
+}
+
+/// 详细模拟的单局结算结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GameResolution {
+    pub home_roll: f64,
+    pub away_roll: f64,
+    pub home_won: bool,
+}
+
+/// 参与模拟的选手属性
+#[derive(Debug, Clone, Copy)]
+pub struct PerformanceInput<'a> {
+    pub ability: u8,
+    pub stability: u8,
+    pub condition: i8,
+    pub traits: &'a [TraitType],
+    /// BP 等额外的能力加成
+    pub bonus: f64,
+    /// 额外的状态调整（如 TeamLeader 队友加成）
+    pub condition_adjust: i8,
+}
+
+/// 统一模拟核心
+pub struct SimulationCore;
+
+impl SimulationCore {
+    /// 标准正态分布随机数
+    pub fn standard_normal(rng: &mut impl Rng) -> f64 {
+        rng.sample(StandardNormal)
+    }
+
+    /// 稳定性对应的选手发挥标准差 σ = (100 - stability) / 10
+    pub fn stability_sigma(stability: u8) -> f64 {
+        (100.0 - stability.min(100) as f64) / 10.0
+    }
+
+    /// 计算选手单局发挥（含特性修正）
+    pub fn roll_player(input: &PerformanceInput, trait_ctx: &TraitContext, rng: &mut impl Rng) -> PerformanceRoll {
+        let modifiers = TraitEngine::calculate_combined_modifiers(input.traits, trait_ctx);
+        let (modified_ability, modified_stability, modified_condition, ability_ceiling) =
+            TraitEngine::apply_modifiers(input.ability, input.stability, input.condition, &modifiers);
+        let condition = (modified_condition as i16 + input.condition_adjust as i16).clamp(-10, 10);
+
+        Self::roll(
+            modified_ability as f64,
+            modified_stability,
+            condition as f64,
+            ability_ceiling as f64,
+            input.bonus,
+            rng,
+        )
+    }
+
+    /// 按已修正的属性计算发挥：能力 + 加成 + 状态 + 稳定性波动，钳位到 [能力 - 15, 上限]
+    pub fn roll(
+        ability: f64,
+        stability: u8,
+        condition: f64,
+        ceiling: f64,
+        bonus: f64,
+        rng: &mut impl Rng,
+    ) -> PerformanceRoll {
+        let stability_noise = Self::standard_normal(rng) * Self::stability_sigma(stability);
+        let raw_ability = ability + bonus + condition + stability_noise;
+        let min_ability = (ability - PERFORMANCE_FLOOR_MARGIN).max(0.0);
+        let max_ability = ceiling.min(100.0).max(min_ability);
+
+        PerformanceRoll {
+            modified_ability: ability,
+            condition_bonus: condition,
+            stability_noise,
+            actual_ability: raw_ability.clamp(min_ability, max_ability),
+        }
+    }
+
+    /// 队伍战力：Meta 加权后的选手发挥
+    pub fn team_power(players: &[(f64, &str)], meta_weights: &MetaWeights) -> f64 {
+        MetaEngine::calculate_team_power_weighted(players, meta_weights)
+    }
+
+    /// 选手影响力 = 实际发挥 - 队伍平均发挥
+    pub fn impact_score(actual_ability: f64, team_average: f64) -> f64 {
+        ((actual_ability - team_average) * 10.0).round() / 10.0
+    }
+
+    /// 单局队伍发挥值 N(战力, σ)
+    pub fn team_performance(power: f64, std_dev: f64, rng: &mut impl Rng) -> f64 {
+        power + Self::standard_normal(rng) * std_dev
+    }
+
+    /// 模拟单局双方发挥值，返回 (主队发挥, 客队发挥)，发挥值高者获胜
+    pub fn sample_game(home_power: f64, away_power: f64, std_dev: f64, rng: &mut impl Rng) -> (f64, f64) {
+        (
+            Self::team_performance(home_power, std_dev, rng),
+            Self::team_performance(away_power, std_dev, rng),
+        )
+    }
+
+    /// 详细模拟单局结算：主队战力叠加地图边/战术偏移，战术方差系数放大单局波动
+    pub fn resolve_detailed_game(
+        home_power: f64,
+        away_power: f64,
+        home_shift: f64,
+        variance_scale: f64,
+        rng: &mut impl Rng,
+    ) -> GameResolution {
+        let (home_roll, away_roll) = Self::sample_game(
+            home_power + home_shift,
+            away_power,
+            TEAM_PERFORMANCE_SIGMA * variance_scale,
+            rng,
+        );
+        GameResolution {
+            home_roll,
+            away_roll,
+            home_won: home_roll > away_roll,
+        }
+    }
+
+    /// 单局主队胜率：P(N(主队战力, σ) > N(客队战力, σ))
+    ///
+    /// 双方发挥之差服从 N(战力差, √2σ)，故 P = Φ(战力差 / √2σ) = ½(1 + erf(战力差 / 2σ))
+    pub fn win_probability(home_power: f64, away_power: f64, std_dev: f64) -> f64 {
+        let z = (home_power - away_power) / (2.0 * std_dev);
+        0.5 * (1.0 + erf_approx(z))
+    }
+
+    /// 标准正态分布累积分布函数 Φ(x)
+    pub fn normal_cdf(x: f64) -> f64 {
+        0.5 * (1.0 + erf_approx(x / std::f64::consts::SQRT_2))
+    }
+}
+
+/// 误差函数的近似计算
+fn erf_approx(x: f64) -> f64 {
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    let t = 1.0 / (1.0 + p * x);
+    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
+
+    sign * y
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::comp_phase::{CompPhaseEngine, CompPhaseRuleset};
+    use crate::engines::match_simulation::MatchSimulationEngine;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const SAMPLES: usize = 20_000;
+    /// 经验胜率与理论胜率允许的偏差（约 5 倍标准误）
+    const TOLERANCE: f64 = 0.02;
+
+    fn trait_ctx() -> TraitContext {
+        TraitContext {
+            tournament_type: "league".to_string(),
+            is_playoff: false,
+            is_international: false,
+            game_number: 1,
+            score_diff: 0,
+            age: 24,
+            is_first_season: false,
+            games_since_rest: 0,
+        }
+    }
+
+    #[test]
+    fn test_stability_sigma() {
+        assert_eq!(SimulationCore::stability_sigma(100), 0.0);
+        assert_eq!(SimulationCore::stability_sigma(60), 4.0);
+        assert_eq!(SimulationCore::stability_sigma(0), 10.0);
+    }
+
+    #[test]
+    fn test_player_performance_range() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let input = PerformanceInput {
+            ability: 80,
+            stability: 80,
+            condition: 0,
+            traits: &[],
+            bonus: 0.0,
+            condition_adjust: 0,
+        };
+        let rolls: Vec<f64> = (0..SAMPLES)
+            .map(|_| SimulationCore::roll_player(&input, &trait_ctx(), &mut rng).actual_ability)
+            .collect();
+        assert!(rolls.iter().all(|r| (65.0..=90.0).contains(r)));
+
+        // σ=2 时钳位几乎不生效，均值应贴近能力值
+        let mean = rolls.iter().sum::<f64>() / SAMPLES as f64;
+        assert!((mean - 80.0).abs() < 0.1, "mean = {}", mean);
+    }
+
+    #[test]
+    fn test_condition_adjust_is_clamped() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let input = PerformanceInput {
+            ability: 70,
+            stability: 100,
+            condition: 10,
+            traits: &[],
+            bonus: 0.0,
+            condition_adjust: 1,
+        };
+        let roll = SimulationCore::roll_player(&input, &trait_ctx(), &mut rng);
+        assert_eq!(roll.condition_bonus, 10.0);
+        assert_eq!(roll.actual_ability, 80.0);
+    }
+
+    /// 快速模拟、详细模拟与胜率预测共用同一分布：经验胜率应与理论胜率一致
+    #[test]
+    fn test_fast_detailed_and_prediction_match() {
+        let engine = MatchSimulationEngine::default();
+        let mut rng = StdRng::seed_from_u64(3);
+
+        for (home, away) in [(75.0, 75.0), (78.0, 75.0), (82.0, 72.0), (65.0, 80.0)] {
+            let predicted = engine.calculate_win_probability(home, away);
+            assert!(
+                (predicted - SimulationCore::win_probability(home, away, TEAM_PERFORMANCE_SIGMA)).abs() < 1e-12
+            );
+
+            let fast_wins = (0..SAMPLES)
+                .filter(|_| engine.simulate_game(home, away, 1, 2).2 == 1)
+                .count();
+            let detailed_wins = (0..SAMPLES)
+                .filter(|_| SimulationCore::resolve_detailed_game(home, away, 0.0, 1.0, &mut rng).home_won)
+                .count();
+
+            let fast_rate = fast_wins as f64 / SAMPLES as f64;
+            let detailed_rate = detailed_wins as f64 / SAMPLES as f64;
+            assert!((fast_rate - predicted).abs() < TOLERANCE, "fast {} vs {}", fast_rate, predicted);
+            assert!(
+                (detailed_rate - predicted).abs() < TOLERANCE,
+                "detailed {} vs {}",
+                detailed_rate,
+                predicted
+            );
+        }
+    }
+
+    /// 详细模拟的结算口径调整：地图边/战术偏移等同战力差，战术方差按比例放大 σ，战力差 5 的胜率约 72%
+    #[test]
+    fn test_detailed_resolution_rebalance() {
+        let mut rng = StdRng::seed_from_u64(6);
+        let rate = |home: f64, away: f64, shift: f64, scale: f64, rng: &mut StdRng| {
+            (0..SAMPLES)
+                .filter(|_| SimulationCore::resolve_detailed_game(home, away, shift, scale, rng).home_won)
+                .count() as f64
+                / SAMPLES as f64
+        };
+
+        let edge = SimulationCore::win_probability(80.0, 75.0, TEAM_PERFORMANCE_SIGMA);
+        assert!((edge - 0.722).abs() < 0.005, "edge = {}", edge);
+        assert!((rate(80.0, 75.0, 0.0, 1.0, &mut rng) - edge).abs() < TOLERANCE);
+
+        let shifted = rate(75.0, 75.0, 5.0, 1.0, &mut rng);
+        assert!((shifted - edge).abs() < TOLERANCE, "shifted {} vs {}", shifted, edge);
+
+        let volatile = rate(80.0, 75.0, 0.0, 1.5, &mut rng);
+        let expected = SimulationCore::win_probability(80.0, 75.0, TEAM_PERFORMANCE_SIGMA * 1.5);
+        assert!((volatile - expected).abs() < TOLERANCE, "volatile {} vs {}", volatile, expected);
+        assert!(volatile < edge);
+    }
+
+    /// 平衡调整前后的胜率曲线：调整前详细模拟按 Φ(战力差 / 3)，调整后所有路径按 Φ(战力差 / 6√2)
+    #[test]
+    fn test_rebalanced_win_curve() {
+        let legacy_sigma = 3.0 / std::f64::consts::SQRT_2;
+        for (diff, legacy, unified) in [(1.0, 0.631, 0.547), (3.0, 0.841, 0.638), (5.0, 0.952, 0.722), (10.0, 1.0, 0.881)] {
+            let before = SimulationCore::win_probability(75.0 + diff, 75.0, legacy_sigma);
+            let after = SimulationCore::win_probability(75.0 + diff, 75.0, TEAM_PERFORMANCE_SIGMA);
+            assert!((before - legacy).abs() < 0.001, "legacy {} vs {}", before, legacy);
+            assert!((after - unified).abs() < 0.001, "unified {} vs {}", after, unified);
+        }
+    }
+
+    /// 赛前胜率预测：调整前按 Φ(战力差 / 6)，调整后与结算一致按 Φ(战力差 / 6√2)
+    #[test]
+    fn test_rebalanced_prediction_curve() {
+        let legacy_sigma = TEAM_PERFORMANCE_SIGMA / std::f64::consts::SQRT_2;
+        for (diff, legacy, unified) in [(1.0, 0.566, 0.547), (3.0, 0.691, 0.638), (6.0, 0.841, 0.760), (10.0, 0.952, 0.881)] {
+            let before = SimulationCore::win_probability(75.0 + diff, 75.0, legacy_sigma);
+            let after = SimulationCore::win_probability(75.0 + diff, 75.0, TEAM_PERFORMANCE_SIGMA);
+            assert!((before - legacy).abs() < 0.001, "legacy {} vs {}", before, legacy);
+            assert!((after - unified).abs() < 0.001, "unified {} vs {}", after, unified);
+        }
+    }
+
+    /// BP 加成逐选手计入发挥
+    #[test]
+    fn test_bp_bonus_applies_per_player() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let input = PerformanceInput {
+            ability: 70,
+            stability: 100,
+            condition: 0,
+            traits: &[],
+            bonus: 3.0,
+            condition_adjust: 0,
+        };
+        assert_eq!(SimulationCore::roll_player(&input, &trait_ctx(), &mut rng).actual_ability, 73.0);
+    }
+
+    /// 天梯走分阶段结算：无阵容体系时强队同样更常获胜，战力相同时接近五五开
+    #[test]
+    fn test_phased_path_agrees_on_direction() {
+        let ruleset = CompPhaseRuleset::default();
+        let mut rng = StdRng::seed_from_u64(4);
+        let rate = |home: f64, away: f64, rng: &mut StdRng| {
+            (0..SAMPLES)
+                .filter(|_| {
+                    CompPhaseEngine::resolve_game(&ruleset, home, away, None, None, TEAM_PERFORMANCE_SIGMA, rng)
+                        .home_won
+                })
+                .count() as f64
+                / SAMPLES as f64
+        };
+
+        assert!((rate(75.0, 75.0, &mut rng) - 0.5).abs() < TOLERANCE);
+        let strong = rate(82.0, 72.0, &mut rng);
+        assert!(strong > SimulationCore::win_probability(82.0, 72.0, TEAM_PERFORMANCE_SIGMA) - TOLERANCE);
+    }
+
+    /// 天梯走分阶段结算：无阵容体系时经验胜率与理论胜率一致
+    #[test]
+    fn test_phased_path_matches_prediction() {
+        let ruleset = CompPhaseRuleset::default();
+        let mut rng = StdRng::seed_from_u64(8);
+
+        for (home, away) in [(75.0, 75.0), (78.0, 75.0), (82.0, 72.0), (65.0, 80.0)] {
+            let predicted = SimulationCore::win_probability(home, away, TEAM_PERFORMANCE_SIGMA);
+            let phased_wins = (0..SAMPLES)
+                .filter(|_| {
+                    CompPhaseEngine::resolve_game(&ruleset, home, away, None, None, TEAM_PERFORMANCE_SIGMA, &mut rng)
+                        .home_won
+                })
+                .count();
+            let phased_rate = phased_wins as f64 / SAMPLES as f64;
+            assert!((phased_rate - predicted).abs() < TOLERANCE, "phased {} vs {}", phased_rate, predicted);
+        }
+    }
+
+    #[test]
+    fn test_higher_power_wins_more() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let strong = PerformanceInput {
+            ability: 90,
+            stability: 80,
+            condition: 5,
+            traits: &[],
+            bonus: 0.0,
+            condition_adjust: 0,
+        };
+        let weak = PerformanceInput {
+            ability: 60,
+            stability: 60,
+            condition: -2,
+            ..strong
+        };
+        let weights = MetaWeights::balanced();
+        let positions = ["TOP", "JUG", "MID", "ADC", "SUP"];
+
+        let mut strong_wins = 0;
+        for _ in 0..100 {
+            let power = |input: &PerformanceInput, rng: &mut StdRng| {
+                let rolls: Vec<(f64, &str)> = positions
+                    .iter()
+                    .map(|pos| (SimulationCore::roll_player(input, &trait_ctx(), rng).actual_ability, *pos))
+                    .collect();
+                SimulationCore::team_power(&rolls, &weights)
+            };
+            let strong_power = power(&strong, &mut rng);
+            let weak_power = power(&weak, &mut rng);
+            let (s, w) = SimulationCore::sample_game(strong_power, weak_power, TEAM_PERFORMANCE_SIGMA, &mut rng);
+            if s > w {
+                strong_wins += 1;
+            }
+        }
+        assert!(strong_wins >= 60, "Strong team won {} out of 100 games", strong_wins);
+    }
+}
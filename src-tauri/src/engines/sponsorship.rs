@@ -0,0 +1,565 @@
+//! 赞助合同引擎 (Sponsorship Engine)
+//!
+//! - 赞助商目录：冠名 / 主要 / 普通三档，冠名赞助在同一赛区内独家
+//! - 每队最多 3 个赞助，同一品类只能有一个赞助商，冠名赞助最多一个
+//! - 合同为多赛季：每赛季支付基础赞助费，夺冠与晋级国际赛另有绩效奖金
+//! - 品牌价值或胜率跌破合同条款时，赞助商在赛季结算时提前解约
+//!
+//! 赛季结算在转会期第 6 轮执行：解约 → 到期 → 补签空缺 → 支付本赛季款项
+
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::models::{SponsorContract, SponsorContractStatus, SponsorOffer, SponsorSettlement, SponsorTier};
+
+/// 每队赞助商数量上限
+pub const MAX_SPONSORS_PER_TEAM: usize = 3;
+/// 冠军奖金占基础赞助费比例
+const TITLE_BONUS_RATIO: f64 = 0.2;
+/// 国际赛奖金占基础赞助费比例
+const INTERNATIONAL_BONUS_RATIO: f64 = 0.15;
+/// 品牌价值跌破准入线该比例时可解约
+const BRAND_FLOOR_RATIO: f64 = 0.8;
+
+/// 赞助商目录条目
+#[derive(Debug, Clone, Copy)]
+pub struct SponsorProfile {
+    pub code: &'static str,
+    pub name: &'static str,
+    pub category: &'static str,
+    pub tier: SponsorTier,
+    /// 基础年度赞助费（按球队品牌与战力缩放）
+    pub base_annual: i64,
+    /// 准入品牌价值
+    pub min_brand: f64,
+}
+
+/// 赞助商目录
+pub const SPONSOR_CATALOG: &[SponsorProfile] = &[
+    SponsorProfile { code: "apex_motors", name: "星途汽车", category: "汽车", tier: SponsorTier::Title, base_annual: 4_000_000, min_brand: 60.0 },
+    SponsorProfile { code: "hengxin_bank", name: "恒信银行", category: "金融", tier: SponsorTier::Title, base_annual: 4_000_000, min_brand: 60.0 },
+    SponsorProfile { code: "tianqiong_mobile", name: "天穹手机", category: "手机", tier: SponsorTier::Title, base_annual: 4_500_000, min_brand: 70.0 },
+    SponsorProfile { code: "leiting_gear", name: "雷霆外设", category: "外设", tier: SponsorTier::Major, base_annual: 2_000_000, min_brand: 30.0 },
+    SponsorProfile { code: "jiguang_energy", name: "极光能量饮料", category: "饮料", tier: SponsorTier::Major, base_annual: 2_000_000, min_brand: 30.0 },
+    SponsorProfile { code: "ruidong_apparel", name: "锐动运动", category: "服饰", tier: SponsorTier::Major, base_annual: 1_800_000, min_brand: 25.0 },
+    SponsorProfile { code: "yunduan_cloud", name: "云端科技", category: "科技", tier: SponsorTier::Major, base_annual: 2_200_000, min_brand: 40.0 },
+    SponsorProfile { code: "chicheng_chair", name: "驰骋电竞椅", category: "家具", tier: SponsorTier::Major, base_annual: 1_600_000, min_brand: 20.0 },
+    SponsorProfile { code: "xunjie_net", name: "迅捷网络", category: "网络", tier: SponsorTier::Minor, base_annual: 1_000_000, min_brand: 0.0 },
+    SponsorProfile { code: "lanhai_drink", name: "蓝海饮料", category: "饮料", tier: SponsorTier::Minor, base_annual: 900_000, min_brand: 0.0 },
+    SponsorProfile { code: "jifeng_gear", name: "疾风外设", category: "外设", tier: SponsorTier::Minor, base_annual: 1_000_000, min_brand: 0.0 },
+    SponsorProfile { code: "yanhuo_hotel", name: "焰火电竞酒店", category: "酒店", tier: SponsorTier::Minor, base_annual: 800_000, min_brand: 0.0 },
+    SponsorProfile { code: "baobao_food", name: "饱饱外卖", category: "餐饮", tier: SponsorTier::Minor, base_annual: 900_000, min_brand: 0.0 },
+];
+
+/// 球队赛季结算所需的数据
+#[derive(Debug, Clone)]
+pub struct TeamSponsorContext {
+    pub team_id: i64,
+    pub region_id: i64,
+    pub brand_value: f64,
+    pub power_rating: f64,
+    /// 本赛季常规赛胜率（无比赛记录时为空，不触发胜率条款）
+    pub win_rate: Option<f64>,
+    /// 本赛季夺冠次数
+    pub titles: u32,
+    /// 本赛季是否晋级国际赛
+    pub qualified_international: bool,
+}
+
+/// 赛季结算用的战绩汇总
+#[derive(Debug, Clone, Default)]
+pub struct SeasonSponsorResults {
+    /// 各队夺冠次数
+    pub titles: HashMap<i64, u32>,
+    /// 参加过国际赛的队伍
+    pub international_teams: HashSet<i64>,
+    /// 各队常规赛胜率
+    pub win_rates: HashMap<i64, f64>,
+}
+
+/// 赞助合同引擎
+pub struct SponsorshipEngine;
+
+impl SponsorshipEngine {
+    pub fn find_profile(code: &str) -> Option<&'static SponsorProfile> {
+        SPONSOR_CATALOG.iter().find(|p| p.code == code)
+    }
+
+    /// 球队商业吸引力系数（品牌价值 × 战力，与原赞助公式的战力分档一致）
+    pub fn team_factor(brand_value: f64, power_rating: f64) -> f64 {
+        let brand_factor = 0.6 + brand_value.clamp(0.0, 100.0) / 100.0 * 0.9;
+        let power_factor = match power_rating as u32 {
+            70..=100 => 1.5,
+            65..=69 => 1.2,
+            60..=64 => 1.0,
+            55..=59 => 0.85,
+            50..=54 => 0.7,
+            _ => 0.5,
+        };
+        brand_factor * power_factor
+    }
+
+    /// 赞助商给出的合同报价
+    pub fn build_offer(profile: &SponsorProfile, brand_value: f64, power_rating: f64) -> SponsorOffer {
+        let annual_base = ((profile.base_annual as f64 * Self::team_factor(brand_value, power_rating)) / 10_000.0).round() as i64 * 10_000;
+        let (years, min_win_rate) = match profile.tier {
+            SponsorTier::Title => (3, 0.40),
+            SponsorTier::Major => (2, 0.30),
+            SponsorTier::Minor => (1, 0.0),
+        };
+        SponsorOffer {
+            sponsor_code: profile.code.to_string(),
+            sponsor_name: profile.name.to_string(),
+            category: profile.category.to_string(),
+            tier: profile.tier,
+            years,
+            annual_base,
+            title_bonus: (annual_base as f64 * TITLE_BONUS_RATIO) as i64,
+            international_bonus: (annual_base as f64 * INTERNATIONAL_BONUS_RATIO) as i64,
+            brand_floor: profile.min_brand * BRAND_FLOOR_RATIO,
+            min_win_rate,
+            is_exclusive: profile.tier == SponsorTier::Title,
+        }
+    }
+
+    /// 检查球队能否签下该赞助商（名额、品类独占、冠名唯一、赛区独家）
+    pub fn can_sign(
+        profile: &SponsorProfile,
+        brand_value: f64,
+        held: &[SponsorContract],
+        taken_exclusive: &HashSet<String>,
+    ) -> Result<(), String> {
+        if held.len() >= MAX_SPONSORS_PER_TEAM {
+            return Err(format!("赞助名额已满（最多{}个）", MAX_SPONSORS_PER_TEAM));
+        }
+        if brand_value < profile.min_brand {
+            return Err(format!("品牌价值{:.0}未达到{}的准入线{:.0}", brand_value, profile.name, profile.min_brand));
+        }
+        if held.iter().any(|c| c.sponsor_code == profile.code) {
+            return Err(format!("已与{}签约", profile.name));
+        }
+        if held.iter().any(|c| c.category == profile.category) {
+            return Err(format!("已有{}品类赞助商，品类独占", profile.category));
+        }
+        if profile.tier == SponsorTier::Title && held.iter().any(|c| c.tier == SponsorTier::Title) {
+            return Err("已有冠名赞助".to_string());
+        }
+        if profile.tier == SponsorTier::Title && taken_exclusive.contains(profile.code) {
+            return Err(format!("{}已独家赞助本赛区其他战队", profile.name));
+        }
+        Ok(())
+    }
+
+    /// 当前可签的全部报价（按基础赞助费降序）
+    pub fn available_offers(
+        brand_value: f64,
+        power_rating: f64,
+        held: &[SponsorContract],
+        taken_exclusive: &HashSet<String>,
+    ) -> Vec<SponsorOffer> {
+        let mut offers: Vec<SponsorOffer> = SPONSOR_CATALOG
+            .iter()
+            .filter(|p| Self::can_sign(p, brand_value, held, taken_exclusive).is_ok())
+            .map(|p| Self::build_offer(p, brand_value, power_rating))
+            .collect();
+        offers.sort_by_key(|o| std::cmp::Reverse(o.annual_base));
+        offers
+    }
+
+    /// 赞助商提前解约的理由（未触发条款返回 None）
+    pub fn breach_reason(contract: &SponsorContract, brand_value: f64, win_rate: Option<f64>) -> Option<String> {
+        if brand_value < contract.brand_floor {
+            return Some(format!("品牌价值{:.0}跌破条款线{:.0}", brand_value, contract.brand_floor));
+        }
+        let win_rate = win_rate?;
+        if win_rate < contract.min_win_rate {
+            return Some(format!("赛季胜率{:.0}%低于条款线{:.0}%", win_rate * 100.0, contract.min_win_rate * 100.0));
+        }
+        None
+    }
+
+    /// 单份合同本赛季的（基础赞助费, 绩效奖金）
+    pub fn season_payout(contract: &SponsorContract, titles: u32, qualified_international: bool) -> (i64, i64) {
+        let bonus = contract.title_bonus * titles as i64
+            + if qualified_international { contract.international_bonus } else { 0 };
+        (contract.annual_base, bonus)
+    }
+
+    // ============================================
+    // 数据库
+    // ============================================
+
+    fn contract_from_row(row: &sqlx::sqlite::SqliteRow) -> SponsorContract {
+        SponsorContract {
+            id: row.get::<i64, _>("id") as u64,
+            team_id: row.get::<i64, _>("team_id") as u64,
+            sponsor_code: row.get("sponsor_code"),
+            sponsor_name: row.get("sponsor_name"),
+            category: row.get("category"),
+            tier: SponsorTier::parse(&row.get::<String, _>("tier")),
+            start_season: row.get::<i64, _>("start_season") as u32,
+            end_season: row.get::<i64, _>("end_season") as u32,
+            annual_base: row.get("annual_base"),
+            title_bonus: row.get("title_bonus"),
+            international_bonus: row.get("international_bonus"),
+            brand_floor: row.get("brand_floor"),
+            min_win_rate: row.get("min_win_rate"),
+            is_exclusive: row.get::<i64, _>("is_exclusive") != 0,
+            status: SponsorContractStatus::parse(&row.get::<String, _>("status")),
+            terminated_season: row.get::<Option<i64>, _>("terminated_season").map(|s| s as u32),
+            termination_reason: row.get("termination_reason"),
+        }
+    }
+
+    /// 查询赞助合同（team_id 为空时返回全部，active_only 只返回生效中的合同）
+    pub async fn load_contracts(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: Option<u64>,
+        active_only: bool,
+    ) -> Result<Vec<SponsorContract>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM sponsor_contracts
+            WHERE save_id = ? AND (? IS NULL OR team_id = ?) AND (? = 0 OR status = 'Active')
+            ORDER BY team_id, start_season DESC, id DESC
+            "#,
+        )
+        .bind(save_id)
+        .bind(team_id.map(|t| t as i64))
+        .bind(team_id.map(|t| t as i64))
+        .bind(active_only as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询赞助合同失败: {}", e))?;
+
+        Ok(rows.iter().map(Self::contract_from_row).collect())
+    }
+
+    /// 赛区内已被独家签下的赞助商（exclude_team 的合同不计入）
+    pub async fn taken_exclusive(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        region_id: i64,
+        exclude_team: i64,
+    ) -> Result<HashSet<String>, String> {
+        let codes: Vec<String> = sqlx::query_scalar(
+            r#"
+            SELECT c.sponsor_code FROM sponsor_contracts c
+            JOIN teams t ON t.id = c.team_id
+            WHERE c.save_id = ? AND c.status = 'Active' AND c.is_exclusive = 1
+              AND t.region_id = ? AND c.team_id != ?
+            "#,
+        )
+        .bind(save_id)
+        .bind(region_id)
+        .bind(exclude_team)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询独家赞助失败: {}", e))?;
+        Ok(codes.into_iter().collect())
+    }
+
+    /// 写入新合同（从 start_season 起生效）
+    pub async fn insert_contract(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: i64,
+        offer: &SponsorOffer,
+        start_season: u32,
+    ) -> Result<u64, String> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO sponsor_contracts (
+                save_id, team_id, sponsor_code, sponsor_name, category, tier, start_season, end_season,
+                annual_base, title_bonus, international_bonus, brand_floor, min_win_rate, is_exclusive, status
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'Active')
+            "#,
+        )
+        .bind(save_id)
+        .bind(team_id)
+        .bind(&offer.sponsor_code)
+        .bind(&offer.sponsor_name)
+        .bind(&offer.category)
+        .bind(offer.tier.as_str())
+        .bind(start_season as i64)
+        .bind((start_season + offer.years - 1) as i64)
+        .bind(offer.annual_base)
+        .bind(offer.title_bonus)
+        .bind(offer.international_bonus)
+        .bind(offer.brand_floor)
+        .bind(offer.min_win_rate)
+        .bind(offer.is_exclusive as i64)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("写入赞助合同失败: {}", e))?;
+        Ok(result.last_insert_rowid() as u64)
+    }
+
+    async fn close_contract(
+        pool: &Pool<Sqlite>,
+        contract_id: u64,
+        status: SponsorContractStatus,
+        season: u32,
+        reason: Option<&str>,
+    ) -> Result<(), String> {
+        sqlx::query("UPDATE sponsor_contracts SET status = ?, terminated_season = ?, termination_reason = ? WHERE id = ?")
+            .bind(status.as_str())
+            .bind(season as i64)
+            .bind(reason)
+            .bind(contract_id as i64)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新赞助合同失败: {}", e))?;
+        Ok(())
+    }
+
+    /// 本赛季各队夺冠次数、晋级国际赛的队伍与常规赛胜率
+    pub async fn load_season_results(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season: u32,
+    ) -> Result<SeasonSponsorResults, String> {
+        let title_rows = sqlx::query(
+            r#"SELECT team_id, COUNT(*) as titles FROM honors
+               WHERE save_id = ? AND season_id = ? AND honor_type = 'TEAM_CHAMPION' AND team_id IS NOT NULL
+               GROUP BY team_id"#,
+        )
+        .bind(save_id)
+        .bind(season as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询冠军荣誉失败: {}", e))?;
+        let titles = title_rows
+            .iter()
+            .map(|r| (r.get::<i64, _>("team_id"), r.get::<i64, _>("titles") as u32))
+            .collect();
+
+        let intl_teams: Vec<i64> = sqlx::query_scalar(
+            r#"SELECT DISTINCT team_id FROM (
+                   SELECT m.home_team_id as team_id FROM matches m JOIN tournaments t ON m.tournament_id = t.id
+                   WHERE m.save_id = ? AND t.season_id = ?
+                     AND t.tournament_type IN ('Msi','WorldChampionship','MadridMasters','ShanghaiMasters','ClaudeIntercontinental','IcpIntercontinental','SuperIntercontinental')
+                   UNION
+                   SELECT m.away_team_id FROM matches m JOIN tournaments t ON m.tournament_id = t.id
+                   WHERE m.save_id = ? AND t.season_id = ?
+                     AND t.tournament_type IN ('Msi','WorldChampionship','MadridMasters','ShanghaiMasters','ClaudeIntercontinental','IcpIntercontinental','SuperIntercontinental')
+               ) WHERE team_id IS NOT NULL"#,
+        )
+        .bind(save_id)
+        .bind(season as i64)
+        .bind(save_id)
+        .bind(season as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询国际赛参赛队伍失败: {}", e))?;
+
+        let standing_rows = sqlx::query(
+            r#"SELECT ls.team_id, SUM(ls.wins) as wins, SUM(ls.matches_played) as played
+               FROM league_standings ls
+               JOIN tournaments t ON ls.tournament_id = t.id
+               WHERE t.save_id = ? AND t.season_id = ? AND t.tournament_type IN ('SpringRegular', 'SummerRegular')
+               GROUP BY ls.team_id"#,
+        )
+        .bind(save_id)
+        .bind(season as i64)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询常规赛战绩失败: {}", e))?;
+        let win_rates = standing_rows
+            .iter()
+            .filter_map(|r| {
+                let played: i64 = r.get("played");
+                (played > 0).then(|| (r.get::<i64, _>("team_id"), r.get::<i64, _>("wins") as f64 / played as f64))
+            })
+            .collect();
+
+        Ok(SeasonSponsorResults {
+            titles,
+            international_teams: intl_teams.into_iter().collect(),
+            win_rates,
+        })
+    }
+
+    /// 单支球队的赛季赞助结算：解约 → 到期 → 补签空缺 → 写入本赛季赞助收入交易
+    ///
+    /// 只写入合同与交易记录，球队余额由调用方更新
+    pub async fn settle_team_season(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season: u32,
+        ctx: &TeamSponsorContext,
+    ) -> Result<SponsorSettlement, String> {
+        let mut settlement = SponsorSettlement {
+            team_id: ctx.team_id as u64,
+            ..Default::default()
+        };
+
+        let mut held = Vec::new();
+        for contract in Self::load_contracts(pool, save_id, Some(ctx.team_id as u64), true).await? {
+            if contract.end_season < season {
+                Self::close_contract(pool, contract.id, SponsorContractStatus::Expired, season, None).await?;
+            } else if let Some(reason) = Self::breach_reason(&contract, ctx.brand_value, ctx.win_rate) {
+                Self::close_contract(pool, contract.id, SponsorContractStatus::Terminated, season, Some(&reason)).await?;
+                log::info!("赞助解约: 队伍{} {}提前解约（{}）", ctx.team_id, contract.sponsor_name, reason);
+                settlement.terminated.push(contract.sponsor_name);
+            } else {
+                held.push(contract);
+            }
+        }
+
+        // 补签空缺：按报价从高到低依次签约
+        let taken = Self::taken_exclusive(pool, save_id, ctx.region_id, ctx.team_id).await?;
+        while held.len() < MAX_SPONSORS_PER_TEAM {
+            let Some(offer) = Self::available_offers(ctx.brand_value, ctx.power_rating, &held, &taken).into_iter().next() else {
+                break;
+            };
+            let id = Self::insert_contract(pool, save_id, ctx.team_id, &offer, season).await?;
+            settlement.signed.push(offer.sponsor_name.clone());
+            held.push(SponsorContract {
+                id,
+                team_id: ctx.team_id as u64,
+                sponsor_code: offer.sponsor_code,
+                sponsor_name: offer.sponsor_name,
+                category: offer.category,
+                tier: offer.tier,
+                start_season: season,
+                end_season: season + offer.years - 1,
+                annual_base: offer.annual_base,
+                title_bonus: offer.title_bonus,
+                international_bonus: offer.international_bonus,
+                brand_floor: offer.brand_floor,
+                min_win_rate: offer.min_win_rate,
+                is_exclusive: offer.is_exclusive,
+                status: SponsorContractStatus::Active,
+                terminated_season: None,
+                termination_reason: None,
+            });
+        }
+
+        for contract in held.iter().filter(|c| c.covers(season)) {
+            let (base, bonus) = Self::season_payout(contract, ctx.titles, ctx.qualified_international);
+            Self::record_income(pool, save_id, ctx.team_id, season, "Sponsorship", base, &format!("S{}赛季{}{}费", season, contract.sponsor_name, contract.tier.name())).await?;
+            if bonus > 0 {
+                Self::record_income(pool, save_id, ctx.team_id, season, "SponsorBonus", bonus, &format!("S{}赛季{}绩效奖金", season, contract.sponsor_name)).await?;
+            }
+            settlement.base_income += base;
+            settlement.bonus_income += bonus;
+        }
+
+        Ok(settlement)
+    }
+
+    async fn record_income(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        team_id: i64,
+        season: u32,
+        transaction_type: &str,
+        amount: i64,
+        description: &str,
+    ) -> Result<(), String> {
+        sqlx::query(
+            "INSERT INTO financial_transactions (save_id, team_id, season_id, transaction_type, amount, description) VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(save_id)
+        .bind(team_id)
+        .bind(season as i64)
+        .bind(transaction_type)
+        .bind(amount)
+        .bind(description)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("记录赞助收入失败: {}", e))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract_from_offer(offer: &SponsorOffer, start: u32) -> SponsorContract {
+        SponsorContract {
+            id: 0,
+            team_id: 1,
+            sponsor_code: offer.sponsor_code.clone(),
+            sponsor_name: offer.sponsor_name.clone(),
+            category: offer.category.clone(),
+            tier: offer.tier,
+            start_season: start,
+            end_season: start + offer.years - 1,
+            annual_base: offer.annual_base,
+            title_bonus: offer.title_bonus,
+            international_bonus: offer.international_bonus,
+            brand_floor: offer.brand_floor,
+            min_win_rate: offer.min_win_rate,
+            is_exclusive: offer.is_exclusive,
+            status: SponsorContractStatus::Active,
+            terminated_season: None,
+            termination_reason: None,
+        }
+    }
+
+    #[test]
+    fn test_offer_scales_with_brand_and_power() {
+        let profile = SponsorshipEngine::find_profile("leiting_gear").unwrap();
+        let strong = SponsorshipEngine::build_offer(profile, 90.0, 72.0);
+        let weak = SponsorshipEngine::build_offer(profile, 35.0, 52.0);
+        assert!(strong.annual_base > weak.annual_base * 2);
+        assert_eq!(strong.years, 2);
+        assert!(!strong.is_exclusive);
+    }
+
+    #[test]
+    fn test_category_and_title_exclusivity() {
+        let none = HashSet::new();
+        let title = SponsorshipEngine::build_offer(SponsorshipEngine::find_profile("apex_motors").unwrap(), 80.0, 70.0);
+        let drink = SponsorshipEngine::build_offer(SponsorshipEngine::find_profile("jiguang_energy").unwrap(), 80.0, 70.0);
+        let held = vec![contract_from_offer(&title, 1), contract_from_offer(&drink, 1)];
+
+        // 同品类饮料不能再签
+        let lanhai = SponsorshipEngine::find_profile("lanhai_drink").unwrap();
+        assert!(SponsorshipEngine::can_sign(lanhai, 80.0, &held, &none).is_err());
+        // 已有冠名不能再签第二个冠名
+        let bank = SponsorshipEngine::find_profile("hengxin_bank").unwrap();
+        assert!(SponsorshipEngine::can_sign(bank, 80.0, &held, &none).is_err());
+        // 赛区内被独家签下的冠名赞助不可签
+        let taken: HashSet<String> = ["hengxin_bank".to_string()].into_iter().collect();
+        assert!(SponsorshipEngine::can_sign(bank, 80.0, &[], &taken).is_err());
+        assert!(SponsorshipEngine::can_sign(bank, 80.0, &[], &none).is_ok());
+        // 品牌不足无法签冠名
+        assert!(SponsorshipEngine::can_sign(bank, 40.0, &[], &none).is_err());
+    }
+
+    #[test]
+    fn test_available_offers_respect_slots() {
+        let none = HashSet::new();
+        let offers = SponsorshipEngine::available_offers(20.0, 60.0, &[], &none);
+        assert!(!offers.is_empty());
+        assert!(offers.iter().all(|o| o.tier != SponsorTier::Title));
+        assert!(offers.windows(2).all(|w| w[0].annual_base >= w[1].annual_base));
+
+        let held: Vec<SponsorContract> = offers.iter().take(MAX_SPONSORS_PER_TEAM).map(|o| contract_from_offer(o, 1)).collect();
+        assert!(SponsorshipEngine::available_offers(20.0, 60.0, &held, &none).is_empty());
+    }
+
+    #[test]
+    fn test_breach_and_payout() {
+        let title = SponsorshipEngine::build_offer(SponsorshipEngine::find_profile("apex_motors").unwrap(), 80.0, 70.0);
+        let contract = contract_from_offer(&title, 1);
+        assert!(SponsorshipEngine::breach_reason(&contract, 75.0, Some(0.6)).is_none());
+        assert!(SponsorshipEngine::breach_reason(&contract, 40.0, Some(0.6)).is_some());
+        assert!(SponsorshipEngine::breach_reason(&contract, 75.0, Some(0.3)).is_some());
+        // 无比赛记录时不触发胜率条款
+        assert!(SponsorshipEngine::breach_reason(&contract, 75.0, None).is_none());
+
+        let (base, bonus) = SponsorshipEngine::season_payout(&contract, 2, true);
+        assert_eq!(base, contract.annual_base);
+        assert_eq!(bonus, contract.title_bonus * 2 + contract.international_bonus);
+        assert_eq!(SponsorshipEngine::season_payout(&contract, 0, false).1, 0);
+        assert!(contract.covers(3));
+        assert!(!contract.covers(4));
+    }
+}
@@ -0,0 +1,474 @@
+//! 青训管线引擎 (Talent Pipeline Engine)
+//!
+//! 取代一次性读取的选秀池 JSON，每个赛季为各赛区生成一届新秀：
+//! - 人才密度决定届次规模（LCK/LPL 人才储备最深）
+//! - 赛区财力决定青训基建，基建越好新秀潜力越高
+//! - 届次强弱随机波动，偶尔出现"黄金一代"
+//! - 基建落后赛区的新秀有概率流向基建更好的赛区
+//! - 每届排名靠前的新秀进入选秀池，其余以自由球员身份进入市场
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::rookie_generator::{GeneratedRookie, RookieGenerator};
+use crate::models::RegionCode;
+
+/// 每届进入选秀池的新秀数（与选秀轮次对应）
+pub const DRAFT_SLOTS_PER_CLASS: usize = 14;
+
+/// 出现黄金一代的概率
+pub const GOLDEN_GENERATION_CHANCE: f64 = 0.06;
+
+/// 基准届次规模（人才密度 1.0、基建 0.5 时）
+const BASE_CLASS_SIZE: f64 = 18.0;
+const MIN_CLASS_SIZE: usize = 12;
+const MAX_CLASS_SIZE: usize = 28;
+
+/// 普通届次的天才比例（与 RookieGenerator 默认一致）
+const BASE_GENIUS_RATE: f64 = 0.20;
+const GOLDEN_GENIUS_RATE: f64 = 0.50;
+
+/// 基建差距为 1.0 时的流出概率
+const BASE_EMIGRATION_RATE: f64 = 0.25;
+const MAX_EMIGRATION_CHANCE: f64 = 0.30;
+
+/// 新秀评分上限，避免黄金一代直接生成顶级选手
+const MAX_PROSPECT_ABILITY: u8 = 70;
+const MAX_PROSPECT_POTENTIAL: u8 = 76;
+
+/// 单个赛区的青训画像
+#[derive(Debug, Clone)]
+pub struct RegionTalentProfile {
+    /// 数据库中的赛区 ID
+    pub region_id: u64,
+    pub region_code: RegionCode,
+    pub talent_density: f64,
+    pub infrastructure: f64,
+}
+
+impl RegionTalentProfile {
+    pub fn new(region_id: u64, region_code: RegionCode, avg_balance: f64, avg_brand_value: f64) -> Self {
+        Self {
+            region_id,
+            region_code,
+            talent_density: TalentPipelineEngine::base_density(region_code),
+            infrastructure: TalentPipelineEngine::infrastructure_from_wealth(
+                region_code,
+                avg_balance,
+                avg_brand_value,
+            ),
+        }
+    }
+
+    /// RookieGenerator 使用的配置赛区 ID（决定 ID 池、姓名与国籍）
+    pub fn config_region_id(&self) -> u64 {
+        match self.region_code {
+            RegionCode::LPL => 1,
+            RegionCode::LCK => 2,
+            RegionCode::LEC => 3,
+            RegionCode::LCS => 4,
+            RegionCode::Other => 1,
+        }
+    }
+}
+
+/// 届次强弱
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClassStrength {
+    /// 能力/潜力整体偏移
+    pub ability_shift: i32,
+    /// 天才比例
+    pub genius_rate: f64,
+    pub is_golden: bool,
+}
+
+impl ClassStrength {
+    pub fn average() -> Self {
+        Self {
+            ability_shift: 0,
+            genius_rate: BASE_GENIUS_RATE,
+            is_golden: false,
+        }
+    }
+}
+
+/// 管线产出的新秀
+#[derive(Debug, Clone)]
+pub struct Prospect {
+    pub rookie: GeneratedRookie,
+    /// 出身赛区
+    pub origin_region_id: u64,
+    /// 最终落地赛区
+    pub region_id: u64,
+}
+
+impl Prospect {
+    /// 选秀排序分：潜力权重更高
+    pub fn draft_score(&self) -> f64 {
+        self.rookie.potential as f64 * 0.6 + self.rookie.ability as f64 * 0.4
+    }
+}
+
+/// 某赛区一届新秀
+#[derive(Debug, Clone)]
+pub struct IntakeClass {
+    pub profile: RegionTalentProfile,
+    pub strength: ClassStrength,
+    pub prospects: Vec<Prospect>,
+    pub generated: u32,
+    pub immigrants: u32,
+    pub emigrants: u32,
+}
+
+impl IntakeClass {
+    /// 按选秀排序分拆分：前 DRAFT_SLOTS_PER_CLASS 名进入选秀池，其余进入自由市场
+    pub fn split_for_draft(mut self) -> (Vec<Prospect>, Vec<Prospect>) {
+        self.prospects.sort_by(|a, b| {
+            b.draft_score()
+                .partial_cmp(&a.draft_score())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let cut = self.prospects.len().min(DRAFT_SLOTS_PER_CLASS);
+        let free_agents = self.prospects.split_off(cut);
+        (self.prospects, free_agents)
+    }
+}
+
+/// 青训管线引擎
+pub struct TalentPipelineEngine {
+    rng: StdRng,
+}
+
+impl TalentPipelineEngine {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn from_entropy() -> Self {
+        Self {
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// 赛区人才密度
+    pub fn base_density(code: RegionCode) -> f64 {
+        match code {
+            RegionCode::LCK => 1.25,
+            RegionCode::LPL => 1.15,
+            RegionCode::LEC => 0.95,
+            RegionCode::LCS => 0.85,
+            RegionCode::Other => 0.8,
+        }
+    }
+
+    /// 由赛区财力（队伍平均余额、平均品牌价值、赛区资本系数）推算青训基建 0.0 ~ 1.0
+    pub fn infrastructure_from_wealth(code: RegionCode, avg_balance: f64, avg_brand_value: f64) -> f64 {
+        let balance_score = (avg_balance / 20_000_000.0).clamp(0.0, 1.0);
+        let brand_score = (avg_brand_value / 100.0).clamp(0.0, 1.0);
+        let capital_score = ((code.market_value_factor() - 0.8) / 0.5).clamp(0.0, 1.0);
+        (balance_score * 0.4 + brand_score * 0.3 + capital_score * 0.3).clamp(0.0, 1.0)
+    }
+
+    /// 届次规模
+    pub fn class_size(profile: &RegionTalentProfile) -> usize {
+        let size = BASE_CLASS_SIZE * profile.talent_density * (0.9 + 0.2 * profile.infrastructure);
+        (size.round() as usize).clamp(MIN_CLASS_SIZE, MAX_CLASS_SIZE)
+    }
+
+    /// 新秀从 origin 流向 destination 的概率：只会流向基建更好的赛区，人才越密集流出越多，天才更抢手
+    pub fn emigration_chance(
+        origin: &RegionTalentProfile,
+        destination: &RegionTalentProfile,
+        is_genius: bool,
+    ) -> f64 {
+        let gap = destination.infrastructure - origin.infrastructure;
+        if gap <= 0.0 {
+            return 0.0;
+        }
+        let genius_factor = if is_genius { 1.5 } else { 1.0 };
+        (BASE_EMIGRATION_RATE * gap * origin.talent_density * genius_factor).min(MAX_EMIGRATION_CHANCE)
+    }
+
+    /// 随机届次强弱：普通年份 ±1 波动，少数年份为黄金一代
+    pub fn roll_class_strength(&mut self) -> ClassStrength {
+        if self.rng.gen::<f64>() < GOLDEN_GENERATION_CHANCE {
+            return ClassStrength {
+                ability_shift: self.rng.gen_range(2..=3),
+                genius_rate: GOLDEN_GENIUS_RATE,
+                is_golden: true,
+            };
+        }
+
+        let roll: f64 = self.rng.gen();
+        let ability_shift = if roll < 0.25 {
+            -1
+        } else if roll < 0.75 {
+            0
+        } else {
+            1
+        };
+        ClassStrength {
+            ability_shift,
+            genius_rate: BASE_GENIUS_RATE + 0.05 * ability_shift as f64,
+            is_golden: false,
+        }
+    }
+
+    /// 为某赛区生成一届新秀，existing_ids 会同步登记新生成的 game_id
+    pub fn generate_class(
+        &mut self,
+        profile: &RegionTalentProfile,
+        strength: ClassStrength,
+        existing_ids: &mut HashSet<String>,
+    ) -> IntakeClass {
+        let size = Self::class_size(profile);
+        let mut generator = RookieGenerator::new(self.rng.gen());
+        let rookies = generator.generate_rookies(profile.config_region_id(), size, existing_ids);
+
+        let prospects: Vec<Prospect> = rookies
+            .into_iter()
+            .map(|mut rookie| {
+                let promote_roll: f64 = self.rng.gen();
+                Self::apply_class_modifiers(&mut rookie, strength, profile.infrastructure, promote_roll);
+                existing_ids.insert(rookie.game_id.clone());
+                Prospect {
+                    rookie,
+                    origin_region_id: profile.region_id,
+                    region_id: profile.region_id,
+                }
+            })
+            .collect();
+
+        IntakeClass {
+            profile: profile.clone(),
+            strength,
+            generated: prospects.len() as u32,
+            prospects,
+            immigrants: 0,
+            emigrants: 0,
+        }
+    }
+
+    /// 应用届次强弱与基建加成
+    ///
+    /// promote_roll 用于决定非天才新秀是否晋升为天才（强届天才更多）
+    pub fn apply_class_modifiers(
+        rookie: &mut GeneratedRookie,
+        strength: ClassStrength,
+        infrastructure: f64,
+        promote_roll: f64,
+    ) {
+        let extra_genius = ((strength.genius_rate - BASE_GENIUS_RATE) / (1.0 - BASE_GENIUS_RATE)).max(0.0);
+        if rookie.tag != "Genius" && promote_roll < extra_genius {
+            rookie.tag = "Genius".to_string();
+            rookie.ability = rookie.ability.max(64);
+            rookie.potential = rookie.potential.max(rookie.ability + 3);
+        }
+
+        let infra_bonus: i32 = if infrastructure >= 0.7 {
+            1
+        } else if infrastructure < 0.3 {
+            -1
+        } else {
+            0
+        };
+
+        let ability = (rookie.ability as i32 + strength.ability_shift).clamp(40, MAX_PROSPECT_ABILITY as i32);
+        let potential = (rookie.potential as i32 + strength.ability_shift + infra_bonus)
+            .clamp(ability + 1, MAX_PROSPECT_POTENTIAL as i32);
+        rookie.ability = ability as u8;
+        rookie.potential = potential as u8;
+    }
+
+    /// 跨赛区流动：逐个新秀判定是否流向基建更好的赛区，返回流动人数
+    pub fn apply_migration(&mut self, classes: &mut [IntakeClass]) -> u32 {
+        let profiles: Vec<RegionTalentProfile> = classes.iter().map(|c| c.profile.clone()).collect();
+        let mut moves: Vec<(usize, Prospect)> = Vec::new();
+
+        for (origin_idx, class) in classes.iter_mut().enumerate() {
+            let origin = &profiles[origin_idx];
+            let mut staying = Vec::with_capacity(class.prospects.len());
+
+            for prospect in class.prospects.drain(..) {
+                let is_genius = prospect.rookie.tag == "Genius";
+                let chances: Vec<(usize, f64)> = profiles
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| *idx != origin_idx)
+                    .map(|(idx, dest)| (idx, Self::emigration_chance(origin, dest, is_genius)))
+                    .filter(|(_, chance)| *chance > 0.0)
+                    .collect();
+
+                // 各目标概率之和为总流出概率，命中后按各自概率加权挑选目标
+                let total: f64 = chances.iter().map(|(_, c)| c).sum::<f64>().min(MAX_EMIGRATION_CHANCE);
+                let roll: f64 = self.rng.gen();
+                if roll >= total {
+                    staying.push(prospect);
+                    continue;
+                }
+
+                let weight_sum: f64 = chances.iter().map(|(_, c)| c).sum();
+                let mut pick = self.rng.gen::<f64>() * weight_sum;
+                let mut dest_idx = chances[0].0;
+                for (idx, chance) in &chances {
+                    if pick < *chance {
+                        dest_idx = *idx;
+                        break;
+                    }
+                    pick -= chance;
+                }
+                moves.push((dest_idx, prospect));
+            }
+
+            class.emigrants = class.generated - staying.len() as u32;
+            class.prospects = staying;
+        }
+
+        let moved = moves.len() as u32;
+        for (dest_idx, mut prospect) in moves {
+            prospect.region_id = classes[dest_idx].profile.region_id;
+            classes[dest_idx].immigrants += 1;
+            classes[dest_idx].prospects.push(prospect);
+        }
+        moved
+    }
+
+    /// 生成全部赛区本赛季的新秀届次（含跨赛区流动）
+    pub fn run_intake(
+        &mut self,
+        profiles: &[RegionTalentProfile],
+        existing_ids: &mut HashSet<String>,
+    ) -> Vec<IntakeClass> {
+        let mut classes: Vec<IntakeClass> = profiles
+            .iter()
+            .map(|profile| {
+                let strength = self.roll_class_strength();
+                self.generate_class(profile, strength, existing_ids)
+            })
+            .collect();
+        self.apply_migration(&mut classes);
+        classes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(region_id: u64, code: RegionCode, infrastructure: f64) -> RegionTalentProfile {
+        RegionTalentProfile {
+            region_id,
+            region_code: code,
+            talent_density: TalentPipelineEngine::base_density(code),
+            infrastructure,
+        }
+    }
+
+    fn rookie(tag: &str, ability: u8, potential: u8) -> GeneratedRookie {
+        GeneratedRookie {
+            game_id: "Test".to_string(),
+            real_name: "测试".to_string(),
+            position: "Mid".to_string(),
+            ability,
+            potential,
+            stability: 50,
+            age: 18,
+            tag: tag.to_string(),
+            nationality: "中国".to_string(),
+            traits: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_infrastructure_follows_wealth() {
+        let rich = TalentPipelineEngine::infrastructure_from_wealth(RegionCode::LPL, 20_000_000.0, 80.0);
+        let poor = TalentPipelineEngine::infrastructure_from_wealth(RegionCode::LPL, 2_000_000.0, 30.0);
+        assert!(rich > poor);
+        assert!((0.0..=1.0).contains(&rich) && (0.0..=1.0).contains(&poor));
+    }
+
+    #[test]
+    fn test_denser_region_produces_larger_class() {
+        let lck = profile(1, RegionCode::LCK, 0.5);
+        let lcs = profile(2, RegionCode::LCS, 0.5);
+        assert!(TalentPipelineEngine::class_size(&lck) > TalentPipelineEngine::class_size(&lcs));
+    }
+
+    #[test]
+    fn test_emigration_only_towards_better_infrastructure() {
+        let poor = profile(1, RegionCode::LCK, 0.3);
+        let rich = profile(2, RegionCode::LCS, 0.8);
+        assert_eq!(TalentPipelineEngine::emigration_chance(&rich, &poor, true), 0.0);
+        let normal = TalentPipelineEngine::emigration_chance(&poor, &rich, false);
+        let genius = TalentPipelineEngine::emigration_chance(&poor, &rich, true);
+        assert!(normal > 0.0 && genius > normal);
+        assert!(genius <= MAX_EMIGRATION_CHANCE);
+    }
+
+    #[test]
+    fn test_golden_class_promotes_and_lifts_ratings() {
+        let golden = ClassStrength {
+            ability_shift: 3,
+            genius_rate: GOLDEN_GENIUS_RATE,
+            is_golden: true,
+        };
+        let mut r = rookie("Normal", 62, 65);
+        TalentPipelineEngine::apply_class_modifiers(&mut r, golden, 0.5, 0.0);
+        assert_eq!(r.tag, "Genius");
+        assert!(r.ability >= 67);
+        assert!(r.potential > r.ability);
+
+        let mut weak = rookie("Ordinary", 60, 62);
+        let weak_class = ClassStrength {
+            ability_shift: -1,
+            genius_rate: BASE_GENIUS_RATE - 0.05,
+            is_golden: false,
+        };
+        TalentPipelineEngine::apply_class_modifiers(&mut weak, weak_class, 0.1, 0.0);
+        assert_eq!(weak.tag, "Ordinary");
+        assert_eq!(weak.ability, 59);
+        assert!(weak.potential > weak.ability);
+    }
+
+    #[test]
+    fn test_intake_conserves_prospects_and_splits_class() {
+        let profiles = vec![
+            profile(1, RegionCode::LPL, 0.9),
+            profile(2, RegionCode::LCK, 0.4),
+            profile(3, RegionCode::LEC, 0.6),
+            profile(4, RegionCode::LCS, 0.2),
+        ];
+        let mut existing = HashSet::new();
+        let mut engine = TalentPipelineEngine::new(7);
+        let classes = engine.run_intake(&profiles, &mut existing);
+
+        let generated: u32 = classes.iter().map(|c| c.generated).sum();
+        let landed: usize = classes.iter().map(|c| c.prospects.len()).sum();
+        let emigrants: u32 = classes.iter().map(|c| c.emigrants).sum();
+        let immigrants: u32 = classes.iter().map(|c| c.immigrants).sum();
+        assert_eq!(generated as usize, landed);
+        assert_eq!(emigrants, immigrants);
+        assert_eq!(existing.len(), landed);
+
+        for class in classes {
+            let total = class.prospects.len();
+            let (draft, free_agents) = class.split_for_draft();
+            assert_eq!(draft.len(), total.min(DRAFT_SLOTS_PER_CLASS));
+            assert_eq!(draft.len() + free_agents.len(), total);
+            if let (Some(last), Some(first_fa)) = (draft.last(), free_agents.first()) {
+                assert!(last.draft_score() >= first_fa.draft_score());
+            }
+        }
+    }
+
+    #[test]
+    fn test_golden_generation_is_rare() {
+        let mut engine = TalentPipelineEngine::new(2024);
+        let golden = (0..2000).filter(|_| engine.roll_class_strength().is_golden).count();
+        assert!(golden > 40 && golden < 250, "黄金一代出现 {} 次", golden);
+    }
+}
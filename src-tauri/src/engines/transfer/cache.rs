@@ -1,7 +1,9 @@
 use sqlx::{Pool, Row, Sqlite};
 use std::collections::{HashMap, HashSet};
 
+use crate::engines::salary_cap::SalaryCapEngine;
 use crate::models::transfer::*;
+use crate::models::CapRules;
 
 use super::utils::normalize_position;
 
@@ -90,6 +92,12 @@ pub struct TransferCache {
     pub team_spring_ranks: HashMap<i64, i32>,
     pub team_summer_ranks: HashMap<i64, i32>,
     pub player_season_stats: HashMap<i64, CachedPlayerStats>,
+    /// 各球队所属赛区的薪资帽规则
+    pub team_cap_rules: HashMap<i64, CapRules>,
+    /// 各球队本赛季死钱合计
+    pub team_dead_cap: HashMap<i64, i64>,
+    /// 计算到期合同预留额所用的赛季
+    pub cap_season_id: i64,
 }
 
 impl TransferCache {
@@ -378,6 +386,10 @@ impl TransferCache {
             team_reputations.insert(team_id, overall);
         }
 
+        // 7. 薪资帽规则与本赛季死钱
+        let team_cap_rules = SalaryCapEngine::load_team_rules(pool, save_id).await?;
+        let team_dead_cap = SalaryCapEngine::load_dead_cap_totals(pool, save_id, season_id).await?;
+
         Ok(Self {
             team_names,
             team_balances,
@@ -392,6 +404,9 @@ impl TransferCache {
             team_spring_ranks,
             team_summer_ranks,
             player_season_stats,
+            team_cap_rules,
+            team_dead_cap,
+            cap_season_id: season_id,
         })
     }
 
@@ -522,6 +537,42 @@ impl TransferCache {
         }
     }
 
+    /// 球队薪资帽规则
+    pub fn get_cap_rules(&self, team_id: i64) -> CapRules {
+        self.team_cap_rules.get(&team_id).copied().unwrap_or_default()
+    }
+
+    /// 球队计入薪资帽总额（在约薪资 + 到期预留额 + 死钱）
+    pub fn team_payroll(&self, team_id: i64) -> i64 {
+        let dead_cap = self.team_dead_cap.get(&team_id).copied().unwrap_or(0);
+        let contracts = self
+            .team_rosters
+            .get(&team_id)
+            .map(|roster| roster.iter().map(|p| (p.salary, p.contract_end_season)).collect::<Vec<_>>())
+            .unwrap_or_default();
+        SalaryCapEngine::payroll(contracts, self.cap_season_id, dead_cap)
+    }
+
+    /// 签约薪资帽检查（outgoing 为同时移出的计入额）
+    pub fn check_cap(&self, team_id: i64, incoming: i64, outgoing: i64) -> Result<(), String> {
+        SalaryCapEngine::check_signing(&self.get_cap_rules(team_id), self.team_payroll(team_id), incoming, outgoing)
+    }
+
+    /// 选手当前合同计入薪资帽的金额
+    pub fn player_cap_hit(&self, player: &CachedPlayer) -> i64 {
+        SalaryCapEngine::cap_hit(player.salary, player.contract_end_season, self.cap_season_id)
+    }
+
+    /// 追加本赛季死钱
+    pub fn add_dead_cap(&mut self, team_id: i64, amount: i64) {
+        *self.team_dead_cap.entry(team_id).or_insert(0) += amount;
+    }
+
+    /// 新合同薪资截断至单人上限
+    pub fn clamp_salary(&self, team_id: i64, salary: i64) -> i64 {
+        SalaryCapEngine::clamp_salary(&self.get_cap_rules(team_id), salary)
+    }
+
     /// 释放选手（从队伍移除，变为自由球员）
     pub fn release_player(&mut self, player_id: i64, team_id: i64) {
         if let Some(roster) = self.team_rosters.get_mut(&team_id) {
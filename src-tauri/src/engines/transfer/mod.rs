@@ -10,6 +10,7 @@ mod round4_free_agent;
 mod round5_contracted;
 mod round6_financial;
 mod round7_remedy;
+mod role_transitions;
 mod scoring;
 mod utils;
 
@@ -119,7 +120,11 @@ impl TransferEngine {
         eprintln!("[转会] R{} cache构建完成 {:?}", round, round_start.elapsed());
 
         let result = match round {
-            1 => self.execute_season_settlement(pool, window_id, save_id, window.season_id, &mut cache).await?,
+            1 => {
+                let mut result = self.execute_season_settlement(pool, window_id, save_id, window.season_id, &mut cache).await?;
+                result.events.extend(self.complete_position_retrainings(pool, window_id, save_id, window.season_id).await?);
+                result
+            }
             2 => self.execute_bidirectional_evaluation(pool, window_id, save_id, window.season_id, &mut cache).await?,
             3 => self.execute_renewal_negotiations(pool, window_id, save_id, window.season_id, &mut cache).await?,
             4 => self.execute_free_agent_bidding(pool, window_id, save_id, window.season_id, &mut cache).await?,
@@ -0,0 +1,203 @@
+use rand::Rng;
+use rand::SeedableRng;
+use sqlx::{Pool, Row, Sqlite};
+use std::collections::HashMap;
+
+use crate::engines::role_transition::RoleTransitionEngine;
+use crate::engines::traits::TraitType;
+use crate::models::transfer::*;
+use crate::models::{ContractRole, Position};
+
+use super::cache::TransferCache;
+use super::TransferEngine;
+
+impl TransferEngine {
+    // ============================================
+    // 角色转型（第2轮评估后执行）
+    // ============================================
+
+    /// AI 球队根据本轮生成的位置需求，用阵容内富余选手转位置补缺；
+    /// 同时为符合条件的老将替补签下选手兼教练/第六人合同
+    pub(crate) async fn execute_role_transitions(
+        &self,
+        pool: &Pool<Sqlite>,
+        window_id: i64,
+        save_id: &str,
+        season_id: i64,
+        cache: &mut TransferCache,
+    ) -> Result<Vec<TransferEvent>, String> {
+        let mut events = Vec::new();
+        let mut rng = rand::rngs::StdRng::from_entropy();
+
+        // 1. 转位置补缺：每队每个转会期最多一次，缺首发必转，重要需求 50%
+        let need_rows = sqlx::query(
+            r#"SELECT e.team_id, n.position, n.need_level, n.min_ability_target
+               FROM team_position_needs n
+               JOIN team_season_evaluations e ON n.evaluation_id = e.id
+               WHERE e.window_id = ? AND n.need_level IN ('CRITICAL', 'IMPORTANT')
+                 AND n.position NOT LIKE '%_BENCH'
+               ORDER BY CASE n.need_level WHEN 'CRITICAL' THEN 0 ELSE 1 END"#
+        )
+        .bind(window_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询位置需求失败: {}", e))?;
+
+        let mut retrained_teams: Vec<i64> = Vec::new();
+        for row in &need_rows {
+            let team_id: i64 = row.get("team_id");
+            if retrained_teams.contains(&team_id) {
+                continue;
+            }
+            let need_level: String = row.get("need_level");
+            if need_level != "CRITICAL" && !rng.gen_bool(0.5) {
+                continue;
+            }
+
+            let need_position = Position::from_str(&row.get::<String, _>("position"));
+            let min_target: i64 = row.try_get::<i64, _>("min_ability_target").unwrap_or(0);
+            let roster = cache.get_roster(team_id);
+
+            let Some((player_id, _)) = RoleTransitionEngine::pick_retrain_candidate(need_position, min_target, &roster) else {
+                continue;
+            };
+
+            let retraining = match RoleTransitionEngine::start_retraining(pool, save_id, player_id as u64, need_position, season_id).await {
+                Ok(r) => r,
+                Err(e) => {
+                    log::warn!("[角色转型] 选手 {} 转位置失败: {}", player_id, e);
+                    continue;
+                }
+            };
+            retrained_teams.push(team_id);
+
+            let mut new_ability = 0;
+            if let Some(p) = cache.team_rosters.get_mut(&team_id).and_then(|r| r.iter_mut().find(|p| p.id == player_id)) {
+                p.position = retraining.to_position.clone();
+                p.ability = (p.ability - retraining.ability_penalty as i64).max(1);
+                p.is_starter = false;
+                new_ability = p.ability;
+            }
+
+            let team_name = cache.get_team_name(team_id);
+            let event = self.record_event(
+                pool, window_id, 2,
+                TransferEventType::PositionChange,
+                EventLevel::C,
+                player_id, &retraining.player_name, new_ability,
+                Some(team_id), Some(&team_name),
+                Some(team_id), Some(&team_name),
+                0, 0, 0,
+                &format!(
+                    "{}安排{}由{}转练{}补缺 | 临时能力-{}",
+                    team_name, retraining.player_name, retraining.from_position, retraining.to_position, retraining.ability_penalty
+                ),
+            ).await?;
+            events.push(event);
+        }
+
+        // 2. 老将角色：替补老将按特性签下选手兼教练/第六人合同（60%）
+        let veterans: Vec<(i64, i64, String, i64, i64)> = cache.team_rosters.iter()
+            .flat_map(|(&team_id, roster)| {
+                roster.iter()
+                    .filter(|p| !p.is_starter && p.age >= 27 && !ContractRole::parse(&p.contract_role).is_veteran_role())
+                    .map(move |p| (team_id, p.id, p.game_id.clone(), p.age, p.ability))
+            })
+            .collect();
+
+        if veterans.is_empty() {
+            return Ok(events);
+        }
+
+        let trait_rows = sqlx::query("SELECT player_id, trait_type FROM player_traits WHERE save_id = ?")
+            .bind(save_id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("查询特性失败: {}", e))?;
+        let mut traits_map: HashMap<i64, Vec<TraitType>> = HashMap::new();
+        for row in &trait_rows {
+            if let Some(tt) = TraitType::from_str(&row.get::<String, _>("trait_type")) {
+                traits_map.entry(row.get("player_id")).or_default().push(tt);
+            }
+        }
+
+        for (team_id, player_id, game_id, age, ability) in veterans {
+            let traits = traits_map.get(&player_id).cloned().unwrap_or_default();
+            let Some(role) = RoleTransitionEngine::eligible_veteran_role(age as u8, &traits) else {
+                continue;
+            };
+            if !rng.gen_bool(0.6) {
+                continue;
+            }
+
+            let new_salary = match RoleTransitionEngine::assign_veteran_role(pool, save_id, player_id as u64, role).await {
+                Ok(s) => s,
+                Err(e) => {
+                    log::warn!("[角色转型] 选手 {} 签约老将角色失败: {}", player_id, e);
+                    continue;
+                }
+            };
+            cache.update_player_salary(player_id, team_id, new_salary);
+            if let Some(p) = cache.team_rosters.get_mut(&team_id).and_then(|r| r.iter_mut().find(|p| p.id == player_id)) {
+                p.contract_role = role.as_str().to_string();
+            }
+
+            let role_name = match role {
+                ContractRole::PlayingCoach => "选手兼教练",
+                _ => "第六人",
+            };
+            let team_name = cache.get_team_name(team_id);
+            let event = self.record_event(
+                pool, window_id, 2,
+                TransferEventType::VeteranRole,
+                EventLevel::C,
+                player_id, &game_id, ability,
+                Some(team_id), Some(&team_name),
+                Some(team_id), Some(&team_name),
+                0, new_salary, 0,
+                &format!("{}老将{}转任{} | {}岁", team_name, game_id, role_name, age),
+            ).await?;
+            events.push(event);
+        }
+
+        Ok(events)
+    }
+
+    /// 第1轮结算后：完成已满一个赛季的转位置训练
+    pub(crate) async fn complete_position_retrainings(
+        &self,
+        pool: &Pool<Sqlite>,
+        window_id: i64,
+        save_id: &str,
+        season_id: i64,
+    ) -> Result<Vec<TransferEvent>, String> {
+        let completed = RoleTransitionEngine::complete_due_retrainings(pool, save_id, season_id).await?;
+        let mut events = Vec::with_capacity(completed.len());
+
+        for r in completed {
+            let row = sqlx::query(
+                "SELECT p.ability, p.team_id, t.name as team_name FROM players p LEFT JOIN teams t ON p.team_id = t.id WHERE p.id = ?"
+            )
+            .bind(r.player_id as i64)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| format!("查询选手信息失败: {}", e))?;
+            let ability: i64 = row.get("ability");
+            let team_id: Option<i64> = row.get("team_id");
+            let team_name: Option<String> = row.get("team_name");
+            let event = self.record_event(
+                pool, window_id, 1,
+                TransferEventType::PositionChange,
+                EventLevel::C,
+                r.player_id as i64, &r.player_name, ability,
+                team_id, team_name.as_deref(),
+                team_id, team_name.as_deref(),
+                0, 0, 0,
+                &format!("{}完成{}→{}转位置训练", r.player_name, r.from_position, r.to_position),
+            ).await?;
+            events.push(event);
+        }
+
+        Ok(events)
+    }
+}
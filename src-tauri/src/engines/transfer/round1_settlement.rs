@@ -4,9 +4,11 @@ use sqlx::{Pool, Row, Sqlite};
 use std::collections::{HashMap, HashSet};
 
 use crate::engines::champion::{self, MasteryTier};
+use crate::engines::champion_patch::ChampionPatchEngine;
 use crate::engines::market_value::MarketValueEngine;
 use crate::engines::traits::{TraitEngine, TraitType};
 use crate::models::transfer::*;
+use crate::models::ContractRole;
 
 use super::cache::{CachedPlayer, TransferCache};
 use super::TransferEngine;
@@ -274,7 +276,7 @@ impl TransferEngine {
                     0.3
                 };
 
-                // ⑥ 导师效应 — 年轻选手(≤22)受同位置导师/老将加成
+                // ⑥ 导师效应 — 年轻选手(≤22)受同位置导师/选手兼教练/老将加成
                 let mentor_coeff: f64 = if new_age <= 22 {
                     if let Some(tid) = team_id {
                         let teammates = cache.team_rosters.get(&tid);
@@ -294,8 +296,17 @@ impl TransferEngine {
                                     && !traits_map.get(&t.id).map_or(false, |tr| tr.contains(&TraitType::Mentor))
                             })
                         });
+                        // 选手兼教练带教全队，不限位置
+                        let has_playing_coach = teammates.is_some_and(|roster| {
+                            roster.iter().any(|t| {
+                                t.id != player_id
+                                    && ContractRole::parse(&t.contract_role) == ContractRole::PlayingCoach
+                            })
+                        });
                         if has_mentor_same_pos {
                             1.25
+                        } else if has_playing_coach {
+                            1.15
                         } else if has_veteran_same_pos {
                             1.10
                         } else {
@@ -631,8 +642,9 @@ impl TransferEngine {
 
             if retire_chance > 0.0 && rng.r#gen::<f64>() < retire_chance {
                 sqlx::query(
-                    "UPDATE players SET status = 'RETIRED', team_id = NULL WHERE id = ? AND save_id = ?"
+                    "UPDATE players SET status = 'RETIRED', team_id = NULL, retire_season = ? WHERE id = ? AND save_id = ?"
                 )
+                .bind(season_id)
                 .bind(player_id)
                 .bind(save_id)
                 .execute(pool)
@@ -661,65 +673,6 @@ impl TransferEngine {
                     &retire_desc,
                 ).await?;
 
-                if let Ok(Some(hof_entry)) = crate::engines::honor::HonorEngine::evaluate_hall_of_fame(
-                    pool, save_id, player_id, season_id,
-                )
-                .await
-                {
-                    let _ = sqlx::query(
-                        "INSERT OR IGNORE INTO hall_of_fame (save_id, player_id, player_name, position, region_id, induction_season, total_score, tier, peak_ability, career_seasons, honors_json) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
-                    )
-                    .bind(save_id)
-                    .bind(player_id)
-                    .bind(&hof_entry.player_name)
-                    .bind(&hof_entry.position)
-                    .bind(hof_entry.region_id)
-                    .bind(hof_entry.induction_season)
-                    .bind(hof_entry.total_score)
-                    .bind(&hof_entry.tier)
-                    .bind(hof_entry.peak_ability)
-                    .bind(hof_entry.career_seasons)
-                    .bind(&hof_entry.honors_json)
-                    .execute(pool)
-                    .await;
-
-                    let hof_desc = format!(
-                        "入选{}！总积分{}，巅峰能力{}，职业生涯{}赛季",
-                        if hof_entry.tier == "Legend" {
-                            "传奇殿堂"
-                        } else {
-                            "名人堂"
-                        },
-                        hof_entry.total_score,
-                        hof_entry.peak_ability,
-                        hof_entry.career_seasons
-                    );
-                    let hof_event = self.record_event(
-                        pool,
-                        window_id,
-                        1,
-                        TransferEventType::PlayerRetirement,
-                        EventLevel::A,
-                        player_id,
-                        game_id,
-                        new_ability as i64,
-                        team_id,
-                        if from_team_name.is_empty() {
-                            None
-                        } else {
-                            Some(&from_team_name)
-                        },
-                        None,
-                        None,
-                        0,
-                        0,
-                        0,
-                        &hof_desc,
-                    )
-                    .await?;
-                    events.push(hof_event);
-                }
-
                 events.push(event);
             }
         }
@@ -857,11 +810,7 @@ impl TransferEngine {
             let mut sat_change: i32 = 0;
 
             // 上场时间 — 基于 contract_role 的期望出场率动态计算
-            let expected_rate: f64 = match player.contract_role.as_str() {
-                "Sub" => 0.20,
-                "Prospect" => 0.05,
-                _ => 0.75, // Starter
-            };
+            let expected_rate: f64 = ContractRole::parse(&player.contract_role).expected_play_rate();
             let rate_gap = expected_rate - play_rate; // >0 表示出场不足
             if rate_gap > 0.0 {
                 // 出场低于预期：penalty = gap × 25 × (ability/50)
@@ -1118,6 +1067,7 @@ impl TransferEngine {
             log::info!("✅ 特性觉醒/退化完成：{} 次觉醒，{} 次退化", awakening_count, decay_count);
         }
 
+        let patch_strengths = ChampionPatchEngine::load_strengths(pool, save_id, season_id).await;
         let mastery_rows = sqlx::query(
             "SELECT pcm.player_id, pcm.champion_id, pcm.mastery_tier, pcm.games_played, pcm.games_won, p.game_id, p.team_id, p.ability
              FROM player_champion_mastery pcm
@@ -1147,7 +1097,14 @@ impl TransferEngine {
                 None => continue,
             };
 
-            let new_tier = champion::evolve_mastery(current, games_played as u32, games_won as u32, &mut rng);
+            let patch_strength = patch_strengths.get(&(champion_id as u8)).copied().unwrap_or(0.0);
+            let new_tier = champion::evolve_mastery_with_patch(
+                current,
+                games_played as u32,
+                games_won as u32,
+                patch_strength,
+                &mut rng,
+            );
 
             if new_tier != current {
                 if new_tier == MasteryTier::B {
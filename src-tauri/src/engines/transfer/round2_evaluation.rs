@@ -265,6 +265,9 @@ impl TransferEngine {
             }
         }
 
+        // 4. 角色转型：转位置补缺 + 老将角色合同
+        events.extend(self.execute_role_transitions(pool, window_id, save_id, season_id, cache).await?);
+
         Ok(RoundResult {
             round: 2,
             round_name: "双向评估".to_string(),
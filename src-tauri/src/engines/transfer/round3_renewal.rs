@@ -4,7 +4,10 @@ use sqlx::{Pool, Row, Sqlite};
 use std::collections::HashMap;
 
 use crate::engines::market_value::MarketValueEngine;
+use crate::engines::role_transition::RoleTransitionEngine;
+use crate::engines::salary_cap::SalaryCapEngine;
 use crate::models::team::FinancialStatus;
+use crate::models::ContractRole;
 use crate::models::transfer::*;
 
 use super::cache::TransferCache;
@@ -119,7 +122,8 @@ impl TransferEngine {
             let should_retire =
                 forced_retire || (retire_chance > 0.0 && rng.gen_range(0.0..1.0) < retire_chance);
             if should_retire {
-                sqlx::query("UPDATE players SET status = 'Retired' WHERE id = ?")
+                sqlx::query("UPDATE players SET status = 'Retired', retire_season = ? WHERE id = ?")
+                    .bind(season_id)
                     .bind(player_id)
                     .execute(pool)
                     .await
@@ -358,6 +362,8 @@ impl TransferEngine {
                 }
             }
 
+            let mut renewal_terms: Option<(i64, i64)> = None;
+            let mut cap_blocked: Option<String> = None;
             if renewed {
                 // 续约合同年限：沿用原逻辑
                 let base_years: i64 = if age <= 22 { 3 } else if age <= 25 { 2 } else if age <= 28 { 2 } else { 1 };
@@ -371,10 +377,23 @@ impl TransferEngine {
                 let new_contract_years = (base_years + random_adj).clamp(1, 4);
 
                 let mut new_salary = ((final_team_offer + player_expected_salary) / 2).max(1);
-                if contract_role == "Sub" {
+                let role = ContractRole::parse(&contract_role);
+                if role == ContractRole::Sub {
                     new_salary = ((new_salary as f64 * 0.85).round() as i64).max(1);
+                } else if role.is_veteran_role() {
+                    new_salary = ((new_salary as f64 * RoleTransitionEngine::role_salary_factor(role)).round() as i64).max(1);
                 }
+                let new_salary = cache.clamp_salary(team_id, new_salary);
 
+                // 薪资帽检查：续约合同替换该选手的到期预留额
+                let cap_hold = SalaryCapEngine::cap_hit(salary, Some(season_id), season_id);
+                match cache.check_cap(team_id, new_salary, cap_hold) {
+                    Ok(()) => renewal_terms = Some((new_salary, new_contract_years)),
+                    Err(reason) => cap_blocked = Some(reason),
+                }
+            }
+
+            if let Some((new_salary, new_contract_years)) = renewal_terms {
                 sqlx::query(
                     "UPDATE players SET salary = ?, contract_end_season = ?, loyalty = MIN(loyalty + 5, 100) WHERE id = ?",
                 )
@@ -384,6 +403,10 @@ impl TransferEngine {
                 .execute(pool)
                 .await
                 .map_err(|e| format!("续约更新失败: {}", e))?;
+                if let Some(p) = cache.team_rosters.get_mut(&team_id).and_then(|r| r.iter_mut().find(|p| p.id == player_id)) {
+                    p.salary = new_salary;
+                    p.contract_end_season = Some(season_id + new_contract_years);
+                }
 
                 // 续约成功后清理R2可能生成的挂牌记录
                 sqlx::query("UPDATE player_listings SET status = 'CANCELLED' WHERE player_id = ? AND window_id = ? AND status = 'ACTIVE'")
@@ -437,7 +460,7 @@ impl TransferEngine {
                 events.push(event);
             }
 
-            if !renewed {
+            if renewal_terms.is_none() {
                 // 续约失败，成为自由球员
                 cache.renewal_failed_pairs.insert((player_id, team_id));
                 sqlx::query(
@@ -467,7 +490,10 @@ impl TransferEngine {
                     Some(team_id), Some(&team_name),
                     None, None,
                     0, salary, 0,
-                    &format!("[合同到期] 续约谈判失败，{}成为自由球员 | {}岁 忠诚{}满意{}", game_id, age, loyalty, satisfaction),
+                    &match &cap_blocked {
+                        Some(reason) => format!("[合同到期] 薪资帽限制无法续约（{}），{}成为自由球员 | {}岁", reason, game_id, age),
+                        None => format!("[合同到期] 续约谈判失败，{}成为自由球员 | {}岁 忠诚{}满意{}", game_id, age, loyalty, satisfaction),
+                    },
                 ).await?;
                 events.push(event);
             }
@@ -3,6 +3,7 @@ use rand::SeedableRng;
 use sqlx::{Pool, Row, Sqlite};
 use std::collections::HashMap;
 
+use crate::engines::ladder::AmateurKind;
 use crate::engines::market_value::MarketValueEngine;
 use crate::models::team::FinancialStatus;
 use crate::models::transfer::*;
@@ -29,11 +30,13 @@ impl TransferEngine {
 
         // 获取所有自由球员（不在任何队伍中的选手，需从数据库查询，因为缓存只存有队伍的选手）
         let free_agents: Vec<sqlx::sqlite::SqliteRow> = sqlx::query(
-            r#"SELECT id, game_id, ability, salary, age, position, loyalty, potential, tag,
-                      home_region_id, region_loyalty, stability, calculated_market_value
-               FROM players
-               WHERE save_id = ? AND status = 'Active' AND team_id IS NULL
-               ORDER BY ability DESC"#
+            r#"SELECT p.id, p.game_id, p.ability, p.salary, p.age, p.position, p.loyalty, p.potential, p.tag,
+                      p.home_region_id, p.region_loyalty, p.stability, p.calculated_market_value,
+                      la.kind as amateur_kind
+               FROM players p
+               LEFT JOIN ladder_amateur la ON la.save_id = p.save_id AND la.player_id = p.id AND la.outcome = 'FreeAgent'
+               WHERE p.save_id = ? AND p.status = 'Active' AND p.team_id IS NULL
+               ORDER BY p.ability DESC"#
         )
         .bind(save_id)
         .fetch_all(pool)
@@ -70,6 +73,14 @@ impl TransferEngine {
             let tag: String = free_agent.try_get("tag").unwrap_or_else(|_| "NORMAL".to_string());
             let stability: i64 = free_agent.try_get("stability").unwrap_or(60);
             let calculated_market_value: i64 = free_agent.try_get("calculated_market_value").unwrap_or(0);
+            // 天梯发掘的路人/主播
+            let amateur_note = free_agent
+                .try_get::<Option<String>, _>("amateur_kind")
+                .ok()
+                .flatten()
+                .and_then(|k| AmateurKind::parse(&k))
+                .map(|k| format!(" | 天梯发掘的{}", k.name()))
+                .unwrap_or_default();
 
             // 使用完整身价（含荣誉系数）计算期望薪资
             let market_value = if calculated_market_value > 0 {
@@ -184,6 +195,11 @@ impl TransferEngine {
                     let base = (expected_salary as f64 * salary_multiplier) as i64;
                     if is_bench_signing { (base as f64 * 0.85) as i64 } else { base }
                 };
+                let offered_salary = cache.clamp_salary(team_id, offered_salary);
+                // 薪资帽空间不足的球队无法报价
+                if cache.check_cap(team_id, offered_salary, 0).is_err() {
+                    continue;
+                }
                 let contract_years = {
                     let base: i64 = if age <= 22 { 3 } else if age <= 25 { 2 } else if age <= 28 { 2 } else { 1 };
                     let personality_adj: i64 = if weights.long_term_focus > 0.7 { 1 } else if weights.short_term_focus > 0.7 { -1 } else { 0 };
@@ -231,7 +247,7 @@ impl TransferEngine {
                 1.0
             };
             for offer in offers.iter_mut() {
-                offer.offered_salary = (offer.offered_salary as f64 * market_awareness) as i64;
+                offer.offered_salary = cache.clamp_salary(offer.team_id, (offer.offered_salary as f64 * market_awareness) as i64);
             }
 
             // 对所有 offers 计算 willingness，收集竞价数据
@@ -308,8 +324,9 @@ impl TransferEngine {
                 70..=79 => 38.0,
                 _ => 40.0,
             };
+            let cap_ok = |idx: usize| cache.check_cap(offers[idx].team_id, offers[idx].offered_salary, 0).is_ok();
             let winner_idx = bid_records.iter()
-                .find(|r| r.willingness >= willingness_threshold)
+                .find(|r| r.willingness >= willingness_threshold && cap_ok(r.offer_idx))
                 .map(|r| r.offer_idx);
 
             // 写入所有竞价记录
@@ -320,6 +337,8 @@ impl TransferEngine {
                     None
                 } else if record.willingness < willingness_threshold {
                     Some("willingness_too_low")
+                } else if !cap_ok(record.offer_idx) {
+                    Some("salary_cap")
                 } else {
                     Some("outbid")
                 };
@@ -427,9 +446,9 @@ impl TransferEngine {
                     None, None,
                     Some(to_team_id), Some(&to_team_name),
                     0, offer.offered_salary, offer.contract_years,
-                    &format!("{}以自由球员身份加入{}，年薪{}万，合同{}年 | {}岁{}位 潜力{}",
+                    &format!("{}以自由球员身份加入{}，年薪{}万，合同{}年 | {}岁{}位 潜力{}{}",
                              game_id, to_team_name, offer.offered_salary / 10000, offer.contract_years,
-                             age, position, potential),
+                             age, position, potential, amateur_note),
                 ).await?;
                 events.push(event);
             }
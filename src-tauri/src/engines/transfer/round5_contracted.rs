@@ -174,7 +174,11 @@ impl TransferEngine {
                     let random_factor = 0.92 + rng.gen::<f64>() * 0.16;
                     base_mult * random_factor
                 };
-                let expected_salary = (base_salary as f64 * salary_multiplier) as i64;
+                let expected_salary = cache.clamp_salary(team_id, (base_salary as f64 * salary_multiplier) as i64);
+                // 薪资帽空间不足的球队无法竞标
+                if cache.check_cap(team_id, expected_salary, 0).is_err() {
+                    continue;
+                }
                 let contract_years = {
                     let base: i64 = if age <= 22 { 3 } else if age <= 25 { 2 } else if age <= 28 { 2 } else { 1 };
                     let personality_adj: i64 = if weights.long_term_focus > 0.7 { 1 } else if weights.short_term_focus > 0.7 { -1 } else { 0 };
@@ -259,8 +263,9 @@ impl TransferEngine {
                 70..=79 => 38.0,
                 _ => 40.0,
             };
+            let cap_ok = |idx: usize| cache.check_cap(all_bids[idx].0, all_bids[idx].3, 0).is_ok();
             let winner_idx = bid_records.iter()
-                .find(|r| r.willingness >= willingness_threshold)
+                .find(|r| r.willingness >= willingness_threshold && cap_ok(r.idx))
                 .map(|r| r.idx);
 
             // 写入所有竞价记录
@@ -271,6 +276,8 @@ impl TransferEngine {
                     None
                 } else if record.willingness < willingness_threshold {
                     Some("willingness_too_low")
+                } else if !cap_ok(record.idx) {
+                    Some("salary_cap")
                 } else {
                     Some("outbid")
                 };
@@ -4,6 +4,8 @@ use sqlx::{Pool, Row, Sqlite};
 
 use crate::engines::financial::FinancialEngine;
 use crate::engines::market_value::MarketValueEngine;
+use crate::engines::salary_cap::{SalaryCapEngine, DEAD_CAP_RATIO};
+use crate::engines::sponsorship::{SponsorshipEngine, TeamSponsorContext};
 use crate::models::transfer::*;
 
 use super::cache::TransferCache;
@@ -28,7 +30,7 @@ impl TransferEngine {
         eprintln!("[R6] 开始执行");
 
         // 清理之前中断执行留下的 round6 残留数据，确保幂等
-        let r6_types = vec!["Salary", "Sponsorship", "OperatingCost", "FacilityMaintenance", "LuxuryTax", "Penalty"];
+        let r6_types = vec!["Salary", "Sponsorship", "SponsorBonus", "OperatingCost", "FacilityMaintenance", "LuxuryTax", "Penalty"];
         for tx_type in &r6_types {
             sqlx::query(
                 "DELETE FROM financial_transactions WHERE save_id = ? AND season_id = ? AND transaction_type = ?"
@@ -69,7 +71,6 @@ impl TransferEngine {
         struct TeamDetail {
             balance: i64,
             power_rating: f64,
-            win_rate: f64,
             brand_value: f64,
             training_facility: i64,
         }
@@ -155,7 +156,7 @@ impl TransferEngine {
         }
 
         let team_detail_rows = sqlx::query(
-            "SELECT id, balance, power_rating, win_rate, brand_value, training_facility FROM teams WHERE save_id = ?",
+            "SELECT id, balance, power_rating, brand_value, training_facility FROM teams WHERE save_id = ?",
         )
         .bind(save_id)
         .fetch_all(pool)
@@ -170,7 +171,6 @@ impl TransferEngine {
                 TeamDetail {
                     balance: row.get("balance"),
                     power_rating: row.get("power_rating"),
-                    win_rate: row.get("win_rate"),
                     brand_value: row.get("brand_value"),
                     training_facility: row.get("training_facility"),
                 },
@@ -267,6 +267,7 @@ impl TransferEngine {
         // 1.5 赞助收入发放 + 运营成本扣除 + 训练设施维护费
         // ============================================
         let financial_engine = FinancialEngine::new();
+        let season_results = SponsorshipEngine::load_season_results(pool, save_id, season_id as u32).await?;
 
         for team in &all_teams {
             let team_id: i64 = team.get("id");
@@ -277,51 +278,37 @@ impl TransferEngine {
             }
 
             if let Some(team_detail) = team_details.get(&team_id).copied() {
-                let balance = cache
-                    .team_balances
-                    .get(&team_id)
-                    .copied()
-                    .unwrap_or(team_detail.balance);
-
-                let temp_team = crate::models::Team {
-                    id: team_id as u64,
-                    region_id: 0,
-                    name: team_name.clone(),
-                    short_name: None,
-                    power_rating: team_detail.power_rating,
-                    total_matches: 0,
-                    wins: 0,
-                    win_rate: team_detail.win_rate,
-                    annual_points: 0,
-                    cross_year_points: 0,
-                    balance,
+                // 赞助合同结算：解约/到期/补签后支付本赛季赞助费与绩效奖金
+                let ctx = TeamSponsorContext {
+                    team_id,
+                    region_id: cache.team_region_ids.get(&team_id).copied().flatten().unwrap_or(0),
                     brand_value: team_detail.brand_value,
+                    power_rating: team_detail.power_rating,
+                    win_rate: season_results.win_rates.get(&team_id).copied(),
+                    titles: season_results.titles.get(&team_id).copied().unwrap_or(0),
+                    qualified_international: season_results.international_teams.contains(&team_id),
                 };
-                let sponsorship = financial_engine.calculate_sponsorship(&temp_team);
-                if sponsorship > 0 {
-                    if sqlx::query("UPDATE teams SET balance = balance + ? WHERE id = ? AND save_id = ?")
-                        .bind(sponsorship as i64)
+                let settlement = SponsorshipEngine::settle_team_season(pool, save_id, season_id as u32, &ctx).await?;
+                let sponsorship = settlement.base_income + settlement.bonus_income;
+                if sponsorship > 0
+                    && sqlx::query("UPDATE teams SET balance = balance + ? WHERE id = ? AND save_id = ?")
+                        .bind(sponsorship)
                         .bind(team_id)
                         .bind(save_id)
                         .execute(pool)
                         .await
                         .is_ok()
-                    {
-                        cache.update_balance(team_id, sponsorship as i64);
-                    }
-
-                    sqlx::query(
-                        "INSERT INTO financial_transactions (save_id, team_id, season_id, transaction_type, amount, description) VALUES (?, ?, ?, ?, ?, ?)",
-                    )
-                    .bind(save_id)
-                    .bind(team_id)
-                    .bind(season_id)
-                    .bind("Sponsorship")
-                    .bind(sponsorship as i64)
-                    .bind(format!("S{}赛季赞助收入", season_id))
-                    .execute(pool)
-                    .await
-                    .ok();
+                {
+                    cache.update_balance(team_id, sponsorship);
+                }
+                if !settlement.signed.is_empty() || !settlement.terminated.is_empty() {
+                    log::info!(
+                        "R6赞助: {}新签[{}]，解约[{}]，赞助收入{}万",
+                        team_name,
+                        settlement.signed.join("、"),
+                        settlement.terminated.join("、"),
+                        sponsorship / 10000
+                    );
                 }
 
                 let team_annual_salary = *team_annual_salaries.get(&team_id).unwrap_or(&0);
@@ -464,13 +451,33 @@ impl TransferEngine {
                 .map(|roster| roster.len() as i64)
                 .unwrap_or(0);
 
-            let over_count = roster_count - self.config.luxury_tax_threshold;
-            if over_count <= 0 {
+            // 阵容人数税：线性递增，每超出1人缴纳 luxury_tax_per_player
+            let over_count = (roster_count - self.config.luxury_tax_threshold).max(0);
+            let roster_tax = over_count * self.config.luxury_tax_per_player;
+
+            // 薪资帽税：计入薪资帽总额超出帽线的部分按比例缴税
+            let cap_rules = cache.get_cap_rules(team_id);
+            let payroll = cache.team_payroll(team_id);
+            let payroll_tax = SalaryCapEngine::luxury_tax(&cap_rules, payroll);
+
+            let tax_amount = roster_tax + payroll_tax;
+            if tax_amount <= 0 {
                 continue;
             }
 
-            // 线性递增：每超出1人缴纳 luxury_tax_per_player
-            let tax_amount = over_count * self.config.luxury_tax_per_player;
+            let mut tax_parts = Vec::new();
+            if roster_tax > 0 {
+                tax_parts.push(format!("阵容{}人，超出{}人，每人{}万", roster_count, over_count, self.config.luxury_tax_per_player / 10000));
+            }
+            if payroll_tax > 0 {
+                tax_parts.push(format!(
+                    "薪资{}万，超出{}帽线{}万",
+                    payroll / 10000,
+                    cap_rules.cap_type.name(),
+                    (payroll - cap_rules.salary_cap) / 10000
+                ));
+            }
+            let tax_detail = tax_parts.join("；");
 
             // 扣除奢侈税
             sqlx::query("UPDATE teams SET balance = balance - ? WHERE id = ? AND save_id = ?")
@@ -490,12 +497,12 @@ impl TransferEngine {
             .bind(team_id)
             .bind(season_id)
             .bind(-tax_amount)
-            .bind(format!("S{}奢侈税：阵容{}人，超出{}人，每人{}万", season_id, roster_count, over_count, self.config.luxury_tax_per_player / 10000))
+            .bind(format!("S{}奢侈税：{}", season_id, tax_detail))
             .execute(pool)
             .await
             .map_err(|e| format!("记录奢侈税交易失败: {}", e))?;
 
-            log::info!("R6奢侈税: {}阵容{}人，超出{}人，缴税{}万", team_name, roster_count, over_count, tax_amount / 10000);
+            log::info!("R6奢侈税: {}（{}），缴税{}万", team_name, tax_detail, tax_amount / 10000);
 
             let representative_id = cache
                 .team_rosters
@@ -511,7 +518,7 @@ impl TransferEngine {
                 Some(team_id), Some(&team_name),
                 None, None,
                 tax_amount, 0, 0,
-                &format!("{}缴纳奢侈税{}万（阵容{}人，超出{}人，每人{}万）", team_name, tax_amount / 10000, roster_count, over_count, self.config.luxury_tax_per_player / 10000),
+                &format!("{}缴纳奢侈税{}万（{}）", team_name, tax_amount / 10000, tax_detail),
             ).await?;
             events.push(event);
 
@@ -633,6 +640,11 @@ impl TransferEngine {
                     .ok();
                 cache.release_player(player_id, team_id);
 
+                // 剩余薪资按比例计入本赛季死钱
+                let dead_cap = (candidate.salary as f64 * DEAD_CAP_RATIO) as i64;
+                SalaryCapEngine::record_dead_cap(pool, save_id, team_id, season_id, player_id, dead_cap, "超额解约").await?;
+                cache.add_dead_cap(team_id, dead_cap);
+
                 let event = self.record_event(
                     pool, window_id, 6,
                     TransferEventType::PlayerRelease,
@@ -3,6 +3,7 @@ use rand::SeedableRng;
 use sqlx::{Pool, Row, Sqlite};
 
 use crate::engines::market_value::MarketValueEngine;
+use crate::engines::salary_cap::SalaryCapEngine;
 use crate::models::player::Position;
 use crate::models::transfer::*;
 
@@ -146,6 +147,10 @@ impl TransferEngine {
                     } else {
                         (base_salary as f64 * 1.05) as i64
                     };
+                    // 缺位紧急签约：超帽时仅允许底薪合同豁免，超出上限线的部分按奢侈税率计入球队
+                    let cap_rules = cache.get_cap_rules(team_id);
+                    let (salary, cap_overage) =
+                        SalaryCapEngine::emergency_signing(&cap_rules, cache.team_payroll(team_id), salary);
                     let contract_years: i64 = if age <= 25 && rng.gen::<f64>() < 0.4 { 2 } else { 1 };
 
                     let emergency_loyalty: i64 = if team_rank <= 4 { 50 } else if team_rank <= 8 { 45 } else { 35 };
@@ -169,9 +174,10 @@ impl TransferEngine {
                     let current_balance = cache.team_balances.get(&team_id).copied().unwrap_or(0);
                     let actual_bonus = signing_bonus.min(current_balance.max(0));
                     if actual_bonus > 0 {
-                        sqlx::query("UPDATE teams SET balance = balance - ? WHERE id = ?")
+                        sqlx::query("UPDATE teams SET balance = balance - ? WHERE id = ? AND save_id = ?")
                             .bind(actual_bonus)
                             .bind(team_id)
+                            .bind(save_id)
                             .execute(pool)
                             .await
                             .map_err(|e| format!("紧急签约扣款失败: {}", e))?;
@@ -186,6 +192,27 @@ impl TransferEngine {
                         ).await?;
                     }
 
+                    if cap_overage > 0 {
+                        let overage_tax = (cap_overage as f64 * cap_rules.luxury_tax_rate) as i64;
+                        sqlx::query("UPDATE teams SET balance = balance - ? WHERE id = ? AND save_id = ?")
+                            .bind(overage_tax)
+                            .bind(team_id)
+                            .bind(save_id)
+                            .execute(pool)
+                            .await
+                            .map_err(|e| format!("紧急签约超帽扣款失败: {}", e))?;
+                        cache.update_balance(team_id, -overage_tax);
+
+                        Self::record_financial_transaction(
+                            pool, save_id, season_id, team_id,
+                            "LuxuryTax",
+                            -overage_tax,
+                            &format!("S{}紧急签约{}超出{}上限{}万", season_id, game_id, cap_rules.cap_type.name(), cap_overage / 10000),
+                            player_id,
+                        ).await?;
+                        log::info!("R7: {}缺少{}位置，底薪紧急签约{}超帽{}万，计税{}万", team_name, pos_str, game_id, cap_overage / 10000, overage_tax / 10000);
+                    }
+
                     Self::insert_contract(pool, save_id, player_id, team_id, "EMERGENCY", salary * contract_years, contract_years, season_id, 0, actual_bonus).await?;
 
                     let new_player = CachedPlayer {
@@ -55,6 +55,9 @@ fn empty_cache() -> TransferCache {
         team_spring_ranks: HashMap::new(),
         team_summer_ranks: HashMap::new(),
         player_season_stats: HashMap::new(),
+        team_cap_rules: HashMap::new(),
+        team_dead_cap: HashMap::new(),
+        cap_season_id: 1,
     }
 }
 
@@ -32,7 +32,7 @@ use commands::{
     get_international_champions, get_champion_detail, get_player_honor_rankings,
     get_team_honor_rankings, get_player_honor_detail, get_team_honor_detail,
     cleanup_duplicate_honors, regenerate_tournament_honors, regenerate_all_honors,
-    get_hall_of_fame,
+    get_hall_of_fame, get_team_hall_of_fame, get_retired_jerseys, get_records_book, get_record_history,
     // 选秀命令
     generate_draft_pool, run_draft_lottery, get_draft_order, get_available_draft_players,
     make_draft_pick, ai_auto_draft, get_draft_region_status,
@@ -71,7 +71,7 @@ use commands::{
     record_player_performance, batch_record_player_performance, record_championship,
     get_season_impact_ranking, get_position_ranking, get_player_stats,
     get_team_player_stats, clear_season_stats, get_player_impact_history,
-    get_tournament_mvp_ranking, recalculate_yearly_scores, get_annual_awards_data,
+    get_tournament_mvp_ranking, recalculate_yearly_scores, get_annual_awards_data, get_award_votes,
     get_player_market_value_changes, get_player_season_history, get_player_contract_history,
     get_player_tournament_history, get_player_yearly_top_history,
     get_player_growth_logs,
@@ -108,13 +108,38 @@ use commands::{
     // 性能监控命令
     get_perf_records, get_perf_summary, toggle_perf_monitoring, clear_perf_records,
     // 版本系统命令
-    get_current_meta, get_meta_history, get_all_meta_types, get_meta_detail,
+    get_current_meta, get_meta_history, get_all_meta_types, get_meta_detail, get_region_meta_styles,
     // 英雄/BP系统命令
     get_champion_list, get_champion_stats, get_draft_result, get_comp_stats, get_comp_matchups,
     get_meta_comp_effects, get_player_champion_mastery, get_player_champion_usage, get_team_comp_usage,
+    get_champion_patch_notes, get_draft_scouting_report,
     // 天梯赛系统命令
     initialize_ladder_tournament, simulate_ladder_round, get_ladder_rankings, get_ladder_matches,
     complete_ladder_tournament, get_ladder_tournaments, get_ladder_match_detail, get_player_ladder_rating_history,
+    set_ladder_rating_system, get_ladder_profile, get_ladder_leaderboard,
+    // 球探系统命令
+    assign_scout, recall_scout, get_scouting_assignments, get_scouting_report,
+    set_managed_team, get_managed_team,
+    // 青训管线命令
+    get_talent_classes,
+    // 角色转型命令
+    start_position_retraining, get_position_retrainings, assign_veteran_role,
+    // 薪资帽命令
+    get_team_cap_sheet,
+    // 赞助合同命令
+    get_sponsor_contracts, get_sponsor_offers, sign_sponsor_contract,
+    // 局内时间线命令
+    get_match_timelines, get_game_timeline_enabled, set_game_timeline_enabled,
+    // 阵容强势期规则命令
+    get_comp_phase_ruleset, update_comp_phase_ruleset, reset_comp_phase_ruleset,
+    // 系列赛BP规则命令
+    get_draft_rule_options, get_tournament_draft_rules, set_tournament_draft_rule, delete_tournament_draft_rule,
+    // 英雄训练命令
+    get_champion_training_plan, set_champion_training_plan, clear_champion_training_plan,
+    // 局间战术命令
+    get_series_tactics, set_series_tactics, clear_series_tactics,
+    // 历史交锋命令
+    get_team_head_to_head, get_team_rivalries, get_player_head_to_head, get_player_lane_opponents,
     // 应用状态
     AppState,
 };
@@ -202,6 +227,10 @@ pub fn run() {
             regenerate_tournament_honors,
             regenerate_all_honors,
             get_hall_of_fame,
+            get_team_hall_of_fame,
+            get_retired_jerseys,
+            get_records_book,
+            get_record_history,
             // 选秀命令
             generate_draft_pool,
             run_draft_lottery,
@@ -327,6 +356,7 @@ pub fn run() {
             get_super_qualified_teams,
             // 年度颁奖命令
             get_annual_awards_data,
+            get_award_votes,
             // 比赛详情持久化命令
             save_match_details,
             get_match_details,
@@ -386,6 +416,7 @@ pub fn run() {
             // 版本系统命令
             get_current_meta,
             get_meta_history,
+            get_region_meta_styles,
             get_all_meta_types,
             get_meta_detail,
             // 英雄/BP系统命令
@@ -398,6 +429,8 @@ pub fn run() {
             get_player_champion_mastery,
             get_player_champion_usage,
             get_team_comp_usage,
+            get_champion_patch_notes,
+            get_draft_scouting_report,
             // 天梯赛系统命令
             initialize_ladder_tournament,
             simulate_ladder_round,
@@ -407,6 +440,54 @@ pub fn run() {
             get_ladder_tournaments,
             get_ladder_match_detail,
             get_player_ladder_rating_history,
+            set_ladder_rating_system,
+            get_ladder_profile,
+            get_ladder_leaderboard,
+            // 球探系统命令
+            assign_scout,
+            recall_scout,
+            get_scouting_assignments,
+            get_scouting_report,
+            set_managed_team,
+            get_managed_team,
+            // 青训管线命令
+            get_talent_classes,
+            // 角色转型命令
+            start_position_retraining,
+            get_position_retrainings,
+            assign_veteran_role,
+            // 薪资帽命令
+            get_team_cap_sheet,
+            // 赞助合同命令
+            get_sponsor_contracts,
+            get_sponsor_offers,
+            sign_sponsor_contract,
+            // 局内时间线命令
+            get_match_timelines,
+            get_game_timeline_enabled,
+            set_game_timeline_enabled,
+            // 阵容强势期规则命令
+            get_comp_phase_ruleset,
+            update_comp_phase_ruleset,
+            reset_comp_phase_ruleset,
+            // 系列赛BP规则命令
+            get_draft_rule_options,
+            get_tournament_draft_rules,
+            set_tournament_draft_rule,
+            delete_tournament_draft_rule,
+            // 英雄训练命令
+            get_champion_training_plan,
+            set_champion_training_plan,
+            clear_champion_training_plan,
+            // 局间战术命令
+            get_series_tactics,
+            set_series_tactics,
+            clear_series_tactics,
+            // 历史交锋命令
+            get_team_head_to_head,
+            get_team_rivalries,
+            get_player_head_to_head,
+            get_player_lane_opponents,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
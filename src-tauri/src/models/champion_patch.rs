@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// 版本补丁中单个英雄的调整
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChampionPatchChange {
+    pub champion_id: u8,
+    pub champion_name: String,
+    /// 强度变化（正数加强、负数削弱）
+    pub delta: f64,
+    /// 调整后的英雄强度
+    pub new_strength: f64,
+    pub reason: String,
+}
+
+impl ChampionPatchChange {
+    pub fn is_buff(&self) -> bool {
+        self.delta > 0.0
+    }
+}
+
+/// 一次版本补丁（在赛季阶段结束时生效）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChampionPatch {
+    pub season_id: u64,
+    /// 赛季内第几个补丁（从 1 开始）
+    pub patch_number: u32,
+    /// 触发补丁的赛季阶段
+    pub phase: String,
+    /// 展示用版本号，如 "S2 第3版本"
+    pub label: String,
+    pub changes: Vec<ChampionPatchChange>,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// 单个英雄的训练时间分配
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrainingAllocation {
+    pub champion_id: u8,
+    /// 训练时长（小时）
+    pub hours: u32,
+}
+
+/// 选手在某个赛季阶段结束后的英雄训练记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChampionTrainingEntry {
+    pub team_id: u64,
+    pub player_id: u64,
+    pub champion_id: u8,
+    pub champion_name: String,
+    pub hours: u32,
+    /// 是否由 AI 自动安排
+    pub is_auto: bool,
+    /// 是否已在阶段结束时结算
+    pub applied: bool,
+    pub progress_gain: f64,
+    /// 结算后的熟练度等级
+    pub tier_after: Option<String>,
+}
+
+/// 一个阶段的训练计划
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingPlanSummary {
+    pub season_id: u64,
+    pub phase: String,
+    /// 每名选手每阶段可用的训练时长
+    pub hours_per_player: u32,
+    pub entries: Vec<ChampionTrainingEntry>,
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+/// 系列赛 BP 规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DraftRule {
+    /// 标准 BP：每局独立
+    #[default]
+    Standard,
+    /// 全局 BP：系列赛中任一方用过的英雄，双方后续都不能再选
+    Fearless,
+    /// 软全局 BP：同一队伍不能重复使用自己用过的英雄
+    SoftFearless,
+    /// 全局禁用池：赛事阶段统一禁用一批英雄
+    GlobalBanPool,
+}
+
+impl DraftRule {
+    pub fn all() -> &'static [DraftRule] {
+        &[
+            DraftRule::Standard,
+            DraftRule::Fearless,
+            DraftRule::SoftFearless,
+            DraftRule::GlobalBanPool,
+        ]
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DraftRule::Standard => "Standard",
+            DraftRule::Fearless => "Fearless",
+            DraftRule::SoftFearless => "SoftFearless",
+            DraftRule::GlobalBanPool => "GlobalBanPool",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DraftRule::Standard => "标准BP",
+            DraftRule::Fearless => "全局BP",
+            DraftRule::SoftFearless => "软全局BP",
+            DraftRule::GlobalBanPool => "全局禁用池",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<DraftRule> {
+        DraftRule::all().iter().copied().find(|r| r.as_str() == id)
+    }
+
+    /// 是否限制系列赛内重复使用英雄（英雄池深度更重要）
+    pub fn restricts_reuse(&self) -> bool {
+        matches!(self, DraftRule::Fearless | DraftRule::SoftFearless)
+    }
+}
+
+/// 赛事（阶段）配置的 BP 规则
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TournamentDraftRule {
+    pub tournament_id: u64,
+    /// 赛事阶段；空字符串表示该赛事全部阶段的默认规则
+    pub stage: String,
+    pub rule: DraftRule,
+    /// 全局禁用池（仅 GlobalBanPool 生效）
+    pub global_bans: Vec<u8>,
+}
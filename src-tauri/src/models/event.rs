@@ -18,6 +18,10 @@ pub enum EventType {
     PlayerAging,
     /// 赛季结算
     SeasonSettlement,
+    /// 天梯路人被发掘
+    AmateurDiscovery,
+    /// 纪录被打破
+    RecordBroken,
 }
 
 impl EventType {
@@ -30,6 +34,8 @@ impl EventType {
             EventType::ContractExpire => "合同到期",
             EventType::PlayerAging => "年龄增长",
             EventType::SeasonSettlement => "赛季结算",
+            EventType::AmateurDiscovery => "路人发掘",
+            EventType::RecordBroken => "纪录打破",
         }
     }
 }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::models::FinancialStatus;
+use crate::models::{FinancialStatus, RegionCode};
 
 /// 财务交易类型
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -126,6 +126,116 @@ impl Default for FinancialConfig {
     }
 }
 
+impl FinancialConfig {
+    /// 按赛区生成薪资帽规则：帽线随赛区资本规模浮动，LPL/LCS 为软帽，LCK/LEC 为硬帽
+    pub fn cap_rules(&self, region: RegionCode) -> CapRules {
+        let salary_cap = (self.salary_cap as f64 * region.market_value_factor()) as i64;
+        let cap_type = match region {
+            RegionCode::LCK | RegionCode::LEC => CapType::Hard,
+            _ => CapType::Soft,
+        };
+        let apron = match cap_type {
+            CapType::Hard => salary_cap,
+            CapType::Soft => (salary_cap as f64 * SOFT_CAP_APRON_RATIO) as i64,
+        };
+        CapRules {
+            cap_type,
+            salary_cap,
+            apron,
+            individual_salary_cap: self.individual_salary_cap as i64,
+            luxury_tax_rate: self.luxury_tax_rate,
+        }
+    }
+}
+
+/// 软帽球队可超帽签约的上限（帽线倍数）
+pub const SOFT_CAP_APRON_RATIO: f64 = 1.3;
+
+/// 薪资帽类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CapType {
+    /// 硬帽：签约后总薪资不得超过帽线
+    Hard,
+    /// 软帽：可超帽签约至上限线，超帽部分缴纳奢侈税
+    Soft,
+}
+
+impl CapType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CapType::Hard => "硬帽",
+            CapType::Soft => "软帽",
+        }
+    }
+}
+
+/// 赛区薪资帽规则（元/赛季）
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CapRules {
+    pub cap_type: CapType,
+    /// 薪资帽线
+    pub salary_cap: i64,
+    /// 签约上限线（硬帽等于帽线）
+    pub apron: i64,
+    /// 单人薪资上限
+    pub individual_salary_cap: i64,
+    /// 超帽部分的奢侈税比例
+    pub luxury_tax_rate: f64,
+}
+
+impl Default for CapRules {
+    fn default() -> Self {
+        FinancialConfig::default().cap_rules(RegionCode::Other)
+    }
+}
+
+/// 薪资表条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapSheetEntry {
+    pub player_id: u64,
+    pub player_name: String,
+    pub position: String,
+    pub salary: i64,
+    pub contract_end_season: Option<u32>,
+    /// 合同本赛季到期，按续约预留额计入
+    pub is_expiring: bool,
+    /// 实际计入薪资帽的金额
+    pub cap_hit: i64,
+}
+
+/// 死钱条目（解约后仍计入薪资帽的部分）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadCapEntry {
+    pub player_id: u64,
+    pub player_name: String,
+    pub amount: i64,
+    pub reason: String,
+}
+
+/// 球队薪资表
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamCapSheet {
+    pub team_id: u64,
+    pub team_name: String,
+    pub season_id: u64,
+    pub region_code: String,
+    pub rules: CapRules,
+    pub entries: Vec<CapSheetEntry>,
+    pub dead_cap: Vec<DeadCapEntry>,
+    /// 在约合同薪资合计
+    pub committed_salary: i64,
+    /// 到期合同预留额合计
+    pub cap_holds: i64,
+    /// 死钱合计
+    pub dead_cap_total: i64,
+    /// 计入薪资帽总额
+    pub payroll: i64,
+    /// 剩余薪资空间（可为负）
+    pub cap_space: i64,
+    /// 按当前薪资预估的奢侈税
+    pub projected_luxury_tax: i64,
+}
+
 /// 排名奖金配置（单位：元）
 pub fn ranking_bonus_config() -> Vec<(u32, u64)> {
     vec![
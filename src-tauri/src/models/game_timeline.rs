@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+/// 时间线事件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimelineEventKind {
+    FirstBlood,
+    SoloKill,
+    FirstTower,
+    Tower,
+    Dragon,
+    DragonSoul,
+    Herald,
+    Baron,
+    Teamfight,
+    Inhibitor,
+    Nexus,
+}
+
+impl TimelineEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimelineEventKind::FirstBlood => "FirstBlood",
+            TimelineEventKind::SoloKill => "SoloKill",
+            TimelineEventKind::FirstTower => "FirstTower",
+            TimelineEventKind::Tower => "Tower",
+            TimelineEventKind::Dragon => "Dragon",
+            TimelineEventKind::DragonSoul => "DragonSoul",
+            TimelineEventKind::Herald => "Herald",
+            TimelineEventKind::Baron => "Baron",
+            TimelineEventKind::Teamfight => "Teamfight",
+            TimelineEventKind::Inhibitor => "Inhibitor",
+            TimelineEventKind::Nexus => "Nexus",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimelineEventKind::FirstBlood => "一血",
+            TimelineEventKind::SoloKill => "单杀",
+            TimelineEventKind::FirstTower => "一塔",
+            TimelineEventKind::Tower => "推塔",
+            TimelineEventKind::Dragon => "小龙",
+            TimelineEventKind::DragonSoul => "龙魂",
+            TimelineEventKind::Herald => "峡谷先锋",
+            TimelineEventKind::Baron => "大龙",
+            TimelineEventKind::Teamfight => "团战",
+            TimelineEventKind::Inhibitor => "水晶",
+            TimelineEventKind::Nexus => "基地",
+        }
+    }
+}
+
+/// 对线期单路结果（经济差以主队视角计）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaneOutcome {
+    pub position: String,
+    pub home_player_id: u64,
+    pub away_player_id: u64,
+    /// 对线优势方，None 表示均势
+    pub winner_team_id: Option<u64>,
+    pub gold_diff: i64,
+}
+
+/// 单次击杀记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineKill {
+    pub minute: u32,
+    pub team_id: u64,
+    pub killer_id: u64,
+    pub victim_id: u64,
+    pub assist_ids: Vec<u64>,
+}
+
+/// 时间线事件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEvent {
+    pub minute: u32,
+    pub kind: TimelineEventKind,
+    /// 获利方队伍
+    pub team_id: u64,
+    pub description: String,
+    /// 事件带来的经济变化（获利方视角）
+    pub gold_swing: i64,
+}
+
+/// 经济曲线采样点（每分钟一个）
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct GoldPoint {
+    pub minute: u32,
+    pub home_gold: i64,
+    pub away_gold: i64,
+    /// 主队经济领先值
+    pub gold_diff: i64,
+}
+
+/// 由时间线推导出的选手单局数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelinePlayerLine {
+    pub player_id: u64,
+    pub team_id: u64,
+    pub kills: u32,
+    pub deaths: u32,
+    pub assists: u32,
+    pub cs: u32,
+    pub gold: u64,
+}
+
+/// 单局比赛时间线
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTimeline {
+    pub home_team_id: u64,
+    pub away_team_id: u64,
+    pub winner_id: u64,
+    pub duration_minutes: u32,
+    pub home_comp: Option<String>,
+    pub away_comp: Option<String>,
+    pub lanes: Vec<LaneOutcome>,
+    pub events: Vec<TimelineEvent>,
+    pub kills: Vec<TimelineKill>,
+    pub gold_curve: Vec<GoldPoint>,
+    pub players: Vec<TimelinePlayerLine>,
+    pub home_dragons: u32,
+    pub away_dragons: u32,
+    pub home_towers: u32,
+    pub away_towers: u32,
+}
+
+impl GameTimeline {
+    /// 指定选手的时间线数据
+    pub fn player_line(&self, player_id: u64) -> Option<&TimelinePlayerLine> {
+        self.players.iter().find(|p| p.player_id == player_id)
+    }
+
+    /// 终局经济差（主队视角）
+    pub fn final_gold_diff(&self) -> i64 {
+        self.gold_curve.last().map(|g| g.gold_diff).unwrap_or(0)
+    }
+}
+
+/// 已保存的单局时间线
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedGameTimeline {
+    pub match_id: u64,
+    pub game_number: u32,
+    pub timeline: GameTimeline,
+}
@@ -53,6 +53,16 @@ pub enum HonorType {
     AnnualMostDominant,
     /// 年度最佳新秀
     AnnualRookie,
+    /// 年度进步最快选手
+    AnnualMostImproved,
+    /// 年度最佳复出选手
+    AnnualComeback,
+    /// 年度最佳第六人（替补选手）
+    AnnualSixthMan,
+    /// 年度最佳外援
+    AnnualBestImport,
+    /// 赛区年度MVP
+    AnnualRegionalMvp,
 }
 
 impl HonorType {
@@ -79,6 +89,11 @@ impl HonorType {
             HonorType::AnnualMostConsistent => "年度最稳定选手",
             HonorType::AnnualMostDominant => "年度最具统治力",
             HonorType::AnnualRookie => "年度最佳新秀",
+            HonorType::AnnualMostImproved => "年度进步最快选手",
+            HonorType::AnnualComeback => "年度最佳复出选手",
+            HonorType::AnnualSixthMan => "年度最佳第六人",
+            HonorType::AnnualBestImport => "年度最佳外援",
+            HonorType::AnnualRegionalMvp => "赛区年度MVP",
         }
     }
 
@@ -103,6 +118,7 @@ impl HonorType {
                 | HonorType::RegularSeasonMvp
                 | HonorType::PlayoffsFmvp
                 | HonorType::AnnualMvp
+                | HonorType::AnnualRegionalMvp
         )
     }
 
@@ -129,6 +145,11 @@ impl HonorType {
                 | HonorType::AnnualMostConsistent
                 | HonorType::AnnualMostDominant
                 | HonorType::AnnualRookie
+                | HonorType::AnnualMostImproved
+                | HonorType::AnnualComeback
+                | HonorType::AnnualSixthMan
+                | HonorType::AnnualBestImport
+                | HonorType::AnnualRegionalMvp
         )
     }
 }
@@ -10,6 +10,10 @@ pub struct MetaInfo {
     pub meta_name: String,
     pub description: String,
     pub weights: MetaWeightsInfo,
+    /// 赛季内第几次补丁（赛季初为 1）
+    pub patch_number: i64,
+    /// 本次版本调整的原因
+    pub reason: Option<String>,
 }
 
 /// Meta 权重信息
@@ -26,6 +30,9 @@ pub struct MetaWeightsInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MetaHistoryEntry {
     pub season_id: i64,
+    pub patch_number: i64,
+    /// 补丁生效的赛季阶段
+    pub phase: Option<String>,
     pub meta_type: String,
     pub meta_name: String,
     pub weight_top: f64,
@@ -33,6 +40,7 @@ pub struct MetaHistoryEntry {
     pub weight_mid: f64,
     pub weight_adc: f64,
     pub weight_sup: f64,
+    pub reason: Option<String>,
 }
 
 /// Meta 类型信息（所有 20 种）
@@ -19,6 +19,14 @@ pub mod llm_task_log;
 pub mod transfer;
 pub mod init_config;
 pub mod meta;
+pub mod scouting;
+pub mod talent_pipeline;
+pub mod role_transition;
+pub mod sponsorship;
+pub mod game_timeline;
+pub mod champion_patch;
+pub mod draft_rule;
+pub mod champion_training;
 
 pub use player::*;
 pub use team::*;
@@ -41,3 +49,11 @@ pub use llm_task_log::*;
 pub use transfer::*;
 pub use init_config::*;
 pub use meta::*;
+pub use scouting::*;
+pub use talent_pipeline::*;
+pub use role_transition::*;
+pub use sponsorship::*;
+pub use game_timeline::*;
+pub use champion_patch::*;
+pub use draft_rule::*;
+pub use champion_training::*;
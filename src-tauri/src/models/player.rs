@@ -50,6 +50,8 @@ pub enum PlayerStatus {
     Active,
     /// 退役
     Retired,
+    /// 天梯路人/主播（未进入职业体系，仅参加天梯赛）
+    Amateur,
 }
 
 /// 选手位置
@@ -0,0 +1,93 @@
+//! 老将角色转型数据模型
+
+use serde::{Deserialize, Serialize};
+
+/// 合同角色（players.contract_role）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContractRole {
+    Starter,
+    Sub,
+    Prospect,
+    /// 第六人：随时轮换登场的老将替补
+    SixthMan,
+    /// 选手兼教练：少量出场，带教全队年轻选手
+    PlayingCoach,
+}
+
+impl ContractRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContractRole::Starter => "Starter",
+            ContractRole::Sub => "Sub",
+            ContractRole::Prospect => "Prospect",
+            ContractRole::SixthMan => "SixthMan",
+            ContractRole::PlayingCoach => "PlayingCoach",
+        }
+    }
+
+    /// 未知值按首发处理（与历史数据默认值一致）
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "Sub" => ContractRole::Sub,
+            "Prospect" => ContractRole::Prospect,
+            "SixthMan" => ContractRole::SixthMan,
+            "PlayingCoach" => ContractRole::PlayingCoach,
+            _ => ContractRole::Starter,
+        }
+    }
+
+    /// 合同约定的期望出场率
+    pub fn expected_play_rate(&self) -> f64 {
+        match self {
+            ContractRole::Starter => 0.75,
+            ContractRole::Sub => 0.20,
+            ContractRole::Prospect => 0.05,
+            ContractRole::SixthMan => 0.35,
+            ContractRole::PlayingCoach => 0.10,
+        }
+    }
+
+    /// 老将特殊角色：换季自动排首发时保留
+    pub fn is_veteran_role(&self) -> bool {
+        matches!(self, ContractRole::SixthMan | ContractRole::PlayingCoach)
+    }
+}
+
+/// 转位置训练状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetrainingStatus {
+    InProgress,
+    Completed,
+}
+
+impl RetrainingStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetrainingStatus::InProgress => "InProgress",
+            RetrainingStatus::Completed => "Completed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "Completed" => RetrainingStatus::Completed,
+            _ => RetrainingStatus::InProgress,
+        }
+    }
+}
+
+/// 转位置训练记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionRetraining {
+    pub id: i64,
+    pub player_id: u64,
+    pub player_name: String,
+    pub team_id: Option<u64>,
+    pub from_position: String,
+    pub to_position: String,
+    pub start_season: i64,
+    /// 转位置时扣除的能力值
+    pub ability_penalty: u8,
+    pub status: RetrainingStatus,
+    pub completed_season: Option<i64>,
+}
@@ -0,0 +1,122 @@
+//! 球探系统数据模型
+
+use serde::{Deserialize, Serialize};
+
+/// 球探派遣目标
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoutTarget {
+    /// 考察某赛区的职业选手
+    Region,
+    /// 考察某赛区的选秀池新秀
+    DraftPool,
+}
+
+impl ScoutTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoutTarget::Region => "Region",
+            ScoutTarget::DraftPool => "DraftPool",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Region" => Some(ScoutTarget::Region),
+            "DraftPool" => Some(ScoutTarget::DraftPool),
+            _ => None,
+        }
+    }
+
+    /// 该目标对应的情报主体类型
+    pub fn subject(&self) -> ScoutSubject {
+        match self {
+            ScoutTarget::Region => ScoutSubject::Player,
+            ScoutTarget::DraftPool => ScoutSubject::Prospect,
+        }
+    }
+}
+
+/// 情报主体类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScoutSubject {
+    /// players 表中的职业选手 / 自由球员
+    Player,
+    /// draft_pool 表中的选秀新秀
+    Prospect,
+}
+
+impl ScoutSubject {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoutSubject::Player => "Player",
+            ScoutSubject::Prospect => "Prospect",
+        }
+    }
+}
+
+/// 球探派遣记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoutingAssignment {
+    pub id: i64,
+    pub team_id: u64,
+    pub target_type: ScoutTarget,
+    pub region_id: u64,
+    pub assigned_season: i64,
+    /// 已持续考察的阶段数
+    pub phases_active: i64,
+}
+
+/// 评分区间（闭区间）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RatingRange {
+    pub min: u8,
+    pub max: u8,
+}
+
+impl RatingRange {
+    pub fn exact(value: u8) -> Self {
+        Self { min: value, max: value }
+    }
+
+    /// 区间中点，作为对外展示的估计值
+    pub fn estimate(&self) -> u8 {
+        ((self.min as u16 + self.max as u16) / 2) as u8
+    }
+
+    pub fn contains(&self, value: u8) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    pub fn is_exact(&self) -> bool {
+        self.min == self.max
+    }
+}
+
+/// 由评分派生的数值区间（如身价）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueRange {
+    pub min: u64,
+    pub max: u64,
+}
+
+impl ValueRange {
+    /// 区间中点，作为对外展示的估计值
+    pub fn estimate(&self) -> u64 {
+        self.min / 2 + self.max / 2
+    }
+}
+
+/// 某队视角下的选手评分情报
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoutedRatings {
+    pub viewer_team_id: u64,
+    /// 了解程度 0.0 ~ 1.0
+    pub knowledge: f64,
+    pub ability_range: RatingRange,
+    pub potential_range: RatingRange,
+    /// 尚未被球探揭示的特性数量
+    pub hidden_trait_count: u32,
+    /// 按评分区间折算的身价区间（仅选手详情提供）
+    #[serde(default)]
+    pub market_value_range: Option<ValueRange>,
+}
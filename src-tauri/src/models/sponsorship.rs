@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+/// 赞助级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SponsorTier {
+    /// 冠名赞助：每队最多一个，独家
+    Title,
+    /// 主要赞助
+    Major,
+    /// 普通赞助
+    Minor,
+}
+
+impl SponsorTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SponsorTier::Title => "Title",
+            SponsorTier::Major => "Major",
+            SponsorTier::Minor => "Minor",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "Title" => SponsorTier::Title,
+            "Major" => SponsorTier::Major,
+            _ => SponsorTier::Minor,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SponsorTier::Title => "冠名赞助",
+            SponsorTier::Major => "主要赞助",
+            SponsorTier::Minor => "普通赞助",
+        }
+    }
+}
+
+/// 赞助合同状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SponsorContractStatus {
+    Active,
+    /// 合同期满
+    Expired,
+    /// 赞助商因战绩/品牌下滑提前解约
+    Terminated,
+}
+
+impl SponsorContractStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SponsorContractStatus::Active => "Active",
+            SponsorContractStatus::Expired => "Expired",
+            SponsorContractStatus::Terminated => "Terminated",
+        }
+    }
+
+    pub fn parse(s: &str) -> Self {
+        match s {
+            "Expired" => SponsorContractStatus::Expired,
+            "Terminated" => SponsorContractStatus::Terminated,
+            _ => SponsorContractStatus::Active,
+        }
+    }
+}
+
+/// 赞助报价（签约前）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SponsorOffer {
+    pub sponsor_code: String,
+    pub sponsor_name: String,
+    pub category: String,
+    pub tier: SponsorTier,
+    /// 合同赛季数
+    pub years: u32,
+    /// 每赛季基础赞助费
+    pub annual_base: i64,
+    /// 每夺得一个冠军的奖金
+    pub title_bonus: i64,
+    /// 晋级国际赛的奖金
+    pub international_bonus: i64,
+    /// 品牌价值低于该线时赞助商可提前解约
+    pub brand_floor: f64,
+    /// 赛季胜率低于该线时赞助商可提前解约
+    pub min_win_rate: f64,
+    /// 独家赞助：同一时间只赞助一支队伍
+    pub is_exclusive: bool,
+}
+
+/// 赞助合同
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SponsorContract {
+    pub id: u64,
+    pub team_id: u64,
+    pub sponsor_code: String,
+    pub sponsor_name: String,
+    pub category: String,
+    pub tier: SponsorTier,
+    pub start_season: u32,
+    pub end_season: u32,
+    pub annual_base: i64,
+    pub title_bonus: i64,
+    pub international_bonus: i64,
+    pub brand_floor: f64,
+    pub min_win_rate: f64,
+    pub is_exclusive: bool,
+    pub status: SponsorContractStatus,
+    pub terminated_season: Option<u32>,
+    pub termination_reason: Option<String>,
+}
+
+impl SponsorContract {
+    /// 合同是否覆盖指定赛季
+    pub fn covers(&self, season: u32) -> bool {
+        self.status == SponsorContractStatus::Active && self.start_season <= season && season <= self.end_season
+    }
+}
+
+/// 单支球队的赛季赞助结算结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SponsorSettlement {
+    pub team_id: u64,
+    /// 基础赞助费合计
+    pub base_income: i64,
+    /// 绩效奖金合计
+    pub bonus_income: i64,
+    /// 本次新签的赞助商
+    pub signed: Vec<String>,
+    /// 本次提前解约的赞助商
+    pub terminated: Vec<String>,
+}
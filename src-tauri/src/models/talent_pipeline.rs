@@ -0,0 +1,27 @@
+//! 青训管线数据模型
+
+use serde::{Deserialize, Serialize};
+
+/// 某赛季某赛区的新秀届次记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TalentClassRecord {
+    pub season_id: u64,
+    pub region_id: u64,
+    pub region_name: String,
+    /// 人才密度（1.0 为基准）
+    pub talent_density: f64,
+    /// 青训基建水平 0.0 ~ 1.0（由赛区财力决定）
+    pub infrastructure: f64,
+    /// 届次强弱带来的能力偏移
+    pub ability_shift: i32,
+    /// 是否为"黄金一代"
+    pub is_golden: bool,
+    pub prospects_generated: u32,
+    pub to_draft_pool: u32,
+    pub to_free_agency: u32,
+    /// 从其他赛区流入的新秀数
+    pub immigrants: u32,
+    /// 流向其他赛区的新秀数
+    pub emigrants: u32,
+    pub avg_potential: f64,
+}
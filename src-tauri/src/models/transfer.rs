@@ -70,6 +70,8 @@ pub enum TransferEventType {
     DraftPickAuction,
     FinancialAdjustment,
     PlayerRelease,
+    PositionChange,
+    VeteranRole,
 }
 
 impl TransferEventType {
@@ -88,6 +90,8 @@ impl TransferEventType {
             TransferEventType::DraftPickAuction => "DRAFT_PICK_AUCTION",
             TransferEventType::FinancialAdjustment => "FINANCIAL_ADJUSTMENT",
             TransferEventType::PlayerRelease => "PLAYER_RELEASE",
+            TransferEventType::PositionChange => "POSITION_CHANGE",
+            TransferEventType::VeteranRole => "VETERAN_ROLE",
         }
     }
 
@@ -106,6 +110,8 @@ impl TransferEventType {
             "DRAFT_PICK_AUCTION" => TransferEventType::DraftPickAuction,
             "FINANCIAL_ADJUSTMENT" => TransferEventType::FinancialAdjustment,
             "PLAYER_RELEASE" => TransferEventType::PlayerRelease,
+            "POSITION_CHANGE" => TransferEventType::PositionChange,
+            "VETERAN_ROLE" => TransferEventType::VeteranRole,
             _ => TransferEventType::SeasonSettlement,
         }
     }
@@ -1,21 +1,12 @@
+use std::collections::HashMap;
+
 use sqlx::{Pool, Row, Sqlite};
 
-use super::GameFlowService;
+use crate::db::HonorRepository;
+use crate::engines::{AwardCandidate, AwardRegistry, AwardVoteResult, AwardVotingEngine, SeasonLine};
+use crate::models::{ContractRole, Honor, HonorType};
 
-/// 年度选手信息结构
-#[allow(dead_code)]
-pub(crate) struct AnnualPlayerInfo {
-    pub(crate) player_id: u64,
-    pub(crate) player_name: String,
-    pub(crate) team_id: u64,
-    pub(crate) team_name: String,
-    pub(crate) position: String,
-    pub(crate) yearly_score: f64,
-    pub(crate) age: u8,
-    pub(crate) consistency_score: f64,
-    pub(crate) dominance_score: f64,
-    pub(crate) games_played: i32,
-}
+use super::{GameFlowService, HonorAwarded};
 
 impl GameFlowService {
     pub(crate) async fn recalculate_yearly_scores_with_big_stage(
@@ -98,255 +89,212 @@ impl GameFlowService {
         Ok(())
     }
 
-    /// 获取年度Top20选手
-    pub(crate) async fn get_annual_top20(
+    /// 读取本赛季参评选手（含前两个赛季的年度得分，用于进步奖与复出奖）
+    pub(crate) async fn load_award_candidates(
         &self,
         pool: &Pool<Sqlite>,
         save_id: &str,
         season_id: u64,
-    ) -> Result<Vec<AnnualPlayerInfo>, String> {
-        let rows = sqlx::query(
-            r#"
-            SELECT
-                pss.player_id,
-                pss.player_name,
-                pss.team_id,
-                COALESCE(t.name, '未知') as team_name,
-                pss.position,
-                pss.yearly_top_score,
-                COALESCE(p.age, 0) as age,
-                pss.consistency_score,
-                COALESCE(pss.dominance_score, 0.0) as dominance_score,
-                pss.games_played
-            FROM player_season_stats pss
-            LEFT JOIN teams t ON pss.team_id = t.id
-            LEFT JOIN players p ON pss.player_id = p.id
-            WHERE pss.save_id = ? AND pss.season_id = ? AND pss.games_played >= 10
-            ORDER BY pss.yearly_top_score DESC
-            LIMIT 20
-            "#
+    ) -> Result<Vec<AwardCandidate>, String> {
+        let sid = season_id as i64;
+
+        let history_rows = sqlx::query(
+            r#"SELECT player_id, season_id, yearly_top_score, games_played
+               FROM player_season_stats
+               WHERE save_id = ? AND season_id IN (?, ?)"#
         )
         .bind(save_id)
-        .bind(season_id as i64)
+        .bind(sid - 1)
+        .bind(sid - 2)
         .fetch_all(pool)
         .await
-        .map_err(|e| format!("Failed to get top20: {}", e))?;
-
-        Ok(rows.iter().map(|row| AnnualPlayerInfo {
-            player_id: row.get::<i64, _>("player_id") as u64,
-            player_name: row.get::<String, _>("player_name"),
-            team_id: row.get::<Option<i64>, _>("team_id").unwrap_or(0) as u64,
-            team_name: row.get::<String, _>("team_name"),
-            position: row.get::<String, _>("position"),
-            yearly_score: row.get::<f64, _>("yearly_top_score"),
-            age: row.get::<i64, _>("age") as u8,
-            consistency_score: row.get::<f64, _>("consistency_score"),
-            dominance_score: row.get::<f64, _>("dominance_score"),
-            games_played: row.get::<i32, _>("games_played"),
-        }).collect())
-    }
+        .map_err(|e| format!("查询往季统计失败: {}", e))?;
 
-    /// 获取年度最佳阵容三阵（每位置Top3）
-    pub(crate) async fn get_annual_all_pro_3teams(
-        &self,
-        pool: &Pool<Sqlite>,
-        save_id: &str,
-        season_id: u64,
-    ) -> Result<Vec<(u8, AnnualPlayerInfo)>, String> {
-        let positions = vec!["TOP", "JUG", "MID", "ADC", "SUP"];
-        let mut results = Vec::new();
-
-        for position in positions {
-            let rows = sqlx::query(
-                r#"
-                SELECT
-                    pss.player_id,
-                    pss.player_name,
-                    pss.team_id,
-                    COALESCE(t.name, '未知') as team_name,
-                    pss.position,
-                    pss.yearly_top_score,
-                    COALESCE(p.age, 0) as age,
-                    pss.consistency_score,
-                    COALESCE(pss.dominance_score, 0.0) as dominance_score,
-                    pss.games_played
-                FROM player_season_stats pss
-                LEFT JOIN teams t ON pss.team_id = t.id
-                LEFT JOIN players p ON pss.player_id = p.id
-                WHERE pss.save_id = ? AND pss.season_id = ? AND UPPER(pss.position) = UPPER(?) AND pss.games_played >= 10
-                ORDER BY pss.yearly_top_score DESC
-                LIMIT 3
-                "#
-            )
-            .bind(save_id)
-            .bind(season_id as i64)
-            .bind(position)
-            .fetch_all(pool)
-            .await
-            .map_err(|e| format!("Failed to get best {}: {}", position, e))?;
-
-            for (tier_idx, row) in rows.iter().enumerate() {
-                let tier = (tier_idx + 1) as u8;
-                results.push((tier, AnnualPlayerInfo {
-                    player_id: row.get::<i64, _>("player_id") as u64,
-                    player_name: row.get::<String, _>("player_name"),
-                    team_id: row.get::<Option<i64>, _>("team_id").unwrap_or(0) as u64,
-                    team_name: row.get::<String, _>("team_name"),
-                    position: row.get::<String, _>("position"),
-                    yearly_score: row.get::<f64, _>("yearly_top_score"),
-                    age: row.get::<i64, _>("age") as u8,
-                    consistency_score: row.get::<f64, _>("consistency_score"),
-                    dominance_score: row.get::<f64, _>("dominance_score"),
-                    games_played: row.get::<i32, _>("games_played"),
-                }));
-            }
+        let mut history: HashMap<(i64, i64), SeasonLine> = HashMap::new();
+        for row in &history_rows {
+            history.insert(
+                (row.get("player_id"), row.get("season_id")),
+                SeasonLine {
+                    yearly_score: row.get("yearly_top_score"),
+                    games_played: row.get("games_played"),
+                },
+            );
         }
 
-        Ok(results)
-    }
+        let sub_rows = sqlx::query(
+            r#"SELECT ml.player_id, COUNT(*) as sub_games
+               FROM match_lineups ml
+               JOIN matches m ON ml.match_id = m.id
+               JOIN tournaments tr ON m.tournament_id = tr.id
+               WHERE ml.save_id = ? AND tr.season_id = ? AND ml.is_substitution = 1
+               GROUP BY ml.player_id"#
+        )
+        .bind(save_id)
+        .bind(sid)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询替补登场记录失败: {}", e))?;
 
-    /// 获取年度最稳定选手（consistency_score最高，>=30场）
-    pub(crate) async fn get_annual_most_consistent(
-        &self,
-        pool: &Pool<Sqlite>,
-        save_id: &str,
-        season_id: u64,
-    ) -> Result<Option<AnnualPlayerInfo>, String> {
-        let row = sqlx::query(
+        let sub_appearances: HashMap<i64, i32> = sub_rows
+            .iter()
+            .map(|row| (row.get("player_id"), row.get::<i64, _>("sub_games") as i32))
+            .collect();
+
+        let rows = sqlx::query(
             r#"
             SELECT
                 pss.player_id,
                 pss.player_name,
-                pss.team_id,
+                COALESCE(pss.team_id, 0) as team_id,
                 COALESCE(t.name, '未知') as team_name,
                 pss.position,
                 pss.yearly_top_score,
-                COALESCE(p.age, 0) as age,
                 pss.consistency_score,
                 COALESCE(pss.dominance_score, 0.0) as dominance_score,
-                pss.games_played
+                pss.champion_bonus,
+                pss.games_played,
+                COALESCE(p.age, 0) as age,
+                COALESCE(p.contract_role, 'Starter') as contract_role,
+                p.home_region_id,
+                COALESCE(t.region_id, 0) as region_id,
+                COALESCE(r.name, '') as region_name,
+                COALESCE(t.win_rate, 0.0) as team_win_rate
             FROM player_season_stats pss
             LEFT JOIN teams t ON pss.team_id = t.id
+            LEFT JOIN regions r ON t.region_id = r.id
             LEFT JOIN players p ON pss.player_id = p.id
-            WHERE pss.save_id = ? AND pss.season_id = ? AND pss.games_played >= 30
-            ORDER BY pss.consistency_score DESC
-            LIMIT 1
+            WHERE pss.save_id = ? AND pss.season_id = ? AND pss.games_played > 0
             "#
         )
         .bind(save_id)
-        .bind(season_id as i64)
-        .fetch_optional(pool)
+        .bind(sid)
+        .fetch_all(pool)
         .await
-        .map_err(|e| format!("Failed to get most consistent: {}", e))?;
+        .map_err(|e| format!("查询年度参评选手失败: {}", e))?;
 
-        Ok(row.map(|r| AnnualPlayerInfo {
-            player_id: r.get::<i64, _>("player_id") as u64,
-            player_name: r.get::<String, _>("player_name"),
-            team_id: r.get::<Option<i64>, _>("team_id").unwrap_or(0) as u64,
-            team_name: r.get::<String, _>("team_name"),
-            position: r.get::<String, _>("position"),
-            yearly_score: r.get::<f64, _>("yearly_top_score"),
-            age: r.get::<i64, _>("age") as u8,
-            consistency_score: r.get::<f64, _>("consistency_score"),
-            dominance_score: r.get::<f64, _>("dominance_score"),
-            games_played: r.get::<i32, _>("games_played"),
-        }))
+        Ok(rows.iter().map(|row| {
+            let player_id: i64 = row.get("player_id");
+            AwardCandidate {
+                player_id: player_id as u64,
+                player_name: row.get("player_name"),
+                team_id: row.get::<i64, _>("team_id") as u64,
+                team_name: row.get("team_name"),
+                position: row.get("position"),
+                region_id: row.get::<i64, _>("region_id") as u64,
+                region_name: row.get("region_name"),
+                home_region_id: row.get::<Option<i64>, _>("home_region_id").map(|id| id as u64),
+                age: row.get::<i64, _>("age") as u8,
+                contract_role: ContractRole::parse(&row.get::<String, _>("contract_role")),
+                sub_appearances: sub_appearances.get(&player_id).copied().unwrap_or(0),
+                yearly_score: row.get("yearly_top_score"),
+                consistency_score: row.get("consistency_score"),
+                dominance_score: row.get("dominance_score"),
+                champion_bonus: row.get("champion_bonus"),
+                team_win_rate: row.get("team_win_rate"),
+                games_played: row.get("games_played"),
+                previous: history.get(&(player_id, sid - 1)).copied(),
+                two_seasons_ago: history.get(&(player_id, sid - 2)).copied(),
+            }
+        }).collect())
     }
 
-    /// 获取年度最具统治力选手（dominance_score最高，>=20场）
-    pub(crate) async fn get_annual_most_dominant(
+    /// 年度奖项投票：按注册表评选全部奖项，保存得票明细
+    ///
+    /// 本赛季已有投票记录时（阶段重跑）直接沿用已保存的结果，不重新投票
+    pub(crate) async fn run_annual_award_votes(
         &self,
         pool: &Pool<Sqlite>,
         save_id: &str,
         season_id: u64,
-    ) -> Result<Option<AnnualPlayerInfo>, String> {
-        let row = sqlx::query(
-            r#"
-            SELECT
-                pss.player_id,
-                pss.player_name,
-                pss.team_id,
-                COALESCE(t.name, '未知') as team_name,
-                pss.position,
-                pss.yearly_top_score,
-                COALESCE(p.age, 0) as age,
-                pss.consistency_score,
-                COALESCE(pss.dominance_score, 0.0) as dominance_score,
-                pss.games_played
-            FROM player_season_stats pss
-            LEFT JOIN teams t ON pss.team_id = t.id
-            LEFT JOIN players p ON pss.player_id = p.id
-            WHERE pss.save_id = ? AND pss.season_id = ? AND pss.games_played >= 20
-            ORDER BY COALESCE(pss.dominance_score, 0.0) DESC
-            LIMIT 1
-            "#
-        )
-        .bind(save_id)
-        .bind(season_id as i64)
-        .fetch_optional(pool)
-        .await
-        .map_err(|e| format!("Failed to get most dominant: {}", e))?;
+        registry: &AwardRegistry,
+    ) -> Result<Vec<AwardVoteResult>, String> {
+        let stored = AwardVotingEngine::load_results(pool, save_id, season_id as i64, None).await?;
+        if !stored.is_empty() {
+            log::debug!("S{} 年度奖项已评选，沿用已保存的 {} 组投票结果", season_id, stored.len());
+            return Ok(stored);
+        }
 
-        Ok(row.map(|r| AnnualPlayerInfo {
-            player_id: r.get::<i64, _>("player_id") as u64,
-            player_name: r.get::<String, _>("player_name"),
-            team_id: r.get::<Option<i64>, _>("team_id").unwrap_or(0) as u64,
-            team_name: r.get::<String, _>("team_name"),
-            position: r.get::<String, _>("position"),
-            yearly_score: r.get::<f64, _>("yearly_top_score"),
-            age: r.get::<i64, _>("age") as u8,
-            consistency_score: r.get::<f64, _>("consistency_score"),
-            dominance_score: r.get::<f64, _>("dominance_score"),
-            games_played: r.get::<i32, _>("games_played"),
-        }))
+        let candidates = self.load_award_candidates(pool, save_id, season_id).await?;
+        log::debug!("年度奖项参评选手 {} 人", candidates.len());
+
+        let results = {
+            let mut rng = rand::thread_rng();
+            AwardVotingEngine::run_ceremony(registry, &candidates, &mut rng)
+        };
+        AwardVotingEngine::save_results(pool, save_id, season_id as i64, &results).await?;
+        Ok(results)
     }
 
-    /// 获取年度最佳新秀（20岁及以下）
-    pub(crate) async fn get_annual_rookie(
+    /// 按投票结果颁发年度荣誉（先清除本赛季已颁发的年度荣誉，重跑时不会重复颁发）
+    pub(crate) async fn grant_annual_honors(
         &self,
         pool: &Pool<Sqlite>,
         save_id: &str,
         season_id: u64,
-    ) -> Result<Option<AnnualPlayerInfo>, String> {
-        let row = sqlx::query(
-            r#"
-            SELECT
-                pss.player_id,
-                pss.player_name,
-                pss.team_id,
-                COALESCE(t.name, '未知') as team_name,
-                pss.position,
-                pss.yearly_top_score,
-                p.age,
-                pss.consistency_score,
-                COALESCE(pss.dominance_score, 0.0) as dominance_score,
-                pss.games_played
-            FROM player_season_stats pss
-            JOIN players p ON pss.player_id = p.id
-            LEFT JOIN teams t ON pss.team_id = t.id
-            WHERE pss.save_id = ? AND pss.season_id = ? AND p.age <= 20 AND pss.games_played >= 10
-            ORDER BY pss.yearly_top_score DESC
-            LIMIT 1
-            "#
-        )
-        .bind(save_id)
-        .bind(season_id as i64)
-        .fetch_optional(pool)
-        .await
-        .map_err(|e| format!("Failed to get rookie of the year: {}", e))?;
+        registry: &AwardRegistry,
+        results: &[AwardVoteResult],
+    ) -> Vec<HonorAwarded> {
+        let mut honors_awarded = Vec::new();
 
-        Ok(row.map(|r| AnnualPlayerInfo {
-            player_id: r.get::<i64, _>("player_id") as u64,
-            player_name: r.get::<String, _>("player_name"),
-            team_id: r.get::<Option<i64>, _>("team_id").unwrap_or(0) as u64,
-            team_name: r.get::<String, _>("team_name"),
-            position: r.get::<String, _>("position"),
-            yearly_score: r.get::<f64, _>("yearly_top_score"),
-            age: r.get::<i64, _>("age") as u8,
-            consistency_score: r.get::<f64, _>("consistency_score"),
-            dominance_score: r.get::<f64, _>("dominance_score"),
-            games_played: r.get::<i32, _>("games_played"),
-        }))
-    }
+        if let Err(e) = sqlx::query("DELETE FROM honors WHERE save_id = ? AND season_id = ? AND tournament_type = 'ANNUAL'")
+            .bind(save_id)
+            .bind(season_id as i64)
+            .execute(pool)
+            .await
+        {
+            log::error!("清除S{}年度荣誉失败，跳过颁奖: {}", season_id, e);
+            return honors_awarded;
+        }
+
+        for result in results {
+            let Some(category) = registry.get(&result.award_id) else {
+                continue;
+            };
+            for winner in result.winners() {
+                log::debug!(
+                    "{}{}: {}（总分 {:.1}，媒体 {} / 职业选手 {} / 球迷 {}）",
+                    result.scope_label, result.award_name, winner.player_name, winner.total_score,
+                    winner.media_points, winner.pros_points, winner.fans_points
+                );
+                for honor_type in (category.grants)(winner.rank as usize) {
+                    let (tournament_name, label) = match honor_type {
+                        HonorType::AnnualTop20 => {
+                            let name = format!("年度Top{}", winner.rank);
+                            (name.clone(), name)
+                        }
+                        HonorType::AnnualRegionalMvp => (
+                            format!("{}年度颁奖典礼", result.scope_label),
+                            format!("{}年度MVP", result.scope_label),
+                        ),
+                        _ => ("年度颁奖典礼".to_string(), honor_type.name().to_string()),
+                    };
+                    let honor = Honor::new_player_honor(
+                        save_id,
+                        honor_type,
+                        season_id,
+                        None,
+                        &tournament_name,
+                        "ANNUAL",
+                        winner.team_id,
+                        &winner.team_name,
+                        winner.player_id,
+                        &winner.player_name,
+                        &winner.position,
+                        None,
+                    );
+                    if let Err(e) = HonorRepository::create(pool, save_id, &honor).await {
+                        log::error!("Failed to create {} honor for player {}: {}", label, winner.player_id, e);
+                    } else {
+                        honors_awarded.push(HonorAwarded {
+                            honor_type: label,
+                            recipient_name: winner.player_name.clone(),
+                            tournament_name: "年度颁奖典礼".to_string(),
+                        });
+                    }
+                }
+            }
+        }
 
+        honors_awarded
+    }
 }
@@ -0,0 +1,481 @@
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sqlx::{Pool, Row, Sqlite};
+
+use crate::db::{EventRepository, PlayerRepository};
+use crate::engines::ladder::amateur::{
+    AmateurStanding, GeneratedAmateur, AMATEUR_MAX_SEASONS, AMATEUR_POOL_SIZE,
+};
+use crate::engines::ladder::{AmateurDiscovery, AmateurKind, AmateurOutcome, LadderAmateurEngine};
+use crate::engines::market_value::MarketValueEngine;
+use crate::models::{EventType, GameEvent, Player, PlayerStatus, Position};
+use crate::services::InitService;
+
+use super::GameFlowService;
+
+impl GameFlowService {
+    /// 天梯赛开赛：补足路人/主播选手池并报名本届赛事，返回报名人数
+    ///
+    /// 在选手池中停留满 AMATEUR_MAX_SEASONS 个赛季仍未被发掘的路人先退出选手池
+    pub async fn enroll_ladder_amateurs(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        tournament_id: i64,
+    ) -> Result<u32, String> {
+        let (season, platform): (i64, String) = sqlx::query_as(
+            "SELECT season, event_type FROM ladder_tournament WHERE save_id = ? AND id = ?"
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询天梯赛失败: {}", e))?;
+
+        sqlx::query(
+            "UPDATE ladder_amateur SET status = 'retired' WHERE save_id = ? AND status = 'active' AND joined_season <= ?"
+        )
+        .bind(save_id)
+        .bind(season - AMATEUR_MAX_SEASONS)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("更新路人选手池失败: {}", e))?;
+
+        let active: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM ladder_amateur WHERE save_id = ? AND status = 'active'"
+        )
+        .bind(save_id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询路人选手池失败: {}", e))?;
+
+        let missing = AMATEUR_POOL_SIZE.saturating_sub(active as usize);
+        if missing > 0 {
+            let existing_ids: HashSet<String> = sqlx::query_scalar(
+                "SELECT game_id FROM draft_pool WHERE save_id = ? UNION SELECT game_id FROM players WHERE save_id = ?",
+            )
+            .bind(save_id)
+            .bind(save_id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("查询已有选手ID失败: {}", e))?
+            .into_iter()
+            .collect();
+            let home_region_id: Option<i64> = sqlx::query_scalar(
+                "SELECT id FROM regions WHERE save_id = ? AND name = 'LPL'"
+            )
+            .bind(save_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("查询赛区失败: {}", e))?;
+
+            let mut rng = StdRng::from_entropy();
+            for amateur in LadderAmateurEngine::generate(rng.gen(), missing, &existing_ids) {
+                Self::create_ladder_amateur(pool, save_id, season, &platform, home_region_id, &amateur, &mut rng).await?;
+            }
+            log::debug!("天梯路人选手池补充 {} 人", missing);
+        }
+
+        let enrolled = sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO ladder_rating (save_id, ladder_tournament_id, player_id, player_name, game_id, position, team_name, rating)
+            SELECT la.save_id, ?, p.id, COALESCE(p.real_name, p.game_id), p.game_id, p.position, NULL, 1200
+            FROM ladder_amateur la
+            JOIN players p ON p.id = la.player_id
+            WHERE la.save_id = ? AND la.status = 'active'
+            "#
+        )
+        .bind(tournament_id)
+        .bind(save_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("路人报名天梯赛失败: {}", e))?;
+
+        Ok(enrolled.rows_affected() as u32)
+    }
+
+    /// 天梯赛结束：发掘排名靠前的路人，进入选秀池或自由球员市场并记录事件（每届只评选一次）
+    pub async fn review_ladder_amateurs(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        tournament_id: i64,
+    ) -> Result<Vec<AmateurDiscovery>, String> {
+        let tournament: Option<(i64, String, String, i64)> = sqlx::query_as(
+            "SELECT season, event_type, event_name, amateurs_reviewed FROM ladder_tournament WHERE save_id = ? AND id = ?"
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询天梯赛失败: {}", e))?;
+
+        let Some((season, event_type, event_name, reviewed)) = tournament else {
+            return Ok(Vec::new());
+        };
+        if reviewed != 0 {
+            return Ok(Vec::new());
+        }
+
+        let rows = sqlx::query(
+            r#"
+            SELECT lr.player_id, lr.rating, lr.games_played, la.status as amateur_status,
+                   p.age, p.potential
+            FROM ladder_rating lr
+            LEFT JOIN ladder_amateur la ON la.save_id = lr.save_id AND la.player_id = lr.player_id
+            JOIN players p ON p.id = lr.player_id
+            WHERE lr.save_id = ? AND lr.ladder_tournament_id = ?
+            ORDER BY lr.rating DESC, lr.wins DESC, lr.mvp_count DESC
+            "#
+        )
+        .bind(save_id)
+        .bind(tournament_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询天梯排名失败: {}", e))?;
+
+        let field_size = rows.len();
+        let standings: Vec<AmateurStanding> = rows
+            .iter()
+            .enumerate()
+            .filter(|(_, r)| r.get::<Option<String>, _>("amateur_status").as_deref() == Some("active"))
+            .map(|(idx, r)| AmateurStanding {
+                player_id: r.get("player_id"),
+                rank: idx + 1,
+                rating: r.get("rating"),
+                games_played: r.get("games_played"),
+                age: r.get::<i64, _>("age") as u8,
+                potential: r.get::<i64, _>("potential") as u8,
+            })
+            .collect();
+
+        let mut discoveries = Vec::new();
+        for standing in LadderAmateurEngine::select_standouts(&standings, field_size) {
+            let discovery = Self::discover_ladder_amateur(
+                pool, save_id, season, tournament_id, &event_type, &event_name, &standing, field_size,
+            )
+            .await?;
+            log::info!(
+                "{} {} {} 第{}名被发掘，{}",
+                event_name,
+                discovery.kind.name(),
+                discovery.game_id,
+                discovery.rank,
+                discovery.outcome.name()
+            );
+            discoveries.push(discovery);
+        }
+
+        sqlx::query("UPDATE ladder_tournament SET amateurs_reviewed = 1 WHERE id = ?")
+            .bind(tournament_id)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("更新天梯赛状态失败: {}", e))?;
+
+        Ok(discoveries)
+    }
+
+    /// 写入一名路人选手（状态为 Amateur，不进入职业选手查询）
+    async fn create_ladder_amateur(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season: i64,
+        platform: &str,
+        home_region_id: Option<i64>,
+        amateur: &GeneratedAmateur,
+        rng: &mut StdRng,
+    ) -> Result<u64, String> {
+        let r = &amateur.rookie;
+        let position = Position::from_str(&r.position);
+        let tag = InitService::determine_player_tag(r.ability, r.potential, r.age);
+
+        let player = Player {
+            id: 0,
+            game_id: r.game_id.clone(),
+            real_name: Some(r.real_name.clone()),
+            nationality: Some(r.nationality.clone()),
+            age: r.age,
+            ability: r.ability,
+            potential: r.potential,
+            stability: r.stability,
+            tag,
+            status: PlayerStatus::Amateur,
+            position: Some(position),
+            team_id: None,
+            salary: InitService::calculate_initial_salary(r.ability, r.potential, tag),
+            market_value: MarketValueEngine::calculate_base_market_value_enum(r.ability, r.age, r.potential, &tag, &position),
+            calculated_market_value: 0,
+            contract_end_season: None,
+            join_season: season as u32,
+            retire_season: None,
+            is_starter: false,
+            loyalty: InitService::calculate_initial_loyalty(r.ability, r.potential, r.age, tag),
+            satisfaction: InitService::calculate_initial_satisfaction(r.ability, r.potential, r.age, false, tag),
+            growth_accumulator: 0.0,
+        };
+
+        let player_id = PlayerRepository::create(pool, save_id, &player)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        sqlx::query("UPDATE players SET home_region_id = ? WHERE id = ?")
+            .bind(home_region_id)
+            .bind(player_id as i64)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("设置路人选手赛区失败 {}: {}", player_id, e))?;
+
+        for trait_type in &r.traits {
+            let trait_str = serde_json::to_string(trait_type)
+                .map(|s| s.trim_matches('"').to_string())
+                .unwrap_or_else(|_| format!("{:?}", trait_type).to_lowercase());
+            sqlx::query(
+                "INSERT OR IGNORE INTO player_traits (save_id, player_id, trait_type, acquired_season) VALUES (?, ?, ?, ?)"
+            )
+            .bind(save_id)
+            .bind(player_id as i64)
+            .bind(&trait_str)
+            .bind(season)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("写入路人选手特性失败 {}: {}", player_id, e))?;
+        }
+
+        sqlx::query(
+            r#"
+            INSERT INTO player_form_factors (
+                save_id, player_id, form_cycle, momentum,
+                last_performance, last_match_won, perf_history, games_since_rest
+            ) VALUES (?, ?, ?, 0, 0.0, 1, '', 0)
+            "#,
+        )
+        .bind(save_id)
+        .bind(player_id as i64)
+        .bind(rng.gen_range(0.0..100.0))
+        .execute(pool)
+        .await
+        .map_err(|e| format!("初始化路人选手状态因子失败 {}: {}", player_id, e))?;
+
+        InitService::assign_champion_mastery(pool, save_id, player_id, position, r.ability, rng).await?;
+
+        sqlx::query(
+            "INSERT INTO ladder_amateur (save_id, player_id, kind, platform, joined_season, status) VALUES (?, ?, ?, ?, ?, 'active')"
+        )
+        .bind(save_id)
+        .bind(player_id as i64)
+        .bind(amateur.kind.as_str())
+        .bind(platform)
+        .bind(season)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("写入路人选手池失败 {}: {}", player_id, e))?;
+
+        Ok(player_id)
+    }
+
+    /// 发掘单名路人：进入选秀池或转为自由球员，并记录发掘事件
+    ///
+    /// 进入选秀池的路人保留原选手档案（仍为 Amateur），通过 ladder_amateur.draft_pool_id 关联选秀池记录，
+    /// 被选中时由 promote_drafted_amateur 转为正式球员，不会再新建同 ID 选手
+    #[allow(clippy::too_many_arguments)]
+    async fn discover_ladder_amateur(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season: i64,
+        tournament_id: i64,
+        event_type: &str,
+        event_name: &str,
+        standing: &AmateurStanding,
+        field_size: usize,
+    ) -> Result<AmateurDiscovery, String> {
+        let row = sqlx::query(
+            r#"
+            SELECT p.game_id, p.real_name, p.nationality, p.age, p.ability, p.potential, p.position, p.tag,
+                   p.home_region_id, la.kind, la.platform
+            FROM players p
+            JOIN ladder_amateur la ON la.player_id = p.id
+            WHERE p.id = ?
+            "#
+        )
+        .bind(standing.player_id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("查询路人选手失败: {}", e))?;
+
+        let game_id: String = row.get("game_id");
+        let kind = AmateurKind::parse(&row.get::<String, _>("kind")).unwrap_or(AmateurKind::Amateur);
+        let age = row.get::<i64, _>("age") as u8;
+        let ability = row.get::<i64, _>("ability") as u8;
+        let potential = row.get::<i64, _>("potential") as u8;
+        let outcome = LadderAmateurEngine::discovery_outcome(age, potential);
+
+        let mut tx = pool.begin().await.map_err(|e| format!("开启事务失败: {}", e))?;
+        let mut draft_pool_id: Option<i64> = None;
+        match outcome {
+            AmateurOutcome::DraftPool => {
+                let region_id: i64 = row.get::<Option<i64>, _>("home_region_id").unwrap_or(1);
+                draft_pool_id = Some(
+                    sqlx::query_scalar(
+                        r#"
+                        INSERT INTO draft_pool (save_id, region_id, game_id, real_name, nationality, age, ability, potential, position, tag, status, created_season, origin_region_id)
+                        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'available', ?, ?)
+                        RETURNING id
+                        "#,
+                    )
+                    .bind(save_id)
+                    .bind(region_id)
+                    .bind(&game_id)
+                    .bind(row.get::<Option<String>, _>("real_name"))
+                    .bind(row.get::<Option<String>, _>("nationality"))
+                    .bind(age as i64)
+                    .bind(ability as i64)
+                    .bind(potential as i64)
+                    .bind(row.get::<String, _>("position"))
+                    .bind(row.get::<String, _>("tag"))
+                    .bind(season)
+                    .bind(region_id)
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|e| format!("路人进入选秀池失败: {}", e))?,
+                );
+            }
+            AmateurOutcome::FreeAgent => {
+                sqlx::query("UPDATE players SET status = 'Active', team_id = NULL, join_season = ? WHERE id = ?")
+                    .bind(season)
+                    .bind(standing.player_id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| format!("路人转为自由球员失败: {}", e))?;
+            }
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE ladder_amateur
+            SET status = 'discovered', outcome = ?, draft_pool_id = ?, discovered_season = ?, discovered_tournament_id = ?
+            WHERE save_id = ? AND player_id = ?
+            "#
+        )
+        .bind(outcome.as_str())
+        .bind(draft_pool_id)
+        .bind(season)
+        .bind(tournament_id)
+        .bind(save_id)
+        .bind(standing.player_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("更新路人选手池失败: {}", e))?;
+
+        tx.commit().await.map_err(|e| format!("提交事务失败: {}", e))?;
+
+        let discovery = AmateurDiscovery {
+            player_id: standing.player_id,
+            game_id,
+            kind,
+            outcome,
+            event_name: event_name.to_string(),
+            rank: standing.rank as i32,
+            field_size: field_size as i32,
+            rating: standing.rating,
+            ability,
+            potential,
+            age,
+        };
+
+        let platform_prefix = match (kind, row.get::<Option<String>, _>("platform").as_deref()) {
+            (AmateurKind::Streamer, Some("douyu")) => "斗鱼",
+            (AmateurKind::Streamer, Some("douyin")) => "抖音",
+            (AmateurKind::Streamer, Some("huya")) => "虎牙",
+            _ => "",
+        };
+        let phase = match event_type {
+            "douyu" => "DouyuLadder",
+            "douyin" => "DouyinLadder",
+            _ => "HuyaLadder",
+        };
+        let event = GameEvent {
+            id: 0,
+            save_id: save_id.to_string(),
+            season_id: season as u64,
+            event_type: EventType::AmateurDiscovery,
+            player_id: Some(standing.player_id as u64),
+            team_id: None,
+            description: format!(
+                "{}{} {} 在{}中排名第{}（{}分），被职业圈发掘，{}",
+                platform_prefix,
+                kind.name(),
+                discovery.game_id,
+                event_name,
+                discovery.rank,
+                discovery.rating,
+                outcome.name()
+            ),
+            details: serde_json::to_string(&discovery).ok(),
+            phase: Some(phase.to_string()),
+        };
+        if let Err(e) = EventRepository::create(pool, &event).await {
+            log::warn!("记录路人发掘事件失败: {}", e);
+        }
+
+        Ok(discovery)
+    }
+
+    /// 选秀选中由天梯发掘的路人时沿用其原选手档案（保留特性、英雄熟练度与天梯记录）并签下选秀合同，
+    /// 返回选手 ID；该选秀池记录不是天梯路人时返回 None，由调用方新建选手
+    #[allow(clippy::too_many_arguments)]
+    pub async fn promote_drafted_amateur(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        region_id: i64,
+        game_id: &str,
+        team_id: i64,
+        season: i64,
+        salary: i64,
+        market_value: i64,
+        contract_end_season: i64,
+        region_loyalty: i64,
+    ) -> Result<Option<i64>, String> {
+        let player_id: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT la.player_id
+            FROM ladder_amateur la
+            JOIN draft_pool dp ON dp.id = la.draft_pool_id
+            JOIN players p ON p.id = la.player_id
+            WHERE la.save_id = ? AND la.outcome = 'DraftPool' AND dp.region_id = ? AND dp.game_id = ?
+              AND p.status = 'Amateur'
+            "#
+        )
+        .bind(save_id)
+        .bind(region_id)
+        .bind(game_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("查询天梯路人档案失败: {}", e))?;
+
+        let Some(player_id) = player_id else {
+            return Ok(None);
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE players
+            SET status = 'Active', team_id = ?, salary = ?, market_value = ?, contract_end_season = ?,
+                join_season = ?, is_starter = 0, home_region_id = ?, region_loyalty = ?
+            WHERE id = ?
+            "#
+        )
+        .bind(team_id)
+        .bind(salary)
+        .bind(market_value)
+        .bind(contract_end_season)
+        .bind(season)
+        .bind(region_id)
+        .bind(region_loyalty)
+        .bind(player_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("天梯路人转为正式球员失败 {}: {}", player_id, e))?;
+
+        Ok(Some(player_id))
+    }
+}
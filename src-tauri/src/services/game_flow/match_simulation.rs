@@ -2,13 +2,14 @@ use std::collections::HashMap;
 
 use crate::db::*;
 use crate::engines::bp_engine::{
-    BpEngine, CompType, DraftResult, PlayerChampionPool, SeriesContext, TeamSide,
+    CompType, DraftResult, PlayerChampionPool, SeriesContext, TeamSide,
 };
-use crate::engines::champion::{self, MasteryTier, VersionTier};
+use crate::engines::champion::{MasteryTier, VersionTier};
+use crate::engines::champion_patch::ChampionPatchEngine;
 use crate::engines::meta_engine::MetaType;
 use crate::engines::{
-    ConditionEngine, MatchPlayerInfo, MatchSimContext, MatchSimulationEngine, MetaEngine,
-    PlayerFormFactors, TraitType,
+    AutoSeriesPlanner, CompPhaseEngine, ConditionEngine, DraftRulesEngine, DraftScoutingEngine, MapSide, MatchPlayerInfo, MatchSimContext, MatchSimulationEngine,
+    GameTactics, MetaEngine, PlannedGame, PlayerFormFactors, SeriesGameState, SeriesRecordInput, SeriesTacticsEngine, SidePlan, SideSelectionEngine, TraitType,
 };
 use crate::models::*;
 use crate::models::transfer::AITeamPersonality;
@@ -60,7 +61,7 @@ impl GameFlowService {
             .await
             .unwrap_or_else(|_| crate::engines::MetaWeights::balanced());
         let meta_type_row: Option<String> = sqlx::query_scalar(
-            "SELECT meta_type FROM meta_versions WHERE save_id = ? AND season_id = ? LIMIT 1"
+            "SELECT meta_type FROM meta_versions WHERE save_id = ? AND season_id = ? ORDER BY patch_number DESC LIMIT 1"
         )
         .bind(save_id)
         .bind(save.current_season as i64)
@@ -70,9 +71,8 @@ impl GameFlowService {
         let meta_type = meta_type_row
             .and_then(|s| MetaType::from_id(&s))
             .unwrap_or(MetaType::Balanced);
-        let version_tiers: HashMap<u8, VersionTier> = champion::calculate_version_tiers(meta_type)
-            .into_iter()
-            .collect();
+        let version_tiers: HashMap<u8, VersionTier> =
+            ChampionPatchEngine::load_version_tiers(pool, save_id, save.current_season as i64, meta_type).await;
         let mut bp_rng = StdRng::from_entropy();
 
         // 构建比赛情境
@@ -89,7 +89,8 @@ impl GameFlowService {
             is_international,
             tournament_type: tournament_type_str,
         };
-        let match_engine = MatchSimulationEngine::default();
+        let match_engine = MatchSimulationEngine::default()
+            .with_phase_ruleset(CompPhaseEngine::load_ruleset(pool, save_id).await);
 
         if is_playoff {
             // 季后赛：逐场模拟以确保正确生成后续对阵
@@ -149,12 +150,17 @@ impl GameFlowService {
                                 ),
                             )
                         };
-                        let home_team_comp_history =
-                            Self::load_team_comp_history(pool, save_id, match_info.home_team_id as i64)
-                                .await;
-                        let away_team_comp_history =
-                            Self::load_team_comp_history(pool, save_id, match_info.away_team_id as i64)
-                                .await;
+                        let home_scouting =
+                            DraftScoutingEngine::load_report(pool, save_id, match_info.home_team_id).await;
+                        let away_scouting =
+                            DraftScoutingEngine::load_report(pool, save_id, match_info.away_team_id).await;
+                        let draft_rules = DraftRulesEngine::load_series_rules(
+                            pool,
+                            save_id,
+                            match_info.tournament_id,
+                            &match_info.stage,
+                        )
+                        .await;
                         let series_ctx = if matches!(match_info.format.clone(), MatchFormat::Bo3 | MatchFormat::Bo5) {
                             Self::load_previous_series_context(
                                 pool,
@@ -167,48 +173,57 @@ impl GameFlowService {
                         } else {
                             None
                         };
-                        let draft = BpEngine::run_draft(
-                            &home_pools,
-                            &away_pools,
-                            &version_tiers,
-                            meta_type,
-                            &mut bp_rng,
-                            &home_team_comp_history,
-                            &away_team_comp_history,
-                            series_ctx.as_ref(),
-                        );
-
-                        Self::save_draft_result(pool, save_id, match_info.id, &draft).await?;
-
-                        if let Some(players) = team_players.get_mut(&match_info.home_team_id) {
-                            for p in players.iter_mut() {
-                                p.bp_modifier = draft
-                                    .home_bp_modifiers
-                                    .get(&p.player_id)
-                                    .copied()
-                                    .unwrap_or(0.0);
-                                p.champion_version_score = Self::calculate_champion_version_score(
-                                    p.player_id, &player_mastery_map, &version_tiers,
-                                );
-                            }
-                        }
-                        if let Some(players) = team_players.get_mut(&match_info.away_team_id) {
-                            for p in players.iter_mut() {
-                                p.bp_modifier = draft
-                                    .away_bp_modifiers
-                                    .get(&p.player_id)
-                                    .copied()
-                                    .unwrap_or(0.0);
-                                p.champion_version_score = Self::calculate_champion_version_score(
-                                    p.player_id, &player_mastery_map, &version_tiers,
-                                );
+                        let side_plan = Self::load_side_plan(
+                            pool, match_info, &home_pools, &away_pools, &version_tiers, meta_type,
+                        )
+                        .await;
+                        // 替补也需要英雄池：局间换人后按本局上场选手运行 BP
+                        let champion_pools: HashMap<u64, PlayerChampionPool> = home_pools
+                            .into_iter()
+                            .chain(away_pools)
+                            .chain(Self::build_champion_pools(
+                                home_bench_players,
+                                &player_mastery_map,
+                                &player_games_played_map,
+                                &player_games_won_map,
+                            ))
+                            .chain(Self::build_champion_pools(
+                                away_bench_players,
+                                &player_mastery_map,
+                                &player_games_played_map,
+                                &player_games_won_map,
+                            ))
+                            .map(|p| (p.player_id, p))
+                            .collect();
+
+                        for team_id in [match_info.home_team_id, match_info.away_team_id] {
+                            if let Some(players) = team_players.get_mut(&team_id) {
+                                for p in players.iter_mut() {
+                                    p.champion_version_score = Self::calculate_champion_version_score(
+                                        p.player_id, &player_mastery_map, &version_tiers,
+                                    );
+                                }
                             }
                         }
 
                         let home_players = team_players.get(&match_info.home_team_id).map(|v| v.as_slice()).unwrap_or(&[]);
                         let away_players = team_players.get(&match_info.away_team_id).map(|v| v.as_slice()).unwrap_or(&[]);
 
-                        match_engine.simulate_match_with_traits(
+                        let manual_tactics = Self::load_manual_tactics(pool, save_id, match_info).await;
+                        let mut planner = AutoSeriesPlanner::new(
+                            match_info.format,
+                            meta_type,
+                            side_plan.higher_seed,
+                            &champion_pools,
+                            &version_tiers,
+                            &home_scouting,
+                            &away_scouting,
+                            draft_rules,
+                            &mut bp_rng,
+                        )
+                        .with_series_context(series_ctx)
+                        .with_manual_tactics(manual_tactics);
+                        let result = match_engine.simulate_match_with_traits(
                             match_info.id, match_info.tournament_id, &match_info.stage,
                             match_info.format.clone(), match_info.home_team_id, match_info.away_team_id,
                             home_players, away_players,
@@ -216,7 +231,10 @@ impl GameFlowService {
                             &sim_ctx, &meta_weights,
                             &home_pers, &away_pers,
                             save.current_season as u32,
-                        )
+                            Some(&mut |state: &SeriesGameState| planner.plan_game(state)),
+                        );
+                        Self::save_planned_games(pool, save_id, match_info, planner.games()).await?;
+                        result
                     } else {
                         let home_team = TeamRepository::get_by_id(pool, match_info.home_team_id)
                             .await.map_err(|e| e.to_string())?;
@@ -242,6 +260,11 @@ impl GameFlowService {
                     Self::update_season_games_played(pool, save_id, played).await?;
                     let total_games_this_match = (result.home_score + result.away_score) as i64;
                     Self::update_season_games_total(pool, save_id, match_info.home_team_id, match_info.away_team_id, total_games_this_match).await?;
+                    if let Err(e) = Self::update_records_after_series(
+                        pool, save_id, save.current_season as i64, &SeriesRecordInput::from_match_result(&result),
+                    ).await {
+                        log::warn!("更新纪录簿失败: {}", e);
+                    }
 
                     // 比赛后更新 form factors
                     let home_won = result.winner_id == match_info.home_team_id;
@@ -333,54 +356,67 @@ impl GameFlowService {
                                 ),
                             )
                         };
-                        let home_team_comp_history =
-                            Self::load_team_comp_history(pool, save_id, match_info.home_team_id as i64)
-                                .await;
-                        let away_team_comp_history =
-                            Self::load_team_comp_history(pool, save_id, match_info.away_team_id as i64)
-                                .await;
-                        let draft = BpEngine::run_draft(
-                            &home_pools,
-                            &away_pools,
-                            &version_tiers,
-                            meta_type,
-                            &mut bp_rng,
-                            &home_team_comp_history,
-                            &away_team_comp_history,
-                            None,
-                        );
-
-                        Self::save_draft_result(pool, save_id, match_info.id, &draft).await?;
-
-                        if let Some(players) = team_players.get_mut(&match_info.home_team_id) {
-                            for p in players.iter_mut() {
-                                p.bp_modifier = draft
-                                    .home_bp_modifiers
-                                    .get(&p.player_id)
-                                    .copied()
-                                    .unwrap_or(0.0);
-                                p.champion_version_score = Self::calculate_champion_version_score(
-                                    p.player_id, &player_mastery_map, &version_tiers,
-                                );
-                            }
-                        }
-                        if let Some(players) = team_players.get_mut(&match_info.away_team_id) {
-                            for p in players.iter_mut() {
-                                p.bp_modifier = draft
-                                    .away_bp_modifiers
-                                    .get(&p.player_id)
-                                    .copied()
-                                    .unwrap_or(0.0);
-                                p.champion_version_score = Self::calculate_champion_version_score(
-                                    p.player_id, &player_mastery_map, &version_tiers,
-                                );
+                        let home_scouting =
+                            DraftScoutingEngine::load_report(pool, save_id, match_info.home_team_id).await;
+                        let away_scouting =
+                            DraftScoutingEngine::load_report(pool, save_id, match_info.away_team_id).await;
+                        let draft_rules = DraftRulesEngine::load_series_rules(
+                            pool,
+                            save_id,
+                            match_info.tournament_id,
+                            &match_info.stage,
+                        )
+                        .await;
+                        let side_plan = Self::load_side_plan(
+                            pool, match_info, &home_pools, &away_pools, &version_tiers, meta_type,
+                        )
+                        .await;
+                        // 替补也需要英雄池：局间换人后按本局上场选手运行 BP
+                        let champion_pools: HashMap<u64, PlayerChampionPool> = home_pools
+                            .into_iter()
+                            .chain(away_pools)
+                            .chain(Self::build_champion_pools(
+                                home_bench_players,
+                                &player_mastery_map,
+                                &player_games_played_map,
+                                &player_games_won_map,
+                            ))
+                            .chain(Self::build_champion_pools(
+                                away_bench_players,
+                                &player_mastery_map,
+                                &player_games_played_map,
+                                &player_games_won_map,
+                            ))
+                            .map(|p| (p.player_id, p))
+                            .collect();
+
+                        for team_id in [match_info.home_team_id, match_info.away_team_id] {
+                            if let Some(players) = team_players.get_mut(&team_id) {
+                                for p in players.iter_mut() {
+                                    p.champion_version_score = Self::calculate_champion_version_score(
+                                        p.player_id, &player_mastery_map, &version_tiers,
+                                    );
+                                }
                             }
                         }
 
                         let home_players = team_players.get(&match_info.home_team_id).map(|v| v.as_slice()).unwrap_or(&[]);
                         let away_players = team_players.get(&match_info.away_team_id).map(|v| v.as_slice()).unwrap_or(&[]);
 
-                        match_engine.simulate_match_with_traits(
+                        let manual_tactics = Self::load_manual_tactics(pool, save_id, match_info).await;
+                        let mut planner = AutoSeriesPlanner::new(
+                            match_info.format,
+                            meta_type,
+                            side_plan.higher_seed,
+                            &champion_pools,
+                            &version_tiers,
+                            &home_scouting,
+                            &away_scouting,
+                            draft_rules,
+                            &mut bp_rng,
+                        )
+                        .with_manual_tactics(manual_tactics);
+                        let result = match_engine.simulate_match_with_traits(
                             match_info.id, match_info.tournament_id, &match_info.stage,
                             match_info.format.clone(), match_info.home_team_id, match_info.away_team_id,
                             home_players, away_players,
@@ -388,7 +424,10 @@ impl GameFlowService {
                             &sim_ctx, &meta_weights,
                             &home_pers, &away_pers,
                             save.current_season as u32,
-                        )
+                            Some(&mut |state: &SeriesGameState| planner.plan_game(state)),
+                        );
+                        Self::save_planned_games(pool, save_id, match_info, planner.games()).await?;
+                        result
                     } else {
                         let home_team = TeamRepository::get_by_id(pool, match_info.home_team_id)
                             .await.map_err(|e| e.to_string())?;
@@ -413,6 +452,11 @@ impl GameFlowService {
                     Self::update_season_games_played(pool, save_id, played).await?;
                     let total_games_this_match = (result.home_score + result.away_score) as i64;
                     Self::update_season_games_total(pool, save_id, match_info.home_team_id, match_info.away_team_id, total_games_this_match).await?;
+                    if let Err(e) = Self::update_records_after_series(
+                        pool, save_id, save.current_season as i64, &SeriesRecordInput::from_match_result(&result),
+                    ).await {
+                        log::warn!("更新纪录簿失败: {}", e);
+                    }
 
                     // 比赛后更新 form factors
                     let home_won = result.winner_id == match_info.home_team_id;
@@ -473,54 +517,6 @@ impl GameFlowService {
             .collect()
     }
 
-    async fn load_team_comp_history(
-        pool: &Pool<Sqlite>,
-        save_id: &str,
-        team_id: i64,
-    ) -> Vec<(CompType, u32)> {
-        let rows = match sqlx::query(
-            r#"
-            SELECT d.home_comp, d.away_comp, m.home_team_id, m.away_team_id
-            FROM game_draft_results d
-            JOIN matches m ON m.id = d.match_id
-            WHERE d.save_id = ? AND (m.home_team_id = ? OR m.away_team_id = ?)
-            "#,
-        )
-        .bind(save_id)
-        .bind(team_id)
-        .bind(team_id)
-        .fetch_all(pool)
-        .await
-        {
-            Ok(rows) => rows,
-            Err(_) => return Vec::new(),
-        };
-
-        let mut comp_counts: HashMap<CompType, u32> = HashMap::new();
-        for row in rows {
-            let home_team_id: i64 = row.get("home_team_id");
-            let away_team_id: i64 = row.get("away_team_id");
-
-            let comp_str: Option<String> = if team_id == home_team_id {
-                row.get("home_comp")
-            } else if team_id == away_team_id {
-                row.get("away_comp")
-            } else {
-                None
-            };
-
-            if let Some(comp_str) = comp_str {
-                if let Some(comp) = CompType::from_id(comp_str.trim()) {
-                    *comp_counts.entry(comp).or_insert(0) += 1;
-                }
-            }
-        }
-
-        let mut history: Vec<(CompType, u32)> = comp_counts.into_iter().collect();
-        history.sort_by(|left, right| right.1.cmp(&left.1));
-        history
-    }
-
     fn calculate_champion_version_score(
         player_id: u64,
         player_mastery_map: &HashMap<u64, HashMap<u8, MasteryTier>>,
@@ -897,12 +893,70 @@ impl GameFlowService {
         Ok(())
     }
 
+    /// 玩家为该场比赛预设的局间战术
+    async fn load_manual_tactics(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_info: &Match,
+    ) -> HashMap<(u8, TeamSide), GameTactics> {
+        let records = match SeriesTacticsEngine::load_match(pool, save_id, match_info.id).await {
+            Ok(records) => records,
+            Err(e) => {
+                log::warn!("{}", e);
+                return HashMap::new();
+            }
+        };
+        records
+            .into_iter()
+            .filter(|r| r.tactics.is_manual)
+            .filter_map(|r| {
+                let side = if r.team_id == match_info.home_team_id {
+                    TeamSide::Home
+                } else if r.team_id == match_info.away_team_id {
+                    TeamSide::Away
+                } else {
+                    return None;
+                };
+                Some(((r.game_number, side), r.tactics))
+            })
+            .collect()
+    }
+
+    /// 保存快速模拟各局的 BP 结果与局间战术
+    async fn save_planned_games(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        match_info: &Match,
+        games: &[PlannedGame],
+    ) -> Result<(), String> {
+        for game in games {
+            Self::save_draft_result(pool, save_id, match_info.id, game.game_number, &game.draft, game.home_side).await?;
+            if game.game_number > 1 {
+                for (team_id, tactics) in [
+                    (match_info.home_team_id, &game.home_tactics),
+                    (match_info.away_team_id, &game.away_tactics),
+                ] {
+                    if let Err(e) = SeriesTacticsEngine::save_tactics(
+                        pool, save_id, match_info.id, game.game_number, team_id, tactics, true,
+                    )
+                    .await
+                    {
+                        log::warn!("{}", e);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// 将 draft 结果存入 game_draft_results 表
     async fn save_draft_result(
         pool: &Pool<Sqlite>,
         save_id: &str,
         match_id: u64,
+        game_number: u8,
         draft: &DraftResult,
+        home_side: MapSide,
     ) -> Result<(), String> {
         let bans_json = serde_json::to_string(&draft.bans).unwrap_or_default();
         let home_picks_json = serde_json::to_string(&draft.home_picks).unwrap_or_default();
@@ -913,17 +967,19 @@ impl GameFlowService {
         sqlx::query(
             r#"
             INSERT OR REPLACE INTO game_draft_results
-                (save_id, match_id, game_number, bans_json, home_picks_json, away_picks_json, home_comp, away_comp)
-            VALUES (?, ?, 1, ?, ?, ?, ?, ?)
+                (save_id, match_id, game_number, bans_json, home_picks_json, away_picks_json, home_comp, away_comp, home_side)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
         )
         .bind(save_id)
         .bind(match_id as i64)
+        .bind(game_number)
         .bind(&bans_json)
         .bind(&home_picks_json)
         .bind(&away_picks_json)
         .bind(&home_comp)
         .bind(&away_comp)
+        .bind(home_side.as_str())
         .execute(pool)
         .await
         .map_err(|e| format!("存储BP结果失败: {}", e))?;
@@ -931,6 +987,37 @@ impl GameFlowService {
         Ok(())
     }
 
+    /// 生成系列赛选边计划：以队伍战力评级排定种子
+    async fn load_side_plan(
+        pool: &Pool<Sqlite>,
+        match_info: &Match,
+        home_pools: &[PlayerChampionPool],
+        away_pools: &[PlayerChampionPool],
+        version_tiers: &HashMap<u8, VersionTier>,
+        meta_type: MetaType,
+    ) -> SidePlan {
+        let rating = |team_id: u64| async move {
+            sqlx::query_scalar::<_, f64>("SELECT power_rating FROM teams WHERE id = ?")
+                .bind(team_id as i64)
+                .fetch_optional(pool)
+                .await
+                .ok()
+                .flatten()
+                .unwrap_or(0.0)
+        };
+        let home_rating = rating(match_info.home_team_id).await;
+        let away_rating = rating(match_info.away_team_id).await;
+
+        SideSelectionEngine::plan_series(
+            home_rating,
+            away_rating,
+            home_pools,
+            away_pools,
+            version_tiers,
+            meta_type,
+        )
+    }
+
     async fn load_previous_series_context(
         pool: &Pool<Sqlite>,
         save_id: &str,
@@ -1040,6 +1127,7 @@ impl GameFlowService {
             away_score,
             game_number,
             wins_needed,
+            ..Default::default()
         }))
     }
 
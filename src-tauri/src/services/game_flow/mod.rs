@@ -9,6 +9,9 @@ mod match_simulation;
 mod annual_awards;
 mod market_value;
 mod season_management;
+mod youth_intake;
+mod ladder_amateur;
+mod records_book;
 pub(crate) mod helpers;
 
 #[cfg(test)]
@@ -179,6 +179,10 @@ impl GameFlowService {
                                         log::debug!("获取荣誉列表失败: {}", e);
                                     }
                                 }
+
+                                if let Err(e) = Self::update_title_records(pool, save_id, season_id as i64, tournament.id as i64).await {
+                                    log::error!("更新冠军纪录失败: tournament_id={}, error={}", tournament.id, e);
+                                }
                             }
                             Err(e) => {
                                 log::debug!("荣誉处理失败: tournament_id={}, error={}", tournament.id, e);
@@ -194,193 +198,44 @@ impl GameFlowService {
 
                 self.recalculate_yearly_scores_with_big_stage(pool, save_id, season_id).await?;
 
-                let top20 = self.get_annual_top20(pool, save_id, season_id).await?;
-                log::debug!("获取到 {} 位Top20选手", top20.len());
-
-                for (idx, player) in top20.iter().enumerate() {
-                    // 第一名同时获得年度MVP
-                    if idx == 0 {
-                        let mvp_honor = crate::models::Honor::new_player_honor(
-                            save_id,
-                            HonorType::AnnualMvp,
-                            season_id,
-                            None,
-                            "年度颁奖典礼",
-                            "ANNUAL",
-                            player.team_id,
-                            &player.team_name,
-                            player.player_id,
-                            &player.player_name,
-                            &player.position,
-                            None,
+                // 各奖项由媒体、职业选手、球迷三方投票评选，得票明细写入 award_votes
+                let registry = crate::engines::AwardRegistry::default();
+                let vote_results = self.run_annual_award_votes(pool, save_id, season_id, &registry).await?;
+                log::debug!("年度奖项投票完成，共 {} 组评选", vote_results.len());
+                honors_awarded.extend(
+                    self.grant_annual_honors(pool, save_id, season_id, &registry, &vote_results).await,
+                );
+
+                // 名人堂评选：退役满等待期的选手、退役球衣与战队名人堂
+                match crate::engines::HallOfFameEngine::run_induction(pool, save_id, season_id as i64).await {
+                    Ok(class) => {
+                        log::debug!(
+                            "S{} 名人堂入选 {} 人（顺延 {} 人），战队 {} 支，退役球衣 {} 件",
+                            season_id, class.players.len(), class.deferred, class.teams.len(), class.jerseys.len()
                         );
-                        if let Err(e) = HonorRepository::create(pool, save_id, &mvp_honor).await {
-                            log::error!("Failed to create annual MVP honor: {}", e);
-                        } else {
+                        for inductee in &class.players {
                             honors_awarded.push(HonorAwarded {
-                                honor_type: "年度MVP".to_string(),
-                                recipient_name: player.player_name.clone(),
-                                tournament_name: "年度颁奖典礼".to_string(),
+                                honor_type: inductee.tier.name().to_string(),
+                                recipient_name: inductee.player_name.clone(),
+                                tournament_name: "名人堂入选仪式".to_string(),
+                            });
+                        }
+                        for jersey in &class.jerseys {
+                            honors_awarded.push(HonorAwarded {
+                                honor_type: format!("{} 退役球衣", jersey.team_name),
+                                recipient_name: jersey.player_name.clone(),
+                                tournament_name: "名人堂入选仪式".to_string(),
+                            });
+                        }
+                        for team in &class.teams {
+                            honors_awarded.push(HonorAwarded {
+                                honor_type: format!("战队{}", team.tier.name()),
+                                recipient_name: team.team_name.clone(),
+                                tournament_name: "名人堂入选仪式".to_string(),
                             });
                         }
                     }
-
-                    // Top20荣誉
-                    let rank = idx + 1;
-                    let top20_honor = crate::models::Honor::new_player_honor(
-                        save_id,
-                        HonorType::AnnualTop20,
-                        season_id,
-                        None,
-                        &format!("年度Top{}", rank),
-                        "ANNUAL",
-                        player.team_id,
-                        &player.team_name,
-                        player.player_id,
-                        &player.player_name,
-                        &player.position,
-                        None,
-                    );
-                    if let Err(e) = HonorRepository::create(pool, save_id, &top20_honor).await {
-                        log::error!("Failed to create annual top20 honor for player {}: {}", player.player_id, e);
-                    } else {
-                        honors_awarded.push(HonorAwarded {
-                            honor_type: format!("年度Top{}", idx + 1),
-                            recipient_name: player.player_name.clone(),
-                            tournament_name: "年度颁奖典礼".to_string(),
-                        });
-                    }
-                }
-
-                // 获取三阵选手并颁发荣誉
-                let all_pro_3teams = self.get_annual_all_pro_3teams(pool, save_id, season_id).await?;
-                log::debug!("获取到 {} 位最佳阵容选手", all_pro_3teams.len());
-
-                for (tier, player) in &all_pro_3teams {
-                    let honor_type = match tier {
-                        1 => HonorType::AnnualAllPro1st,
-                        2 => HonorType::AnnualAllPro2nd,
-                        3 => HonorType::AnnualAllPro3rd,
-                        _ => continue,
-                    };
-
-                    let tier_name = match tier {
-                        1 => "最佳阵容一阵",
-                        2 => "最佳阵容二阵",
-                        3 => "最佳阵容三阵",
-                        _ => continue,
-                    };
-
-                    let position_honor = crate::models::Honor::new_player_honor(
-                        save_id,
-                        honor_type,
-                        season_id,
-                        None,
-                        "年度颁奖典礼",
-                        "ANNUAL",
-                        player.team_id,
-                        &player.team_name,
-                        player.player_id,
-                        &player.player_name,
-                        &player.position,
-                        None,
-                    );
-                    if let Err(e) = HonorRepository::create(pool, save_id, &position_honor).await {
-                        log::error!("Failed to create annual all-pro honor: {}", e);
-                    } else {
-                        honors_awarded.push(HonorAwarded {
-                            honor_type: tier_name.to_string(),
-                            recipient_name: player.player_name.clone(),
-                            tournament_name: "年度颁奖典礼".to_string(),
-                        });
-                    }
-                }
-
-                // 获取最稳定选手
-                if let Ok(Some(consistent)) = self.get_annual_most_consistent(pool, save_id, season_id).await {
-                    log::debug!("年度最稳定选手: {}", consistent.player_name);
-
-                    let honor = crate::models::Honor::new_player_honor(
-                        save_id,
-                        HonorType::AnnualMostConsistent,
-                        season_id,
-                        None,
-                        "年度颁奖典礼",
-                        "ANNUAL",
-                        consistent.team_id,
-                        &consistent.team_name,
-                        consistent.player_id,
-                        &consistent.player_name,
-                        &consistent.position,
-                        None,
-                    );
-                    if let Err(e) = HonorRepository::create(pool, save_id, &honor).await {
-                        log::error!("Failed to create annual most consistent honor: {}", e);
-                    } else {
-                        honors_awarded.push(HonorAwarded {
-                            honor_type: "年度最稳定选手".to_string(),
-                            recipient_name: consistent.player_name.clone(),
-                            tournament_name: "年度颁奖典礼".to_string(),
-                        });
-                    }
-                }
-
-                // 获取最具统治力选手
-                if let Ok(Some(dominant)) = self.get_annual_most_dominant(pool, save_id, season_id).await {
-                    log::debug!("年度最具统治力选手: {}", dominant.player_name);
-
-                    let honor = crate::models::Honor::new_player_honor(
-                        save_id,
-                        HonorType::AnnualMostDominant,
-                        season_id,
-                        None,
-                        "年度颁奖典礼",
-                        "ANNUAL",
-                        dominant.team_id,
-                        &dominant.team_name,
-                        dominant.player_id,
-                        &dominant.player_name,
-                        &dominant.position,
-                        None,
-                    );
-                    if let Err(e) = HonorRepository::create(pool, save_id, &honor).await {
-                        log::error!("Failed to create annual most dominant honor: {}", e);
-                    } else {
-                        honors_awarded.push(HonorAwarded {
-                            honor_type: "年度最具统治力".to_string(),
-                            recipient_name: dominant.player_name.clone(),
-                            tournament_name: "年度颁奖典礼".to_string(),
-                        });
-                    }
-                }
-
-                // 获取年度最佳新秀
-                if let Ok(Some(rookie)) = self.get_annual_rookie(pool, save_id, season_id).await {
-                    log::debug!("年度最佳新秀: {}", rookie.player_name);
-
-                    let rookie_honor = crate::models::Honor::new_player_honor(
-                        save_id,
-                        HonorType::AnnualRookie,
-                        season_id,
-                        None,
-                        "年度颁奖典礼",
-                        "ANNUAL",
-                        rookie.team_id,
-                        &rookie.team_name,
-                        rookie.player_id,
-                        &rookie.player_name,
-                        &rookie.position,
-                        None,
-                    );
-                    if let Err(e) = HonorRepository::create(pool, save_id, &rookie_honor).await {
-                        log::error!("Failed to create annual rookie honor: {}", e);
-                    } else {
-                        honors_awarded.push(HonorAwarded {
-                            honor_type: "年度最佳新秀".to_string(),
-                            recipient_name: rookie.player_name.clone(),
-                            tournament_name: "年度颁奖典礼".to_string(),
-                        });
-                    }
+                    Err(e) => log::error!("[complete_phase] 名人堂评选失败: {}", e),
                 }
             }
 
@@ -403,6 +258,18 @@ impl GameFlowService {
                 Ok(count) => log::debug!("品牌价值更新完成，共更新 {} 支队伍", count),
                 Err(e) => log::error!("[complete_phase] 品牌价值更新失败: {}", e),
             }
+
+            // 年度青训：新秀届次进入选秀池与自由球员市场（转会期前完成）
+            match self.run_youth_intake(pool, save_id, season_id).await {
+                Ok(classes) => log::debug!("青训届次生成完成，共 {} 个赛区", classes.len()),
+                Err(e) => log::error!("[complete_phase] 青训届次生成失败: {}", e),
+            }
+
+            // 新秀的路人局天梯档案（球探可参考的公开数据）
+            match crate::engines::LadderProfileEngine::seed_prospect_profiles(pool, save_id, season_id as i64).await {
+                Ok(count) => log::debug!("新秀天梯档案生成完成，共 {} 名", count),
+                Err(e) => log::error!("[complete_phase] 新秀天梯档案生成失败: {}", e),
+            }
         }
 
         // 颁发年度积分（季后赛和国际赛事，Super赛除外）
@@ -493,6 +360,13 @@ impl GameFlowService {
                             log::error!("Failed to update champion stats for tournament {}: {}", tournament.id, e);
                         }
                     }
+
+                    // 国际赛冠军赛区的打法向其他赛区扩散
+                    if is_international {
+                        if let Err(e) = self.spread_international_styles(pool, save_id, season_id, t_type).await {
+                            log::error!("[complete_phase] 赛区风格扩散失败: {}", e);
+                        }
+                    }
                 }
             }
             _ => {}
@@ -534,6 +408,31 @@ impl GameFlowService {
                     .execute(pool)
                     .await;
                 log::debug!("更新天梯赛 {} 状态为 Completed", event_type);
+
+                // 本届结果写回跨赛季天梯档案
+                let ladder_ids: Vec<i64> = sqlx::query_scalar(
+                    "SELECT id FROM ladder_tournament WHERE save_id = ? AND season = ? AND event_type = ?"
+                )
+                .bind(save_id)
+                .bind(season_id as i64)
+                .bind(event_type)
+                .fetch_all(pool)
+                .await
+                .unwrap_or_default();
+                for ladder_id in ladder_ids {
+                    match crate::engines::LadderProfileEngine::sync_from_event(pool, save_id, ladder_id).await {
+                        Ok(sync) => log::debug!(
+                            "天梯档案更新完成: {} 人，{} 人状态动量调整",
+                            sync.profiles_updated, sync.momentum_adjusted
+                        ),
+                        Err(e) => log::error!("[complete_phase] 天梯档案更新失败: {}", e),
+                    }
+                    // 路人/主播发掘：进入选秀池或自由球员市场
+                    match Self::review_ladder_amateurs(pool, save_id, ladder_id).await {
+                        Ok(found) => log::debug!("天梯路人发掘完成，共 {} 人", found.len()),
+                        Err(e) => log::error!("[complete_phase] 天梯路人发掘失败: {}", e),
+                    }
+                }
             }
             _ => {
                 let tournament_type = phase.to_tournament_type();
@@ -550,6 +449,27 @@ impl GameFlowService {
             }
         }
 
+        // 球探情报推进：每个阶段结束时，所有派遣中的球探积累一次考察进度
+        match crate::engines::ScoutingEngine::advance_phase(pool, save_id, season_id as i64, phase).await {
+            Ok(count) => log::debug!("球探情报更新完成，共 {} 条", count),
+            Err(e) => log::error!("[complete_phase] 球探情报更新失败: {}", e),
+        }
+
+        // 版本补丁：部分阶段结束时根据英雄使用数据加强/削弱英雄
+        match crate::engines::ChampionPatchEngine::apply_phase_patch(pool, save_id, season_id as i64, phase).await {
+            Ok(Some(patch)) => log::debug!("{} 发布，共调整 {} 个英雄", patch.label, patch.changes.len()),
+            Ok(None) => {}
+            Err(e) => log::error!("[complete_phase] 版本补丁发布失败: {}", e),
+        }
+
+        // 英雄训练：比赛阶段结束后按训练计划提升熟练度（AI 队伍自动安排）
+        if phase.to_tournament_type().is_some() {
+            match crate::engines::ChampionTrainingEngine::run_phase_training(pool, save_id, season_id as i64, phase).await {
+                Ok(count) => log::debug!("英雄训练结算完成，共 {} 项", count),
+                Err(e) => log::error!("[complete_phase] 英雄训练结算失败: {}", e),
+            }
+        }
+
         // 获取下一阶段
         let next_phase = phase.next();
         let can_advance = next_phase.is_some() || phase == SeasonPhase::SeasonEnd;
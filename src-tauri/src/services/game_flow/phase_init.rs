@@ -70,6 +70,13 @@ impl GameFlowService {
             }
         }
 
+        // 季中版本调整：马德里大师赛、夏季常规赛开始前根据阵容体系表现决定是否调整版本
+        match crate::engines::MetaEngine::apply_mid_season_shift(pool, save_id, season_id as i64, phase).await {
+            Ok(Some(patch)) => log::debug!("季中版本调整为 {}（第 {} 次补丁）", patch.meta.display_name(), patch.patch_number),
+            Ok(None) => {}
+            Err(e) => log::error!("[initialize_phase] 季中版本调整失败: {}", e),
+        }
+
         let mut tournaments_created = Vec::new();
 
         match phase {
@@ -853,6 +860,11 @@ impl GameFlowService {
                         .map_err(|e| e.to_string())?;
                     }
 
+                    let amateurs = Self::enroll_ladder_amateurs(pool, save_id, tournament_id).await?;
+                    log::info!("[天梯赛初始化] {} 名路人/主播报名", amateurs);
+
+                    crate::engines::LadderProfileEngine::carry_into_event(pool, save_id, tournament_id).await?;
+
                     tournaments_created.push(TournamentCreated {
                         id: tournament_id as u64,
                         name: format!("S{} {}", season_id, event_name),
@@ -0,0 +1,61 @@
+use sqlx::{Pool, Sqlite};
+
+use crate::db::EventRepository;
+use crate::engines::{BrokenRecord, RecordsBookEngine, SeriesRecordInput};
+use crate::models::{EventType, GameEvent};
+
+use super::GameFlowService;
+
+impl GameFlowService {
+    /// 系列赛结束后更新纪录簿，被打破的纪录记为事件
+    pub async fn update_records_after_series(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        input: &SeriesRecordInput,
+    ) -> Result<Vec<BrokenRecord>, String> {
+        let broken = RecordsBookEngine::check_series(pool, save_id, season_id, input).await?;
+        Self::publish_broken_records(pool, save_id, &broken).await?;
+        Ok(broken)
+    }
+
+    /// 赛事颁奖后更新冠军类纪录
+    pub(crate) async fn update_title_records(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: i64,
+        tournament_id: i64,
+    ) -> Result<Vec<BrokenRecord>, String> {
+        let broken = RecordsBookEngine::check_titles(pool, save_id, season_id, tournament_id).await?;
+        Self::publish_broken_records(pool, save_id, &broken).await?;
+        Ok(broken)
+    }
+
+    async fn publish_broken_records(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        records: &[BrokenRecord],
+    ) -> Result<(), String> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        let events: Vec<GameEvent> = records
+            .iter()
+            .map(|record| GameEvent {
+                id: 0,
+                save_id: save_id.to_string(),
+                season_id: record.season_id as u64,
+                event_type: EventType::RecordBroken,
+                player_id: (record.holder_kind == "PLAYER").then_some(record.holder_id as u64),
+                team_id: (record.holder_kind == "TEAM").then_some(record.holder_id as u64),
+                description: record.describe(),
+                details: serde_json::to_string(record).ok(),
+                phase: None,
+            })
+            .collect();
+        EventRepository::create_batch(pool, &events)
+            .await
+            .map_err(|e| format!("记录纪录打破事件失败: {}", e))?;
+        Ok(())
+    }
+}
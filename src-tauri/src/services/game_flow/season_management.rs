@@ -1,5 +1,6 @@
 use crate::db::*;
-use crate::engines::MetaEngine;
+use crate::engines::{LadderProfileEngine, MetaEngine, RegionalMetaEngine};
+use crate::engines::champion_patch::ChampionPatchEngine;
 use crate::engines::champion::{self, MasteryTier};
 use crate::models::*;
 use rand::rngs::StdRng;
@@ -15,12 +16,16 @@ impl GameFlowService {
         pool: &Pool<Sqlite>,
         save_id: &str,
     ) -> Result<u32, String> {
-        // 清除所有首发标记 & 默认设为 Sub
-        sqlx::query("UPDATE players SET is_starter = 0, contract_role = 'Sub' WHERE save_id = ? AND status = 'Active'")
-            .bind(save_id)
-            .execute(pool)
-            .await
-            .map_err(|e| format!("清除首发失败: {}", e))?;
+        // 清除所有首发标记 & 默认设为 Sub（选手兼教练/第六人保留合同角色）
+        sqlx::query(
+            "UPDATE players SET is_starter = 0, \
+             contract_role = CASE WHEN contract_role IN ('PlayingCoach', 'SixthMan') THEN contract_role ELSE 'Sub' END \
+             WHERE save_id = ? AND status = 'Active'"
+        )
+        .bind(save_id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("清除首发失败: {}", e))?;
 
         // 获取所有队伍
         let teams = TeamRepository::get_all(pool, save_id)
@@ -52,11 +57,15 @@ impl GameFlowService {
 
                 if let Some(row) = result {
                     let player_id: i64 = row.get("id");
-                    sqlx::query("UPDATE players SET is_starter = 1, contract_role = 'Starter' WHERE id = ?")
-                        .bind(player_id)
-                        .execute(pool)
-                        .await
-                        .map_err(|e| format!("设置首发失败: {}", e))?;
+                    sqlx::query(
+                        "UPDATE players SET is_starter = 1, \
+                         contract_role = CASE WHEN contract_role IN ('PlayingCoach', 'SixthMan') THEN contract_role ELSE 'Starter' END \
+                         WHERE id = ?"
+                    )
+                    .bind(player_id)
+                    .execute(pool)
+                    .await
+                    .map_err(|e| format!("设置首发失败: {}", e))?;
                     confirmed_count += 1;
                     team_confirmed += 1;
                 } else {
@@ -186,6 +195,12 @@ impl GameFlowService {
         MetaEngine::roll_new_meta(pool, save_id, save.current_season as i64).await
             .map_err(|e| format!("生成 Meta 版本失败: {}", e))?;
 
+        // 2.62 各赛区风格部分回归传统打法
+        RegionalMetaEngine::revert_for_new_season(pool, save_id, save.current_season as i64).await?;
+
+        // 2.65 天梯档案软重置（归档上赛季定级）
+        LadderProfileEngine::season_soft_reset(pool, save_id, save.current_season as i64).await?;
+
         // 2.7 英雄池赛季演变
         self.evolve_champion_masteries(pool, save_id, save.current_season as i64).await?;
 
@@ -214,8 +229,10 @@ impl GameFlowService {
         &self,
         pool: &Pool<Sqlite>,
         save_id: &str,
-        _new_season: i64,
+        new_season: i64,
     ) -> Result<(), String> {
+        // 熟练度演变依据刚结束赛季的最终版本强度
+        let strengths = ChampionPatchEngine::load_strengths(pool, save_id, new_season - 1).await;
         let rows = sqlx::query(
             "SELECT player_id, champion_id, mastery_tier, games_played, games_won FROM player_champion_mastery WHERE save_id = ?"
         )
@@ -240,7 +257,14 @@ impl GameFlowService {
                 None => continue,
             };
 
-            let new_tier = champion::evolve_mastery(current, games_played as u32, games_won as u32, &mut rng);
+            let patch_strength = strengths.get(&(champion_id as u8)).copied().unwrap_or(0.0);
+            let new_tier = champion::evolve_mastery_with_patch(
+                current,
+                games_played as u32,
+                games_won as u32,
+                patch_strength,
+                &mut rng,
+            );
 
             if new_tier != current {
                 if new_tier == MasteryTier::B {
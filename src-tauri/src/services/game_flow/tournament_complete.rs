@@ -1,5 +1,5 @@
 use crate::db::*;
-use crate::engines::{FinancialEngine, PointsCalculationEngine};
+use crate::engines::{FinancialEngine, PointsCalculationEngine, RegionalMetaEngine};
 use crate::models::*;
 use sqlx::{Pool, Row, Sqlite};
 
@@ -342,6 +342,35 @@ impl GameFlowService {
         Ok(distributed)
     }
 
+    /// 国际赛结束后，冠军队伍所在赛区的风格向其他赛区扩散
+    pub(crate) async fn spread_international_styles(
+        &self,
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: u64,
+        tournament_type: TournamentType,
+    ) -> Result<(), String> {
+        let tournaments = self.get_phase_tournaments(pool, save_id, season_id, tournament_type).await?;
+        for tournament in &tournaments {
+            let results = self.get_tournament_final_results(pool, save_id, tournament.id, tournament_type).await?;
+            let Some((champion_id, _)) = results.iter().find(|(_, position)| position == "CHAMPION") else {
+                continue;
+            };
+            if let Some(region) = RegionalMetaEngine::spread_from_champion(
+                pool,
+                save_id,
+                season_id as i64,
+                tournament.id,
+                *champion_id,
+            )
+            .await?
+            {
+                log::debug!("{} 冠军来自 {}，其打法向其他赛区扩散", tournament.name, region);
+            }
+        }
+        Ok(())
+    }
+
     /// 更新冠军/亚军/季军队伍选手的统计数据
     pub(crate) async fn update_champion_player_stats(
         &self,
@@ -0,0 +1,311 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use sqlx::{Pool, Row, Sqlite, SqliteConnection};
+
+use crate::db::PlayerRepository;
+use crate::engines::market_value::MarketValueEngine;
+use crate::engines::talent_pipeline::{IntakeClass, Prospect, RegionTalentProfile, TalentPipelineEngine};
+use crate::models::{Player, PlayerStatus, Position, RegionCode, TalentClassRecord};
+use crate::services::InitService;
+
+use super::GameFlowService;
+
+/// 未进入选秀池的新秀以自由球员身份入市的最低年龄
+const FREE_AGENT_MIN_AGE: u8 = 18;
+
+impl GameFlowService {
+    /// 年度青训：为各赛区生成本赛季新秀届次，写入选秀池与自由球员市场
+    ///
+    /// 同一赛季只执行一次，重复调用直接返回已有记录；各赛区在同一事务内写入，
+    /// 中途失败整体回滚，保证届次记录与新秀数据同生同灭
+    pub async fn run_youth_intake(
+        &self,
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: u64,
+    ) -> Result<Vec<TalentClassRecord>, String> {
+        let existing = Self::load_talent_classes(pool, save_id, Some(season_id)).await?;
+        if !existing.is_empty() {
+            log::debug!("S{} 青训届次已生成，跳过", season_id);
+            return Ok(existing);
+        }
+
+        let region_rows = sqlx::query(
+            r#"
+            SELECT r.id, r.name,
+                   COALESCE(AVG(t.balance), 0) as avg_balance,
+                   COALESCE(AVG(t.brand_value), 0) as avg_brand
+            FROM regions r
+            LEFT JOIN teams t ON t.region_id = r.id AND t.save_id = r.save_id
+            WHERE r.save_id = ?
+            GROUP BY r.id, r.name
+            ORDER BY r.id
+            "#,
+        )
+        .bind(save_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询赛区财力失败: {}", e))?;
+
+        let profiles: Vec<RegionTalentProfile> = region_rows
+            .iter()
+            .map(|row| {
+                let name: String = row.get("name");
+                RegionTalentProfile::new(
+                    row.get::<i64, _>("id") as u64,
+                    RegionCode::from_str(&name),
+                    row.get::<f64, _>("avg_balance"),
+                    row.get::<f64, _>("avg_brand"),
+                )
+            })
+            .collect();
+        if profiles.is_empty() {
+            return Ok(Vec::new());
+        }
+        let region_names: HashMap<u64, String> = region_rows
+            .iter()
+            .map(|row| (row.get::<i64, _>("id") as u64, row.get::<String, _>("name")))
+            .collect();
+
+        let mut existing_ids: HashSet<String> = sqlx::query_scalar(
+            "SELECT game_id FROM draft_pool WHERE save_id = ? UNION SELECT game_id FROM players WHERE save_id = ?",
+        )
+        .bind(save_id)
+        .bind(save_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询已有选手ID失败: {}", e))?
+        .into_iter()
+        .collect();
+
+        let classes = TalentPipelineEngine::from_entropy().run_intake(&profiles, &mut existing_ids);
+        let mut rng = StdRng::from_entropy();
+        let mut records = Vec::with_capacity(classes.len());
+
+        let mut tx = pool.begin().await.map_err(|e| format!("开启事务失败: {}", e))?;
+        for class in classes {
+            let record = Self::persist_intake_class(&mut *tx, save_id, season_id, class, &region_names, &mut rng).await?;
+            log::info!(
+                "S{} {} 青训届次: {} 人 (选秀池 {}, 自由球员 {}, 流入 {}, 流出 {}){}",
+                season_id,
+                record.region_name,
+                record.prospects_generated,
+                record.to_draft_pool,
+                record.to_free_agency,
+                record.immigrants,
+                record.emigrants,
+                if record.is_golden { " 【黄金一代】" } else { "" }
+            );
+            records.push(record);
+        }
+        tx.commit().await.map_err(|e| format!("提交事务失败: {}", e))?;
+
+        Ok(records)
+    }
+
+    /// 写入单个赛区的一届新秀并记录届次信息
+    async fn persist_intake_class(
+        conn: &mut SqliteConnection,
+        save_id: &str,
+        season_id: u64,
+        class: IntakeClass,
+        region_names: &HashMap<u64, String>,
+        rng: &mut StdRng,
+    ) -> Result<TalentClassRecord, String> {
+        let profile = class.profile.clone();
+        let strength = class.strength;
+        let (generated, immigrants, emigrants) = (class.generated, class.immigrants, class.emigrants);
+        let avg_potential = if class.prospects.is_empty() {
+            0.0
+        } else {
+            class.prospects.iter().map(|p| p.rookie.potential as f64).sum::<f64>() / class.prospects.len() as f64
+        };
+
+        let (draft, free_agents) = class.split_for_draft();
+
+        for prospect in &draft {
+            let r = &prospect.rookie;
+            sqlx::query(
+                r#"
+                INSERT INTO draft_pool (save_id, region_id, game_id, real_name, nationality, age, ability, potential, position, tag, status, created_season, origin_region_id)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'available', ?, ?)
+                "#,
+            )
+            .bind(save_id)
+            .bind(prospect.region_id as i64)
+            .bind(&r.game_id)
+            .bind(&r.real_name)
+            .bind(&r.nationality)
+            .bind(r.age as i64)
+            .bind(r.ability as i64)
+            .bind(r.potential as i64)
+            .bind(&r.position)
+            .bind(&r.tag)
+            .bind(season_id as i64)
+            .bind(prospect.origin_region_id as i64)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| format!("写入选秀池失败: {}", e))?;
+        }
+
+        for prospect in &free_agents {
+            Self::create_prospect_free_agent(&mut *conn, save_id, season_id, prospect, rng).await?;
+        }
+
+        let record = TalentClassRecord {
+            season_id,
+            region_id: profile.region_id,
+            region_name: region_names.get(&profile.region_id).cloned().unwrap_or_default(),
+            talent_density: profile.talent_density,
+            infrastructure: profile.infrastructure,
+            ability_shift: strength.ability_shift,
+            is_golden: strength.is_golden,
+            prospects_generated: generated,
+            to_draft_pool: draft.len() as u32,
+            to_free_agency: free_agents.len() as u32,
+            immigrants,
+            emigrants,
+            avg_potential,
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO talent_classes (
+                save_id, season_id, region_id, talent_density, infrastructure, ability_shift, is_golden,
+                prospects_generated, to_draft_pool, to_free_agency, immigrants, emigrants, avg_potential
+            ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id as i64)
+        .bind(record.region_id as i64)
+        .bind(record.talent_density)
+        .bind(record.infrastructure)
+        .bind(record.ability_shift as i64)
+        .bind(record.is_golden as i64)
+        .bind(record.prospects_generated as i64)
+        .bind(record.to_draft_pool as i64)
+        .bind(record.to_free_agency as i64)
+        .bind(record.immigrants as i64)
+        .bind(record.emigrants as i64)
+        .bind(record.avg_potential)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("写入青训届次失败: {}", e))?;
+
+        Ok(record)
+    }
+
+    /// 未进入选秀池的新秀以自由球员身份入市（流程与初始自由选手一致）
+    async fn create_prospect_free_agent(
+        conn: &mut SqliteConnection,
+        save_id: &str,
+        season_id: u64,
+        prospect: &Prospect,
+        rng: &mut StdRng,
+    ) -> Result<u64, String> {
+        let r = &prospect.rookie;
+        let age = r.age.max(FREE_AGENT_MIN_AGE);
+        let position = Position::from_str(&r.position);
+        let tag = InitService::determine_player_tag(r.ability, r.potential, age);
+
+        let player = Player {
+            id: 0,
+            game_id: r.game_id.clone(),
+            real_name: Some(r.real_name.clone()),
+            nationality: Some(r.nationality.clone()),
+            age,
+            ability: r.ability,
+            potential: r.potential,
+            stability: Player::calculate_stability(age),
+            tag,
+            status: PlayerStatus::Active,
+            position: Some(position),
+            team_id: None,
+            salary: InitService::calculate_initial_salary(r.ability, r.potential, tag),
+            market_value: MarketValueEngine::calculate_base_market_value_enum(r.ability, age, r.potential, &tag, &position),
+            calculated_market_value: 0,
+            contract_end_season: None,
+            join_season: season_id as u32,
+            retire_season: None,
+            is_starter: false,
+            loyalty: InitService::calculate_initial_loyalty(r.ability, r.potential, age, tag),
+            satisfaction: InitService::calculate_initial_satisfaction(r.ability, r.potential, age, false, tag),
+            growth_accumulator: 0.0,
+        };
+
+        let player_id = PlayerRepository::create_with_conn(&mut *conn, save_id, &player)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        sqlx::query("UPDATE players SET home_region_id = ? WHERE id = ?")
+            .bind(prospect.region_id as i64)
+            .bind(player_id as i64)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| format!("设置新秀自由球员赛区失败 {}: {}", player_id, e))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO player_form_factors (
+                save_id, player_id, form_cycle, momentum,
+                last_performance, last_match_won, perf_history, games_since_rest
+            ) VALUES (?, ?, ?, 0, 0.0, 1, '', 0)
+            "#,
+        )
+        .bind(save_id)
+        .bind(player_id as i64)
+        .bind(rng.gen_range(0.0..100.0))
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("初始化新秀状态因子失败 {}: {}", player_id, e))?;
+
+        InitService::assign_champion_mastery_with_conn(&mut *conn, save_id, player_id, position, r.ability, rng).await?;
+
+        Ok(player_id)
+    }
+
+    /// 查询青训届次记录（season_id 为空时返回全部赛季）
+    pub async fn load_talent_classes(
+        pool: &Pool<Sqlite>,
+        save_id: &str,
+        season_id: Option<u64>,
+    ) -> Result<Vec<TalentClassRecord>, String> {
+        let rows = sqlx::query(
+            r#"
+            SELECT tc.*, r.name as region_name
+            FROM talent_classes tc
+            LEFT JOIN regions r ON r.id = tc.region_id
+            WHERE tc.save_id = ? AND (? IS NULL OR tc.season_id = ?)
+            ORDER BY tc.season_id DESC, tc.region_id
+            "#,
+        )
+        .bind(save_id)
+        .bind(season_id.map(|s| s as i64))
+        .bind(season_id.map(|s| s as i64))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("查询青训届次失败: {}", e))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| TalentClassRecord {
+                season_id: row.get::<i64, _>("season_id") as u64,
+                region_id: row.get::<i64, _>("region_id") as u64,
+                region_name: row.get::<Option<String>, _>("region_name").unwrap_or_default(),
+                talent_density: row.get("talent_density"),
+                infrastructure: row.get("infrastructure"),
+                ability_shift: row.get::<i64, _>("ability_shift") as i32,
+                is_golden: row.get::<i64, _>("is_golden") != 0,
+                prospects_generated: row.get::<i64, _>("prospects_generated") as u32,
+                to_draft_pool: row.get::<i64, _>("to_draft_pool") as u32,
+                to_free_agency: row.get::<i64, _>("to_free_agency") as u32,
+                immigrants: row.get::<i64, _>("immigrants") as u32,
+                emigrants: row.get::<i64, _>("emigrants") as u32,
+                avg_potential: row.get("avg_potential"),
+            })
+            .collect())
+    }
+}
@@ -10,7 +10,7 @@ use crate::engines::champion::{self, MasteryTier};
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use sqlx::{Pool, Sqlite};
+use sqlx::{Pool, Sqlite, SqliteConnection};
 
 /// 初始化服务 - 生成游戏初始数据
 pub struct InitService;
@@ -287,7 +287,7 @@ impl InitService {
 
     /// 判定选手标签 (Ordinary=平庸, Normal=一般, Genius=天才)
     /// 缩放后阈值：天才 ability≥68 且 potential≥67；平庸 ability≤59 或 potential≤59；其余为一般
-    fn determine_player_tag(ability: u8, potential: u8, _age: u8) -> PlayerTag {
+    pub(crate) fn determine_player_tag(ability: u8, potential: u8, _age: u8) -> PlayerTag {
         if ability >= 68 && potential >= 67 {
             PlayerTag::Genius
         } else if ability <= 59 || potential <= 59 {
@@ -302,7 +302,7 @@ impl InitService {
     ///
     /// 设计原则：大多数选手（70-80%）应该对球队有合理的忠诚度
     /// 只有少数选手会有较低的忠诚度想离队
-    fn calculate_initial_loyalty(ability: u8, potential: u8, age: u8, tag: PlayerTag) -> u8 {
+    pub(crate) fn calculate_initial_loyalty(ability: u8, potential: u8, age: u8, tag: PlayerTag) -> u8 {
         let mut rng = rand::thread_rng();
 
         // 基础值提高到 72（大多数选手应该有较高忠诚度）
@@ -350,7 +350,7 @@ impl InitService {
     ///
     /// 设计原则：大多数选手（70-80%）应该对当前处境较为满意
     /// 首发选手普遍满意，替补选手有机会稍微不满
-    fn calculate_initial_satisfaction(ability: u8, potential: u8, age: u8, is_starter: bool, tag: PlayerTag) -> u8 {
+    pub(crate) fn calculate_initial_satisfaction(ability: u8, potential: u8, age: u8, is_starter: bool, tag: PlayerTag) -> u8 {
         let mut rng = rand::thread_rng();
 
         // 基础值提高：首发 78，替补 68
@@ -406,7 +406,7 @@ impl InitService {
 
     /// 计算初始薪资（单位：元）
     /// 与 MarketValueEngine::estimate_salary 对齐
-    fn calculate_initial_salary(ability: u8, _potential: u8, tag: PlayerTag) -> u64 {
+    pub(crate) fn calculate_initial_salary(ability: u8, _potential: u8, tag: PlayerTag) -> u64 {
         // 使用 MarketValueEngine 计算基准薪资，保证与评估体系一致
         let base_market_value = MarketValueEngine::calculate_base_market_value_enum(
             ability, 23, ability, // 23岁作为初始典型年龄，potential=ability
@@ -791,13 +791,26 @@ impl InitService {
     /// - ability < 54: 0 SS + 0 S + 1 A = 1 个非 B
     /// 
     /// 只分配本位置的 10 个英雄，B 级不存库
-    async fn assign_champion_mastery(
+    pub(crate) async fn assign_champion_mastery(
         pool: &Pool<Sqlite>,
         save_id: &str,
         player_id: u64,
         position: Position,
         ability: u8,
         rng: &mut StdRng,
+    ) -> Result<(), String> {
+        let mut conn = pool.acquire().await.map_err(|e| e.to_string())?;
+        Self::assign_champion_mastery_with_conn(&mut conn, save_id, player_id, position, ability, rng).await
+    }
+
+    /// 在指定连接（可为事务）上分配英雄熟练度
+    pub(crate) async fn assign_champion_mastery_with_conn(
+        conn: &mut SqliteConnection,
+        save_id: &str,
+        player_id: u64,
+        position: Position,
+        ability: u8,
+        rng: &mut StdRng,
     ) -> Result<(), String> {
         let mut position_champions: Vec<u8> = champion::get_champions_by_position(position)
             .iter()
@@ -838,7 +851,7 @@ impl InitService {
             .bind(player_id as i64)
             .bind(champion_id as i64)
             .bind(tier.id())
-            .execute(pool)
+            .execute(&mut *conn)
             .await
             .map_err(|e| format!("Failed to insert champion mastery for player {}: {}", player_id, e))?;
             
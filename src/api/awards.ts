@@ -110,10 +110,40 @@ export interface AnnualAwardsData {
   most_dominant: SpecialAwardPlayer | null
   rookie_of_the_year: RookiePlayer | null
   already_awarded: boolean
+  /** 各奖项投票明细（颁奖后才有） */
+  award_votes: AwardVoteResult[]
+}
+
+/** 单个选手在某奖项中的得票 */
+export interface VoteTally {
+  player_id: number
+  player_name: string
+  team_id: number
+  team_name: string
+  position: string
+  media_points: number
+  pros_points: number
+  fans_points: number
+  first_place_votes: number
+  total_score: number
+  rank: number
+  is_winner: boolean
+}
+
+/** 奖项投票结果（赛区奖按赛区、最佳阵容按位置分组） */
+export interface AwardVoteResult {
+  award_id: string
+  award_name: string
+  scope_key: string
+  scope_label: string
+  tallies: VoteTally[]
 }
 
 export const awardsApi = {
   /** 获取年度颁奖数据 */
   getAnnualAwardsData: (seasonId?: number) =>
     invokeCommand<AnnualAwardsData>('get_annual_awards_data', { seasonId }),
+  /** 获取年度奖项投票明细 */
+  getAwardVotes: (seasonId?: number, awardId?: string) =>
+    invokeCommand<AwardVoteResult[]>('get_award_votes', { seasonId, awardId }),
 }
@@ -0,0 +1,44 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 英雄熟练度训练计划
+// ========================================
+
+export interface TrainingAllocation {
+  champion_id: number
+  hours: number
+}
+
+export interface ChampionTrainingEntry {
+  team_id: number
+  player_id: number
+  champion_id: number
+  champion_name: string
+  hours: number
+  is_auto: boolean  // 由 AI 自动安排
+  applied: boolean  // 已在阶段结束时结算
+  progress_gain: number
+  tier_after: string | null
+}
+
+export interface TrainingPlanSummary {
+  season_id: number
+  phase: string
+  hours_per_player: number  // 每名选手每阶段可用训练时长
+  entries: ChampionTrainingEntry[]
+}
+
+/** 获取队伍本阶段的英雄训练计划 */
+export async function getChampionTrainingPlan(teamId: number) {
+  return invokeCommand<TrainingPlanSummary>('get_champion_training_plan', { teamId })
+}
+
+/** 为选手安排本阶段的英雄训练（阶段结束时结算） */
+export async function setChampionTrainingPlan(playerId: number, allocations: TrainingAllocation[]) {
+  return invokeCommand<void>('set_champion_training_plan', { playerId, allocations })
+}
+
+/** 取消选手本阶段尚未结算的训练计划 */
+export async function clearChampionTrainingPlan(playerId: number) {
+  return invokeCommand<void>('clear_champion_training_plan', { playerId })
+}
@@ -0,0 +1,39 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 阵容强势期规则
+// ========================================
+
+/** 决出胜负的比赛阶段 */
+export type GamePhase = 'Early' | 'Mid' | 'Late'
+
+/** 单个阵容体系的三段战力修正 */
+export interface PhaseCurve {
+  early: number
+  mid: number
+  late: number
+}
+
+export interface CompPhaseRuleset {
+  early_decisive_margin: number  // 前期发挥差超过该值直接结束
+  mid_decisive_margin: number  // 中期发挥差超过该值直接结束
+  early_minutes: [number, number]  // 各阶段结束比赛的时长区间
+  mid_minutes: [number, number]
+  late_minutes: [number, number]
+  curves: Record<string, PhaseCurve>  // 键为阵容体系（CompType）
+}
+
+/** 获取当前存档生效的阵容强势期规则 */
+export async function getCompPhaseRuleset() {
+  return invokeCommand<CompPhaseRuleset>('get_comp_phase_ruleset')
+}
+
+/** 覆盖当前存档的阵容强势期规则 */
+export async function updateCompPhaseRuleset(ruleset: CompPhaseRuleset) {
+  return invokeCommand<CompPhaseRuleset>('update_comp_phase_ruleset', { ruleset })
+}
+
+/** 恢复内置规则 */
+export async function resetCompPhaseRuleset() {
+  return invokeCommand<CompPhaseRuleset>('reset_comp_phase_ruleset')
+}
@@ -0,0 +1,50 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 系列赛 BP 规则
+// ========================================
+
+/** Standard 标准BP / Fearless 全局BP / SoftFearless 软全局BP / GlobalBanPool 全局禁用池 */
+export type DraftRule = 'Standard' | 'Fearless' | 'SoftFearless' | 'GlobalBanPool'
+
+export interface DraftRuleOption {
+  rule: DraftRule
+  name: string
+}
+
+export interface TournamentDraftRule {
+  tournament_id: number
+  stage: string  // 空字符串表示赛事全部阶段的默认规则
+  rule: DraftRule
+  global_bans: number[]  // 全局禁用池（仅 GlobalBanPool 生效）
+}
+
+/** 获取全部可选的 BP 规则 */
+export async function getDraftRuleOptions() {
+  return invokeCommand<DraftRuleOption[]>('get_draft_rule_options')
+}
+
+/** 获取赛事已配置的 BP 规则 */
+export async function getTournamentDraftRules(tournamentId: number) {
+  return invokeCommand<TournamentDraftRule[]>('get_tournament_draft_rules', { tournamentId })
+}
+
+/** 设置赛事（阶段）的 BP 规则，不传 stage 时作为赛事默认 */
+export async function setTournamentDraftRule(
+  tournamentId: number,
+  rule: DraftRule,
+  stage?: string,
+  globalBans?: number[],
+) {
+  return invokeCommand<TournamentDraftRule>('set_tournament_draft_rule', {
+    tournamentId,
+    stage: stage ?? null,
+    rule,
+    globalBans: globalBans ?? null,
+  })
+}
+
+/** 删除赛事（阶段）的 BP 规则 */
+export async function deleteTournamentDraftRule(tournamentId: number, stage?: string) {
+  return invokeCommand<void>('delete_tournament_draft_rule', { tournamentId, stage: stage ?? null })
+}
@@ -43,6 +43,7 @@ export interface SeasonFinanceReport {
   salary_expense: number
   prize_money: number
   sponsorship: number
+  sponsor_bonus: number  // 赞助绩效奖金
   league_share: number
   transfer_net: number
   operating_cost: number
@@ -0,0 +1,101 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 局内时间线
+// ========================================
+
+/** 时间线事件类型 */
+export type TimelineEventKind =
+  | 'FirstBlood'
+  | 'SoloKill'
+  | 'FirstTower'
+  | 'Tower'
+  | 'Dragon'
+  | 'DragonSoul'
+  | 'Herald'
+  | 'Baron'
+  | 'Teamfight'
+  | 'Inhibitor'
+  | 'Nexus'
+
+/** 对线期单路结果 */
+export interface LaneOutcome {
+  position: string
+  home_player_id: number
+  away_player_id: number
+  winner_team_id: number | null  // null 表示均势
+  gold_diff: number  // 主队视角
+}
+
+export interface TimelineKill {
+  minute: number
+  team_id: number
+  killer_id: number
+  victim_id: number
+  assist_ids: number[]
+}
+
+export interface TimelineEvent {
+  minute: number
+  kind: TimelineEventKind
+  team_id: number  // 获利方
+  description: string
+  gold_swing: number
+}
+
+/** 经济曲线采样点（每分钟） */
+export interface GoldPoint {
+  minute: number
+  home_gold: number
+  away_gold: number
+  gold_diff: number  // 主队领先值
+}
+
+export interface TimelinePlayerLine {
+  player_id: number
+  team_id: number
+  kills: number
+  deaths: number
+  assists: number
+  cs: number
+  gold: number
+}
+
+export interface GameTimeline {
+  home_team_id: number
+  away_team_id: number
+  winner_id: number
+  duration_minutes: number
+  home_comp: string | null
+  away_comp: string | null
+  lanes: LaneOutcome[]
+  events: TimelineEvent[]
+  kills: TimelineKill[]
+  gold_curve: GoldPoint[]
+  players: TimelinePlayerLine[]
+  home_dragons: number
+  away_dragons: number
+  home_towers: number
+  away_towers: number
+}
+
+export interface SavedGameTimeline {
+  match_id: number
+  game_number: number
+  timeline: GameTimeline
+}
+
+/** 获取一场比赛各小局的时间线 */
+export async function getMatchTimelines(matchId: number) {
+  return invokeCommand<SavedGameTimeline[]>('get_match_timelines', { matchId })
+}
+
+/** 当前存档是否启用局内时间线 */
+export async function getGameTimelineEnabled() {
+  return invokeCommand<boolean>('get_game_timeline_enabled')
+}
+
+/** 开关局内时间线 */
+export async function setGameTimelineEnabled(enabled: boolean) {
+  return invokeCommand<boolean>('set_game_timeline_enabled', { enabled })
+}
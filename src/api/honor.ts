@@ -145,6 +145,11 @@ export const HONOR_TYPE_MAP: Record<string, string> = {
   'ANNUAL_BEST_ADC': '年度最佳ADC',
   'ANNUAL_BEST_SUPPORT': '年度最佳辅助',
   'ANNUAL_ROOKIE': '年度最佳新秀',
+  'ANNUAL_MOST_IMPROVED': '年度进步最快选手',
+  'ANNUAL_COMEBACK': '年度最佳复出选手',
+  'ANNUAL_SIXTH_MAN': '年度最佳第六人',
+  'ANNUAL_BEST_IMPORT': '年度最佳外援',
+  'ANNUAL_REGIONAL_MVP': '赛区年度MVP',
 }
 
 // 荣誉类型对应的标签颜色
@@ -176,6 +181,11 @@ export const HONOR_TAG_TYPE: Record<string, string> = {
   'ANNUAL_BEST_ADC': 'success',    // 绿色
   'ANNUAL_BEST_SUPPORT': 'success',// 绿色
   'ANNUAL_ROOKIE': 'primary',      // 主色（新秀）
+  'ANNUAL_MOST_IMPROVED': 'success',  // 绿色
+  'ANNUAL_COMEBACK': 'success',       // 绿色
+  'ANNUAL_SIXTH_MAN': 'info',         // 蓝色
+  'ANNUAL_BEST_IMPORT': 'primary',    // 主色
+  'ANNUAL_REGIONAL_MVP': 'danger',    // 红色
 }
 
 export function getHonorTagType(honorType: string): string {
@@ -1,5 +1,7 @@
 import { invoke } from '@tauri-apps/api/core'
 
+export type RatingSystem = 'Glicko2' | 'TrueSkill'
+
 export interface LadderTournamentInfo {
   id: number
   save_id: string
@@ -10,6 +12,7 @@ export interface LadderTournamentInfo {
   total_rounds: number
   current_round: number
   status: string
+  rating_system: RatingSystem
 }
 
 export interface LadderRankingEntry {
@@ -27,6 +30,10 @@ export interface LadderRankingEntry {
   mvp_count: number
   avg_influence: number
   max_rating: number
+  rating_deviation: number
+  volatility: number
+  /** 路人/主播类型，职业选手为 null */
+  amateur_kind: 'Amateur' | 'Streamer' | null
 }
 
 export interface LadderPlayerInfo {
@@ -48,6 +55,8 @@ export interface LadderMatchInfo {
   red_avg_rating: number
   winner_side: string | null
   mvp_player_name: string | null
+  match_quality: number | null
+  queue_time_secs: number | null
 }
 
 export interface PlayerReward {
@@ -123,6 +132,8 @@ export interface LadderMatchDetail {
   performances: Record<string, number> | null
   draft_result_json: string | null
   rating_changes: Record<string, number> | null
+  match_quality: number | null
+  queue_time_secs: number | null
 }
 
 export async function getLadderMatchDetail(
@@ -145,6 +156,9 @@ export interface RatingHistoryPoint {
   round: number
   rating: number
   change: number
+  deviation: number
+  volatility: number
+  expected_score: number | null
 }
 
 export async function getPlayerLadderRatingHistory(
@@ -153,3 +167,59 @@ export async function getPlayerLadderRatingHistory(
 ): Promise<RatingHistoryPoint[]> {
   return invokeCmd<RatingHistoryPoint[]>('get_player_ladder_rating_history', { tournamentId, playerId })
 }
+
+export async function setLadderRatingSystem(
+  tournamentId: number,
+  ratingSystem: RatingSystem
+): Promise<void> {
+  await invokeCmd<null>('set_ladder_rating_system', { tournamentId, ratingSystem })
+}
+
+export type LadderTier =
+  | 'Iron' | 'Bronze' | 'Silver' | 'Gold' | 'Platinum'
+  | 'Diamond' | 'Master' | 'Grandmaster' | 'Challenger'
+
+export interface LadderRank {
+  tier: LadderTier
+  division: number | null
+}
+
+export interface LadderProfile {
+  subject_type: 'Player' | 'Prospect'
+  subject_id: number
+  rating: number
+  rating_deviation: number
+  volatility: number
+  peak_rating: number
+  games_played: number
+  wins: number
+  losses: number
+  season_games: number
+  season_peak: number
+  updated_season: number
+  rank: LadderRank
+  rank_label: string
+}
+
+export interface LadderSeasonRecord {
+  season: number
+  final_rating: number
+  peak_rating: number
+  rank: LadderRank
+  rank_label: string
+  games_played: number
+  wins: number
+}
+
+export interface LadderProfileDetail {
+  profile: LadderProfile | null
+  seasons: LadderSeasonRecord[]
+}
+
+export async function getLadderProfile(playerId: number): Promise<LadderProfileDetail> {
+  return invokeCmd<LadderProfileDetail>('get_ladder_profile', { playerId })
+}
+
+export async function getLadderLeaderboard(limit?: number): Promise<LadderProfile[]> {
+  return invokeCmd<LadderProfile[]>('get_ladder_leaderboard', { limit: limit ?? null })
+}
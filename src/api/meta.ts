@@ -20,11 +20,18 @@ export interface MetaInfo {
   meta_name: string
   description: string
   weights: MetaWeightsInfo
+  /** 赛季内第几次补丁（赛季初为 1） */
+  patch_number: number
+  /** 本次版本调整的原因 */
+  reason: string | null
 }
 
 /** Meta 历史记录 */
 export interface MetaHistoryEntry {
   season_id: number
+  patch_number: number
+  /** 补丁生效的赛季阶段 */
+  phase: string | null
   meta_type: string
   meta_name: string
   weight_top: number
@@ -32,6 +39,21 @@ export interface MetaHistoryEntry {
   weight_mid: number
   weight_adc: number
   weight_sup: number
+  reason: string | null
+}
+
+/** 赛区风格档案（各风格偏好 -1 ~ 1，0 为中立） */
+export interface RegionStyleProfile {
+  region_id: number
+  region_name: string
+  style: 'Skirmish' | 'Macro' | 'Teamfight' | 'LateGame' | null
+  aggressive: number
+  scaling: number
+  utility: number
+  splitpush: number
+  teamfight: number
+  /** 最近一次吸收其风格的赛区 */
+  adopted_from: string | null
 }
 
 /** Meta 类型信息 */
@@ -52,6 +74,11 @@ export async function getMetaHistory(saveId: string) {
   return invokeCommand<MetaHistoryEntry[]>('get_meta_history', { saveId })
 }
 
+/** 获取各赛区对版本的理解（风格档案） */
+export async function getRegionMetaStyles(saveId: string) {
+  return invokeCommand<RegionStyleProfile[]>('get_region_meta_styles', { saveId })
+}
+
 /** 获取全部 20 种 Meta 类型配置 */
 export async function getAllMetaTypes() {
   return invokeCommand<MetaTypeInfo[]>('get_all_meta_types')
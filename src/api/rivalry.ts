@@ -0,0 +1,79 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 历史交锋与宿敌
+// ========================================
+
+export interface RivalryMeeting {
+  match_id: number
+  season_id: number
+  tournament_name: string
+  tournament_type: string
+  stage: string
+  home_team_id: number
+  away_team_id: number
+  home_score: number
+  away_score: number
+  winner_id: number
+}
+
+export interface TeamHeadToHead {
+  save_id: string
+  team1_id: number  // team1_id < team2_id
+  team2_id: number
+  team1_name: string
+  team2_name: string
+  total_matches: number
+  team1_wins: number
+  team2_wins: number
+  team1_games: number
+  team2_games: number
+  last_match_id: number | null
+  final_meetings: number
+  playoff_meetings: number
+  international_meetings: number
+  last_meeting_season: number | null
+  rivalry_score: number
+  is_rivalry: boolean
+  rivalry_reasons: string[]
+  recent_meetings: RivalryMeeting[]  // 最新在前
+}
+
+export interface PlayerHeadToHead {
+  player_id: number
+  player_name: string
+  opponent_id: number
+  opponent_name: string
+  position: string
+  games: number
+  wins: number
+  losses: number
+  kills: number
+  deaths: number
+  assists: number
+  opponent_kills: number
+  opponent_deaths: number
+  opponent_assists: number
+  avg_impact: number
+  opponent_avg_impact: number
+}
+
+/** 获取两支战队的历史交锋 */
+export async function getTeamHeadToHead(teamAId: number, teamBId: number) {
+  return invokeCommand<TeamHeadToHead>('get_team_head_to_head', { teamAId, teamBId })
+}
+
+/** 获取宿敌列表（可限定某支战队） */
+export async function getTeamRivalries(teamId?: number, limit?: number) {
+  return invokeCommand<TeamHeadToHead[]>('get_team_rivalries', { teamId, limit })
+}
+
+/** 获取两名选手的同位置对位交锋 */
+export async function getPlayerHeadToHead(playerId: number, opponentId: number) {
+  return invokeCommand<PlayerHeadToHead | null>('get_player_head_to_head', { playerId, opponentId })
+}
+
+/** 获取选手最常交手的对位对手 */
+export async function getPlayerLaneOpponents(playerId: number, limit?: number) {
+  return invokeCommand<PlayerHeadToHead[]>('get_player_lane_opponents', { playerId, limit })
+}
@@ -0,0 +1,37 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 老将角色转型
+// ========================================
+
+/** 合同角色 */
+export type ContractRole = 'Starter' | 'Sub' | 'Prospect' | 'SixthMan' | 'PlayingCoach'
+
+/** 转位置训练记录 */
+export interface PositionRetraining {
+  id: number
+  player_id: number
+  player_name: string
+  team_id: number | null
+  from_position: string
+  to_position: string
+  start_season: number
+  ability_penalty: number  // 训练期间临时扣除的能力
+  status: 'InProgress' | 'Completed'
+  completed_season: number | null
+}
+
+/** 开始转位置训练 */
+export async function startPositionRetraining(playerId: number, toPosition: string) {
+  return invokeCommand<PositionRetraining>('start_position_retraining', { playerId, toPosition })
+}
+
+/** 获取转位置训练记录（不传队伍则返回全部） */
+export async function getPositionRetrainings(teamId?: number) {
+  return invokeCommand<PositionRetraining[]>('get_position_retrainings', { teamId })
+}
+
+/** 签下选手兼教练 / 第六人合同，返回调整后的薪资 */
+export async function assignVeteranRole(playerId: number, role: 'SixthMan' | 'PlayingCoach') {
+  return invokeCommand<number>('assign_veteran_role', { playerId, role })
+}
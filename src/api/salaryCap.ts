@@ -0,0 +1,58 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 薪资帽与奢侈税
+// ========================================
+
+/** 薪资帽类型：硬帽 / 软帽 */
+export type CapType = 'Hard' | 'Soft'
+
+/** 赛区薪资帽规则（元/赛季） */
+export interface CapRules {
+  cap_type: CapType
+  salary_cap: number
+  apron: number  // 签约上限线（硬帽等于帽线）
+  individual_salary_cap: number
+  luxury_tax_rate: number
+}
+
+/** 薪资表条目 */
+export interface CapSheetEntry {
+  player_id: number
+  player_name: string
+  position: string
+  salary: number
+  contract_end_season: number | null
+  is_expiring: boolean  // 本赛季到期，按续约预留额计入
+  cap_hit: number
+}
+
+/** 死钱条目 */
+export interface DeadCapEntry {
+  player_id: number
+  player_name: string
+  amount: number
+  reason: string
+}
+
+/** 球队薪资表 */
+export interface TeamCapSheet {
+  team_id: number
+  team_name: string
+  season_id: number
+  region_code: string
+  rules: CapRules
+  entries: CapSheetEntry[]
+  dead_cap: DeadCapEntry[]
+  committed_salary: number
+  cap_holds: number
+  dead_cap_total: number
+  payroll: number
+  cap_space: number  // 可为负
+  projected_luxury_tax: number
+}
+
+/** 获取球队薪资表 */
+export async function getTeamCapSheet(teamId: number) {
+  return invokeCommand<TeamCapSheet>('get_team_cap_sheet', { teamId })
+}
@@ -0,0 +1,70 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 球探系统
+// ========================================
+
+/** 球探派遣目标：赛区职业选手 / 赛区选秀池 */
+export type ScoutTarget = 'Region' | 'DraftPool'
+
+/** 球探派遣记录 */
+export interface ScoutingAssignment {
+  id: number
+  team_id: number
+  target_type: ScoutTarget
+  region_id: number
+  assigned_season: number
+  phases_active: number
+}
+
+/** 评分区间（闭区间） */
+export interface RatingRange {
+  min: number
+  max: number
+}
+
+/** 由评分派生的数值区间（如身价） */
+export interface ValueRange {
+  min: number
+  max: number
+}
+
+/** 某队视角下的选手评分情报 */
+export interface ScoutedRatings {
+  viewer_team_id: number
+  knowledge: number  // 0.0 ~ 1.0
+  ability_range: RatingRange
+  potential_range: RatingRange
+  hidden_trait_count: number
+  market_value_range: ValueRange | null  // 仅选手详情提供
+}
+
+/** 设置执教队伍（选手详情、选秀池默认以该队的球探情报展示；传 null 切换为观察者视角） */
+export async function setManagedTeam(teamId: number | null) {
+  return invokeCommand<void>('set_managed_team', { teamId })
+}
+
+/** 获取执教队伍 */
+export async function getManagedTeam() {
+  return invokeCommand<number | null>('get_managed_team')
+}
+
+/** 派遣球探 */
+export async function assignScout(teamId: number, targetType: ScoutTarget, regionId: number) {
+  return invokeCommand<ScoutingAssignment>('assign_scout', { teamId, targetType, regionId })
+}
+
+/** 召回球探（已积累的情报保留） */
+export async function recallScout(assignmentId: number) {
+  return invokeCommand<void>('recall_scout', { assignmentId })
+}
+
+/** 获取队伍的球探派遣列表 */
+export async function getScoutingAssignments(teamId: number) {
+  return invokeCommand<ScoutingAssignment[]>('get_scouting_assignments', { teamId })
+}
+
+/** 获取某队对某名选手的球探报告 */
+export async function getScoutingReport(teamId: number, playerId: number) {
+  return invokeCommand<ScoutedRatings>('get_scouting_report', { teamId, playerId })
+}
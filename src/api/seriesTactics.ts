@@ -0,0 +1,42 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 系列赛局间战术
+// ========================================
+
+export interface GameTactics {
+  comp_pool: string[]  // 切换后的阵容体系池，为空表示沿用原计划
+  target_player_id: number | null  // 重点针对的对手选手
+  aggressive: boolean  // 提高侵略性：战力上移但波动加大
+  is_manual: boolean
+  reasons: string[]
+}
+
+export interface TacticModifier {
+  power_shift: number
+  opponent_shift: number
+  variance_scale: number
+}
+
+export interface SeriesTacticRecord {
+  game_number: number
+  team_id: number
+  tactics: GameTactics
+  modifier: TacticModifier | null
+  applied: boolean  // 已在模拟中生效
+}
+
+/** 获取某场比赛各小局的局间战术 */
+export async function getSeriesTactics(matchId: number) {
+  return invokeCommand<SeriesTacticRecord[]>('get_series_tactics', { matchId })
+}
+
+/** 为队伍预设某一小局的战术（从第二局开始） */
+export async function setSeriesTactics(matchId: number, teamId: number, gameNumber: number, tactics: GameTactics) {
+  return invokeCommand<void>('set_series_tactics', { matchId, teamId, gameNumber, tactics })
+}
+
+/** 取消尚未生效的预设战术 */
+export async function clearSeriesTactics(matchId: number, teamId: number, gameNumber: number) {
+  return invokeCommand<void>('clear_series_tactics', { matchId, teamId, gameNumber })
+}
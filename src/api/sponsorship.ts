@@ -0,0 +1,59 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 赞助合同
+// ========================================
+
+/** 赞助级别：冠名 / 主要 / 普通 */
+export type SponsorTier = 'Title' | 'Major' | 'Minor'
+
+/** 赞助报价 */
+export interface SponsorOffer {
+  sponsor_code: string
+  sponsor_name: string
+  category: string
+  tier: SponsorTier
+  years: number
+  annual_base: number
+  title_bonus: number  // 每个冠军的奖金
+  international_bonus: number  // 晋级国际赛奖金
+  brand_floor: number  // 品牌价值低于此线可提前解约
+  min_win_rate: number  // 胜率低于此线可提前解约
+  is_exclusive: boolean  // 赛区内独家
+}
+
+/** 赞助合同 */
+export interface SponsorContract {
+  id: number
+  team_id: number
+  sponsor_code: string
+  sponsor_name: string
+  category: string
+  tier: SponsorTier
+  start_season: number
+  end_season: number
+  annual_base: number
+  title_bonus: number
+  international_bonus: number
+  brand_floor: number
+  min_win_rate: number
+  is_exclusive: boolean
+  status: 'Active' | 'Expired' | 'Terminated'
+  terminated_season: number | null
+  termination_reason: string | null
+}
+
+/** 获取赞助合同（不传队伍则返回全部） */
+export async function getSponsorContracts(teamId?: number) {
+  return invokeCommand<SponsorContract[]>('get_sponsor_contracts', { teamId })
+}
+
+/** 获取球队当前可签的赞助报价 */
+export async function getSponsorOffers(teamId: number) {
+  return invokeCommand<SponsorOffer[]>('get_sponsor_offers', { teamId })
+}
+
+/** 签下赞助合同 */
+export async function signSponsorContract(teamId: number, sponsorCode: string) {
+  return invokeCommand<SponsorContract>('sign_sponsor_contract', { teamId, sponsorCode })
+}
@@ -0,0 +1,27 @@
+import { invokeCommand } from './client'
+
+// ========================================
+// 青训管线
+// ========================================
+
+/** 某赛季某赛区的新秀届次 */
+export interface TalentClassRecord {
+  season_id: number
+  region_id: number
+  region_name: string
+  talent_density: number   // 人才密度，1.0 为基准
+  infrastructure: number   // 青训基建 0.0 ~ 1.0
+  ability_shift: number    // 届次强弱带来的能力偏移
+  is_golden: boolean       // 黄金一代
+  prospects_generated: number
+  to_draft_pool: number
+  to_free_agency: number
+  immigrants: number
+  emigrants: number
+  avg_potential: number
+}
+
+/** 获取青训届次记录（不传赛季则返回所有赛季） */
+export async function getTalentClasses(seasonId?: number) {
+  return invokeCommand<TalentClassRecord[]>('get_talent_classes', { seasonId })
+}
@@ -7,6 +7,8 @@ import { appDataDir, join } from '@tauri-apps/api/path'
 import { createLogger } from '@/utils/logger'
 import { usePerformanceStoreRaw } from '@/stores/usePerformanceStore'
 import type { GameInitConfig } from '@/types/initConfig'
+import type { ScoutedRatings } from './scouting'
+import type { GameTactics } from './seriesTactics'
 
 const logger = createLogger('TauriAPI')
 
@@ -277,6 +279,7 @@ export interface Player {
   is_starter: boolean
   satisfaction: number  // 满意度 0-100
   loyalty: number  // 忠诚度 0-100
+  scouting?: ScoutedRatings | null  // 执教队伍视角的球探情报，观察者视角时为 null
 }
 
 // 特性信息
@@ -305,6 +308,7 @@ export interface PlayerFullDetail {
   player: Player
   traits: TraitInfo[]
   condition_info: PlayerConditionInfo
+  scouting: ScoutedRatings | null  // 执教队伍视角的球探情报，观察者视角时为 null
 }
 
 // 选手属性更新请求
@@ -504,6 +508,22 @@ export const honorApi = {
   /** 获取名人堂 */
   getHallOfFame: () =>
     invoke<HallOfFameEntry[]>('get_hall_of_fame'),
+
+  /** 获取战队名人堂 */
+  getTeamHallOfFame: () =>
+    invoke<TeamHallOfFameEntry[]>('get_team_hall_of_fame'),
+
+  /** 获取退役球衣（可按战队筛选） */
+  getRetiredJerseys: (teamId?: number) =>
+    invoke<RetiredJerseyEntry[]>('get_retired_jerseys', { teamId }),
+
+  /** 获取纪录簿（scopeType: GLOBAL / REGION / LEAGUE） */
+  getRecordsBook: (scopeType?: RecordScopeType) =>
+    invoke<RecordEntry[]>('get_records_book', { scopeType }),
+
+  /** 获取某项纪录的刷新历史 */
+  getRecordHistory: (recordType: string, scopeType: RecordScopeType, scopeKey?: string) =>
+    invoke<RecordHistoryEntry[]>('get_record_history', { recordType, scopeType, scopeKey }),
 }
 
 export interface HallOfFameEntry {
@@ -518,6 +538,65 @@ export interface HallOfFameEntry {
   peak_ability: number | null
   career_seasons: number | null
   honors_json: string  // JSON string of honor details
+  retire_season: number | null
+  reasoning: string[]  // 入选理由
+}
+
+export interface TeamHallOfFameEntry {
+  id: number
+  team_id: number
+  team_name: string
+  induction_season: number
+  promoted_season: number | null
+  tier: string  // 'Legend' | 'HallOfFame'
+  total_score: number
+  reasoning: string[]
+}
+
+export interface RetiredJerseyEntry {
+  id: number
+  team_id: number
+  team_name: string
+  player_id: number
+  player_name: string
+  position: string | null
+  seasons_with_team: number
+  retired_season: number
+}
+
+export type RecordScopeType = 'GLOBAL' | 'REGION' | 'LEAGUE'
+
+export interface RecordEntry {
+  record_type: string  // 'LONGEST_WIN_STREAK' | 'LONGEST_GAME' | 'SHORTEST_GAME' | ...
+  record_name: string
+  scope_type: RecordScopeType
+  scope_key: string
+  scope_label: string
+  holder_kind: string  // 'TEAM' | 'PLAYER' | 'MATCH'
+  holder_id: number
+  holder_name: string
+  value: number
+  display_value: string
+  season_id: number
+  tournament_id: number | null
+  match_id: number | null
+}
+
+export interface RecordHistoryEntry {
+  id: number
+  record_type: string
+  scope_label: string
+  holder_kind: string
+  holder_id: number
+  holder_name: string
+  value: number
+  display_value: string
+  previous_holder_name: string | null
+  previous_value: number | null
+  previous_display_value: string | null
+  season_id: number
+  tournament_id: number | null
+  match_id: number | null
 }
 
 // 荣誉类型中文转换
@@ -549,6 +628,11 @@ export const HONOR_TYPE_MAP: Record<string, string> = {
   'ANNUAL_BEST_ADC': '年度最佳ADC',
   'ANNUAL_BEST_SUPPORT': '年度最佳辅助',
   'ANNUAL_ROOKIE': '年度最佳新秀',
+  'ANNUAL_MOST_IMPROVED': '年度进步最快选手',
+  'ANNUAL_COMEBACK': '年度最佳复出选手',
+  'ANNUAL_SIXTH_MAN': '年度最佳第六人',
+  'ANNUAL_BEST_IMPORT': '年度最佳外援',
+  'ANNUAL_REGIONAL_MVP': '赛区年度MVP',
 }
 
 // 荣誉类型对应的标签颜色
@@ -580,6 +664,11 @@ export const HONOR_TAG_TYPE: Record<string, string> = {
   'ANNUAL_BEST_ADC': 'success',    // 绿色
   'ANNUAL_BEST_SUPPORT': 'success',// 绿色
   'ANNUAL_ROOKIE': 'primary',      // 主色（新秀）
+  'ANNUAL_MOST_IMPROVED': 'success',  // 绿色
+  'ANNUAL_COMEBACK': 'success',       // 绿色
+  'ANNUAL_SIXTH_MAN': 'info',         // 蓝色
+  'ANNUAL_BEST_IMPORT': 'primary',    // 主色
+  'ANNUAL_REGIONAL_MVP': 'danger',    // 红色
 }
 
 export function getHonorTagType(honorType: string): string {
@@ -682,6 +771,7 @@ export interface DraftPlayer {
   tag: string
   draft_rank: number
   is_picked: boolean
+  scouting: ScoutedRatings | null  // 执教队伍视角的球探情报，观察者视角时为 null
 }
 
 export interface DraftOrder {
@@ -1249,6 +1339,7 @@ export interface SeasonFinanceReport {
   salary_expense: number
   prize_money: number
   sponsorship: number
+  sponsor_bonus: number  // 赞助绩效奖金
   league_share: number
   transfer_net: number
   operating_cost: number
@@ -1654,6 +1745,8 @@ export interface DetailedGameResult {
   away_bp_bonus: number | null
   home_version_bonus: number | null
   away_version_bonus: number | null
+  home_tactics: GameTactics | null  // 局间战术（第一局为空）
+  away_tactics: GameTactics | null
 }
 
 export interface PlayerGameStats {
@@ -2405,12 +2498,42 @@ export interface AnnualAwardsData {
   most_dominant: SpecialAwardPlayer | null
   rookie_of_the_year: RookiePlayer | null
   already_awarded: boolean
+  /** 各奖项投票明细（颁奖后才有） */
+  award_votes: AwardVoteResult[]
+}
+
+/** 单个选手在某奖项中的得票 */
+export interface VoteTally {
+  player_id: number
+  player_name: string
+  team_id: number
+  team_name: string
+  position: string
+  media_points: number
+  pros_points: number
+  fans_points: number
+  first_place_votes: number
+  total_score: number
+  rank: number
+  is_winner: boolean
+}
+
+/** 奖项投票结果（赛区奖按赛区、最佳阵容按位置分组） */
+export interface AwardVoteResult {
+  award_id: string
+  award_name: string
+  scope_key: string
+  scope_label: string
+  tallies: VoteTally[]
 }
 
 export const awardsApi = {
   /** 获取年度颁奖数据 */
   getAnnualAwardsData: (seasonId?: number) =>
     invokeCommand<AnnualAwardsData>('get_annual_awards_data', { seasonId }),
+  /** 获取年度奖项投票明细 */
+  getAwardVotes: (seasonId?: number, awardId?: string) =>
+    invokeCommand<AwardVoteResult[]>('get_award_votes', { seasonId, awardId }),
 }
 
 // ========================================
@@ -3287,6 +3410,10 @@ export interface ReleasePlayerResult {
   team_name: string
   release_fee: number
   remaining_balance: number
+  /** 计入本赛季薪资帽的死钱 */
+  dead_cap: number
+  /** 解约后剩余薪资空间 */
+  cap_space: number
 }
 
 /** 竞价总览 */
@@ -3488,11 +3615,18 @@ export interface MetaInfo {
   meta_name: string
   description: string
   weights: MetaWeightsInfo
+  /** 赛季内第几次补丁（赛季初为 1） */
+  patch_number: number
+  /** 本次版本调整的原因 */
+  reason: string | null
 }
 
 /** Meta 历史记录 */
 export interface MetaHistoryEntry {
   season_id: number
+  patch_number: number
+  /** 补丁生效的赛季阶段 */
+  phase: string | null
   meta_type: string
   meta_name: string
   weight_top: number
@@ -3500,6 +3634,21 @@ export interface MetaHistoryEntry {
   weight_mid: number
   weight_adc: number
   weight_sup: number
+  reason: string | null
+}
+
+/** 赛区风格档案（各风格偏好 -1 ~ 1，0 为中立） */
+export interface RegionStyleProfile {
+  region_id: number
+  region_name: string
+  style: 'Skirmish' | 'Macro' | 'Teamfight' | 'LateGame' | null
+  aggressive: number
+  scaling: number
+  utility: number
+  splitpush: number
+  teamfight: number
+  /** 最近一次吸收其风格的赛区 */
+  adopted_from: string | null
 }
 
 /** Meta 类型信息 */
@@ -3520,6 +3669,11 @@ export async function getMetaHistory(saveId: string) {
   return invokeCommand<MetaHistoryEntry[]>('get_meta_history', { saveId })
 }
 
+/** 获取各赛区对版本的理解（风格档案） */
+export async function getRegionMetaStyles(saveId: string) {
+  return invokeCommand<RegionStyleProfile[]>('get_region_meta_styles', { saveId })
+}
+
 /** 获取全部 20 种 Meta 类型配置 */
 export async function getAllMetaTypes() {
   return invokeCommand<MetaTypeInfo[]>('get_all_meta_types')
@@ -3597,6 +3751,10 @@ export interface ChampionInfo {
   position: string
   archetype: string
   archetype_name: string
+  positions: string[]
+  tags: string[]
+  base_strength: number
+  role_strength: Record<string, number>
 }
 
 export interface ChampionStatInfo {
@@ -3624,6 +3782,10 @@ export interface CompStatInfo {
   comp_type: string
   pick_count: number
   win_count: number
+  blue_pick_count: number
+  blue_win_count: number
+  red_pick_count: number
+  red_win_count: number
 }
 
 export interface CompMatchupInfo {
@@ -3648,6 +3810,60 @@ export function getChampionList() {
   return invokeCommand<ChampionInfo[]>('get_champion_list')
 }
 
+export interface ChampionPatchChange {
+  champion_id: number
+  champion_name: string
+  delta: number
+  new_strength: number
+  reason: string
+}
+
+export interface ChampionPatch {
+  season_id: number
+  patch_number: number
+  phase: string
+  label: string
+  changes: ChampionPatchChange[]
+}
+
+export function getChampionPatchNotes(saveId: string, seasonId?: number) {
+  return invokeCommand<ChampionPatch[]>('get_champion_patch_notes', { saveId, seasonId: seasonId || null })
+}
+
+export interface ScoutedChampion {
+  champion_id: number
+  champion_name: string
+  games: number
+  wins: number
+}
+
+export interface PlayerDraftTendency {
+  player_id: number
+  position: string
+  games: number
+  most_played: ScoutedChampion[]
+  comfort_picks: number[]
+}
+
+export interface CompPreference {
+  comp_type: string
+  games: number
+  wins: number
+}
+
+export interface DraftScoutingReport {
+  team_id: number
+  games_analyzed: number
+  comp_preferences: CompPreference[]
+  players: PlayerDraftTendency[]
+  /** 所属赛区的风格档案 */
+  region_style: RegionStyleProfile
+}
+
+export function getDraftScoutingReport(saveId: string, teamId: number) {
+  return invokeCommand<DraftScoutingReport>('get_draft_scouting_report', { saveId, teamId })
+}
+
 export function getChampionStats(saveId: string, seasonId?: number) {
   return invokeCommand<ChampionStatInfo[]>('get_champion_stats', { saveId, seasonId: seasonId || null })
 }
@@ -45,6 +45,8 @@ export const EVENT_TYPE_NAMES: Record<string, string> = {
   LOAN: '租借',
   LOAN_RETURN: '租借归还',
   PLAYER_RELEASE: '解约放人',
+  POSITION_CHANGE: '转位置',
+  VETERAN_ROLE: '老将转任',
 }
 
 /** 事件等级配置 */